@@ -0,0 +1,195 @@
+//! Aggregation of dealer packages.
+
+use anyhow::Result;
+use group::{Group, GroupEncoding};
+use zeroize::Zeroize;
+
+use super::{DealerPackage, Error, HandoffKind, SecretShare, VerifiableSecretShare};
+
+/// Aggregates the shares of the given shareholder from the packages
+/// of multiple dealers.
+///
+/// Each share is decrypted using the secret key of the shareholder and
+/// verified against the verification matrix of its dealer. The shares
+/// and the matrices are then summed, so that the aggregated share can be
+/// verified against the summed matrix. As long as at least one dealer
+/// is honest, no party learns the shared secret, which allows a dealerless
+/// setup on top of the existing dealer.
+///
+/// The packages should be authenticated beforehand, e.g. by verifying
+/// the signatures of their dealers.
+///
+/// Fails if no packages are given, if the packages belong to different
+/// handoffs or have matrices of different dimensions, or if any share
+/// cannot be decrypted or verified.
+pub fn aggregate_packages<G>(
+    packages: &[DealerPackage<G>],
+    x: &G::Scalar,
+    sk: &G::Scalar,
+    context: &[u8],
+) -> Result<VerifiableSecretShare<G>>
+where
+    G: Group + GroupEncoding + Zeroize,
+    G::Scalar: Zeroize,
+{
+    let (first, rest) = packages.split_first().ok_or(Error::NotEnoughDealers)?;
+    let epoch = first.epoch();
+    let kind = first.kind();
+    let dimensions = first.verification_matrix().dimensions();
+
+    for package in rest {
+        if package.epoch() != epoch || package.kind() != kind {
+            return Err(Error::DealerPackageMismatch.into());
+        }
+        if package.verification_matrix().dimensions() != dimensions {
+            return Err(Error::VerificationMatrixDimensionMismatch.into());
+        }
+    }
+
+    let open = |index: usize, package: &DealerPackage<G>| -> Result<SecretShare<G::Scalar>> {
+        let share = package.decrypt_share(x, sk, context)?;
+        let vm = package.verification_matrix();
+
+        let verified = match kind {
+            HandoffKind::DealingPhase => vm.verify_x(x, share.polynomial()),
+            HandoffKind::CommitteeUnchanged => vm.verify_x(x, share.polynomial()),
+            HandoffKind::CommitteeChanged | HandoffKind::Emergency => {
+                vm.verify_y(x, share.polynomial())
+            }
+        };
+        if !verified {
+            return Err(Error::InvalidDealerShare { index }.into());
+        }
+
+        Ok(share)
+    };
+
+    let mut share = open(0, first)?;
+    let mut vm = first.verification_matrix().clone();
+
+    for (index, package) in rest.iter().enumerate() {
+        share += open(index + 1, package)?;
+        vm += package.verification_matrix();
+    }
+
+    Ok(VerifiableSecretShare::new(share, vm))
+}
+
+#[cfg(test)]
+mod tests {
+    use group::{ff::Field, Group as _};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::churp::{DealerPackage, HandoffKind};
+
+    use super::aggregate_packages;
+
+    type PrimeField = p384::Scalar;
+    type Group = p384::ProjectivePoint;
+    type Dealer = crate::churp::Dealer<Group>;
+
+    #[test]
+    fn test_aggregate_packages() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let keys: Vec<_> = (1..=3)
+            .map(|x| (PrimeField::from_u64(x), PrimeField::random(&mut rng)))
+            .collect();
+        let recipients: Vec<_> = keys
+            .iter()
+            .map(|(x, sk)| (*x, Group::generator() * sk))
+            .collect();
+
+        for kind in [
+            HandoffKind::DealingPhase,
+            HandoffKind::CommitteeUnchanged,
+            HandoffKind::CommitteeChanged,
+        ] {
+            let dealers: Vec<_> = (0..3).map(|_| Dealer::new(2, &mut rng).unwrap()).collect();
+            let packages: Vec<_> = dealers
+                .iter()
+                .map(|d| {
+                    d.make_package(7, kind, &recipients, b"context", &mut rng)
+                        .unwrap()
+                })
+                .collect();
+
+            let bp = dealers
+                .iter()
+                .map(|d| d.bivariate_polynomial().clone())
+                .reduce(|a, b| a + b)
+                .unwrap();
+            let dealer = Dealer::from(bp);
+
+            for (x, sk) in keys.iter() {
+                let share = aggregate_packages(&packages, x, sk, b"context").unwrap();
+                assert_eq!(share.verification_matrix(), dealer.verification_matrix());
+                assert_eq!(share.secret_share().x(), x);
+                assert!(
+                    share.secret_share().polynomial() == dealer.make_share(*x, kind).polynomial()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_aggregate_packages_failures() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let kind = HandoffKind::DealingPhase;
+        let (x, sk) = (PrimeField::from_u64(1), PrimeField::random(&mut rng));
+        let recipients = vec![(x, Group::generator() * sk)];
+
+        let dealers: Vec<_> = (0..2).map(|_| Dealer::new(2, &mut rng).unwrap()).collect();
+        let packages: Vec<_> = dealers
+            .iter()
+            .map(|d| {
+                d.make_package(7, kind, &recipients, b"context", &mut rng)
+                    .unwrap()
+            })
+            .collect();
+
+        // No packages.
+        let result = aggregate_packages::<Group>(&[], &x, &sk, b"context");
+        assert!(result.is_err());
+        assert_eq!(result.err().unwrap().to_string(), "not enough dealers");
+
+        // Different epochs.
+        let other = dealers[1]
+            .make_package(8, kind, &recipients, b"context", &mut rng)
+            .unwrap();
+        let result = aggregate_packages(&[packages[0].clone(), other], &x, &sk, b"context");
+        assert!(result.is_err());
+        assert_eq!(result.err().unwrap().to_string(), "dealer package mismatch");
+
+        // Different dimensions.
+        let other = Dealer::new(1, &mut rng)
+            .unwrap()
+            .make_package(7, kind, &recipients, b"context", &mut rng)
+            .unwrap();
+        let result = aggregate_packages(&[packages[0].clone(), other], &x, &sk, b"context");
+        assert!(result.is_err());
+        assert_eq!(
+            result.err().unwrap().to_string(),
+            "verification matrix dimension mismatch"
+        );
+
+        // Invalid share.
+        let invalid = DealerPackage::new(
+            7,
+            kind,
+            packages[1].verification_matrix().clone(),
+            packages[0].shares().to_vec(),
+        )
+        .unwrap();
+        let result = aggregate_packages(&[packages[0].clone(), invalid], &x, &sk, b"context");
+        assert!(result.is_err());
+        assert_eq!(
+            result.err().unwrap().to_string(),
+            "invalid share from dealer 1"
+        );
+
+        // Unknown shareholder.
+        let result = aggregate_packages(&packages, &PrimeField::from_u64(2), &sk, b"context");
+        assert!(result.is_err());
+        assert_eq!(result.err().unwrap().to_string(), "unknown shareholder");
+    }
+}
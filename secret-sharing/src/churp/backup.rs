@@ -0,0 +1,174 @@
+//! Encrypted backups of player states.
+
+use anyhow::Result;
+use group::{ff::Field, Group, GroupEncoding};
+use rand_core::RngCore;
+use zeroize::Zeroize;
+
+use super::{signing::signing_bytes, Ciphertext, Error, PlayerState};
+
+/// The version of the backup envelope.
+const SHARE_BACKUP_VERSION: u8 = 1;
+
+/// Domain separation tag for the authenticated data of backups.
+const SHARE_BACKUP_DST: &[u8] = b"CHURP-ShareBackup";
+
+/// A versioned envelope containing the state of a player, including its
+/// share, encrypted to the recovery key of the operator.
+///
+/// The state is encrypted using the same hybrid encryption as ciphertexts
+/// addressed to the committee, with the recovery key as the recipient.
+/// The version of the envelope and the given context, e.g. the identity
+/// of the node and the runtime, are authenticated together with
+/// the ciphertext, so that backups cannot be restored to another node
+/// or downgraded to another version.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShareBackup<G>
+where
+    G: Group,
+{
+    /// The version of the envelope.
+    version: u8,
+    /// The encrypted state of the player.
+    ciphertext: Ciphertext<G>,
+}
+
+impl<G> ShareBackup<G>
+where
+    G: Group + GroupEncoding,
+    G::Scalar: Zeroize,
+{
+    /// Encrypts the given state of a player to the given recovery key.
+    pub fn seal(
+        state: &PlayerState<G>,
+        recovery_key: &G,
+        context: &[u8],
+        rng: &mut impl RngCore,
+    ) -> Result<Self> {
+        let version = SHARE_BACKUP_VERSION;
+        let aad = Self::authenticated_data(version, context);
+        let bytes = state.to_bytes();
+        let ciphertext = Ciphertext::encrypt(bytes.expose(), recovery_key, &aad, rng)?;
+
+        Ok(Self {
+            version,
+            ciphertext,
+        })
+    }
+
+    /// Decrypts the state of the player using the given secret recovery key.
+    ///
+    /// Fails if the version is not supported, if the backup was encrypted
+    /// to another key or in another context, or if it was tampered with.
+    pub fn open(&self, recovery_sk: &G::Scalar, context: &[u8]) -> Result<PlayerState<G>> {
+        if self.version != SHARE_BACKUP_VERSION {
+            return Err(Error::UnsupportedBackupVersion {
+                version: self.version,
+            }
+            .into());
+        }
+        if recovery_sk.is_zero().into() {
+            return Err(Error::DecryptionFailed.into());
+        }
+
+        let ephemeral = self.ciphertext.ephemeral_key();
+        if ephemeral.is_identity().into() {
+            return Err(Error::DecryptionFailed.into());
+        }
+
+        let pk = G::generator() * recovery_sk;
+        let shared = *ephemeral * recovery_sk;
+        let aad = Self::authenticated_data(self.version, context);
+        let mut bytes = self.ciphertext.decrypt(&shared, &pk, &aad)?;
+        let state = PlayerState::from_bytes(&bytes);
+        bytes.zeroize();
+
+        state
+    }
+
+    /// Returns the version of the envelope.
+    pub fn version(&self) -> u8 {
+        self.version
+    }
+
+    /// Returns the byte representation of the backup.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![self.version];
+        bytes.extend_from_slice(&self.ciphertext.to_bytes());
+        bytes
+    }
+
+    /// Attempts to create a backup from its byte representation.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let (version, ciphertext) = bytes.split_first().ok_or(Error::InvalidBackup)?;
+        let ciphertext = Ciphertext::from_bytes(ciphertext).ok_or(Error::InvalidBackup)?;
+
+        Ok(Self {
+            version: *version,
+            ciphertext,
+        })
+    }
+
+    /// Returns the data authenticated together with the ciphertext.
+    fn authenticated_data(version: u8, context: &[u8]) -> Vec<u8> {
+        signing_bytes(SHARE_BACKUP_DST, context, &[version])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use group::{ff::Field, Group as _};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::churp::{HandoffKind, Player, PlayerState, VerifiableSecretShare};
+
+    use super::ShareBackup;
+
+    type PrimeField = p384::Scalar;
+    type Group = p384::ProjectivePoint;
+    type Dealer = crate::churp::Dealer<Group>;
+
+    #[test]
+    fn test_share_backup() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let kind = HandoffKind::DealingPhase;
+        let dealer = Dealer::new(2, &mut rng).unwrap();
+        let vm = dealer.verification_matrix().clone();
+        let player = Player::new_with_epoch(2, kind, 4);
+        let x = PrimeField::from_u64(1);
+        let share = VerifiableSecretShare::new(dealer.make_share(x, kind), vm);
+        let state = PlayerState::new(&player, Some(share.into()), vec![]);
+
+        let sk = PrimeField::random(&mut rng);
+        let pk = Group::generator() * sk;
+
+        let backup = ShareBackup::seal(&state, &pk, b"node", &mut rng).unwrap();
+        let backup = ShareBackup::<Group>::from_bytes(&backup.to_bytes()).unwrap();
+        let restored = backup.open(&sk, b"node").unwrap();
+        assert_eq!(restored.epoch(), 4);
+        assert_eq!(restored.to_bytes().expose(), state.to_bytes().expose());
+
+        // Another key or context.
+        let result = backup.open(&PrimeField::ONE, b"node");
+        assert!(result.is_err());
+        assert_eq!(result.err().unwrap().to_string(), "decryption failed");
+        let result = backup.open(&sk, b"other node");
+        assert!(result.is_err());
+
+        // Downgraded version.
+        let mut bytes = backup.to_bytes();
+        bytes[0] = 2;
+        let invalid = ShareBackup::<Group>::from_bytes(&bytes).unwrap();
+        let result = invalid.open(&sk, b"node");
+        assert!(result.is_err());
+        assert_eq!(
+            result.err().unwrap().to_string(),
+            "unsupported backup version 2"
+        );
+
+        // Malformed backup.
+        let result = ShareBackup::<Group>::from_bytes(&[1]);
+        assert!(result.is_err());
+        assert_eq!(result.err().unwrap().to_string(), "invalid backup");
+    }
+}
@@ -0,0 +1,506 @@
+//! Batch handoff of multiple secrets.
+
+use std::{
+    iter::zip,
+    sync::{Arc, Mutex},
+};
+
+use anyhow::Result;
+use group::Group;
+use zeroize::Zeroize;
+
+use crate::vss::VerificationMatrix;
+
+use super::{
+    CommitteeChanged, CommitteeUnchanged, DealingPhase, Error, Handoff, HandoffKind, Shareholder,
+    SwitchPoint, VerifiableSecretShare,
+};
+
+/// A handoff of multiple independent secrets, e.g. a master secret together
+/// with ephemeral secrets, executed by the same committee.
+///
+/// All secrets share the handoff kind, the threshold and the encoded
+/// identities of the shareholders, so their handoffs advance in lockstep.
+/// Every message therefore carries one item per secret, ordered as
+/// the secrets, which reduces the number of network rounds from one
+/// handoff per secret to a single one.
+///
+/// Items of a message are added to the handoffs of the secrets
+/// independently, so a rejected item doesn't prevent the other items
+/// from being accepted, and the first error is returned. Handoffs which
+/// have already accepted an item from the sender, or have completed
+/// the phase, skip their items, so the sender can resend the message
+/// with the rejected items replaced.
+pub struct BatchHandoff<G>
+where
+    G: Group,
+    G::Scalar: Zeroize,
+{
+    /// The handoffs of the secrets.
+    handoffs: Vec<Box<dyn Handoff<G>>>,
+
+    /// The phases completed by the handoffs of the secrets.
+    completed: Mutex<Vec<Vec<BatchPhase>>>,
+}
+
+/// A phase of the handoffs driven by a batch.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum BatchPhase {
+    /// Share reduction accumulating switch points.
+    ShareReductionSwitchPoints,
+    /// Full share distribution accumulating switch points.
+    FullShareDistributionSwitchPoints,
+    /// Merging of bivariate shares.
+    BivariateShares,
+}
+
+impl<G> BatchHandoff<G>
+where
+    G: Group,
+    G::Scalar: Zeroize,
+{
+    /// Creates a new batch of independent handoffs of the given kind,
+    /// one per secret.
+    ///
+    /// The handoffs share no state, they are only driven in lockstep
+    /// by the messages of the batch.
+    pub fn new(
+        secrets: usize,
+        threshold: u8,
+        kind: HandoffKind,
+        me: G::Scalar,
+        shareholders: Vec<G::Scalar>,
+    ) -> Result<Self> {
+        if secrets == 0 {
+            return Err(Error::EmptyBatch.into());
+        }
+
+        let mut handoffs: Vec<Box<dyn Handoff<G>>> = Vec::with_capacity(secrets);
+        for _ in 0..secrets {
+            let handoff: Box<dyn Handoff<G>> = match kind {
+                HandoffKind::DealingPhase => {
+                    Box::new(DealingPhase::new(threshold, me, shareholders.clone())?)
+                }
+                HandoffKind::CommitteeUnchanged => Box::new(CommitteeUnchanged::new(
+                    threshold,
+                    me,
+                    shareholders.clone(),
+                )?),
+                HandoffKind::CommitteeChanged | HandoffKind::Emergency => {
+                    Box::new(CommitteeChanged::new(threshold, me, shareholders.clone())?)
+                }
+            };
+            handoffs.push(handoff);
+        }
+
+        let completed = Mutex::new(vec![Vec::new(); secrets]);
+
+        Ok(Self {
+            handoffs,
+            completed,
+        })
+    }
+
+    /// Returns the number of secrets.
+    pub fn len(&self) -> usize {
+        self.handoffs.len()
+    }
+
+    /// Returns true if the batch is empty, which is never the case.
+    pub fn is_empty(&self) -> bool {
+        self.handoffs.is_empty()
+    }
+
+    /// Returns the handoff of the secret with the given index.
+    pub fn handoff(&self, index: usize) -> Option<&dyn Handoff<G>> {
+        self.handoffs.get(index).map(|handoff| handoff.as_ref())
+    }
+
+    /// Checks if the handoffs need the verification matrices from
+    /// the previous handoffs.
+    pub fn needs_verification_matrices(&self) -> Result<bool> {
+        self.needs(|handoff| handoff.needs_verification_matrix())
+    }
+
+    /// Sets the verification matrices from the previous handoffs.
+    ///
+    /// Handoffs which already have the verification matrix are skipped.
+    pub fn set_verification_matrices(&self, vms: Vec<VerificationMatrix<G>>) -> Result<()> {
+        self.verify_size(vms.len())?;
+        for (handoff, vm) in zip(self.handoffs.iter(), vms) {
+            if handoff.needs_verification_matrix()? {
+                handoff.set_verification_matrix(vm)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks if the handoffs need the shareholders from the previous
+    /// handoffs.
+    pub fn needs_shareholders(&self) -> Result<bool> {
+        self.needs(|handoff| handoff.needs_shareholder())
+    }
+
+    /// Sets the shareholders from the previous handoffs.
+    ///
+    /// Handoffs which already have the shareholder are skipped.
+    pub fn set_shareholders(&self, shareholders: Vec<Arc<Shareholder<G>>>) -> Result<()> {
+        self.verify_size(shareholders.len())?;
+        for (handoff, shareholder) in zip(self.handoffs.iter(), shareholders) {
+            if handoff.needs_shareholder()? {
+                handoff.set_shareholder(shareholder)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks if share reduction needs switch points from the given
+    /// shareholder.
+    pub fn needs_share_reduction_switch_points(&self, x: &G::Scalar) -> Result<bool> {
+        self.needs(|handoff| handoff.needs_share_reduction_switch_point(x))
+    }
+
+    /// Adds the switch points of the given shareholder to share reduction.
+    ///
+    /// Returns true if share reduction completed for all secrets.
+    pub fn add_share_reduction_switch_points(
+        &self,
+        x: &G::Scalar,
+        bijs: Vec<G::Scalar>,
+    ) -> Result<bool> {
+        self.verify_size(bijs.len())?;
+        self.add(
+            BatchPhase::ShareReductionSwitchPoints,
+            bijs,
+            |handoff| handoff.needs_share_reduction_switch_point(x),
+            |handoff, bij| handoff.add_share_reduction_switch_point(SwitchPoint::new(*x, bij)),
+        )
+    }
+
+    /// Checks if full share distribution needs switch points from the given
+    /// shareholder.
+    pub fn needs_full_share_distribution_switch_points(&self, x: &G::Scalar) -> Result<bool> {
+        self.needs(|handoff| handoff.needs_full_share_distribution_switch_point(x))
+    }
+
+    /// Adds the switch points of the given shareholder to full share
+    /// distribution.
+    ///
+    /// Returns true if full share distribution completed for all secrets.
+    pub fn add_full_share_distribution_switch_points(
+        &self,
+        x: &G::Scalar,
+        bijs: Vec<G::Scalar>,
+    ) -> Result<bool> {
+        self.verify_size(bijs.len())?;
+        self.add(
+            BatchPhase::FullShareDistributionSwitchPoints,
+            bijs,
+            |handoff| handoff.needs_full_share_distribution_switch_point(x),
+            |handoff, bij| {
+                handoff.add_full_share_distribution_switch_point(SwitchPoint::new(*x, bij))
+            },
+        )
+    }
+
+    /// Checks if bivariate shares are needed from the given shareholder.
+    pub fn needs_bivariate_shares(&self, x: &G::Scalar) -> Result<bool> {
+        self.needs(|handoff| handoff.needs_bivariate_share(x))
+    }
+
+    /// Adds the bivariate shares of the given shareholder.
+    ///
+    /// Returns true if proactivization completed for all secrets.
+    pub fn add_bivariate_shares(
+        &self,
+        x: &G::Scalar,
+        verifiable_shares: Vec<VerifiableSecretShare<G>>,
+    ) -> Result<bool> {
+        self.verify_size(verifiable_shares.len())?;
+        self.add(
+            BatchPhase::BivariateShares,
+            verifiable_shares,
+            |handoff| handoff.needs_bivariate_share(x),
+            |handoff, verifiable_share| handoff.add_bivariate_share(x, verifiable_share),
+        )
+    }
+
+    /// Returns the shareholders resulting from share reduction.
+    pub fn get_reduced_shareholders(&self) -> Result<Vec<Arc<Shareholder<G>>>> {
+        self.handoffs
+            .iter()
+            .map(|handoff| handoff.get_reduced_shareholder())
+            .collect()
+    }
+
+    /// Returns the shareholders resulting from full share distribution.
+    pub fn get_full_shareholders(&self) -> Result<Vec<Arc<Shareholder<G>>>> {
+        self.handoffs
+            .iter()
+            .map(|handoff| handoff.get_full_shareholder())
+            .collect()
+    }
+
+    /// Returns true if any of the handoffs needs the input.
+    fn needs<F>(&self, needs: F) -> Result<bool>
+    where
+        F: Fn(&dyn Handoff<G>) -> Result<bool>,
+    {
+        let mut needed = false;
+        for handoff in self.handoffs.iter() {
+            needed |= needs(handoff.as_ref())?;
+        }
+        Ok(needed)
+    }
+
+    /// Adds the items to the handoffs of the secrets which still need them
+    /// and returns true if all handoffs completed the given phase.
+    ///
+    /// Every item is added regardless of whether the others are rejected,
+    /// and the first error is returned.
+    fn add<T, N, F>(&self, phase: BatchPhase, items: Vec<T>, needs: N, add: F) -> Result<bool>
+    where
+        N: Fn(&dyn Handoff<G>) -> Result<bool>,
+        F: Fn(&dyn Handoff<G>, T) -> Result<bool>,
+    {
+        let mut completed = self.completed.lock().unwrap();
+        let mut result = Ok(());
+
+        for ((handoff, item), completed) in zip(zip(&self.handoffs, items), completed.iter_mut()) {
+            if completed.contains(&phase) {
+                continue;
+            }
+            let added = match needs(handoff.as_ref()) {
+                Ok(false) => continue,
+                Ok(true) => add(handoff.as_ref(), item),
+                Err(err) => Err(err),
+            };
+            match added {
+                Ok(true) => completed.push(phase),
+                Ok(false) => (),
+                Err(err) => {
+                    if result.is_ok() {
+                        result = Err(err);
+                    }
+                }
+            }
+        }
+        result?;
+
+        let done = completed.iter().all(|completed| completed.contains(&phase));
+        Ok(done)
+    }
+
+    /// Verifies that the message carries one item per secret.
+    fn verify_size(&self, actual: usize) -> Result<()> {
+        let expected = self.handoffs.len();
+        if actual != expected {
+            return Err(Error::BatchSizeMismatch { expected, actual }.into());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{iter::zip, sync::Arc};
+
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::{
+        churp::{self, HandoffKind, VerifiableSecretShare},
+        suites::{self, p384},
+    };
+
+    type Suite = p384::Sha3_384;
+    type Group = <Suite as suites::Suite>::Group;
+    type PrimeField = <Suite as suites::Suite>::PrimeField;
+    type Shareholder = churp::Shareholder<Group>;
+    type Dealer = churp::Dealer<Group>;
+    type BatchHandoff = churp::BatchHandoff<Group>;
+
+    fn prepare_shareholders(ids: &[u64]) -> Vec<PrimeField> {
+        ids.iter().map(|&id| id.into()).collect()
+    }
+
+    #[test]
+    fn test_batch_handoff() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let threshold = 2;
+        let secrets = 2;
+        let committee = prepare_shareholders(&[1, 2, 3, 4]);
+
+        // Every dealer deals one bivariate polynomial per secret.
+        let run = |kind: HandoffKind,
+                   dealers: &[Vec<Dealer>],
+                   shareholders: &[Vec<Arc<Shareholder>>]| {
+            committee
+                .iter()
+                .enumerate()
+                .map(|(i, alice)| {
+                    let batch =
+                        BatchHandoff::new(secrets, threshold, kind, *alice, committee.clone())
+                            .unwrap();
+                    assert_eq!(batch.len(), secrets);
+
+                    if kind == HandoffKind::CommitteeUnchanged {
+                        assert!(batch.needs_shareholders().unwrap());
+                        batch.set_shareholders(shareholders[i].clone()).unwrap();
+                    }
+
+                    for (j, (bob, dealers)) in zip(committee.iter(), dealers.iter()).enumerate() {
+                        let verifiable_shares = dealers
+                            .iter()
+                            .map(|dealer| {
+                                let share = dealer.make_share(*alice, kind);
+                                let vm = dealer.verification_matrix().clone();
+                                VerifiableSecretShare::new(share, vm)
+                            })
+                            .collect();
+
+                        assert!(batch.needs_bivariate_shares(bob).unwrap());
+                        let done = batch.add_bivariate_shares(bob, verifiable_shares).unwrap();
+                        assert_eq!(done, j + 1 == committee.len());
+                    }
+
+                    batch.get_full_shareholders().unwrap()
+                })
+                .collect::<Vec<_>>()
+        };
+
+        // Dealing phase.
+        let kind = HandoffKind::DealingPhase;
+        let dealers: Vec<Vec<_>> = committee
+            .iter()
+            .map(|_| {
+                (0..secrets)
+                    .map(|_| Dealer::new(threshold, &mut rng).unwrap())
+                    .collect()
+            })
+            .collect();
+        let shareholders = run(kind, &dealers, &[]);
+
+        let secret = |shareholders: &[Vec<Arc<Shareholder>>], k: usize| {
+            let vm = shareholders[0][k].verifiable_share().verification_matrix();
+            *vm.element(0, 0).unwrap()
+        };
+        let expected: Vec<_> = (0..secrets).map(|k| secret(&shareholders, k)).collect();
+        assert!(expected[0] != expected[1]);
+
+        for shareholders in shareholders.iter() {
+            for (k, shareholder) in shareholders.iter().enumerate() {
+                let share = shareholder.verifiable_share();
+                share.verify(threshold, false, true).unwrap();
+                assert_eq!(
+                    *share.verification_matrix().element(0, 0).unwrap(),
+                    expected[k]
+                );
+            }
+        }
+
+        // Proactivization preserves all secrets.
+        let kind = HandoffKind::CommitteeUnchanged;
+        let dealers: Vec<Vec<_>> = committee
+            .iter()
+            .map(|_| {
+                (0..secrets)
+                    .map(|_| Dealer::new_proactive(threshold, &mut rng).unwrap())
+                    .collect()
+            })
+            .collect();
+        let shareholders = run(kind, &dealers, &shareholders);
+
+        for (k, expected) in expected.iter().enumerate() {
+            assert_eq!(secret(&shareholders, k), *expected);
+        }
+
+        // Messages must carry one item per secret.
+        let batch =
+            BatchHandoff::new(secrets, threshold, kind, committee[0], committee.clone()).unwrap();
+        let result = batch.set_shareholders(shareholders[0][..1].to_vec());
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "batch size mismatch: expected 2, got 1"
+        );
+
+        // Empty batches are not allowed.
+        let result = BatchHandoff::new(0, threshold, kind, committee[0], committee.clone());
+        assert!(result.is_err());
+        assert_eq!(result.err().unwrap().to_string(), "empty batch");
+    }
+
+    #[test]
+    fn test_batch_handoff_invalid_item() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let threshold = 2;
+        let secrets = 2;
+        let kind = HandoffKind::DealingPhase;
+        let committee = prepare_shareholders(&[1, 2, 3, 4]);
+        let alice = committee[0];
+
+        let dealers: Vec<Vec<_>> = committee
+            .iter()
+            .map(|_| {
+                (0..secrets)
+                    .map(|_| Dealer::new(threshold, &mut rng).unwrap())
+                    .collect()
+            })
+            .collect();
+        let make_share = |dealer: &Dealer, x: PrimeField| {
+            let share = dealer.make_share(x, kind);
+            let vm = dealer.verification_matrix().clone();
+            VerifiableSecretShare::new(share, vm)
+        };
+
+        let batch = BatchHandoff::new(secrets, threshold, kind, alice, committee.clone()).unwrap();
+
+        // A bad item is rejected without affecting the other items.
+        let bob = &committee[0];
+        let verifiable_shares = vec![
+            make_share(&dealers[0][0], alice),
+            make_share(&dealers[0][1], committee[1]),
+        ];
+        let result = batch.add_bivariate_shares(bob, verifiable_shares);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "shareholder identity mismatch"
+        );
+        assert!(!batch
+            .handoff(0)
+            .unwrap()
+            .needs_bivariate_share(bob)
+            .unwrap());
+        assert!(batch
+            .handoff(1)
+            .unwrap()
+            .needs_bivariate_share(bob)
+            .unwrap());
+        assert!(batch.needs_bivariate_shares(bob).unwrap());
+
+        // Resending the message only adds the previously rejected items.
+        for (j, (bob, dealers)) in zip(committee.iter(), dealers.iter()).enumerate() {
+            let verifiable_shares = dealers
+                .iter()
+                .map(|dealer| make_share(dealer, alice))
+                .collect();
+            let done = batch.add_bivariate_shares(bob, verifiable_shares).unwrap();
+            assert_eq!(done, j + 1 == committee.len());
+        }
+
+        for (k, shareholder) in batch.get_full_shareholders().unwrap().iter().enumerate() {
+            let share = shareholder.verifiable_share();
+            share.verify(threshold, false, true).unwrap();
+
+            let expected = dealers
+                .iter()
+                .map(|dealers| *dealers[k].verification_matrix().element(0, 0).unwrap())
+                .sum::<Group>();
+            assert_eq!(
+                *share.verification_matrix().element(0, 0).unwrap(),
+                expected
+            );
+        }
+    }
+}
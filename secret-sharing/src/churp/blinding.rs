@@ -0,0 +1,99 @@
+//! Blinded evaluation of shares.
+
+use anyhow::Result;
+use group::{ff::Field, Group};
+use rand_core::RngCore;
+use zeroize::Zeroize;
+
+use crate::suites::GroupDigest;
+
+/// The blinding factor of a client requesting a blinded evaluation
+/// of the shared secret, e.g. to derive a per-client key without
+/// revealing which key was requested.
+///
+/// The client hashes the key ID to an element of the group, blinds it
+/// with a random factor and sends the blinded element to the players.
+/// Each player evaluates the blinded element using its share, and the
+/// client combines the blinded key shares and removes the blinding factor
+/// to obtain the key.
+pub struct Blinding<G>
+where
+    G: Group,
+    G::Scalar: Zeroize,
+{
+    /// The random non-zero blinding factor.
+    r: G::Scalar,
+}
+
+impl<G> Blinding<G>
+where
+    G: Group,
+    G::Scalar: Zeroize,
+{
+    /// Hashes the given key ID to an element of the group and blinds it
+    /// with a random non-zero factor.
+    ///
+    /// Returns the blinding factor, which must be kept secret, together
+    /// with the blinded element, which should be sent to the players.
+    ///
+    /// This function is not constant time because it uses rejection sampling.
+    pub fn blind<H>(key_id: &[u8], dst: &[u8], rng: &mut impl RngCore) -> Result<(Self, G)>
+    where
+        H: GroupDigest<Output = G>,
+    {
+        let hash = H::hash_to_group(key_id, dst)?;
+
+        let mut r = G::Scalar::random(&mut *rng);
+        while r.is_zero().into() {
+            r = G::Scalar::random(&mut *rng);
+        }
+
+        Ok((Self { r }, hash * r))
+    }
+
+    /// Removes the blinding factor from the blinded key, recovered from
+    /// the blinded key shares of the players.
+    pub fn unblind(&self, blinded_key: &G) -> G {
+        let mut r_inv = self.r.invert().expect("blinding factor should be non-zero");
+        let key = *blinded_key * r_inv;
+        r_inv.zeroize();
+
+        key
+    }
+}
+
+impl<G> Drop for Blinding<G>
+where
+    G: Group,
+    G::Scalar: Zeroize,
+{
+    fn drop(&mut self) {
+        self.r.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::suites::{p384::Sha3_384, GroupDigest};
+
+    use super::Blinding;
+
+    type Group = p384::ProjectivePoint;
+
+    #[test]
+    fn test_blinding() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let hash = Sha3_384::hash_to_group(b"key id", b"dst").unwrap();
+
+        let (blinding, blinded) =
+            Blinding::<Group>::blind::<Sha3_384>(b"key id", b"dst", &mut rng).unwrap();
+        assert_ne!(blinded, hash);
+        assert_eq!(blinding.unblind(&blinded), hash);
+
+        // Fresh blinding factors.
+        let (_, other) = Blinding::<Group>::blind::<Sha3_384>(b"key id", b"dst", &mut rng).unwrap();
+        assert_ne!(blinded, other);
+    }
+}
@@ -0,0 +1,160 @@
+//! Cache of verification matrices of previous epochs.
+
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, Mutex},
+};
+
+use anyhow::Result;
+use group::Group;
+
+use crate::vss::VerificationMatrix;
+
+use super::Error;
+
+/// The key of a cached matrix, i.e. the scheme ID and the epoch.
+type CacheKey = (Vec<u8>, u64);
+
+/// A bounded cache mapping scheme instances and epochs to verification
+/// matrices.
+///
+/// Keeping the matrices of a few previous epochs allows points and shares
+/// that arrive after the local matrix was replaced, e.g. late responses
+/// of a previous handoff, to still be verified and attributed. When the cache
+/// is full, the matrix of the oldest epoch is evicted first.
+pub struct MatrixCache<G>
+where
+    G: Group,
+{
+    /// The maximum number of cached matrices.
+    capacity: usize,
+    /// The cached matrices, keyed by scheme ID and epoch.
+    matrices: Mutex<BTreeMap<CacheKey, Arc<VerificationMatrix<G>>>>,
+}
+
+impl<G> MatrixCache<G>
+where
+    G: Group,
+{
+    /// Creates a new empty cache holding at most the given number
+    /// of matrices.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            matrices: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    /// Returns the maximum number of cached matrices.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of cached matrices.
+    pub fn len(&self) -> usize {
+        self.matrices.lock().unwrap().len()
+    }
+
+    /// Returns true if the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.matrices.lock().unwrap().is_empty()
+    }
+
+    /// Caches the verification matrix of the given scheme instance
+    /// and epoch, replacing any previous one, and evicts the matrices
+    /// of the oldest epochs if the cache is full.
+    pub fn insert(&self, scheme_id: &[u8], epoch: u64, vm: VerificationMatrix<G>) {
+        let mut matrices = self.matrices.lock().unwrap();
+        matrices.insert((scheme_id.to_vec(), epoch), Arc::new(vm));
+
+        while matrices.len() > self.capacity {
+            let oldest = matrices
+                .keys()
+                .min_by_key(|(scheme_id, epoch)| (*epoch, scheme_id.clone()))
+                .cloned()
+                .unwrap();
+            matrices.remove(&oldest);
+        }
+    }
+
+    /// Returns the verification matrix of the given scheme instance
+    /// and epoch, if cached.
+    pub fn get(&self, scheme_id: &[u8], epoch: u64) -> Option<Arc<VerificationMatrix<G>>> {
+        self.matrices
+            .lock()
+            .unwrap()
+            .get(&(scheme_id.to_vec(), epoch))
+            .cloned()
+    }
+
+    /// Returns the verification matrix of the given scheme instance
+    /// and epoch, failing if it is not cached.
+    pub fn require(&self, scheme_id: &[u8], epoch: u64) -> Result<Arc<VerificationMatrix<G>>> {
+        self.get(scheme_id, epoch)
+            .ok_or_else(|| Error::UnknownEpoch { epoch }.into())
+    }
+
+    /// Removes the verification matrices of the given scheme instance
+    /// older than the given epoch, e.g. once late messages of those epochs
+    /// are no longer accepted.
+    pub fn prune(&self, scheme_id: &[u8], epoch: u64) {
+        self.matrices
+            .lock()
+            .unwrap()
+            .retain(|(id, e), _| id != scheme_id || *e >= epoch);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::OsRng;
+
+    use crate::{
+        churp,
+        suites::{self, p384},
+    };
+
+    use super::MatrixCache;
+
+    // Types used in tests.
+    type Suite = p384::Sha3_384;
+    type Group = <Suite as suites::Suite>::Group;
+    type Dealer = churp::Dealer<Group>;
+
+    #[test]
+    fn test_matrix_cache() {
+        let cache = MatrixCache::<Group>::new(3);
+        let vms: Vec<_> = (0..4)
+            .map(|_| {
+                Dealer::new(2, &mut OsRng)
+                    .unwrap()
+                    .verification_matrix()
+                    .clone()
+            })
+            .collect();
+
+        cache.insert(b"a", 1, vms[0].clone());
+        cache.insert(b"b", 1, vms[1].clone());
+        cache.insert(b"a", 2, vms[2].clone());
+        assert_eq!(cache.len(), 3);
+        assert!(*cache.get(b"a", 1).unwrap() == vms[0]);
+        assert!(*cache.require(b"b", 1).unwrap() == vms[1]);
+        assert!(cache.get(b"b", 2).is_none());
+
+        // The oldest epoch is evicted first.
+        cache.insert(b"b", 2, vms[3].clone());
+        assert_eq!(cache.len(), 3);
+        assert!(cache.get(b"a", 1).is_none());
+        assert!(cache.get(b"b", 1).is_some());
+
+        let result = cache.require(b"a", 1);
+        assert!(result.is_err());
+        assert_eq!(result.err().unwrap().to_string(), "unknown epoch 1");
+
+        // Pruning only affects the given scheme.
+        cache.prune(b"b", 2);
+        assert!(cache.get(b"b", 1).is_none());
+        assert!(cache.get(b"a", 2).is_some());
+        assert_eq!(cache.len(), 2);
+    }
+}
@@ -0,0 +1,189 @@
+//! Complaints against dealers of invalid bivariate shares.
+
+use anyhow::Result;
+use group::Group;
+use zeroize::Zeroize;
+
+use crate::vss::VerificationMatrix;
+
+use super::{Error, SecretShare, VerifiableSecretShare};
+
+/// The outcome of an accusation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    /// The dealer revealed a valid share, so the accusation is dismissed
+    /// and the accuser adopts the revealed share.
+    Dismissed,
+    /// The dealer failed to reveal a valid share and is disqualified,
+    /// i.e. its bivariate shares are excluded from the handoff.
+    Disqualified,
+}
+
+/// An accusation published by the recipient of an invalid bivariate share.
+///
+/// The evidence is the verification matrix the dealer committed to, against
+/// which the share the dealer is required to reveal must verify.
+pub struct Accusation<G>
+where
+    G: Group,
+{
+    /// The identity of the accuser.
+    accuser: G::Scalar,
+    /// The identity of the accused dealer.
+    dealer: G::Scalar,
+    /// The verification matrix of the accused dealer.
+    vm: VerificationMatrix<G>,
+}
+
+impl<G> Accusation<G>
+where
+    G: Group,
+    G::Scalar: Zeroize,
+{
+    /// Creates a new accusation of the given dealer.
+    pub fn new(accuser: G::Scalar, dealer: G::Scalar, vm: VerificationMatrix<G>) -> Self {
+        Self {
+            accuser,
+            dealer,
+            vm,
+        }
+    }
+
+    /// Returns the identity of the accuser.
+    pub fn accuser(&self) -> &G::Scalar {
+        &self.accuser
+    }
+
+    /// Returns the identity of the accused dealer.
+    pub fn dealer(&self) -> &G::Scalar {
+        &self.dealer
+    }
+
+    /// Returns the verification matrix of the accused dealer.
+    pub fn verification_matrix(&self) -> &VerificationMatrix<G> {
+        &self.vm
+    }
+
+    /// Resolves the accusation given the share publicly revealed by
+    /// the accused dealer, if any.
+    ///
+    /// The dealer is disqualified if it didn't reveal the disputed share
+    /// or if the revealed share doesn't verify against its matrix.
+    /// Fails if the revelation doesn't belong to the accusation.
+    pub fn resolve(
+        &self,
+        revelation: Option<&Revelation<G>>,
+        threshold: u8,
+        zero_hole: bool,
+        full_share: bool,
+    ) -> Result<Verdict> {
+        let revelation = match revelation {
+            Some(revelation) => revelation,
+            None => return Ok(Verdict::Disqualified),
+        };
+
+        if revelation.dealer != self.dealer || revelation.share.x() != &self.accuser {
+            return Err(Error::RevelationMismatch.into());
+        }
+
+        match revelation
+            .verifiable_share(&self.vm)
+            .verify(threshold, zero_hole, full_share)
+        {
+            Ok(()) => Ok(Verdict::Dismissed),
+            Err(_) => Ok(Verdict::Disqualified),
+        }
+    }
+}
+
+/// A disputed bivariate share publicly revealed by the accused dealer.
+pub struct Revelation<G>
+where
+    G: Group,
+    G::Scalar: Zeroize,
+{
+    /// The identity of the dealer.
+    dealer: G::Scalar,
+    /// The disputed share of the accuser.
+    share: SecretShare<G::Scalar>,
+}
+
+impl<G> Revelation<G>
+where
+    G: Group,
+    G::Scalar: Zeroize,
+{
+    /// Creates a new revelation of the share the given dealer dealt
+    /// to the accuser.
+    pub fn new(dealer: G::Scalar, share: SecretShare<G::Scalar>) -> Self {
+        Self { dealer, share }
+    }
+
+    /// Returns the identity of the dealer.
+    pub fn dealer(&self) -> &G::Scalar {
+        &self.dealer
+    }
+
+    /// Returns the revealed share.
+    pub fn share(&self) -> &SecretShare<G::Scalar> {
+        &self.share
+    }
+
+    /// Returns the revealed share together with the given matrix.
+    pub(crate) fn verifiable_share(&self, vm: &VerificationMatrix<G>) -> VerifiableSecretShare<G> {
+        let share = SecretShare::new(*self.share.x(), self.share.polynomial().clone());
+        VerifiableSecretShare::new(share, vm.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::OsRng;
+
+    use crate::{
+        churp::{self, HandoffKind, SecretShare},
+        suites::{self, p384},
+    };
+
+    use super::{Accusation, Revelation, Verdict};
+
+    // Types used in tests.
+    type Suite = p384::Sha3_384;
+    type PrimeField = <Suite as suites::Suite>::PrimeField;
+    type Group = <Suite as suites::Suite>::Group;
+    type Dealer = churp::Dealer<Group>;
+
+    #[test]
+    fn test_resolve() {
+        let threshold = 2;
+        let kind = HandoffKind::CommitteeUnchanged;
+        let dealer = Dealer::new_proactive(threshold, &mut OsRng).unwrap();
+        let vm = dealer.verification_matrix().clone();
+        let accuser = PrimeField::from_u64(1);
+        let accused = PrimeField::from_u64(2);
+        let accusation = Accusation::new(accuser, accused, vm);
+        let resolve = |revelation: Option<&Revelation<Group>>| {
+            accusation.resolve(revelation, threshold, true, true)
+        };
+
+        // Valid revelation.
+        let revelation = Revelation::new(accused, dealer.make_share(accuser, kind));
+        assert_eq!(resolve(Some(&revelation)).unwrap(), Verdict::Dismissed);
+
+        // Missing revelation.
+        assert_eq!(resolve(None).unwrap(), Verdict::Disqualified);
+
+        // Invalid revelation.
+        let mut p = dealer.make_share(accuser, kind).polynomial().clone();
+        p.set_coefficient(0, PrimeField::ONE);
+        let revelation = Revelation::new(accused, SecretShare::new(accuser, p));
+        assert_eq!(resolve(Some(&revelation)).unwrap(), Verdict::Disqualified);
+
+        // Revelation of another share.
+        let other = PrimeField::from_u64(3);
+        let revelation = Revelation::new(accused, dealer.make_share(other, kind));
+        let result = resolve(Some(&revelation));
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().to_string(), "revelation mismatch");
+    }
+}
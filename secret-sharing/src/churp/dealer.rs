@@ -1,37 +1,68 @@
 //! CHURP dealer.
 
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    convert::TryFrom,
+    sync::OnceLock,
+};
+
 use anyhow::Result;
-use group::{ff::Field, Group};
+use group::{
+    ff::{Field, PrimeField},
+    Group, GroupEncoding,
+};
+#[cfg(feature = "kzg")]
+use pairing::Engine;
 use rand_core::RngCore;
+use subtle::ConditionallySelectable;
 use zeroize::Zeroize;
 
-use crate::{poly::BivariatePolynomial, vss::VerificationMatrix};
+#[cfg(feature = "kzg")]
+use crate::vss::{KzgCommitment, KzgProof, KzgSetup};
+use crate::{
+    poly::{BivariatePolynomial, XofRng},
+    suites::{FieldDigest, FieldSuite},
+    vss::{
+        CommitmentScheme, HashCommitment, HashCommitmentOpenings, PedersenMatrix,
+        ShareVerificationFailure, VerificationMatrix, ZeroHoleProof,
+    },
+};
 
-use super::{Error, HandoffKind, SecretShare};
+use super::{
+    encode_shareholders, sign_verification_matrix, DealerPackage, DealerSigner, EncryptedShare,
+    Error, FullShare, HandoffKind, ReducedShare, SecretShare, Sensitive,
+};
 
 /// Dealer is responsible for generating a secret bivariate polynomial,
 /// computing a verification matrix, and deriving secret shares for other
 /// participants.
 ///
+/// The verification data is determined by the commitment scheme, which
+/// defaults to the verification matrix, i.e. the Feldman commitment scheme.
+/// Since computing the commitment is expensive, it is deferred until first
+/// used, so callers that only need the shares locally don't pay for it.
+///
 /// Shares must always be distributed over a secure channel and verified
-/// against the matrix. Recovering the secret bivariate polynomial requires
+/// against the commitment. Recovering the secret bivariate polynomial requires
 /// obtaining more than a threshold number of shares from distinct participants.
-pub struct Dealer<G>
+pub struct Dealer<G, C = VerificationMatrix<G>>
 where
     G: Group,
     G::Scalar: Zeroize,
+    C: CommitmentScheme<G>,
 {
     /// Secret bivariate polynomial.
     bp: BivariatePolynomial<G::Scalar>,
 
-    /// Verification matrix.
-    vm: VerificationMatrix<G>,
+    /// Commitment to the secret bivariate polynomial, computed on first use.
+    commitment: OnceLock<C>,
 }
 
-impl<G> Dealer<G>
+impl<G, C> Dealer<G, C>
 where
     G: Group,
     G::Scalar: Zeroize,
+    C: CommitmentScheme<G>,
 {
     /// Creates a new dealer of secret bivariate shares, which can be used
     /// to recover a randomly selected shared secret.
@@ -79,11 +110,158 @@ where
         Ok(bp.into())
     }
 
+    /// Creates a new dealer for a handoff of the given kind to a committee
+    /// of the given size.
+    ///
+    /// Unlike `new` and `new_proactive`, this validates that the handoff
+    /// can succeed with the given threshold and committee size, so that
+    /// impossible configurations are rejected before any shares are dealt.
+    /// A zero-hole bivariate polynomial is used if the handoff requires it.
+    ///
+    /// This function is not constant time because it uses rejection sampling.
+    pub fn new_for_committee(
+        threshold: u8,
+        committee_size: usize,
+        kind: HandoffKind,
+        rng: &mut impl RngCore,
+    ) -> Result<Self> {
+        Self::validate_committee(threshold, committee_size, kind)?;
+
+        match kind.require_zero_hole() {
+            true => Self::new_proactive(threshold, rng),
+            false => Self::new(threshold, rng),
+        }
+    }
+
+    /// Validates that a handoff of the given kind can succeed with the given
+    /// threshold and committee size.
+    ///
+    /// The dealing phase requires at least `t + 2` shareholders, so that
+    /// no single party can know the combined bivariate polynomial, handoffs
+    /// in which the committee doesn't change require at least `t + 1`
+    /// shareholders, and handoffs in which the committee changes require
+    /// at least `2t + 1` shareholders to complete the share reduction.
+    pub fn validate_committee(
+        threshold: u8,
+        committee_size: usize,
+        kind: HandoffKind,
+    ) -> Result<()> {
+        let t = threshold as usize;
+        let expected = match kind {
+            HandoffKind::DealingPhase => t + 2,
+            HandoffKind::CommitteeUnchanged => t + 1,
+            HandoffKind::CommitteeChanged | HandoffKind::Emergency => 2 * t + 1,
+        };
+
+        if committee_size < expected {
+            return Err(Error::CommitteeTooSmall {
+                expected,
+                actual: committee_size,
+            }
+            .into());
+        }
+
+        Ok(())
+    }
+
+    /// Creates a new dealer of secret bivariate shares using a random
+    /// bivariate polynomial `B(x, y)` of the given degrees, which can be
+    /// used to recover a randomly selected shared secret.
+    ///
+    /// Unlike `new`, which always uses `deg_y = 2 * deg_x` as required
+    /// by CHURP, this allows choosing the reconstruction threshold of full
+    /// shares (`deg_x`) and reduced shares (`deg_y`) independently.
+    /// As with `new`, the polynomials `B(x, y)`, `B(x, 0)`, and `B(0, y)`
+    /// are guaranteed to have non-zero leading terms.
+    ///
+    /// Fails if `deg_y` is smaller than `deg_x`, since reduced shares
+    /// could then reveal the secret with fewer shareholders than the full
+    /// shares, and dimension switching wouldn't be possible.
+    ///
+    /// This function is not constant time because it uses rejection sampling.
+    pub fn new_with_degrees(deg_x: u8, deg_y: u8, rng: &mut impl RngCore) -> Result<Self> {
+        Self::validate_degrees(deg_x, deg_y)?;
+        let bp = Self::generate_bivariate_polynomial_with_degrees(deg_x, deg_y, rng)?;
+        Ok(bp.into())
+    }
+
+    /// Creates a new dealer of secret proactive bivariate shares using
+    /// a random zero-hole bivariate polynomial `B(x, y)` of the given
+    /// degrees, which can be used to randomize a shared secret.
+    ///
+    /// See `new_with_degrees` for the validation of the degrees.
+    ///
+    /// This function is not constant time because it uses rejection sampling.
+    pub fn new_proactive_with_degrees(
+        deg_x: u8,
+        deg_y: u8,
+        rng: &mut impl RngCore,
+    ) -> Result<Self> {
+        Self::validate_degrees(deg_x, deg_y)?;
+        let mut bp = Self::generate_bivariate_polynomial_with_degrees(deg_x, deg_y, rng)?;
+        bp.to_zero_hole();
+        Ok(bp.into())
+    }
+
+    /// Creates a new dealer of secret bivariate shares from the given seed,
+    /// which can be used to recover a pseudo-randomly selected shared secret.
+    ///
+    /// The seed is expanded together with the threshold and the kind of
+    /// the dealer using the SHAKE256 extendable-output function and the given
+    /// domain separation tag, so the same inputs always result in the same
+    /// bivariate polynomial, verification matrix and shares. This allows
+    /// persisting only the seed and resuming an interrupted handoff after
+    /// a restart.
+    ///
+    /// The seed must be uniformly random and kept secret. Since the kind
+    /// is part of the input, this and the proactive dealer derived from
+    /// the same seed deal unrelated polynomials, so shares of both don't
+    /// reveal the difference `B(0, 0)`.
+    ///
+    /// This function is not constant time because it uses rejection sampling.
+    pub fn from_seed(threshold: u8, seed: &[u8; 32], dst: &[u8]) -> Result<Self> {
+        let mut rng = Self::seeded_rng(threshold, false, seed, dst);
+        let bp = Self::generate_bivariate_polynomial(threshold, &mut rng)?;
+        Ok(bp.into())
+    }
+
+    /// Creates a new dealer of secret proactive bivariate shares from
+    /// the given seed, which can be used to randomize a shared secret.
+    ///
+    /// See `from_seed` for details on the derivation.
+    ///
+    /// This function is not constant time because it uses rejection sampling.
+    pub fn from_seed_proactive(threshold: u8, seed: &[u8; 32], dst: &[u8]) -> Result<Self> {
+        let mut rng = Self::seeded_rng(threshold, true, seed, dst);
+        let mut bp = Self::generate_bivariate_polynomial(threshold, &mut rng)?;
+        bp.to_zero_hole();
+        Ok(bp.into())
+    }
+
     /// Creates a new dealer of secret bivariate shares, which can be used
     /// to recover a predefined shared secret.
     ///
+    /// The dealer uses a random bivariate polynomial `B(x, y)` with
+    /// `B(0, 0)` set to the given secret, which allows resharing an existing
+    /// secret, e.g. one reconstructed from shares of a previous committee.
+    /// As with randomly selected secrets, the polynomials `B(x, y)`,
+    /// `B(x, 0)`, and `B(0, y)` are guaranteed to have non-zero leading terms.
+    ///
+    /// The secret can also be a share `y_i = P_i(0)` of an existing shared
+    /// secret, which allows resharing the secret to a new committee without
+    /// reconstructing it. Once enough shareholders of the old committee have
+    /// dealt their shares, a member of the new committee with identity `x_j`
+    /// can compute its share of the original secret as the Lagrange
+    /// combination of the received shares, i.e. `\sum_i L_i(0) * B_i(x_j, y)`,
+    /// where `L_i(0)` are the Lagrange coefficients of the old shareholders.
+    ///
+    /// Externally provided secrets, e.g. an already generated master key
+    /// that is being migrated into CHURP custody, are embedded the same way.
+    /// Since zero secrets are accepted, e.g. when resharing shares of
+    /// a zero-hole polynomial, callers importing keys must reject zero keys
+    /// themselves.
+    ///
     /// This function is not constant time because it uses rejection sampling.
-    #[cfg(test)]
     pub fn new_with_secret(
         threshold: u8,
         secret: G::Scalar,
@@ -100,9 +278,127 @@ where
         &self.bp
     }
 
-    /// Returns the verification matrix.
-    pub fn verification_matrix(&self) -> &VerificationMatrix<G> {
-        &self.vm
+    /// Returns the commitment to the secret bivariate polynomial,
+    /// computing it if it hasn't been computed yet.
+    pub fn commitment(&self) -> &C {
+        self.commitment.get_or_init(|| C::commit(&self.bp))
+    }
+
+    /// Returns true if and only if the commitment to the secret bivariate
+    /// polynomial has already been computed.
+    pub fn is_committed(&self) -> bool {
+        self.commitment.get().is_some()
+    }
+
+    /// Computes a hash-based commitment to the secret bivariate polynomial
+    /// for the given shareholders, which can be used instead of
+    /// the verification matrix when verification must remain secure
+    /// against quantum adversaries.
+    ///
+    /// Since every shareholder verifies its share against the evaluations
+    /// at all shareholder points, the number of shareholders must not be
+    /// smaller than the number of coefficients of the bivariate polynomial
+    /// in either variable.
+    pub fn hash_commitment(
+        &self,
+        xs: Vec<G::Scalar>,
+        rng: &mut impl RngCore,
+    ) -> Result<(HashCommitment<G::Scalar>, HashCommitmentOpenings)> {
+        if xs.len() <= self.bp.deg_x.max(self.bp.deg_y) {
+            return Err(Error::NotEnoughShareholders.into());
+        }
+        for (i, x) in xs.iter().enumerate() {
+            if x.is_zero().into() {
+                return Err(Error::ZeroValueShareholder.into());
+            }
+            if xs[..i].contains(x) {
+                return Err(Error::DuplicateShareholder.into());
+            }
+        }
+
+        Ok(HashCommitment::commit(&self.bp, xs, rng))
+    }
+
+    /// Computes a Pedersen commitment to the secret bivariate polynomial
+    /// using a random blinding bivariate polynomial of the same degrees,
+    /// which can be used instead of the verification matrix when the dealing
+    /// must not leak any information about the secret.
+    ///
+    /// Returns the Pedersen matrix, along with the secret blinding
+    /// polynomial, whose evaluations need to be distributed to shareholders
+    /// together with their shares. The caller is responsible for zeroizing
+    /// the blinding polynomial once no longer needed.
+    pub fn pedersen_commitment(
+        &self,
+        h: G,
+        rng: &mut impl RngCore,
+    ) -> (PedersenMatrix<G>, BivariatePolynomial<G::Scalar>) {
+        let rp = BivariatePolynomial::random(self.bp.deg_x as u8, self.bp.deg_y as u8, rng);
+        let pm = PedersenMatrix::new(&self.bp, &rp, h).expect("degrees should match");
+
+        (pm, rp)
+    }
+
+    /// Proves that the given Pedersen matrix, computed using the given
+    /// blinding polynomial, commits to the secret zero-hole bivariate
+    /// polynomial of a proactive dealer.
+    ///
+    /// The proof binds the given context, e.g. the handoff epoch, and allows
+    /// shareholders to verify that a proactivization dealing doesn't change
+    /// the shared secret.
+    pub fn prove_zero_hole(
+        &self,
+        pm: &PedersenMatrix<G>,
+        rp: &BivariatePolynomial<G::Scalar>,
+        context: &[u8],
+        rng: &mut impl RngCore,
+    ) -> Result<ZeroHoleProof<G>>
+    where
+        G: GroupEncoding,
+    {
+        if !self.commitment().is_zero_hole() {
+            return Err(Error::VerificationMatrixZeroHoleMismatch.into());
+        }
+
+        ZeroHoleProof::prove(pm, rp, context, rng)
+            .ok_or(Error::VerificationMatrixZeroHoleMismatch.into())
+    }
+
+    /// Computes a constant-size KZG commitment to the secret bivariate
+    /// polynomial, which can be used instead of the verification matrix
+    /// to reduce the bandwidth of handoffs with large thresholds.
+    #[cfg(feature = "kzg")]
+    pub fn kzg_commitment<E>(&self, setup: &KzgSetup<E>) -> Result<KzgCommitment<E>>
+    where
+        E: Engine<Fr = G::Scalar>,
+    {
+        setup
+            .commit(&self.bp)
+            .ok_or(Error::PolynomialDegreeMismatch.into())
+    }
+
+    /// Generates a share of the secret for the given shareholder, together
+    /// with a constant-size KZG proof that the share is consistent with
+    /// the commitment to the secret bivariate polynomial.
+    #[cfg(feature = "kzg")]
+    pub fn make_kzg_share<E>(
+        &self,
+        x: G::Scalar,
+        kind: HandoffKind,
+        setup: &KzgSetup<E>,
+    ) -> Result<(SecretShare<G::Scalar>, KzgProof<E>)>
+    where
+        E: Engine<Fr = G::Scalar>,
+    {
+        let proof = match kind {
+            HandoffKind::DealingPhase => setup.open_x(&self.bp, &x),
+            HandoffKind::CommitteeUnchanged => setup.open_x(&self.bp, &x),
+            HandoffKind::CommitteeChanged | HandoffKind::Emergency => setup.open_y(&self.bp, &x),
+        }
+        .ok_or(Error::PolynomialDegreeMismatch)?;
+        let share = self.make_share(x, kind);
+
+        Ok((share, proof))
     }
 
     /// Generates shares of the secret for the given shareholders.
@@ -111,7 +407,110 @@ where
         xs: Vec<G::Scalar>,
         kind: HandoffKind,
     ) -> Vec<SecretShare<G::Scalar>> {
-        xs.into_iter().map(|x| self.make_share(x, kind)).collect()
+        let ps = match kind {
+            HandoffKind::DealingPhase => self.bp.eval_x_batch(&xs),
+            HandoffKind::CommitteeUnchanged => self.bp.eval_x_batch(&xs),
+            HandoffKind::CommitteeChanged | HandoffKind::Emergency => self.bp.eval_y_batch(&xs),
+        };
+
+        xs.into_iter()
+            .zip(ps)
+            .map(|(x, p)| SecretShare::new(x, p))
+            .collect()
+    }
+
+    /// Generates shares of the secret for the shareholders with the given
+    /// IDs, keyed by their IDs.
+    ///
+    /// The IDs are encoded to elements of the prime field using the given
+    /// domain separation tag, and the shares are computed in a single batch.
+    ///
+    /// Fails if any of the IDs cannot be encoded, or if the IDs are not
+    /// unique.
+    pub fn derive_shares<H, I>(
+        &self,
+        ids: &[I],
+        dst: &[u8],
+        kind: HandoffKind,
+    ) -> Result<BTreeMap<I, SecretShare<G::Scalar>>>
+    where
+        H: FieldDigest<Output = G::Scalar>,
+        I: AsRef<[u8]> + Ord + Clone,
+    {
+        let xs = encode_shareholders::<H, I>(ids, dst)?;
+        let shares = self.make_shares(xs, kind);
+
+        let mut map = BTreeMap::new();
+        for (id, share) in ids.iter().zip(shares) {
+            if map.insert(id.clone(), share).is_some() {
+                return Err(Error::DuplicateShareholder.into());
+            }
+        }
+
+        Ok(map)
+    }
+
+    /// Generates shares of the secret for the nodes with the given public
+    /// keys, returning a map from public keys to shares.
+    ///
+    /// Shareholder IDs are derived from the public keys using the encoding
+    /// of the given suite and domain separation tag.
+    ///
+    /// Fails if any of the keys cannot be encoded, if the keys are not
+    /// unique, or if two distinct keys encode to the same shareholder ID.
+    pub fn derive_shares_for_keys<S, K>(
+        &self,
+        keys: &[K],
+        dst: &[u8],
+        kind: HandoffKind,
+    ) -> Result<BTreeMap<K, SecretShare<G::Scalar>>>
+    where
+        S: FieldSuite<Field = G::Scalar>,
+        K: AsRef<[u8]> + Ord + Clone,
+    {
+        let xs = S::encode_shareholders(keys, dst)?;
+
+        let mut seen = BTreeSet::new();
+        for (i, x) in xs.iter().enumerate() {
+            if seen.insert(x.to_repr().as_ref().to_vec()) {
+                continue;
+            }
+            if keys[..i].contains(&keys[i]) {
+                return Err(Error::DuplicateShareholder.into());
+            }
+            return Err(Error::ShareholderIdCollision.into());
+        }
+
+        let shares = self.make_shares(xs, kind);
+
+        Ok(keys.iter().cloned().zip(shares).collect())
+    }
+
+    /// Generates shares of the secret for the given shareholders and
+    /// encrypts each of them to the public key of its recipient.
+    ///
+    /// The given context is authenticated together with every ciphertext,
+    /// so the shares can only be decrypted in the same context.
+    ///
+    /// Fails if any of the public keys is invalid.
+    pub fn encrypt_shares(
+        &self,
+        recipients: &[(G::Scalar, G)],
+        kind: HandoffKind,
+        context: &[u8],
+        rng: &mut impl RngCore,
+    ) -> Result<Vec<EncryptedShare<G>>>
+    where
+        G: GroupEncoding,
+    {
+        let xs = recipients.iter().map(|(x, _)| *x).collect();
+        let shares = self.make_shares(xs, kind);
+
+        recipients
+            .iter()
+            .zip(shares.iter())
+            .map(|((_, pk), share)| EncryptedShare::encrypt(share, pk, context, rng))
+            .collect()
     }
 
     /// Generates a share of the secret for the given shareholder.
@@ -119,12 +518,49 @@ where
         let p = match kind {
             HandoffKind::DealingPhase => self.bp.eval_x(&x),
             HandoffKind::CommitteeUnchanged => self.bp.eval_x(&x),
-            HandoffKind::CommitteeChanged => self.bp.eval_y(&x),
+            HandoffKind::CommitteeChanged | HandoffKind::Emergency => self.bp.eval_y(&x),
         };
 
         SecretShare::new(x, p)
     }
 
+    /// Generates a full share `B(x, y)` of the secret for the given
+    /// shareholder, as distributed in the dealing phase and in handoffs
+    /// where the committee doesn't change.
+    pub fn make_full_share(&self, x: G::Scalar) -> FullShare<G::Scalar> {
+        FullShare::new_unchecked(SecretShare::new(x, self.bp.eval_x(&x)))
+    }
+
+    /// Generates a reduced share `B(x, y)` of the secret for the given
+    /// shareholder, as distributed in handoffs where the committee changes.
+    pub fn make_reduced_share(&self, x: G::Scalar) -> ReducedShare<G::Scalar> {
+        ReducedShare::new_unchecked(SecretShare::new(x, self.bp.eval_y(&x)))
+    }
+
+    /// Validates that the given degrees of a bivariate polynomial are safe
+    /// to use for sharing a secret.
+    fn validate_degrees(deg_x: u8, deg_y: u8) -> Result<()> {
+        if deg_y < deg_x {
+            return Err(Error::InvalidPolynomialDegrees.into());
+        }
+
+        Ok(())
+    }
+
+    /// Returns a deterministic random number generator which expands
+    /// the given seed, threshold and dealer kind.
+    fn seeded_rng(threshold: u8, proactive: bool, seed: &[u8; 32], dst: &[u8]) -> XofRng {
+        let mut input = Vec::with_capacity(seed.len() + 2);
+        input.extend_from_slice(seed);
+        input.push(threshold);
+        input.push(proactive as u8);
+
+        let rng = XofRng::new(&input, dst);
+        input.zeroize();
+
+        rng
+    }
+
     /// Generates a random bivariate polynomial `B(x, y)` such that
     /// the polynomials `B(x, y)`, `B(x, 0)`, and `B(0, y)` have non-zero
     /// leading term, and the secret `B(0, 0)` is non-zero.
@@ -140,6 +576,20 @@ where
         let deg_x = threshold;
         let deg_y = threshold.checked_mul(2).ok_or(Error::ThresholdTooLarge)?;
 
+        Self::generate_bivariate_polynomial_with_degrees(deg_x, deg_y, rng)
+    }
+
+    /// Generates a random bivariate polynomial `B(x, y)` of the given
+    /// degrees such that the polynomials `B(x, y)`, `B(x, 0)`, and `B(0, y)`
+    /// have non-zero leading term, and the secret `B(0, 0)` is non-zero.
+    ///
+    /// This function is not constant time because it uses rejection
+    /// sampling to ensure that the polynomials have the maximum degree.
+    fn generate_bivariate_polynomial_with_degrees(
+        deg_x: u8,
+        deg_y: u8,
+        rng: &mut impl RngCore,
+    ) -> Result<BivariatePolynomial<G::Scalar>> {
         // When using a random RNG and a large prime field, this loop
         // should execute once with an extremely high probability,
         // so there is no need to optimize it by randomly selecting
@@ -165,22 +615,229 @@ where
     }
 }
 
-impl<G> From<BivariatePolynomial<G::Scalar>> for Dealer<G>
+/// Dealer committing to the secret bivariate polynomial using
+/// a verification matrix, i.e. the Feldman commitment scheme.
+impl<G> Dealer<G>
+where
+    G: Group + ConditionallySelectable,
+    G::Scalar: Zeroize,
+{
+    /// Returns the verification matrix.
+    pub fn verification_matrix(&self) -> &VerificationMatrix<G> {
+        self.commitment()
+    }
+
+    /// Re-randomizes the shares of an existing dealing with the given
+    /// verification matrix.
+    ///
+    /// A fresh zero-hole bivariate polynomial with the same dimensions
+    /// as the matrix is used to generate update shares for the given
+    /// shareholders, which they add to their existing shares. Returns
+    /// the update shares together with the updated verification matrix,
+    /// against which the updated shares can be verified. Since the update
+    /// polynomial is zero-hole, the shared secret remains unchanged.
+    ///
+    /// This allows re-randomizing the shares cheaply between full handoffs,
+    /// e.g. after a suspected partial compromise, as previously leaked
+    /// shares cannot be combined with the updated ones.
+    ///
+    /// This function is not constant time because it uses rejection sampling.
+    pub fn rerandomize(
+        vm: &VerificationMatrix<G>,
+        xs: Vec<G::Scalar>,
+        kind: HandoffKind,
+        rng: &mut impl RngCore,
+    ) -> Result<Rerandomization<G>> {
+        let (rows, cols) = vm.dimensions();
+        let deg_x = u8::try_from(rows - 1).map_err(|_| Error::InvalidVerificationMatrix)?;
+        let deg_y = u8::try_from(cols - 1).map_err(|_| Error::InvalidVerificationMatrix)?;
+
+        let dealer = Self::new_proactive_with_degrees(deg_x, deg_y, rng)?;
+        let shares = dealer.make_shares(xs, kind);
+        let verification_matrix = vm.clone() + dealer.commitment();
+
+        Ok(Rerandomization {
+            shares,
+            verification_matrix,
+        })
+    }
+
+    /// Assembles a package for the given handoff, which contains
+    /// the verification matrix and the shares of the given shareholders,
+    /// each encrypted to the public key of its recipient.
+    ///
+    /// The returned package is unsigned.
+    ///
+    /// Fails if any of the public keys is invalid, or if the shareholders
+    /// are not unique.
+    pub fn make_package(
+        &self,
+        epoch: u64,
+        kind: HandoffKind,
+        recipients: &[(G::Scalar, G)],
+        context: &[u8],
+        rng: &mut impl RngCore,
+    ) -> Result<DealerPackage<G>>
+    where
+        G: GroupEncoding,
+    {
+        let shares = self.encrypt_shares(recipients, kind, context, rng)?;
+        let shares = recipients.iter().map(|(x, _)| *x).zip(shares).collect();
+
+        DealerPackage::new(epoch, kind, self.commitment().clone(), shares)
+    }
+
+    /// Signs the verification matrix in the given context, e.g. the handoff
+    /// epoch, with the long-term key of the dealer.
+    pub fn sign_verification_matrix(
+        &self,
+        signer: &impl DealerSigner,
+        context: &[u8],
+    ) -> Result<Vec<u8>>
+    where
+        G: GroupEncoding,
+    {
+        sign_verification_matrix(self.commitment(), context, signer)
+    }
+
+    /// Re-verifies the given shares derived by the dealer against its own
+    /// verification matrix, before they are distributed.
+    ///
+    /// This catches faulty shares, e.g. due to RNG or arithmetic faults,
+    /// before they leave the dealer, and reports every share that failed
+    /// to verify together with the reason.
+    pub fn verify_own_shares(
+        &self,
+        shares: &[SecretShare<G::Scalar>],
+        kind: HandoffKind,
+    ) -> ShareCheckReport {
+        let failures = shares
+            .iter()
+            .enumerate()
+            .filter_map(|(i, share)| {
+                let vm = self.commitment();
+                let result = match kind {
+                    HandoffKind::DealingPhase => {
+                        vm.verify_x_detailed(share.x(), share.polynomial())
+                    }
+                    HandoffKind::CommitteeUnchanged => {
+                        vm.verify_x_detailed(share.x(), share.polynomial())
+                    }
+                    HandoffKind::CommitteeChanged | HandoffKind::Emergency => {
+                        vm.verify_y_detailed(share.x(), share.polynomial())
+                    }
+                };
+                result.err().map(|failure| (i, failure))
+            })
+            .collect();
+
+        ShareCheckReport {
+            checked: shares.len(),
+            failures,
+        }
+    }
+
+    /// Returns the byte representation of the dealer, consisting of
+    /// the secret bivariate polynomial followed by the verification matrix.
+    ///
+    /// The representation contains the secret polynomial, so it must be
+    /// sealed before it is persisted, e.g. to restore a dealer after
+    /// a restart in the middle of a handoff.
+    pub fn to_bytes(&self) -> Sensitive<Vec<u8>>
+    where
+        G: GroupEncoding,
+    {
+        let mut bytes = self.bp.to_bytes();
+        self.commitment()
+            .write_to(&mut bytes)
+            .expect("writing to a vector should not fail");
+
+        Sensitive::new(bytes)
+    }
+
+    /// Attempts to restore a dealer from its byte representation.
+    ///
+    /// The verification matrix is restored rather than recomputed,
+    /// so the representation must come from a trusted source, e.g. sealed
+    /// storage. Its dimensions must match the secret polynomial.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self>
+    where
+        G: GroupEncoding,
+    {
+        if bytes.len() < 2 {
+            return Err(Error::InvalidPolynomial.into());
+        }
+
+        let deg_x = bytes[0] as usize;
+        let deg_y = bytes[1] as usize;
+        let size = BivariatePolynomial::<G::Scalar>::byte_size(deg_x, deg_y);
+        if bytes.len() < size {
+            return Err(Error::InvalidPolynomial.into());
+        }
+
+        let (bp, vm) = bytes.split_at(size);
+        let bp = BivariatePolynomial::from_bytes(bp).ok_or(Error::InvalidPolynomial)?;
+        let vm = VerificationMatrix::from_bytes(vm).ok_or(Error::InvalidVerificationMatrix)?;
+
+        if vm.dimensions() != (deg_x + 1, deg_y + 1) {
+            return Err(Error::VerificationMatrixDimensionMismatch.into());
+        }
+
+        let commitment = OnceLock::from(vm);
+
+        Ok(Self { bp, commitment })
+    }
+}
+
+/// Report of the self-check of the shares derived by a dealer.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ShareCheckReport {
+    /// The number of checked shares.
+    pub checked: usize,
+
+    /// The indices of the shares that failed to verify, together with
+    /// the reasons.
+    pub failures: Vec<(usize, ShareVerificationFailure)>,
+}
+
+impl ShareCheckReport {
+    /// Returns true iff all checked shares verified successfully.
+    pub fn is_ok(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+/// Re-randomization of the shares of an existing dealing.
+pub struct Rerandomization<G>
 where
     G: Group,
     G::Scalar: Zeroize,
+{
+    /// Update shares, which shareholders add to their existing shares.
+    pub shares: Vec<SecretShare<G::Scalar>>,
+
+    /// Updated verification matrix.
+    pub verification_matrix: VerificationMatrix<G>,
+}
+
+impl<G, C> From<BivariatePolynomial<G::Scalar>> for Dealer<G, C>
+where
+    G: Group,
+    G::Scalar: Zeroize,
+    C: CommitmentScheme<G>,
 {
     /// Creates a new dealer from the given bivariate polynomial.
     fn from(bp: BivariatePolynomial<G::Scalar>) -> Self {
-        let vm = VerificationMatrix::from(&bp);
-        Self { bp, vm }
+        let commitment = OnceLock::new();
+        Self { bp, commitment }
     }
 }
 
-impl<G> Drop for Dealer<G>
+impl<G, C> Drop for Dealer<G, C>
 where
     G: Group,
     G::Scalar: Zeroize,
+    C: CommitmentScheme<G>,
 {
     fn drop(&mut self) {
         self.bp.zeroize();
@@ -189,8 +846,16 @@ where
 
 #[cfg(test)]
 mod tests {
+    use group::{ff::Field, Group as _};
     use rand::{rngs::StdRng, Error, RngCore, SeedableRng};
 
+    use crate::{
+        churp::{encode_shareholder, Player, SecretShare, VerifiableSecretShare},
+        poly::{lagrange, Polynomial},
+        suites::{p384::Sha3_384, FieldDigest},
+        vss::{Axis, CommitmentScheme, ShareVerificationFailure, VerificationMatrix},
+    };
+
     use super::{BivariatePolynomial, HandoffKind};
 
     type PrimeField = p384::Scalar;
@@ -241,6 +906,119 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_new_for_committee() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let threshold = 2;
+
+        let test_cases = vec![
+            (HandoffKind::DealingPhase, 4, false),
+            (HandoffKind::CommitteeUnchanged, 3, true),
+            (HandoffKind::CommitteeChanged, 5, true),
+        ];
+
+        for (kind, min_size, zero_hole) in test_cases {
+            let dealer = Dealer::new_for_committee(threshold, min_size, kind, &mut rng).unwrap();
+            assert_eq!(dealer.bivariate_polynomial().deg_x, 2);
+            assert_eq!(dealer.bivariate_polynomial().deg_y, 4);
+            assert_eq!(dealer.verification_matrix().is_zero_hole(), zero_hole);
+
+            let result = Dealer::new_for_committee(threshold, min_size - 1, kind, &mut rng);
+            assert!(result.is_err());
+            assert_eq!(
+                result.err().unwrap().to_string(),
+                format!(
+                    "committee too small: expected at least {}, got {}",
+                    min_size,
+                    min_size - 1
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn test_new_with_degrees() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+
+        let test_cases = vec![
+            (0, 0), // Zero degrees.
+            (2, 2), // Equal degrees.
+            (2, 3), // Non-CHURP layout.
+            (2, 4), // CHURP layout.
+        ];
+
+        for (deg_x, deg_y) in test_cases {
+            let dealer = Dealer::new_with_degrees(deg_x, deg_y, &mut rng).unwrap();
+            let bp = dealer.bivariate_polynomial();
+            assert_eq!(bp.deg_x, deg_x as usize);
+            assert_eq!(bp.deg_y, deg_y as usize);
+            assert_eq!(dealer.verification_matrix().rows, deg_x as usize + 1);
+            assert_eq!(dealer.verification_matrix().cols, deg_y as usize + 1);
+            assert_ne!(bp.coefficient(0, 0), Some(&PrimeField::ZERO));
+            assert_ne!(
+                bp.coefficient(deg_x.into(), deg_y.into()),
+                Some(&PrimeField::ZERO)
+            );
+
+            let dealer = Dealer::new_proactive_with_degrees(deg_x, deg_y, &mut rng).unwrap();
+            assert_eq!(dealer.bivariate_polynomial().deg_x, deg_x as usize);
+            assert_eq!(dealer.bivariate_polynomial().deg_y, deg_y as usize);
+            assert!(dealer.verification_matrix().is_zero_hole());
+        }
+
+        // Reduced shares with lower threshold than full shares.
+        let result = Dealer::new_with_degrees(3, 2, &mut rng);
+        assert!(result.is_err());
+        assert_eq!(
+            result.err().unwrap().to_string(),
+            "invalid polynomial degrees"
+        );
+
+        let result = Dealer::new_proactive_with_degrees(3, 2, &mut rng);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_from_seed() {
+        let seed = [7u8; 32];
+
+        let dealer = Dealer::from_seed(2, &seed, b"dst").unwrap();
+        assert_eq!(dealer.bivariate_polynomial().deg_x, 2);
+        assert_eq!(dealer.bivariate_polynomial().deg_y, 4);
+        assert!(!dealer.verification_matrix().is_zero_hole());
+
+        // Deterministic.
+        let other = Dealer::from_seed(2, &seed, b"dst").unwrap();
+        assert!(dealer.bivariate_polynomial() == other.bivariate_polynomial());
+        assert_eq!(dealer.verification_matrix(), other.verification_matrix());
+
+        // Different seed, threshold or domain separation tag.
+        let other = Dealer::from_seed(2, &[8u8; 32], b"dst").unwrap();
+        assert_ne!(dealer.verification_matrix(), other.verification_matrix());
+        let other = Dealer::from_seed(2, &seed, b"other dst").unwrap();
+        assert_ne!(dealer.verification_matrix(), other.verification_matrix());
+        let other = Dealer::from_seed(1, &seed, b"dst").unwrap();
+        assert_ne!(
+            dealer.verification_matrix().element(0, 0),
+            other.verification_matrix().element(0, 0)
+        );
+
+        // Proactive dealers derive unrelated polynomials from the same seed.
+        let proactive = Dealer::from_seed_proactive(2, &seed, b"dst").unwrap();
+        assert!(proactive.verification_matrix().is_zero_hole());
+        let other = Dealer::from_seed_proactive(2, &seed, b"dst").unwrap();
+        assert_eq!(proactive.verification_matrix(), other.verification_matrix());
+
+        for i in 0..=2 {
+            for j in 0..=4 {
+                assert_ne!(
+                    proactive.verification_matrix().element(i, j),
+                    dealer.verification_matrix().element(i, j)
+                );
+            }
+        }
+    }
+
     #[test]
     fn test_new_with_secret() {
         let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
@@ -264,6 +1042,232 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_new_resharing() {
+        let threshold = 2;
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let secret = PrimeField::from_u64(100);
+        let dealer = Dealer::new_with_secret(threshold, secret, &mut rng).unwrap();
+
+        // Old committee.
+        let old_xs: Vec<_> = (1..=3).map(PrimeField::from_u64).collect();
+        let old_shares = dealer.make_shares(old_xs.clone(), HandoffKind::DealingPhase);
+
+        // Every old shareholder reshares its share.
+        let dealers: Vec<_> = old_shares
+            .iter()
+            .map(|share| Dealer::new_with_secret(threshold, *share.y(), &mut rng).unwrap())
+            .collect();
+        for (dealer, share) in dealers.iter().zip(old_shares.iter()) {
+            assert_eq!(
+                dealer.bivariate_polynomial().coefficient(0, 0),
+                Some(share.y())
+            );
+        }
+
+        // New committee combines the received shares.
+        let cs = lagrange::coefficients(&old_xs);
+        let new_shares: Vec<_> = (4..=6)
+            .map(PrimeField::from_u64)
+            .map(|x| {
+                let p = dealers
+                    .iter()
+                    .zip(cs.iter())
+                    .map(|(dealer, c)| {
+                        dealer.make_share(x, HandoffKind::DealingPhase).polynomial() * c
+                    })
+                    .sum();
+                SecretShare::new(x, p)
+            })
+            .collect();
+
+        let player = Player::new(threshold, HandoffKind::DealingPhase);
+        assert_eq!(player.recover_secret(&new_shares).unwrap(), secret);
+    }
+
+    #[test]
+    fn test_rerandomize() {
+        let threshold = 2;
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let secret = PrimeField::from_u64(100);
+        let dealer = Dealer::new_with_secret(threshold, secret, &mut rng).unwrap();
+        let vm = dealer.verification_matrix();
+
+        let xs: Vec<_> = (1..=3).map(PrimeField::from_u64).collect();
+        let shares = dealer.make_shares(xs.clone(), HandoffKind::DealingPhase);
+
+        let rerandomization =
+            Dealer::rerandomize(vm, xs.clone(), HandoffKind::CommitteeUnchanged, &mut rng).unwrap();
+        let updates = rerandomization.shares;
+        let updated_vm = rerandomization.verification_matrix;
+        assert_eq!(updates.len(), xs.len());
+        assert_eq!(updated_vm.dimensions(), vm.dimensions());
+        assert!(updated_vm != *vm);
+
+        let mut new_shares = Vec::new();
+        for (mut share, update) in shares.into_iter().zip(updates.iter()) {
+            let old = share.polynomial().clone();
+            share += update;
+            assert!(share.polynomial() != &old);
+            assert!(!vm.verify_x(share.x(), share.polynomial()));
+            assert!(updated_vm.verify_x(share.x(), share.polynomial()));
+            new_shares.push(share);
+        }
+
+        // The secret remains unchanged.
+        let player = Player::new(threshold, HandoffKind::DealingPhase);
+        assert_eq!(player.recover_secret(&new_shares).unwrap(), secret);
+    }
+
+    #[test]
+    fn test_verify_own_shares() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let dealer = Dealer::new(2, &mut rng).unwrap();
+        let xs: Vec<_> = (1..=3).map(PrimeField::from_u64).collect();
+
+        for kind in [
+            HandoffKind::DealingPhase,
+            HandoffKind::CommitteeUnchanged,
+            HandoffKind::CommitteeChanged,
+        ] {
+            let mut shares = dealer.make_shares(xs.clone(), kind);
+            let report = dealer.verify_own_shares(&shares, kind);
+            assert_eq!(report.checked, 3);
+            assert!(report.is_ok());
+
+            // Faulty shares.
+            let axis = match kind {
+                HandoffKind::CommitteeChanged => Axis::Y,
+                _ => Axis::X,
+            };
+            let p = shares[0].polynomial().clone();
+            shares[0] = SecretShare::new(PrimeField::from_u64(4), p);
+            shares[2] = SecretShare::new(xs[2], Polynomial::with_coefficients(vec![]));
+
+            let report = dealer.verify_own_shares(&shares, kind);
+            assert_eq!(report.checked, 3);
+            assert!(!report.is_ok());
+            assert_eq!(
+                report.failures,
+                vec![
+                    (
+                        0,
+                        ShareVerificationFailure::InvalidCoefficient { axis, index: 0 }
+                    ),
+                    (2, ShareVerificationFailure::InvalidSize { axis }),
+                ]
+            );
+        }
+    }
+
+    #[test]
+    fn test_serialization() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let dealer = Dealer::new(2, &mut rng).unwrap();
+
+        let bytes = dealer.to_bytes();
+        let restored = Dealer::from_bytes(bytes.expose()).unwrap();
+        assert!(restored.bivariate_polynomial() == dealer.bivariate_polynomial());
+        assert_eq!(restored.verification_matrix(), dealer.verification_matrix());
+        assert_eq!(format!("{:?}", bytes), "Sensitive(<redacted>)");
+
+        // The restored dealer derives the same shares.
+        let x = PrimeField::from_u64(1);
+        let kind = HandoffKind::CommitteeChanged;
+        assert!(
+            restored.make_share(x, kind).polynomial() == dealer.make_share(x, kind).polynomial()
+        );
+
+        // Truncated.
+        let bytes = bytes.expose();
+        for len in [0, 1, 2, bytes.len() - 1] {
+            assert!(Dealer::from_bytes(&bytes[..len]).is_err());
+        }
+
+        // Dimension mismatch.
+        let other = Dealer::new(1, &mut rng).unwrap();
+        let mut mismatched = dealer.bivariate_polynomial().to_bytes();
+        mismatched.extend(other.verification_matrix().to_bytes());
+        let result = Dealer::from_bytes(&mismatched);
+        assert!(result.is_err());
+        assert_eq!(
+            result.err().unwrap().to_string(),
+            "verification matrix dimension mismatch"
+        );
+    }
+
+    #[test]
+    fn test_commitment_scheme() {
+        /// Insecure commitment scheme revealing the polynomial.
+        #[derive(Clone)]
+        struct Reveal(BivariatePolynomial<PrimeField>);
+
+        impl CommitmentScheme<Group> for Reveal {
+            fn commit(bp: &BivariatePolynomial<PrimeField>) -> Self {
+                Self(bp.clone())
+            }
+
+            fn verify_x(&self, x: &PrimeField, polynomial: &Polynomial<PrimeField>) -> bool {
+                self.0.eval_x(x) == *polynomial
+            }
+
+            fn verify_y(&self, y: &PrimeField, polynomial: &Polynomial<PrimeField>) -> bool {
+                self.0.eval_y(y) == *polynomial
+            }
+
+            fn is_zero_hole(&self) -> bool {
+                self.0.coefficient(0, 0) == Some(&PrimeField::ZERO)
+            }
+        }
+
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let x = PrimeField::from_u64(1);
+
+        // Verification matrix by default.
+        let dealer = Dealer::new(2, &mut rng).unwrap();
+        assert_eq!(dealer.commitment(), dealer.verification_matrix());
+
+        // Custom commitment scheme.
+        let dealer = super::Dealer::<Group, Reveal>::new(2, &mut rng).unwrap();
+        assert!(dealer.commitment().0 == *dealer.bivariate_polynomial());
+        assert!(!dealer.commitment().is_zero_hole());
+        for kind in [HandoffKind::DealingPhase, HandoffKind::CommitteeChanged] {
+            let share = dealer.make_share(x, kind);
+            match kind {
+                HandoffKind::CommitteeChanged => {
+                    assert!(dealer.commitment().verify_y(&x, share.polynomial()))
+                }
+                _ => assert!(dealer.commitment().verify_x(&x, share.polynomial())),
+            }
+        }
+
+        let dealer = super::Dealer::<Group, Reveal>::new_proactive(2, &mut rng).unwrap();
+        assert!(dealer.commitment().is_zero_hole());
+    }
+
+    #[test]
+    fn test_lazy_commitment() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let dealer = Dealer::new(2, &mut rng).unwrap();
+        assert!(!dealer.is_committed());
+
+        // Shares don't require the commitment.
+        let xs: Vec<_> = (1..=3).map(PrimeField::from_u64).collect();
+        let shares = dealer.make_shares(xs, HandoffKind::DealingPhase);
+        assert!(!dealer.is_committed());
+
+        let vm = dealer.verification_matrix();
+        assert!(dealer.is_committed());
+        assert_eq!(vm, &VerificationMatrix::from(dealer.bivariate_polynomial()));
+        for share in shares.iter() {
+            assert!(vm.verify_x(share.x(), share.polynomial()));
+        }
+
+        // Restored dealers are committed.
+        let restored = Dealer::from_bytes(dealer.to_bytes().expose()).unwrap();
+        assert!(restored.is_committed());
+    }
+
     #[test]
     fn test_make_share() {
         let threshold = 2;
@@ -281,6 +1285,292 @@ mod tests {
             let share = dealer.make_share(x, kind);
             assert_eq!(share.polynomial().size(), size);
         }
+
+        let share = dealer.make_full_share(x);
+        assert!(
+            share.secret_share().polynomial()
+                == dealer.make_share(x, HandoffKind::DealingPhase).polynomial()
+        );
+        let share = dealer.make_reduced_share(x);
+        assert!(
+            share.secret_share().polynomial()
+                == dealer
+                    .make_share(x, HandoffKind::CommitteeChanged)
+                    .polynomial()
+        );
+    }
+
+    #[test]
+    fn test_make_shares() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let dealer = Dealer::new(2, &mut rng).unwrap();
+        let xs: Vec<_> = (1..=5).map(PrimeField::from_u64).collect();
+
+        for kind in [
+            HandoffKind::DealingPhase,
+            HandoffKind::CommitteeUnchanged,
+            HandoffKind::CommitteeChanged,
+        ] {
+            let shares = dealer.make_shares(xs.clone(), kind);
+            assert_eq!(shares.len(), xs.len());
+            for (x, share) in xs.iter().zip(shares.iter()) {
+                assert_eq!(share.x(), x);
+                assert!(share.polynomial() == dealer.make_share(*x, kind).polynomial());
+            }
+        }
+    }
+
+    #[test]
+    fn test_derive_shares() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let dealer = Dealer::new(2, &mut rng).unwrap();
+        let ids = vec![b"alice".to_vec(), b"bob".to_vec(), b"carol".to_vec()];
+
+        for kind in [
+            HandoffKind::DealingPhase,
+            HandoffKind::CommitteeUnchanged,
+            HandoffKind::CommitteeChanged,
+        ] {
+            let shares = dealer
+                .derive_shares::<Sha3_384, _>(&ids, b"dst", kind)
+                .unwrap();
+            assert_eq!(shares.len(), ids.len());
+            for id in ids.iter() {
+                let x = encode_shareholder::<Sha3_384>(id, b"dst").unwrap();
+                let share = shares.get(id).unwrap();
+                assert_eq!(share.x(), &x);
+                assert!(share.polynomial() == dealer.make_share(x, kind).polynomial());
+            }
+        }
+
+        // Duplicate shareholder.
+        let ids = vec![b"alice".to_vec(), b"bob".to_vec(), b"alice".to_vec()];
+        let result = dealer.derive_shares::<Sha3_384, _>(&ids, b"dst", HandoffKind::DealingPhase);
+        assert!(result.is_err());
+        assert_eq!(result.err().unwrap().to_string(), "duplicate shareholder");
+    }
+
+    #[test]
+    fn test_derive_shares_for_keys() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let dealer = Dealer::new(2, &mut rng).unwrap();
+        let keys: Vec<_> = (0..3u8).map(|i| [i; 32]).collect();
+
+        for kind in [
+            HandoffKind::DealingPhase,
+            HandoffKind::CommitteeUnchanged,
+            HandoffKind::CommitteeChanged,
+        ] {
+            let shares = dealer
+                .derive_shares_for_keys::<Sha3_384, _>(&keys, b"dst", kind)
+                .unwrap();
+            assert_eq!(shares.len(), keys.len());
+            for key in keys.iter() {
+                let x = encode_shareholder::<Sha3_384>(key, b"dst").unwrap();
+                let share = shares.get(key).unwrap();
+                assert_eq!(share.x(), &x);
+                assert!(share.polynomial() == dealer.make_share(x, kind).polynomial());
+            }
+        }
+
+        // Duplicate key.
+        let keys = vec![[0u8; 32], [1u8; 32], [0u8; 32]];
+        let result =
+            dealer.derive_shares_for_keys::<Sha3_384, _>(&keys, b"dst", HandoffKind::DealingPhase);
+        assert!(result.is_err());
+        assert_eq!(result.err().unwrap().to_string(), "duplicate shareholder");
+
+        // Colliding shareholder IDs.
+        struct FirstByte;
+
+        impl FieldDigest for FirstByte {
+            type Output = PrimeField;
+
+            fn hash_to_field(msg: &[u8], _dst: &[u8]) -> anyhow::Result<Self::Output> {
+                Ok(PrimeField::from_u64(msg[0] as u64 + 1))
+            }
+        }
+
+        let keys = vec![b"alice".to_vec(), b"bob".to_vec(), b"anna".to_vec()];
+        let result =
+            dealer.derive_shares_for_keys::<FirstByte, _>(&keys, b"dst", HandoffKind::DealingPhase);
+        assert!(result.is_err());
+        assert_eq!(
+            result.err().unwrap().to_string(),
+            "shareholder ID collision"
+        );
+    }
+
+    #[test]
+    fn test_encrypt_shares() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let dealer = Dealer::new(2, &mut rng).unwrap();
+        let kind = HandoffKind::CommitteeChanged;
+
+        let sks: Vec<_> = (0..3).map(|_| PrimeField::random(&mut rng)).collect();
+        let recipients: Vec<_> = sks
+            .iter()
+            .enumerate()
+            .map(|(i, sk)| (PrimeField::from_u64(i as u64 + 1), Group::generator() * sk))
+            .collect();
+
+        let encrypted = dealer
+            .encrypt_shares(&recipients, kind, b"context", &mut rng)
+            .unwrap();
+        assert_eq!(encrypted.len(), recipients.len());
+
+        for ((sk, (x, _)), encrypted) in sks.iter().zip(recipients.iter()).zip(encrypted) {
+            let share = encrypted.decrypt(sk, b"context").unwrap();
+            assert_eq!(share.x(), x);
+            assert!(share.polynomial() == dealer.make_share(*x, kind).polynomial());
+        }
+
+        // Invalid public key.
+        let recipients = vec![(PrimeField::ONE, Group::identity())];
+        let result = dealer.encrypt_shares(&recipients, kind, b"context", &mut rng);
+        assert!(result.is_err());
+        assert_eq!(result.err().unwrap().to_string(), "invalid public key");
+    }
+
+    #[test]
+    fn test_hash_commitment() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let dealer = Dealer::new(2, &mut rng).unwrap();
+
+        // Not enough shareholders.
+        let xs: Vec<_> = (1..=4).map(PrimeField::from_u64).collect();
+        let result = dealer.hash_commitment(xs, &mut rng);
+        assert!(result.is_err());
+        assert_eq!(result.err().unwrap().to_string(), "not enough shareholders");
+
+        // Zero value shareholder.
+        let xs: Vec<_> = (0..5).map(PrimeField::from_u64).collect();
+        let result = dealer.hash_commitment(xs, &mut rng);
+        assert!(result.is_err());
+        assert_eq!(result.err().unwrap().to_string(), "zero value shareholder");
+
+        // Duplicate shareholder.
+        let xs: Vec<_> = vec![1, 2, 3, 4, 1]
+            .into_iter()
+            .map(PrimeField::from_u64)
+            .collect();
+        let result = dealer.hash_commitment(xs, &mut rng);
+        assert!(result.is_err());
+        assert_eq!(result.err().unwrap().to_string(), "duplicate shareholder");
+
+        // Valid shareholders.
+        let xs: Vec<_> = (1..=5).map(PrimeField::from_u64).collect();
+        let (commitment, openings) = dealer.hash_commitment(xs.clone(), &mut rng).unwrap();
+        for (i, x) in xs.into_iter().enumerate() {
+            let share = dealer.make_share(x, HandoffKind::CommitteeUnchanged);
+            let opening = openings.row(i).unwrap();
+            assert!(commitment.verify_x(&x, &opening, share.polynomial()));
+
+            let share = dealer.make_share(x, HandoffKind::CommitteeChanged);
+            let opening = openings.column(i).unwrap();
+            assert!(commitment.verify_y(&x, &opening, share.polynomial()));
+        }
+    }
+
+    #[test]
+    fn test_prove_zero_hole() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let h = <Group as group::Group>::random(&mut rng);
+
+        let dealer = Dealer::new_proactive(2, &mut rng).unwrap();
+        let (pm, rp) = dealer.pedersen_commitment(h, &mut rng);
+        let proof = dealer
+            .prove_zero_hole(&pm, &rp, b"epoch 1", &mut rng)
+            .unwrap();
+        assert!(proof.verify(&pm, b"epoch 1"));
+        assert!(!proof.verify(&pm, b"epoch 2"));
+
+        // Not a proactive dealer.
+        let dealer = Dealer::new(2, &mut rng).unwrap();
+        let (pm, rp) = dealer.pedersen_commitment(h, &mut rng);
+        let result = dealer.prove_zero_hole(&pm, &rp, b"epoch 1", &mut rng);
+        assert!(result.is_err());
+        assert_eq!(
+            result.err().unwrap().to_string(),
+            "verification matrix zero-hole mismatch"
+        );
+    }
+
+    #[test]
+    fn test_pedersen_commitment() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let dealer = Dealer::new(2, &mut rng).unwrap();
+        let h = <Group as group::Group>::random(&mut rng);
+
+        let (pm, rp) = dealer.pedersen_commitment(h, &mut rng);
+        assert_eq!(pm.dimensions(), dealer.verification_matrix().dimensions());
+        assert_ne!(pm.element(0, 0), dealer.verification_matrix().element(0, 0));
+
+        for x in (1..=3).map(PrimeField::from_u64) {
+            let share = dealer.make_share(x, HandoffKind::CommitteeUnchanged);
+            assert!(pm.verify_x(&x, share.polynomial(), &rp.eval_x(&x)));
+
+            let share = dealer.make_share(x, HandoffKind::CommitteeChanged);
+            assert!(pm.verify_y(&x, share.polynomial(), &rp.eval_y(&x)));
+        }
+    }
+
+    #[cfg(all(feature = "kzg", feature = "bls12_381_g1"))]
+    #[test]
+    fn test_kzg_commitment() {
+        use bls12_381_plus::{Bls12, G1Projective, Scalar};
+
+        use crate::vss::KzgSetup;
+
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let dealer = super::Dealer::<G1Projective>::new(2, &mut rng).unwrap();
+
+        // Setup too small.
+        let setup = KzgSetup::<Bls12>::random(2, 3, &mut rng);
+        let result = dealer.kzg_commitment(&setup);
+        assert!(result.is_err());
+        assert_eq!(
+            result.err().unwrap().to_string(),
+            "polynomial degree mismatch"
+        );
+        let kind = HandoffKind::CommitteeChanged;
+        assert!(dealer.make_kzg_share(Scalar::ONE, kind, &setup).is_err());
+
+        // Valid setup.
+        let setup = KzgSetup::<Bls12>::random(2, 4, &mut rng);
+        let commitment = dealer.kzg_commitment(&setup).unwrap();
+        for x in (1..=3u64).map(Scalar::from) {
+            let kind = HandoffKind::CommitteeUnchanged;
+            let (share, proof) = dealer.make_kzg_share(x, kind, &setup).unwrap();
+            assert!(setup.verify_x(&commitment, &x, share.polynomial(), &proof));
+            assert!(!setup.verify_y(&commitment, &x, share.polynomial(), &proof));
+
+            let kind = HandoffKind::CommitteeChanged;
+            let (share, proof) = dealer.make_kzg_share(x, kind, &setup).unwrap();
+            assert!(setup.verify_y(&commitment, &x, share.polynomial(), &proof));
+        }
+    }
+
+    #[test]
+    fn test_verification_vector() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let dealer = Dealer::new(2, &mut rng).unwrap();
+        let x = PrimeField::from_u64(2);
+
+        for (kind, full_share) in [
+            (HandoffKind::CommitteeUnchanged, true),
+            (HandoffKind::CommitteeChanged, false),
+        ] {
+            let share = dealer.make_share(x, kind);
+            let vm = dealer.verification_matrix().clone();
+            let share = VerifiableSecretShare::new(share, vm);
+
+            let vv = share.verification_vector(full_share);
+            assert!(vv.is_from(share.polynomial()));
+            assert!(!share
+                .verification_vector(!full_share)
+                .is_from(share.polynomial()));
+        }
     }
 
     #[test]
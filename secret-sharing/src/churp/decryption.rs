@@ -0,0 +1,454 @@
+//! Threshold decryption.
+
+use anyhow::Result;
+use group::{
+    ff::{Field, PrimeField},
+    Group, GroupEncoding,
+};
+use rand_core::RngCore;
+use zeroize::Zeroize;
+
+use crate::{
+    poly::{lagrange, EncryptedPoint, XofRng},
+    vss::VerificationMatrix,
+};
+
+use super::{ecies::Envelope, Error, KeyPurpose, Player, PurposeShareholder};
+
+/// Domain separation tag for the derivation of threshold encryption keys.
+const THRESHOLD_ENCRYPTION_DST: &[u8] = b"CHURP-ThresholdEncryption";
+
+/// Domain separation tag for the challenge of decryption share proofs.
+const DECRYPTION_SHARE_PROOF_DST: &[u8] = b"CHURP-DecryptionShareProof";
+
+/// Ciphertext encrypted to the public key of the committee.
+///
+/// Messages are encrypted using ElGamal-style hybrid encryption:
+/// the sender generates an ephemeral key pair `(e, E = e * G)`, computes
+/// the shared secret `S = e * PK` with the public key `PK = s * G`
+/// of the committee, derives a symmetric key from it using HKDF-SHA256,
+/// and encrypts the message using ChaCha20-Poly1305.
+///
+/// The committee never reconstructs the secret `s`. Instead, every
+/// shareholder computes a decryption share `D_i = y_i * E`, and any quorum
+/// recovers the shared secret `S = s * E` by Lagrange interpolation
+/// in the exponent. Since whoever submits the ciphertext chooses `E`,
+/// the secret `s` must be dealt separately and dedicated to decryption.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ciphertext<G>
+where
+    G: Group,
+{
+    /// The encrypted message.
+    envelope: Envelope<G>,
+}
+
+impl<G> Ciphertext<G>
+where
+    G: Group + GroupEncoding,
+    G::Scalar: Zeroize,
+{
+    /// Encrypts the given message to the given public key of the committee.
+    ///
+    /// The given context is authenticated together with the ciphertext.
+    pub fn encrypt(message: &[u8], pk: &G, context: &[u8], rng: &mut impl RngCore) -> Result<Self> {
+        if pk.is_identity().into() {
+            return Err(Error::InvalidPublicKey.into());
+        }
+
+        let envelope = Envelope::seal(message, pk, context, THRESHOLD_ENCRYPTION_DST, rng)
+            .ok_or(Error::EncryptionFailed)?;
+
+        Ok(Self { envelope })
+    }
+
+    /// Decrypts the message using the given shared secret `S = s * E`,
+    /// recovered from the decryption shares.
+    ///
+    /// Fails if the message was encrypted in another context, or if
+    /// the ciphertext was tampered with.
+    pub(crate) fn decrypt(&self, shared: &G, pk: &G, context: &[u8]) -> Result<Vec<u8>> {
+        let message = self
+            .envelope
+            .open(shared, pk, context, THRESHOLD_ENCRYPTION_DST)
+            .ok_or(Error::DecryptionFailed)?;
+
+        Ok(message)
+    }
+
+    /// Returns the ephemeral public key.
+    pub fn ephemeral_key(&self) -> &G {
+        self.envelope.ephemeral_key()
+    }
+
+    /// Returns the byte representation of the ciphertext.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.envelope.to_bytes()
+    }
+
+    /// Attempts to create a ciphertext from its byte representation.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let envelope = Envelope::from_bytes(bytes)?;
+        Some(Self { envelope })
+    }
+}
+
+/// A decryption share `D_i = y_i * E` of a shareholder, together with
+/// a proof of its correctness.
+///
+/// The proof is a non-interactive Chaum-Pedersen proof of equality
+/// of the discrete logarithms of the public value `Y_i = y_i * G`
+/// of the share and of the decryption share:
+/// ```text
+///     A = k * G, B = k * E
+///     c = Hash(x_i || Y_i || E || D_i || A || B)
+///     s = k + c * y_i
+/// ```
+/// The proof is valid iff `c` matches the challenge recomputed from
+/// `A = s * G - c * Y_i` and `B = s * E - c * D_i`.
+#[derive(Clone)]
+pub struct DecryptionShare<G>
+where
+    G: Group,
+{
+    /// The decryption share `D_i = y_i * E`.
+    point: EncryptedPoint<G>,
+    /// The challenge of the proof.
+    c: G::Scalar,
+    /// The response of the proof.
+    s: G::Scalar,
+}
+
+impl<G> DecryptionShare<G>
+where
+    G: Group + GroupEncoding,
+{
+    /// Computes the decryption share of the share point `(x, y)`
+    /// for the given ephemeral key.
+    pub(crate) fn new(x: &G::Scalar, y: &G::Scalar, ephemeral: &G, rng: &mut impl RngCore) -> Self
+    where
+        G::Scalar: Zeroize,
+    {
+        let public = G::generator() * y;
+        let d = *ephemeral * y;
+
+        let mut k = G::Scalar::random(rng);
+        let a = G::generator() * k;
+        let b = *ephemeral * k;
+        let c = Self::challenge(x, &public, ephemeral, &d, &a, &b);
+        let s = k + c * y;
+        k.zeroize();
+
+        Self {
+            point: EncryptedPoint::new(*x, d),
+            c,
+            s,
+        }
+    }
+
+    /// Returns the decryption share as a point.
+    pub fn point(&self) -> &EncryptedPoint<G> {
+        &self.point
+    }
+
+    /// Verifies the decryption share for the given ephemeral key against
+    /// the given public value of the share of the shareholder.
+    pub fn verify(&self, public: &G, ephemeral: &G) -> bool {
+        let x = self.point.x();
+        let d = self.point.z();
+        let a = G::generator() * self.s - *public * self.c;
+        let b = *ephemeral * self.s - *d * self.c;
+
+        Self::challenge(x, public, ephemeral, d, &a, &b) == self.c
+    }
+
+    /// Computes the Fiat-Shamir challenge.
+    fn challenge(x: &G::Scalar, public: &G, ephemeral: &G, d: &G, a: &G, b: &G) -> G::Scalar {
+        let mut transcript = Vec::new();
+        transcript.extend_from_slice(x.to_repr().as_ref());
+        for p in [public, ephemeral, d, a, b] {
+            transcript.extend_from_slice(p.to_bytes().as_ref());
+        }
+
+        G::Scalar::random(XofRng::new(&transcript, DECRYPTION_SHARE_PROOF_DST))
+    }
+}
+
+/// Implements [`Player::decrypt_partial`].
+pub(crate) fn decrypt_partial<G>(
+    shareholder: &PurposeShareholder<G>,
+    ciphertext: &Ciphertext<G>,
+    rng: &mut impl RngCore,
+) -> Result<DecryptionShare<G>>
+where
+    G: Group + GroupEncoding,
+    G::Scalar: Zeroize,
+{
+    let shareholder = shareholder.scoped(KeyPurpose::Decryption)?;
+    let ephemeral = ciphertext.ephemeral_key();
+    if ephemeral.is_identity().into() {
+        return Err(Error::DecryptionFailed.into());
+    }
+
+    let share = shareholder.verifiable_share();
+    let share = DecryptionShare::new(share.x(), share.y(), ephemeral, rng);
+
+    Ok(share)
+}
+
+/// Implements [`Player::verify_decryption_share`].
+pub(crate) fn verify_decryption_share<G>(
+    player: &Player,
+    share: &DecryptionShare<G>,
+    ciphertext: &Ciphertext<G>,
+    vm: &VerificationMatrix<G>,
+) -> Result<()>
+where
+    G: Group + GroupEncoding,
+    G::Scalar: Zeroize,
+{
+    player.verify_dimensions(vm)?;
+
+    let x = share.point().x();
+    let public = player.secret_sharing_vector(vm).eval(x);
+    if !share.verify(&public, ciphertext.ephemeral_key()) {
+        let error = Error::InvalidDecryptionShare {
+            shareholder: Player::shareholder(x),
+        };
+        player.record(x, share.point().z().to_bytes().as_ref(), &error);
+        return Err(error.into());
+    }
+
+    Ok(())
+}
+
+/// Implements [`Player::combine_decryption_shares`].
+pub(crate) fn combine_decryption_shares<G>(
+    player: &Player,
+    shares: &[DecryptionShare<G>],
+    ciphertext: &Ciphertext<G>,
+    vm: &VerificationMatrix<G>,
+    context: &[u8],
+) -> Result<Vec<u8>>
+where
+    G: Group + GroupEncoding,
+    G::Scalar: Zeroize,
+{
+    let expected = player.min_shares();
+    if shares.len() < expected {
+        return Err(Error::NotEnoughShares {
+            expected,
+            actual: shares.len(),
+        }
+        .into());
+    }
+
+    for (i, share) in shares.iter().enumerate() {
+        if shares[..i]
+            .iter()
+            .any(|s| s.point().x() == share.point().x())
+        {
+            return Err(Error::DuplicateShareholder.into());
+        }
+        player.verify_decryption_share(share, ciphertext, vm)?;
+    }
+
+    let points: Vec<_> = shares.iter().map(|s| s.point().clone()).collect();
+    let shared = lagrange::lagrange_in_exponent(&points);
+    let pk = player.public_key(vm);
+
+    ciphertext.decrypt(&shared, &pk, context)
+}
+
+#[cfg(test)]
+mod tests {
+    use group::{ff::Field, Group as _};
+    use rand::{rngs::StdRng, SeedableRng};
+    use rand_core::OsRng;
+
+    use crate::{
+        churp::{self, HandoffKind, KeyPurpose, Player, PurposeShareholder, VerifiableSecretShare},
+        kdc::KeyRecoverer,
+    };
+
+    use super::{Ciphertext, DecryptionShare};
+
+    type PrimeField = p384::Scalar;
+    type Group = p384::ProjectivePoint;
+    type Dealer = churp::Dealer<Group>;
+
+    #[test]
+    fn test_ciphertext() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let sk = PrimeField::random(&mut rng);
+        let pk = Group::generator() * sk;
+
+        let ciphertext = Ciphertext::encrypt(b"message", &pk, b"context", &mut rng).unwrap();
+        let shared = *ciphertext.ephemeral_key() * sk;
+        let message = ciphertext.decrypt(&shared, &pk, b"context").unwrap();
+        assert_eq!(message, b"message");
+
+        // Different context or shared secret.
+        let result = ciphertext.decrypt(&shared, &pk, b"other context");
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().to_string(), "decryption failed");
+        let result = ciphertext.decrypt(&Group::generator(), &pk, b"context");
+        assert!(result.is_err());
+
+        // Serialization.
+        let decoded = Ciphertext::<Group>::from_bytes(&ciphertext.to_bytes()).unwrap();
+        assert_eq!(decoded, ciphertext);
+
+        // Identity public key.
+        let result = Ciphertext::encrypt(b"message", &Group::identity(), b"context", &mut rng);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_decryption_share() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let x = PrimeField::from_u64(1);
+        let y = PrimeField::random(&mut rng);
+        let public = Group::generator() * y;
+        let ephemeral = Group::random(&mut rng);
+
+        let share = DecryptionShare::new(&x, &y, &ephemeral, &mut rng);
+        assert_eq!(share.point().z(), &(ephemeral * y));
+        assert!(share.verify(&public, &ephemeral));
+
+        // Different public value or ephemeral key.
+        assert!(!share.verify(&(public + Group::generator()), &ephemeral));
+        assert!(!share.verify(&public, &Group::random(&mut rng)));
+    }
+
+    #[test]
+    fn test_threshold_decryption() {
+        let test_cases = vec![
+            HandoffKind::DealingPhase,
+            HandoffKind::CommitteeUnchanged,
+            HandoffKind::CommitteeChanged,
+        ];
+
+        for kind in test_cases.into_iter() {
+            let threshold = 2;
+            let dealer = Dealer::new(threshold, &mut OsRng).unwrap();
+            let vm = dealer.verification_matrix();
+            let pk = *vm.element(0, 0).unwrap();
+            let player = Player::new(threshold, kind);
+            let min_shares = player.min_shares() as u64;
+            let shareholders: Vec<PurposeShareholder<Group>> = (1..=min_shares + 1)
+                .map(PrimeField::from_u64)
+                .map(|x| VerifiableSecretShare::new(dealer.make_share(x, kind), vm.clone()).into())
+                .map(|sh| PurposeShareholder::new(KeyPurpose::Decryption, sh))
+                .collect();
+
+            let ciphertext = Ciphertext::encrypt(b"message", &pk, b"context", &mut OsRng).unwrap();
+            let mut shares: Vec<_> = shareholders
+                .iter()
+                .map(|sh| player.decrypt_partial(sh, &ciphertext, &mut OsRng).unwrap())
+                .collect();
+
+            // Any quorum can decrypt.
+            for quorum in [&shares[1..], &shares[..shares.len() - 1]] {
+                let message = player
+                    .combine_decryption_shares(quorum, &ciphertext, vm, b"context")
+                    .unwrap();
+                assert_eq!(message, b"message");
+            }
+
+            // Different context.
+            let result = player.combine_decryption_shares(&shares, &ciphertext, vm, b"other");
+            assert!(result.is_err());
+            assert_eq!(result.unwrap_err().to_string(), "decryption failed");
+
+            // Not enough shares.
+            let result =
+                player.combine_decryption_shares(&shares[2..], &ciphertext, vm, b"context");
+            assert!(result.is_err());
+
+            // Share for another ciphertext.
+            let other = Ciphertext::encrypt(b"message", &pk, b"context", &mut OsRng).unwrap();
+            shares[0] = player
+                .decrypt_partial(&shareholders[0], &other, &mut OsRng)
+                .unwrap();
+            let result = player.combine_decryption_shares(&shares, &ciphertext, vm, b"context");
+            assert!(result.is_err());
+            assert_eq!(
+                result.unwrap_err().to_string(),
+                format!(
+                    "invalid decryption share of shareholder {}",
+                    "0".repeat(94) + "01"
+                )
+            );
+        }
+    }
+    #[test]
+    fn test_decryption_key_purpose() {
+        let threshold = 2;
+        let kind = HandoffKind::DealingPhase;
+        let player = Player::new(threshold, kind);
+        let shareholders = |purpose, dealer: &Dealer| -> Vec<PurposeShareholder<Group>> {
+            let vm = dealer.verification_matrix();
+            (1..=3)
+                .map(PrimeField::from_u64)
+                .map(|x| VerifiableSecretShare::new(dealer.make_share(x, kind), vm.clone()).into())
+                .map(|sh| PurposeShareholder::new(purpose, sh))
+                .collect()
+        };
+
+        let dealer = Dealer::new(threshold, &mut OsRng).unwrap();
+        let vm = dealer.verification_matrix();
+        let pk = *vm.element(0, 0).unwrap();
+        let decryption = shareholders(KeyPurpose::Decryption, &dealer);
+        let evaluation = shareholders(
+            KeyPurpose::Evaluation,
+            &Dealer::new(threshold, &mut OsRng).unwrap(),
+        );
+        let ciphertext = Ciphertext::encrypt(b"message", &pk, b"context", &mut OsRng).unwrap();
+
+        // Blinded evaluations at the ephemeral key don't recover
+        // the shared secret.
+        let points: Vec<_> = evaluation
+            .iter()
+            .map(|sh| {
+                player
+                    .evaluate_blinded(sh, ciphertext.ephemeral_key())
+                    .unwrap()
+            })
+            .collect();
+        let shared = player.recover_key(&points).unwrap();
+        let result = ciphertext.decrypt(&shared, &pk, b"context");
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().to_string(), "decryption failed");
+
+        // Evaluations are rejected as decryption shares.
+        let shares: Vec<_> = evaluation
+            .iter()
+            .map(|sh| sh.shareholder().verifiable_share())
+            .map(|s| DecryptionShare::new(s.x(), s.y(), ciphertext.ephemeral_key(), &mut OsRng))
+            .collect();
+        let result = player.combine_decryption_shares(&shares, &ciphertext, vm, b"context");
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            format!(
+                "invalid decryption share of shareholder {}",
+                "0".repeat(94) + "01"
+            )
+        );
+
+        // Keys can't be used for another purpose.
+        let result = player.evaluate_blinded(&decryption[0], ciphertext.ephemeral_key());
+        assert!(result.is_err());
+        assert_eq!(
+            result.err().unwrap().to_string(),
+            "key purpose mismatch: expected Evaluation, got Decryption"
+        );
+        let result = player.decrypt_partial(&evaluation[0], &ciphertext, &mut OsRng);
+        assert!(result.is_err());
+        assert_eq!(
+            result.err().unwrap().to_string(),
+            "key purpose mismatch: expected Decryption, got Evaluation"
+        );
+    }
+}
@@ -0,0 +1,169 @@
+//! Hybrid encryption to public keys in the group of the suite.
+
+use chacha20poly1305::{
+    aead::{Aead, KeyInit, Payload},
+    ChaCha20Poly1305, Key, Nonce,
+};
+use group::{ff::Field, Group, GroupEncoding};
+use hkdf::Hkdf;
+use rand_core::RngCore;
+use sha2::Sha256;
+use zeroize::Zeroize;
+
+/// Message encrypted to a public key using ECIES.
+///
+/// The sender generates an ephemeral key pair `(e, E = e * G)`, computes
+/// the shared secret `S = e * PK` with the public key `PK`, derives
+/// a symmetric key from it using HKDF-SHA256 with the given domain
+/// separation tag, and encrypts the message using ChaCha20-Poly1305.
+///
+/// Since a fresh ephemeral key is generated for every message, every
+/// symmetric key is used only once, so a fixed nonce is used.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Envelope<G>
+where
+    G: Group,
+{
+    /// The ephemeral public key `E = e * G`.
+    ephemeral: G,
+    /// The authenticated ciphertext of the message.
+    ciphertext: Vec<u8>,
+}
+
+impl<G> Envelope<G>
+where
+    G: Group + GroupEncoding,
+    G::Scalar: Zeroize,
+{
+    /// Encrypts the given message to the given public key, authenticating
+    /// the given context together with the ciphertext.
+    ///
+    /// The public key must not be the identity.
+    pub(crate) fn seal(
+        message: &[u8],
+        pk: &G,
+        context: &[u8],
+        dst: &[u8],
+        rng: &mut impl RngCore,
+    ) -> Option<Self> {
+        let mut e = G::Scalar::random(rng);
+        let ephemeral = G::generator() * e;
+        let shared = *pk * e;
+        e.zeroize();
+
+        let cipher = Self::cipher(&shared, &ephemeral, pk, dst);
+        let payload = Payload {
+            msg: message,
+            aad: context,
+        };
+        let ciphertext = cipher.encrypt(&Nonce::default(), payload).ok()?;
+
+        Some(Self {
+            ephemeral,
+            ciphertext,
+        })
+    }
+
+    /// Decrypts the message using the given shared secret `S = sk * E`.
+    ///
+    /// Returns `None` if the message was encrypted to another public key,
+    /// in another context or under another domain separation tag, or if
+    /// the ciphertext was tampered with.
+    pub(crate) fn open(&self, shared: &G, pk: &G, context: &[u8], dst: &[u8]) -> Option<Vec<u8>> {
+        if self.ephemeral.is_identity().into() {
+            return None;
+        }
+
+        let cipher = Self::cipher(shared, &self.ephemeral, pk, dst);
+        let payload = Payload {
+            msg: &self.ciphertext,
+            aad: context,
+        };
+
+        cipher.decrypt(&Nonce::default(), payload).ok()
+    }
+
+    /// Returns the ephemeral public key.
+    pub(crate) fn ephemeral_key(&self) -> &G {
+        &self.ephemeral
+    }
+
+    /// Returns the byte representation of the envelope.
+    pub(crate) fn to_bytes(&self) -> Vec<u8> {
+        let ephemeral = self.ephemeral.to_bytes();
+        let mut bytes = Vec::with_capacity(ephemeral.as_ref().len() + self.ciphertext.len());
+        bytes.extend_from_slice(ephemeral.as_ref());
+        bytes.extend_from_slice(&self.ciphertext);
+        bytes
+    }
+
+    /// Attempts to create an envelope from its byte representation.
+    pub(crate) fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let mut repr: G::Repr = Default::default();
+        let size = repr.as_ref().len();
+        if bytes.len() <= size {
+            return None;
+        }
+
+        let (ephemeral, ciphertext) = bytes.split_at(size);
+        repr.as_mut().copy_from_slice(ephemeral);
+        let ephemeral = Option::from(G::from_bytes(&repr))?;
+        let ciphertext = ciphertext.to_vec();
+
+        Some(Self {
+            ephemeral,
+            ciphertext,
+        })
+    }
+
+    /// Derives the symmetric cipher from the shared secret.
+    fn cipher(shared: &G, ephemeral: &G, pk: &G, dst: &[u8]) -> ChaCha20Poly1305 {
+        let mut ikm = shared.to_bytes();
+        let mut info = Vec::new();
+        info.extend_from_slice(ephemeral.to_bytes().as_ref());
+        info.extend_from_slice(pk.to_bytes().as_ref());
+
+        let hkdf = Hkdf::<Sha256>::new(Some(dst), ikm.as_ref());
+        ikm.as_mut().zeroize();
+
+        let mut key = Key::default();
+        hkdf.expand(&info, &mut key)
+            .expect("key size should be valid");
+        let cipher = ChaCha20Poly1305::new(&key);
+        key.zeroize();
+
+        cipher
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use group::{ff::Field, Group as _};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::Envelope;
+
+    type PrimeField = p384::Scalar;
+    type Group = p384::ProjectivePoint;
+
+    #[test]
+    fn test_envelope() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let sk = PrimeField::random(&mut rng);
+        let pk = Group::generator() * sk;
+
+        let envelope = Envelope::seal(b"message", &pk, b"context", b"dst", &mut rng).unwrap();
+        let shared = *envelope.ephemeral_key() * sk;
+        let message = envelope.open(&shared, &pk, b"context", b"dst").unwrap();
+        assert_eq!(message, b"message");
+
+        // Different domain separation tag or context.
+        assert!(envelope.open(&shared, &pk, b"context", b"other").is_none());
+        assert!(envelope.open(&shared, &pk, b"other", b"dst").is_none());
+
+        // Serialization.
+        let decoded = Envelope::<Group>::from_bytes(&envelope.to_bytes()).unwrap();
+        assert_eq!(decoded, envelope);
+        assert!(Envelope::<Group>::from_bytes(&envelope.to_bytes()[..49]).is_none());
+    }
+}
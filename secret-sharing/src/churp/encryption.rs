@@ -0,0 +1,184 @@
+//! Encryption of secret shares for transport.
+
+use anyhow::Result;
+use group::{ff::PrimeField, Group, GroupEncoding};
+use rand_core::RngCore;
+use zeroize::Zeroize;
+
+use crate::poly::Polynomial;
+
+use super::{ecies::Envelope, Error, SecretShare};
+
+/// Domain separation tag for the derivation of share encryption keys.
+const SHARE_ENCRYPTION_DST: &[u8] = b"CHURP-ShareEncryption";
+
+/// Secret share encrypted to the public key of a shareholder.
+///
+/// Shares are encrypted using ECIES over the group of the suite:
+/// the dealer generates an ephemeral key pair `(e, E = e * G)`, computes
+/// the shared secret `S = e * PK` with the public key `PK = sk * G`
+/// of the shareholder, derives a symmetric key from it using HKDF-SHA256,
+/// and encrypts the share using ChaCha20-Poly1305. The shareholder recovers
+/// the same shared secret as `S = sk * E`.
+///
+/// Since a fresh ephemeral key is generated for every share, every symmetric
+/// key is used only once, so a fixed nonce is used. The given context,
+/// e.g. the handoff epoch and the identity of the dealer, is authenticated
+/// together with the ciphertext, so that shares cannot be replayed
+/// in another context.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EncryptedShare<G>
+where
+    G: Group,
+{
+    /// The encrypted share.
+    envelope: Envelope<G>,
+}
+
+impl<G> EncryptedShare<G>
+where
+    G: Group + GroupEncoding,
+    G::Scalar: Zeroize,
+{
+    /// Encrypts the given share to the given public key of a shareholder.
+    pub fn encrypt(
+        share: &SecretShare<G::Scalar>,
+        pk: &G,
+        context: &[u8],
+        rng: &mut impl RngCore,
+    ) -> Result<Self> {
+        if pk.is_identity().into() {
+            return Err(Error::InvalidPublicKey.into());
+        }
+
+        let mut plaintext = Self::encode(share);
+        let envelope = Envelope::seal(&plaintext, pk, context, SHARE_ENCRYPTION_DST, rng);
+        plaintext.zeroize();
+        let envelope = envelope.ok_or(Error::ShareEncryptionFailed)?;
+
+        Ok(Self { envelope })
+    }
+
+    /// Decrypts the share using the given secret key of the shareholder.
+    ///
+    /// Fails if the share was encrypted to another public key
+    /// or in another context, or if the ciphertext was tampered with.
+    pub fn decrypt(&self, sk: &G::Scalar, context: &[u8]) -> Result<SecretShare<G::Scalar>> {
+        let pk = G::generator() * sk;
+        let shared = *self.envelope.ephemeral_key() * sk;
+
+        let mut plaintext = self
+            .envelope
+            .open(&shared, &pk, context, SHARE_ENCRYPTION_DST)
+            .ok_or(Error::ShareDecryptionFailed)?;
+        let share = Self::decode(&plaintext);
+        plaintext.zeroize();
+
+        share.ok_or(Error::ShareDecryptionFailed.into())
+    }
+
+    /// Returns the ephemeral public key.
+    pub fn ephemeral_key(&self) -> &G {
+        self.envelope.ephemeral_key()
+    }
+
+    /// Returns the byte representation of the encrypted share.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.envelope.to_bytes()
+    }
+
+    /// Attempts to create an encrypted share from its byte representation.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let envelope = Envelope::from_bytes(bytes)?;
+        Some(Self { envelope })
+    }
+
+    /// Encodes the share as `x || P`.
+    fn encode(share: &SecretShare<G::Scalar>) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(share.x().to_repr().as_ref());
+        bytes.extend_from_slice(&share.polynomial().to_bytes());
+        bytes
+    }
+
+    /// Decodes the share from `x || P`.
+    fn decode(bytes: &[u8]) -> Option<SecretShare<G::Scalar>> {
+        let mut repr = <G::Scalar as PrimeField>::Repr::default();
+        let size = repr.as_ref().len();
+        if bytes.len() <= size {
+            return None;
+        }
+
+        let (x, p) = bytes.split_at(size);
+        repr.as_mut().copy_from_slice(x);
+        let x = Option::from(G::Scalar::from_repr(repr))?;
+        let p = Polynomial::from_bytes(p)?;
+
+        Some(SecretShare::new(x, p))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use group::{ff::Field, Group as _};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::poly::Polynomial;
+
+    use super::{EncryptedShare, SecretShare};
+
+    type PrimeField = p384::Scalar;
+    type Group = p384::ProjectivePoint;
+
+    #[test]
+    fn test_encryption() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let sk = PrimeField::random(&mut rng);
+        let pk = Group::generator() * sk;
+
+        let x = PrimeField::from_u64(5);
+        let p = Polynomial::random(4, &mut rng);
+        let share = SecretShare::new(x, p.clone());
+
+        let encrypted = EncryptedShare::encrypt(&share, &pk, b"context", &mut rng).unwrap();
+        let decrypted = encrypted.decrypt(&sk, b"context").unwrap();
+        assert_eq!(decrypted.x(), &x);
+        assert!(decrypted.polynomial() == &p);
+
+        // Fresh ephemeral keys.
+        let other = EncryptedShare::encrypt(&share, &pk, b"context", &mut rng).unwrap();
+        assert_ne!(encrypted.ephemeral_key(), other.ephemeral_key());
+        assert_ne!(encrypted, other);
+
+        // Serialization.
+        let restored = EncryptedShare::<Group>::from_bytes(&encrypted.to_bytes()).unwrap();
+        assert_eq!(encrypted, restored);
+        assert!(EncryptedShare::<Group>::from_bytes(&encrypted.to_bytes()[..49]).is_none());
+
+        // Wrong context.
+        let result = encrypted.decrypt(&sk, b"other context");
+        assert!(result.is_err());
+        assert_eq!(result.err().unwrap().to_string(), "share decryption failed");
+
+        // Wrong secret key.
+        let result = encrypted.decrypt(&PrimeField::random(&mut rng), b"context");
+        assert!(result.is_err());
+
+        // Tampered ciphertext.
+        let mut bytes = encrypted.to_bytes();
+        let last = bytes.len() - 1;
+        bytes[last] ^= 1;
+        let tampered = EncryptedShare::<Group>::from_bytes(&bytes).unwrap();
+        assert!(tampered.decrypt(&sk, b"context").is_err());
+    }
+
+    #[test]
+    fn test_invalid_public_key() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let share = SecretShare::new(PrimeField::ONE, Polynomial::random(2, &mut rng));
+
+        let result = EncryptedShare::encrypt(&share, &Group::identity(), b"context", &mut rng);
+        assert!(result.is_err());
+        assert_eq!(result.err().unwrap().to_string(), "invalid public key");
+    }
+}
@@ -1,49 +1,193 @@
+use std::fmt::Write;
+
+use crate::vss::ShareVerificationFailure;
+
+use super::{HandoffPhase, KeyPurpose};
+
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
+    #[error("batch size mismatch: expected {expected}, got {actual}")]
+    BatchSizeMismatch { expected: usize, actual: usize },
+    #[error("committee mismatch")]
+    CommitteeMismatch,
+    #[error("committee required")]
+    CommitteeRequired,
+    #[error("committee overlap too small: expected at least {expected}, got {actual}")]
+    CommitteeOverlapTooSmall { expected: usize, actual: usize },
+    #[error("committee too small: expected at least {expected}, got {actual}")]
+    CommitteeTooSmall { expected: usize, actual: usize },
+    #[error(
+        "conflicting switch points of shareholder {}: {} and {}",
+        hex(.shareholder),
+        hex(.accepted),
+        hex(.conflicting)
+    )]
+    ConflictingSwitchPoints {
+        shareholder: Vec<u8>,
+        accepted: Vec<u8>,
+        conflicting: Vec<u8>,
+    },
+    #[error("deadline of the {phase:?} phase exceeded")]
+    DeadlineExceeded { phase: HandoffPhase },
+    #[error("dealer package mismatch")]
+    DealerPackageMismatch,
+    #[error("decryption failed")]
+    DecryptionFailed,
+    #[error("duplicate instance")]
+    DuplicateInstance,
     #[error("duplicate shareholder")]
     DuplicateShareholder,
+    #[error("empty batch")]
+    EmptyBatch,
+    #[error("encryption failed")]
+    EncryptionFailed,
+    #[error("epoch mismatch: expected {expected}, got {actual}")]
+    EpochMismatch { expected: u64, actual: u64 },
+    #[error("fresh committee required: {overlap} shareholders of the old committee remain")]
+    FreshCommitteeRequired { overlap: usize },
+    #[error("invalid polynomial degree in x: expected {expected}, got {actual}")]
+    InvalidDegreeX { expected: usize, actual: usize },
+    #[error("invalid polynomial degree in y: expected {expected}, got {actual}")]
+    InvalidDegreeY { expected: usize, actual: usize },
+    #[error("invalid resharing of shareholder {shareholder}")]
+    InvalidResharing { shareholder: String },
+    #[error("invalid repair contribution of shareholder {shareholder}")]
+    InvalidRepairContribution { shareholder: String },
+    #[error("invalid share from dealer {index}")]
+    InvalidDealerShare { index: usize },
+    #[error("invalid backup")]
+    InvalidBackup,
+    #[error("invalid blinded element")]
+    InvalidBlindedElement,
+    #[error("invalid decryption share of shareholder {shareholder}")]
+    InvalidDecryptionShare { shareholder: String },
+    #[error("invalid share of shareholder {shareholder}: {failure}")]
+    InvalidShare {
+        shareholder: String,
+        failure: ShareVerificationFailure,
+    },
+    #[error("invalid share possession proof of shareholder {shareholder}")]
+    InvalidSharePossessionProof { shareholder: String },
+    #[error("invalid share point of shareholder {shareholder}")]
+    InvalidSharePoint { shareholder: String },
+    #[error("invalid evaluation of shareholder {shareholder}")]
+    InvalidEvaluation { shareholder: String },
     #[error("invalid handoff kind")]
     InvalidKind,
+    #[error("invalid partial signature of shareholder {shareholder}")]
+    InvalidPartialSignature { shareholder: String },
     #[error("invalid polynomial")]
     InvalidPolynomial,
+    #[error("invalid polynomial degrees")]
+    InvalidPolynomialDegrees,
+    #[error("invalid public key")]
+    InvalidPublicKey,
+    #[error("invalid signature")]
+    InvalidSignature,
+    #[error("invalid shareholder")]
+    InvalidShareholder,
     #[error("insecure bivariate polynomial")]
     InsecureBivariatePolynomial,
     #[error("invalid switch point")]
     InvalidSwitchPoint,
+    #[error("invalid verification matrix")]
+    InvalidVerificationMatrix,
     #[error("invalid state")]
     InvalidState,
+    #[error("key purpose mismatch: expected {expected:?}, got {actual:?}")]
+    KeyPurposeMismatch {
+        expected: KeyPurpose,
+        actual: KeyPurpose,
+    },
+    #[error("new committee too small: expected at least {expected}, got {actual}")]
+    NewCommitteeTooSmall { expected: usize, actual: usize },
     #[error("not enough bivariate shares")]
     NotEnoughBivariateShares,
+    #[error("not enough dealers")]
+    NotEnoughDealers,
+    #[error("not enough shares: expected at least {expected}, got {actual}")]
+    NotEnoughShares { expected: usize, actual: usize },
     #[error("not enough shareholders")]
     NotEnoughShareholders,
     #[error("not enough switch points")]
     NotEnoughSwitchPoints,
+    #[error("memory protection failed")]
+    MemoryProtectionFailed,
     #[error("merging not finished")]
     MergingNotFinished,
+    #[error("old committee too small: expected at least {expected}, got {actual}")]
+    OldCommitteeTooSmall { expected: usize, actual: usize },
     #[error("polynomial degree mismatch")]
     PolynomialDegreeMismatch,
     #[error("polynomial generation failed")]
     PolynomialGenerationFailed,
+    #[error("repair mask mismatch")]
+    RepairMaskMismatch,
+    #[error("revelation mismatch")]
+    RevelationMismatch,
+    #[error("scheme mismatch")]
+    SchemeMismatch,
+    #[error("share decryption failed")]
+    ShareDecryptionFailed,
+    #[error("share encryption failed")]
+    ShareEncryptionFailed,
+    #[error("signature required")]
+    SignatureRequired,
     #[error("shareholder encoding failed")]
     ShareholderEncodingFailed,
+    #[error("shareholder ID collision")]
+    ShareholderIdCollision,
     #[error("shareholder proactivization already completed")]
     ShareholderProactivizationCompleted,
     #[error("shareholder identity mismatch")]
     ShareholderIdentityMismatch,
     #[error("shareholder identity required")]
     ShareholderIdentityRequired,
+    #[error("stale share from epoch {epoch}")]
+    StaleShare { epoch: u64 },
     #[error("threshold too large")]
     ThresholdTooLarge,
     #[error("too many switch points")]
     TooManySwitchPoints,
+    #[error("unexpected verification matrix dimensions: expected {expected:?}, got {actual:?}")]
+    UnexpectedVerificationMatrixDimensions {
+        expected: (usize, usize),
+        actual: (usize, usize),
+    },
+    #[error("unknown epoch {epoch}")]
+    UnknownEpoch { epoch: u64 },
+    #[error("unknown instance")]
+    UnknownInstance,
     #[error("unknown shareholder")]
     UnknownShareholder,
+    #[error("unknown suite")]
+    UnknownSuite,
+    #[error("unresolved accusations")]
+    UnresolvedAccusations,
+    #[error("unsupported backup version {version}")]
+    UnsupportedBackupVersion { version: u8 },
+    #[error("unsupported snapshot version {version}")]
+    UnsupportedSnapshotVersion { version: u8 },
+    #[error("unsupported state version {version}")]
+    UnsupportedStateVersion { version: u8 },
     #[error("verification matrix dimension mismatch")]
     VerificationMatrixDimensionMismatch,
     #[error("verification matrix zero-hole mismatch")]
     VerificationMatrixZeroHoleMismatch,
+    #[error("verification matrix mismatch")]
+    VerificationMatrixMismatch,
     #[error("verification matrix required")]
     VerificationMatrixRequired,
+    #[error("zero value secret")]
+    ZeroValueSecret,
     #[error("zero value shareholder")]
     ZeroValueShareholder,
 }
+
+/// Returns the hex encoding of the given bytes.
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::new(), |mut hex, b| {
+        let _ = write!(hex, "{:02x}", b);
+        hex
+    })
+}
@@ -0,0 +1,287 @@
+//! Evidence of misbehavior.
+
+use std::convert::TryInto;
+
+use anyhow::Result;
+use sha3::{Digest, Sha3_256};
+
+use super::{
+    package::{append_bytes, Reader},
+    signing::signing_bytes,
+    DealerSigner, DealerVerifier, Error,
+};
+
+/// Domain separation tag for signatures of evidence bundles.
+const EVIDENCE_BUNDLE_SIGNATURE_DST: &[u8] = b"CHURP-EvidenceBundleSignature";
+
+/// The size of the hash of an invalid payload.
+pub const PAYLOAD_HASH_SIZE: usize = 32;
+
+/// The maximum number of misbehavior records kept by a player.
+pub const MAX_EVIDENCE_RECORDS: usize = 1024;
+
+/// A record of an invalid point or polynomial received from a shareholder.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Misbehavior {
+    /// The epoch in which the payload was received.
+    pub epoch: u64,
+    /// The byte representation of the identity of the sender.
+    pub sender: Vec<u8>,
+    /// The SHA3-256 hash of the byte representation of the payload.
+    pub payload_hash: [u8; PAYLOAD_HASH_SIZE],
+    /// The reason why the payload was rejected.
+    pub reason: String,
+}
+
+impl Misbehavior {
+    /// Creates a new record of the given invalid payload.
+    pub fn new(epoch: u64, sender: Vec<u8>, payload: &[u8], reason: String) -> Self {
+        let payload_hash = Sha3_256::digest(payload).into();
+
+        Self {
+            epoch,
+            sender,
+            payload_hash,
+            reason,
+        }
+    }
+
+    /// Appends the byte representation of the record.
+    fn write_to(&self, bytes: &mut Vec<u8>) {
+        bytes.extend_from_slice(&self.epoch.to_le_bytes());
+        append_bytes(bytes, &self.sender);
+        bytes.extend_from_slice(&self.payload_hash);
+        append_bytes(bytes, self.reason.as_bytes());
+    }
+
+    /// Consumes the byte representation of a record.
+    fn read_from(reader: &mut Reader<'_>) -> Option<Self> {
+        let epoch = reader.take_u64()?;
+        let sender = reader.take_prefixed()?.to_vec();
+        let payload_hash = reader.take(PAYLOAD_HASH_SIZE)?.try_into().ok()?;
+        let reason = String::from_utf8(reader.take_prefixed()?.to_vec()).ok()?;
+
+        Some(Self {
+            epoch,
+            sender,
+            payload_hash,
+            reason,
+        })
+    }
+}
+
+/// A bounded log of misbehavior records, deduplicated by sender, epoch
+/// and reason.
+///
+/// Once the log is full, records of the oldest epoch are evicted first,
+/// and new records older than all kept records are dropped.
+#[derive(Default)]
+pub(crate) struct EvidenceLog {
+    records: Vec<Misbehavior>,
+}
+
+impl EvidenceLog {
+    /// Returns the recorded misbehavior in the order of recording.
+    pub(crate) fn records(&self) -> &[Misbehavior] {
+        &self.records
+    }
+
+    /// Records the given misbehavior unless the same sender has already
+    /// been recorded for the same reason in the same epoch.
+    pub(crate) fn record(&mut self, record: Misbehavior) {
+        let duplicate = self.records.iter().any(|r| {
+            r.epoch == record.epoch && r.sender == record.sender && r.reason == record.reason
+        });
+        if duplicate {
+            return;
+        }
+
+        if self.records.len() >= MAX_EVIDENCE_RECORDS {
+            let oldest = self
+                .records
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, r)| r.epoch)
+                .map(|(i, _)| i)
+                .expect("log should not be empty");
+            if self.records[oldest].epoch > record.epoch {
+                return;
+            }
+            self.records.remove(oldest);
+        }
+
+        self.records.push(record);
+    }
+
+    /// Removes and returns all records.
+    pub(crate) fn drain(&mut self) -> Vec<Misbehavior> {
+        std::mem::take(&mut self.records)
+    }
+
+    /// Removes all records of epochs before the given epoch.
+    pub(crate) fn prune(&mut self, epoch: u64) {
+        self.records.retain(|r| r.epoch >= epoch);
+    }
+}
+
+/// A bundle of misbehavior records signed by the reporting node, which can
+/// be published to justify slashing or removal of faulty committee members.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EvidenceBundle {
+    /// The misbehavior records.
+    records: Vec<Misbehavior>,
+    /// The signature of the reporting node.
+    signature: Vec<u8>,
+}
+
+impl EvidenceBundle {
+    /// Signs the given misbehavior records.
+    pub fn sign(records: Vec<Misbehavior>, signer: &impl DealerSigner) -> Result<Self> {
+        let signature = signer.sign(&Self::signed_message(&records))?;
+
+        Ok(Self { records, signature })
+    }
+
+    /// Returns the misbehavior records.
+    pub fn records(&self) -> &[Misbehavior] {
+        &self.records
+    }
+
+    /// Returns the signature of the reporting node.
+    pub fn signature(&self) -> &[u8] {
+        &self.signature
+    }
+
+    /// Verifies the signature of the reporting node.
+    pub fn verify(&self, verifier: &impl DealerVerifier) -> Result<()> {
+        verifier
+            .verify(&Self::signed_message(&self.records), &self.signature)
+            .map_err(|_| Error::InvalidSignature.into())
+    }
+
+    /// Returns the byte representation of the bundle.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Self::records_bytes(&self.records);
+        append_bytes(&mut bytes, &self.signature);
+        bytes
+    }
+
+    /// Attempts to create a bundle from its byte representation.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let mut reader = Reader { bytes };
+
+        let n = reader.take_u32()? as usize;
+        let mut records = Vec::with_capacity(n.min(bytes.len()));
+        for _ in 0..n {
+            records.push(Misbehavior::read_from(&mut reader)?);
+        }
+        let signature = reader.take_prefixed()?.to_vec();
+
+        if !reader.bytes.is_empty() {
+            return None;
+        }
+
+        Some(Self { records, signature })
+    }
+
+    /// Returns the byte representation of the given records.
+    fn records_bytes(records: &[Misbehavior]) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&(records.len() as u32).to_le_bytes());
+        for record in records {
+            record.write_to(&mut bytes);
+        }
+        bytes
+    }
+
+    /// Returns the message signed by the reporting node.
+    fn signed_message(records: &[Misbehavior]) -> Vec<u8> {
+        signing_bytes(
+            EVIDENCE_BUNDLE_SIGNATURE_DST,
+            &[],
+            &Self::records_bytes(records),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::churp::signing::tests::TestSigner;
+
+    use super::{EvidenceBundle, EvidenceLog, Misbehavior, MAX_EVIDENCE_RECORDS};
+
+    #[test]
+    fn test_evidence_bundle() {
+        let signer = TestSigner(b"key".to_vec());
+        let records = vec![
+            Misbehavior::new(1, vec![1], b"payload", "invalid point".to_string()),
+            Misbehavior::new(2, vec![2, 3], b"other payload", "invalid share".to_string()),
+        ];
+
+        let bundle = EvidenceBundle::sign(records.clone(), &signer).unwrap();
+        assert_eq!(bundle.records(), &records[..]);
+        bundle.verify(&signer).unwrap();
+
+        let decoded = EvidenceBundle::from_bytes(&bundle.to_bytes()).unwrap();
+        assert_eq!(decoded, bundle);
+        decoded.verify(&signer).unwrap();
+
+        // Different key.
+        let result = bundle.verify(&TestSigner(b"other key".to_vec()));
+        assert!(result.is_err());
+        assert_eq!(result.err().unwrap().to_string(), "invalid signature");
+
+        // Tampered records.
+        let mut tampered = bundle.clone();
+        tampered.records.pop();
+        assert!(tampered.verify(&signer).is_err());
+
+        // Trailing bytes.
+        let mut bytes = bundle.to_bytes();
+        bytes.push(0);
+        assert!(EvidenceBundle::from_bytes(&bytes).is_none());
+    }
+
+    #[test]
+    fn test_evidence_log() {
+        let record = |epoch: u64, sender: u8, reason: &str| {
+            Misbehavior::new(epoch, vec![sender], b"payload", reason.to_string())
+        };
+        let mut log = EvidenceLog::default();
+
+        // Records are deduplicated by sender, epoch and reason.
+        log.record(record(2, 1, "invalid point"));
+        log.record(Misbehavior::new(
+            2,
+            vec![1],
+            b"other payload",
+            "invalid point".to_string(),
+        ));
+        log.record(record(2, 1, "invalid share"));
+        log.record(record(3, 1, "invalid point"));
+        log.record(record(2, 2, "invalid point"));
+        assert_eq!(log.records().len(), 4);
+
+        // Records of old epochs can be pruned.
+        log.prune(3);
+        assert_eq!(log.records(), &[record(3, 1, "invalid point")]);
+
+        // Records of the oldest epoch are evicted once the log is full.
+        for i in 0..MAX_EVIDENCE_RECORDS - 1 {
+            log.record(record(4, 0, &i.to_string()));
+        }
+        assert_eq!(log.records().len(), MAX_EVIDENCE_RECORDS);
+        log.record(record(5, 0, "invalid point"));
+        assert_eq!(log.records().len(), MAX_EVIDENCE_RECORDS);
+        assert!(log.records().iter().all(|r| r.epoch >= 4));
+
+        // Records older than all kept records are dropped.
+        log.record(record(3, 0, "invalid point"));
+        assert!(log.records().iter().all(|r| r.epoch >= 4));
+
+        let records = log.drain();
+        assert_eq!(records.len(), MAX_EVIDENCE_RECORDS);
+        assert_eq!(records.last(), Some(&record(5, 0, "invalid point")));
+        assert!(log.records().is_empty());
+    }
+}
@@ -1,12 +1,19 @@
 use std::sync::Arc;
 
 use anyhow::Result;
-use group::Group;
+use group::{ff::Field, Group};
+use rand_core::RngCore;
 use zeroize::Zeroize;
 
-use crate::vss::VerificationMatrix;
+use crate::{
+    poly::lagrange,
+    vss::{VerificationMatrix, VerificationVector},
+};
 
-use super::{DimensionSwitch, Error, Shareholder, SwitchPoint, VerifiableSecretShare};
+use super::{
+    Accusation, DimensionSwitch, Error, Player, Revelation, SecretShare, Shareholder, SwitchPoint,
+    SwitchPointReport, SwitchState, SwitchStatus, Verdict, VerifiableSecretShare,
+};
 
 /// Handoff kind.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -61,6 +68,15 @@ pub enum HandoffKind {
     /// back to a (t, n)-threshold scheme by exchanging full share distribution
     /// points and constructing full shares.
     CommitteeChanged,
+    /// The emergency handoff is an out-of-schedule committee change,
+    /// triggered after a suspected compromise of the current committee.
+    ///
+    /// It runs the same phases as the handoff where the committee changes,
+    /// but the new committee must be fresh, i.e. disjoint from the old one,
+    /// so that the compromised shareholders don't receive new shares.
+    /// Shares of the new committee are tagged with the emergency kind,
+    /// so that stale shares created before the incident can be rejected.
+    Emergency,
 }
 
 impl HandoffKind {
@@ -75,7 +91,108 @@ impl HandoffKind {
             HandoffKind::DealingPhase => false,
             HandoffKind::CommitteeUnchanged => true,
             HandoffKind::CommitteeChanged => true,
+            HandoffKind::Emergency => true,
+        }
+    }
+
+    /// Indicates whether the shared secret is transferred to a new committee
+    /// using share reduction and full share distribution.
+    pub fn changes_committee(&self) -> bool {
+        match &self {
+            HandoffKind::DealingPhase => false,
+            HandoffKind::CommitteeUnchanged => false,
+            HandoffKind::CommitteeChanged => true,
+            HandoffKind::Emergency => true,
+        }
+    }
+
+    /// Validates the threshold together with the old and the new committee,
+    /// so that the handoff can't start with a configuration in which it is
+    /// bound to fail.
+    ///
+    /// The old committee is ignored in the dealing phase, where there is
+    /// no shared secret yet.
+    ///
+    /// The error reports the first constraint that isn't satisfied:
+    /// - the members of both committees must be distinct and non-zero,
+    /// - in the dealing phase, the new committee must have at least t + 2
+    ///   members, so that t Byzantine dealers can't learn the secret,
+    /// - if the committee is unchanged, the new committee must have at least
+    ///   t + 2 members and all of them must be members of the old committee,
+    ///   as they proactivize the shares they already hold,
+    /// - if the committee changes, the old committee must have at least t + 1
+    ///   members, which is the reconstruction quorum of the share reduction
+    ///   polynomials, and the new committee must have at least 2t + 1
+    ///   members, which is the reconstruction quorum of the full share
+    ///   distribution polynomials,
+    /// - in the emergency handoff, the committees must additionally be
+    ///   disjoint, so that the compromised shareholders are replaced.
+    pub fn validate_committees<F: Field>(
+        &self,
+        threshold: u8,
+        old_committee: &[F],
+        new_committee: &[F],
+    ) -> Result<()> {
+        let threshold = threshold as usize;
+
+        let committees: &[&[F]] = match self {
+            HandoffKind::DealingPhase => &[new_committee],
+            _ => &[old_committee, new_committee],
+        };
+        for committee in committees {
+            for (i, x) in committee.iter().enumerate() {
+                if x.is_zero().into() {
+                    return Err(Error::ZeroValueShareholder.into());
+                }
+                if committee[..i].contains(x) {
+                    return Err(Error::DuplicateShareholder.into());
+                }
+            }
+        }
+
+        let (old_quorum, new_quorum) = match self {
+            HandoffKind::DealingPhase => (0, threshold + 2),
+            HandoffKind::CommitteeUnchanged => (0, threshold + 2),
+            HandoffKind::CommitteeChanged | HandoffKind::Emergency => {
+                (threshold + 1, 2 * threshold + 1)
+            }
+        };
+
+        if old_committee.len() < old_quorum {
+            return Err(Error::OldCommitteeTooSmall {
+                expected: old_quorum,
+                actual: old_committee.len(),
+            }
+            .into());
+        }
+        if new_committee.len() < new_quorum {
+            return Err(Error::NewCommitteeTooSmall {
+                expected: new_quorum,
+                actual: new_committee.len(),
+            }
+            .into());
+        }
+
+        let overlap = new_committee
+            .iter()
+            .filter(|x| old_committee.contains(x))
+            .count();
+
+        match self {
+            HandoffKind::CommitteeUnchanged if overlap < new_committee.len() => {
+                return Err(Error::CommitteeOverlapTooSmall {
+                    expected: new_committee.len(),
+                    actual: overlap,
+                }
+                .into());
+            }
+            HandoffKind::Emergency if overlap > 0 => {
+                return Err(Error::FreshCommitteeRequired { overlap }.into());
+            }
+            _ => (),
         }
+
+        Ok(())
     }
 }
 
@@ -120,6 +237,16 @@ where
         Err(Error::InvalidKind.into())
     }
 
+    /// Adds the given batch of switch points from multiple shareholders
+    /// to share reduction, verifying them with a single batched check.
+    fn add_share_reduction_switch_points(
+        &self,
+        _points: Vec<SwitchPoint<G::Scalar>>,
+        _rng: &mut dyn RngCore,
+    ) -> Result<SwitchPointReport<G::Scalar>> {
+        Err(Error::InvalidKind.into())
+    }
+
     /// Checks if full share distribution needs a switch point from the given
     /// shareholder.
     fn needs_full_share_distribution_switch_point(&self, _x: &G::Scalar) -> Result<bool> {
@@ -134,6 +261,17 @@ where
         Err(Error::InvalidKind.into())
     }
 
+    /// Adds the given batch of switch points from multiple shareholders
+    /// to full share distribution, verifying them with a single batched
+    /// check.
+    fn add_full_share_distribution_switch_points(
+        &self,
+        _points: Vec<SwitchPoint<G::Scalar>>,
+        _rng: &mut dyn RngCore,
+    ) -> Result<SwitchPointReport<G::Scalar>> {
+        Err(Error::InvalidKind.into())
+    }
+
     /// Checks if bivariate share is needed from the given shareholder.
     fn needs_bivariate_share(&self, _x: &G::Scalar) -> Result<bool> {
         Err(Error::InvalidKind.into())
@@ -148,6 +286,40 @@ where
         Err(Error::InvalidKind.into())
     }
 
+    /// Accuses the given dealer of sending an invalid bivariate share
+    /// and returns the accusation to be published, together with
+    /// the verification matrix the dealer committed to as evidence.
+    ///
+    /// Accusations can only be made during the complaint phase.
+    fn accuse_dealer(&self, _x: &G::Scalar, _vm: VerificationMatrix<G>) -> Result<Accusation<G>> {
+        Err(Error::InvalidKind.into())
+    }
+
+    /// Resolves the given published accusation using the share publicly
+    /// revealed by the accused dealer, if any.
+    ///
+    /// Every participant must resolve every accusation, so that all agree
+    /// on the set of disqualified dealers.
+    fn resolve_accusation(
+        &self,
+        _accusation: &Accusation<G>,
+        _revelation: Option<&Revelation<G>>,
+    ) -> Result<Verdict> {
+        Err(Error::InvalidKind.into())
+    }
+
+    /// Closes the complaint phase, e.g. once its deadline has passed.
+    ///
+    /// Returns true if all bivariate shares have been received
+    /// and the handoff transitioned to the next state.
+    fn close_complaint_phase(&self) -> Result<bool> {
+        Err(Error::InvalidKind.into())
+    }
+
+    /// Returns the progress of the dimension switch currently in progress,
+    /// or of the last one if the handoff has completed.
+    fn status(&self) -> Result<SwitchStatus<G::Scalar>>;
+
     /// Returns the shareholder resulting from share reduction.
     fn get_reduced_shareholder(&self) -> Result<Arc<Shareholder<G>>> {
         Err(Error::InvalidKind.into())
@@ -178,6 +350,18 @@ where
     /// Creates a new handoff where the given shareholders will generate
     /// a random secret and receive corresponding secret shares.
     pub fn new(threshold: u8, me: G::Scalar, shareholders: Vec<G::Scalar>) -> Result<Self> {
+        Self::new_with_complaints(threshold, me, shareholders, false)
+    }
+
+    /// Creates a new handoff with an optional complaint phase, in which
+    /// dealers of invalid bivariate shares are disqualified before
+    /// the shares are combined.
+    pub fn new_with_complaints(
+        threshold: u8,
+        me: G::Scalar,
+        shareholders: Vec<G::Scalar>,
+        complaints: bool,
+    ) -> Result<Self> {
         // The number of shareholders must be at least threshold t + 2,
         // ensuring that even if t Byzantine dealers reveal their secret,
         // an honest shareholder cannot compute the combined bivariate
@@ -188,7 +372,8 @@ where
 
         let zero_hole = HandoffKind::DealingPhase.require_zero_hole();
         let share_distribution =
-            DimensionSwitch::new_full_share_distribution(threshold, zero_hole, me, shareholders)?;
+            DimensionSwitch::new_full_share_distribution(threshold, zero_hole, me, shareholders)?
+                .with_complaints(complaints);
 
         share_distribution.skip_accumulating()?;
         share_distribution.start_merging(None)?;
@@ -215,9 +400,30 @@ where
             .add_bivariate_share(x, verifiable_share)
     }
 
+    fn accuse_dealer(&self, x: &G::Scalar, vm: VerificationMatrix<G>) -> Result<Accusation<G>> {
+        self.share_distribution.accuse_dealer(x, vm)
+    }
+
+    fn resolve_accusation(
+        &self,
+        accusation: &Accusation<G>,
+        revelation: Option<&Revelation<G>>,
+    ) -> Result<Verdict> {
+        self.share_distribution
+            .resolve_accusation(accusation, revelation)
+    }
+
+    fn close_complaint_phase(&self) -> Result<bool> {
+        self.share_distribution.close_complaints()
+    }
+
     fn get_full_shareholder(&self) -> Result<Arc<Shareholder<G>>> {
         self.share_distribution.get_shareholder()
     }
+
+    fn status(&self) -> Result<SwitchStatus<G::Scalar>> {
+        Ok(self.share_distribution.status())
+    }
 }
 
 /// A handoff where the committee remains the same. During this handoff,
@@ -240,13 +446,26 @@ where
     /// Creates a new handoff where the secret shares of the given shareholders
     /// will be randomized.
     pub fn new(threshold: u8, me: G::Scalar, shareholders: Vec<G::Scalar>) -> Result<Self> {
+        Self::new_with_complaints(threshold, me, shareholders, false)
+    }
+
+    /// Creates a new handoff with an optional complaint phase, in which
+    /// dealers of invalid bivariate shares are disqualified before
+    /// the shares are combined.
+    pub fn new_with_complaints(
+        threshold: u8,
+        me: G::Scalar,
+        shareholders: Vec<G::Scalar>,
+        complaints: bool,
+    ) -> Result<Self> {
         if shareholders.len() < threshold as usize + 1 {
             return Err(Error::NotEnoughShareholders.into());
         }
 
         let zero_hole = HandoffKind::CommitteeUnchanged.require_zero_hole();
         let share_distribution =
-            DimensionSwitch::new_full_share_distribution(threshold, zero_hole, me, shareholders)?;
+            DimensionSwitch::new_full_share_distribution(threshold, zero_hole, me, shareholders)?
+                .with_complaints(complaints);
 
         share_distribution.skip_accumulating()?;
 
@@ -280,9 +499,30 @@ where
             .add_bivariate_share(x, verifiable_share)
     }
 
+    fn accuse_dealer(&self, x: &G::Scalar, vm: VerificationMatrix<G>) -> Result<Accusation<G>> {
+        self.share_distribution.accuse_dealer(x, vm)
+    }
+
+    fn resolve_accusation(
+        &self,
+        accusation: &Accusation<G>,
+        revelation: Option<&Revelation<G>>,
+    ) -> Result<Verdict> {
+        self.share_distribution
+            .resolve_accusation(accusation, revelation)
+    }
+
+    fn close_complaint_phase(&self) -> Result<bool> {
+        self.share_distribution.close_complaints()
+    }
+
     fn get_full_shareholder(&self) -> Result<Arc<Shareholder<G>>> {
         self.share_distribution.get_shareholder()
     }
+
+    fn status(&self) -> Result<SwitchStatus<G::Scalar>> {
+        Ok(self.share_distribution.status())
+    }
 }
 
 /// A handoff where the committee changes. During this handoff, committee
@@ -307,13 +547,26 @@ where
     /// Creates a new handoff where the shared secret will be transferred
     /// to a new committee composed of the given shareholders.
     pub fn new(threshold: u8, me: G::Scalar, shareholders: Vec<G::Scalar>) -> Result<Self> {
+        Self::new_with_complaints(threshold, me, shareholders, false)
+    }
+
+    /// Creates a new handoff with an optional complaint phase, in which
+    /// dealers of invalid bivariate shares are disqualified before
+    /// the shares are combined.
+    pub fn new_with_complaints(
+        threshold: u8,
+        me: G::Scalar,
+        shareholders: Vec<G::Scalar>,
+        complaints: bool,
+    ) -> Result<Self> {
         if shareholders.len() < threshold as usize + 1 {
             return Err(Error::NotEnoughShareholders.into());
         }
 
         let zero_hole = HandoffKind::CommitteeChanged.require_zero_hole();
         let share_reduction =
-            DimensionSwitch::new_share_reduction(threshold, zero_hole, me, shareholders)?;
+            DimensionSwitch::new_share_reduction(threshold, zero_hole, me, shareholders)?
+                .with_complaints(complaints);
         let share_distribution =
             DimensionSwitch::new_full_share_distribution(threshold, zero_hole, me, Vec::new())?;
 
@@ -322,6 +575,19 @@ where
             share_distribution,
         })
     }
+
+    /// Starts full share distribution using the verification matrix
+    /// of the reduced shareholder.
+    fn start_full_share_distribution(&self) -> Result<()> {
+        let vm = self
+            .share_reduction
+            .get_shareholder()?
+            .verifiable_share()
+            .verification_matrix()
+            .clone();
+
+        self.share_distribution.start_accumulating(vm)
+    }
 }
 
 impl<G> Handoff<G> for CommitteeChanged<G>
@@ -345,6 +611,14 @@ where
         self.share_reduction.add_switch_point(point)
     }
 
+    fn add_share_reduction_switch_points(
+        &self,
+        points: Vec<SwitchPoint<G::Scalar>>,
+        mut rng: &mut dyn RngCore,
+    ) -> Result<SwitchPointReport<G::Scalar>> {
+        self.share_reduction.add_switch_points(points, &mut rng)
+    }
+
     fn needs_full_share_distribution_switch_point(&self, x: &G::Scalar) -> Result<bool> {
         self.share_distribution.needs_switch_point(x)
     }
@@ -356,6 +630,14 @@ where
         self.share_distribution.add_switch_point(point)
     }
 
+    fn add_full_share_distribution_switch_points(
+        &self,
+        points: Vec<SwitchPoint<G::Scalar>>,
+        mut rng: &mut dyn RngCore,
+    ) -> Result<SwitchPointReport<G::Scalar>> {
+        self.share_distribution.add_switch_points(points, &mut rng)
+    }
+
     fn needs_bivariate_share(&self, x: &G::Scalar) -> Result<bool> {
         self.share_reduction.needs_bivariate_share(x)
     }
@@ -371,14 +653,31 @@ where
 
         // Start full share distribution if share reduction has completed.
         if done {
-            let vm = self
-                .share_reduction
-                .get_shareholder()?
-                .verifiable_share()
-                .verification_matrix()
-                .clone();
+            self.start_full_share_distribution()?;
+        }
+
+        Ok(done)
+    }
 
-            self.share_distribution.start_accumulating(vm)?;
+    fn accuse_dealer(&self, x: &G::Scalar, vm: VerificationMatrix<G>) -> Result<Accusation<G>> {
+        self.share_reduction.accuse_dealer(x, vm)
+    }
+
+    fn resolve_accusation(
+        &self,
+        accusation: &Accusation<G>,
+        revelation: Option<&Revelation<G>>,
+    ) -> Result<Verdict> {
+        self.share_reduction
+            .resolve_accusation(accusation, revelation)
+    }
+
+    fn close_complaint_phase(&self) -> Result<bool> {
+        let done = self.share_reduction.close_complaints()?;
+
+        // Start full share distribution if share reduction has completed.
+        if done {
+            self.start_full_share_distribution()?;
         }
 
         Ok(done)
@@ -391,6 +690,147 @@ where
     fn get_full_shareholder(&self) -> Result<Arc<Shareholder<G>>> {
         self.share_distribution.get_shareholder()
     }
+
+    fn status(&self) -> Result<SwitchStatus<G::Scalar>> {
+        let status = self.share_reduction.status();
+        if status.state != SwitchState::Serving {
+            return Ok(status);
+        }
+
+        Ok(self.share_distribution.status())
+    }
+}
+
+/// A handoff where the threshold changes. During this handoff, a quorum
+/// of the old committee reshares the shared secret to the new committee
+/// using a bivariate polynomial of the new degrees, so the threshold
+/// can be both increased and decreased.
+///
+/// Every old shareholder `i` of the quorum deals a random bivariate
+/// polynomial `B_i(x,y)` of the new degrees with `B_i(0,0)` set to its
+/// share of the secret (see `Dealer::new_with_secret`). Since the shares
+/// of the secret are points on a polynomial of the old degree, the new
+/// bivariate polynomial `\sum_i L_i(0) * B_i(x,y)` shares the same secret,
+/// where `L_i(0)` are the Lagrange coefficients of the quorum. The value
+/// committed to by every dealer is verified against the verification
+/// matrix of the old committee, so a quorum of at least old threshold + 1
+/// dealers is required.
+pub struct ThresholdChange<G>
+where
+    G: Group,
+    G::Scalar: Zeroize,
+{
+    /// The quorum of the old committee dealing bivariate shares.
+    dealers: Vec<G::Scalar>,
+
+    /// The Lagrange coefficients of the dealers.
+    coefficients: Vec<G::Scalar>,
+
+    /// The verification vector of the secret-sharing polynomial
+    /// of the old committee.
+    secret_sharing_vector: VerificationVector<G>,
+
+    /// The share distribution phase of the handoff.
+    share_distribution: DimensionSwitch<G>,
+}
+
+impl<G> ThresholdChange<G>
+where
+    G: Group,
+    G::Scalar: Zeroize,
+{
+    /// Creates a new handoff where the shared secret, committed to by
+    /// the given verification matrix of the old threshold, will be reshared
+    /// by the given quorum of the old committee using the new threshold.
+    pub fn new(
+        old_threshold: u8,
+        threshold: u8,
+        me: G::Scalar,
+        dealers: Vec<G::Scalar>,
+        vm: &VerificationMatrix<G>,
+    ) -> Result<Self> {
+        let old_player = Player::new(old_threshold, HandoffKind::CommitteeUnchanged);
+        old_player.verify_dimensions(vm)?;
+
+        // The shares of the quorum must determine the secret-sharing
+        // polynomial of the old degree.
+        if dealers.len() < old_threshold as usize + 1 {
+            return Err(Error::NotEnoughDealers.into());
+        }
+        for (i, x) in dealers.iter().enumerate() {
+            if x.is_zero().into() {
+                return Err(Error::ZeroValueShareholder.into());
+            }
+            if dealers[..i].contains(x) {
+                return Err(Error::DuplicateShareholder.into());
+            }
+        }
+
+        let coefficients = lagrange::coefficients(&dealers);
+        let secret_sharing_vector = vm.verification_vector_for_x(&G::Scalar::ZERO);
+
+        // The dealt polynomials share non-zero values, so they can't have
+        // a zero hole.
+        let share_distribution =
+            DimensionSwitch::new_full_share_distribution(threshold, false, me, dealers.clone())?;
+
+        share_distribution.skip_accumulating()?;
+        share_distribution.start_merging(None)?;
+
+        Ok(Self {
+            dealers,
+            coefficients,
+            secret_sharing_vector,
+            share_distribution,
+        })
+    }
+}
+
+impl<G> Handoff<G> for ThresholdChange<G>
+where
+    G: Group,
+    G::Scalar: Zeroize,
+{
+    fn needs_bivariate_share(&self, x: &G::Scalar) -> Result<bool> {
+        self.share_distribution.needs_bivariate_share(x)
+    }
+
+    fn add_bivariate_share(
+        &self,
+        x: &G::Scalar,
+        verifiable_share: VerifiableSecretShare<G>,
+    ) -> Result<bool> {
+        let index = self
+            .dealers
+            .iter()
+            .position(|y| y == x)
+            .ok_or(Error::UnknownShareholder)?;
+
+        // The dealt polynomial must share the share of the dealer.
+        let expected = self.secret_sharing_vector.eval(x);
+        let actual = verifiable_share.verification_matrix().element(0, 0);
+        if actual != Some(&expected) {
+            return Err(Error::InvalidResharing {
+                shareholder: Player::shareholder(x),
+            }
+            .into());
+        }
+
+        let c = &self.coefficients[index];
+        let share = SecretShare::new(*verifiable_share.x(), verifiable_share.polynomial() * c);
+        let vm = verifiable_share.verification_matrix() * c;
+
+        self.share_distribution
+            .add_bivariate_share(x, VerifiableSecretShare::new(share, vm))
+    }
+
+    fn get_full_shareholder(&self) -> Result<Arc<Shareholder<G>>> {
+        self.share_distribution.get_shareholder()
+    }
+
+    fn status(&self) -> Result<SwitchStatus<G::Scalar>> {
+        Ok(self.share_distribution.status())
+    }
 }
 
 #[cfg(test)]
@@ -400,7 +840,10 @@ mod tests {
     use rand::{rngs::StdRng, RngCore, SeedableRng};
 
     use crate::{
-        churp::{self, Handoff, HandoffKind, SwitchPoint, VerifiableSecretShare},
+        churp::{
+            self, Handoff, HandoffKind, Revelation, SecretShare, SwitchPoint, Verdict,
+            VerifiableSecretShare,
+        },
         suites::{self, p384},
     };
 
@@ -412,6 +855,7 @@ mod tests {
     type DealingPhase = churp::DealingPhase<Group>;
     type CommitteeUnchanged = churp::CommitteeUnchanged<Group>;
     type CommitteeChanged = churp::CommitteeChanged<Group>;
+    type ThresholdChange = churp::ThresholdChange<Group>;
 
     fn prepare_shareholders(ids: &[u64]) -> Vec<PrimeField> {
         ids.into_iter().map(|&id| id.into()).collect()
@@ -671,4 +1115,310 @@ mod tests {
 
         verify_shareholders(&shareholders, threshold, true);
     }
+
+    #[test]
+    fn test_complaints() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let threshold = 2;
+        let kind = HandoffKind::DealingPhase;
+        let committee = prepare_shareholders(&[1, 2, 3, 4, 5]);
+        let dealers = prepare_dealers(threshold, true, committee.len(), &mut rng);
+        let handoffs: Vec<_> = committee
+            .iter()
+            .map(|alice| {
+                DealingPhase::new_with_complaints(threshold, *alice, committee.clone(), true)
+                    .unwrap()
+            })
+            .collect();
+
+        // Dealer 4 deals an invalid share to shareholder 1, but reveals
+        // a valid one when accused, while dealer 5 deals an invalid share
+        // to shareholder 2 and doesn't reveal it.
+        let cheats = |alice: usize, bob: usize| matches!((alice, bob), (0, 3) | (1, 4));
+        let mut accusations = Vec::new();
+
+        for (i, (alice, handoff)) in zip(committee.iter(), handoffs.iter()).enumerate() {
+            for (j, (bob, dealer)) in zip(committee.iter(), dealers.iter()).enumerate() {
+                let mut share = dealer.make_share(*alice, kind);
+                if cheats(i, j) {
+                    let mut p = share.polynomial().clone();
+                    p.set_coefficient(0, PrimeField::ONE);
+                    share = SecretShare::new(*alice, p);
+                }
+                let vm = dealer.verification_matrix().clone();
+                let verifiable_share = VerifiableSecretShare::new(share, vm.clone());
+
+                match handoff.add_bivariate_share(bob, verifiable_share) {
+                    // Merging waits for the complaint phase to close.
+                    Ok(done) => assert!(!done),
+                    Err(_) => {
+                        assert!(cheats(i, j));
+                        let accusation = handoff.accuse_dealer(bob, vm).unwrap();
+                        assert!(!handoff.needs_bivariate_share(bob).unwrap());
+                        accusations.push((j, accusation));
+                    }
+                }
+            }
+        }
+        assert_eq!(accusations.len(), 2);
+
+        // Accusations can't be left unresolved.
+        let result = handoffs[0].close_complaint_phase();
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().to_string(), "unresolved accusations");
+
+        // Everyone resolves all published accusations.
+        for (j, accusation) in accusations.iter() {
+            let revelation = match j {
+                3 => Some(Revelation::new(
+                    committee[*j],
+                    dealers[*j].make_share(*accusation.accuser(), kind),
+                )),
+                _ => None,
+            };
+            let expected = match j {
+                3 => Verdict::Dismissed,
+                _ => Verdict::Disqualified,
+            };
+
+            for handoff in handoffs.iter() {
+                let verdict = handoff
+                    .resolve_accusation(accusation, revelation.as_ref())
+                    .unwrap();
+                assert_eq!(verdict, expected);
+            }
+        }
+
+        // Accusations of disqualified dealers can't be resolved again.
+        assert!(handoffs[0]
+            .resolve_accusation(&accusations[1].1, None)
+            .is_err());
+
+        let shareholders: Vec<_> = handoffs
+            .iter()
+            .map(|handoff| {
+                assert!(handoff.close_complaint_phase().unwrap());
+                handoff.get_full_shareholder().unwrap()
+            })
+            .collect();
+
+        verify_shareholders(&shareholders, threshold, true);
+
+        // The secret is shared only by the qualified dealers.
+        let expected: Vec<_> = committee
+            .iter()
+            .map(|x| {
+                dealers[..4]
+                    .iter()
+                    .map(|dealer| dealer.make_share(*x, kind).polynomial().clone())
+                    .reduce(|a, b| a + b)
+                    .unwrap()
+            })
+            .collect();
+        for (shareholder, p) in zip(shareholders.iter(), expected.iter()) {
+            assert!(shareholder.verifiable_share().polynomial() == p);
+        }
+
+        // Too many disqualified dealers.
+        let handoff =
+            DealingPhase::new_with_complaints(threshold, committee[0], committee.clone(), true)
+                .unwrap();
+        for (j, dealer) in dealers.iter().enumerate().take(2) {
+            let vm = dealer.verification_matrix().clone();
+            let accusation = handoff.accuse_dealer(&committee[j], vm).unwrap();
+            let result = handoff.resolve_accusation(&accusation, None);
+            match j {
+                0 => assert_eq!(result.unwrap(), Verdict::Disqualified),
+                _ => assert_eq!(result.unwrap_err().to_string(), "not enough dealers"),
+            }
+        }
+
+        // Accusations require the complaint phase.
+        let handoff = DealingPhase::new(threshold, committee[0], committee.clone()).unwrap();
+        let vm = dealers[0].verification_matrix().clone();
+        assert!(handoff.accuse_dealer(&committee[0], vm).is_err());
+    }
+
+    #[test]
+    fn test_threshold_change() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let threshold = 2;
+        let kind = HandoffKind::DealingPhase;
+
+        // Share a secret with the old threshold.
+        let committee = prepare_shareholders(&[1, 2, 3, 4]);
+        let dealer = Dealer::new(threshold, &mut rng).unwrap();
+        let vm = dealer.verification_matrix().clone();
+        let shares: Vec<_> = committee
+            .iter()
+            .map(|x| dealer.make_share(*x, kind))
+            .collect();
+        let secret = vm.element(0, 0).unwrap();
+
+        // Increase and decrease the threshold.
+        for new_threshold in [4, 1] {
+            let new_committee = prepare_shareholders(&[5, 6, 7, 8, 9, 10]);
+            let quorum = &committee[1..];
+            let dealers: Vec<_> = shares[1..]
+                .iter()
+                .map(|share| Dealer::new_with_secret(new_threshold, *share.y(), &mut rng).unwrap())
+                .collect();
+
+            let shareholders: Vec<_> = new_committee
+                .iter()
+                .map(|alice| {
+                    let handoff = ThresholdChange::new(
+                        threshold,
+                        new_threshold,
+                        *alice,
+                        quorum.to_vec(),
+                        &vm,
+                    )
+                    .unwrap();
+
+                    for (j, (bob, dealer)) in zip(quorum.iter(), dealers.iter()).enumerate() {
+                        let share = dealer.make_share(*alice, kind);
+                        let vm = dealer.verification_matrix().clone();
+                        let verifiable_share = VerifiableSecretShare::new(share, vm);
+
+                        assert!(handoff.needs_bivariate_share(bob).unwrap());
+                        let done = handoff.add_bivariate_share(bob, verifiable_share).unwrap();
+                        assert_eq!(done, j + 1 == dealers.len());
+                    }
+
+                    handoff.get_full_shareholder().unwrap()
+                })
+                .collect();
+
+            verify_shareholders(&shareholders, new_threshold, true);
+
+            // The new committee shares the same secret.
+            for shareholder in shareholders.iter() {
+                let vm = shareholder.verifiable_share().verification_matrix();
+                assert_eq!(vm.element(0, 0), Some(secret));
+            }
+        }
+
+        // Resharing of a different share.
+        let quorum = committee[1..].to_vec();
+        let handoff = ThresholdChange::new(threshold, 3, 5u64.into(), quorum.clone(), &vm).unwrap();
+        let dealer = Dealer::new_with_secret(3, *shares[0].y(), &mut rng).unwrap();
+        let share = dealer.make_share(5u64.into(), kind);
+        let verifiable_share =
+            VerifiableSecretShare::new(share, dealer.verification_matrix().clone());
+        let result = handoff.add_bivariate_share(&quorum[0], verifiable_share);
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .starts_with("invalid resharing of shareholder"));
+
+        // Not enough dealers.
+        let result = ThresholdChange::new(threshold, 3, 5u64.into(), committee[2..].to_vec(), &vm);
+        assert!(result.is_err());
+        assert_eq!(result.err().unwrap().to_string(), "not enough dealers");
+
+        // Duplicate dealers.
+        let dealers = prepare_shareholders(&[1, 2, 2]);
+        let result = ThresholdChange::new(threshold, 3, 5u64.into(), dealers, &vm);
+        assert!(result.is_err());
+        assert_eq!(result.err().unwrap().to_string(), "duplicate shareholder");
+
+        // Verification matrix of a different threshold.
+        let result = ThresholdChange::new(3, 2, 5u64.into(), committee.clone(), &vm);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_validate_committees() {
+        let threshold = 2;
+        let validate = |kind: HandoffKind, old: &[u64], new: &[u64]| {
+            kind.validate_committees(
+                threshold,
+                &prepare_shareholders(old),
+                &prepare_shareholders(new),
+            )
+            .map_err(|err| err.to_string())
+        };
+
+        // Valid configurations.
+        validate(HandoffKind::DealingPhase, &[], &[1, 2, 3, 4]).unwrap();
+        validate(
+            HandoffKind::CommitteeUnchanged,
+            &[1, 2, 3, 4, 5],
+            &[1, 2, 3, 4],
+        )
+        .unwrap();
+        validate(HandoffKind::CommitteeChanged, &[1, 2, 3], &[1, 2, 3, 4, 5]).unwrap();
+        validate(HandoffKind::CommitteeChanged, &[1, 2, 3], &[6, 7, 8, 9, 10]).unwrap();
+        validate(HandoffKind::Emergency, &[1, 2, 3], &[6, 7, 8, 9, 10]).unwrap();
+
+        // Invalid configurations.
+        let tests = [
+            (
+                HandoffKind::DealingPhase,
+                &[][..],
+                &[1, 2, 3][..],
+                "new committee too small: expected at least 4, got 3",
+            ),
+            (
+                HandoffKind::DealingPhase,
+                &[][..],
+                &[1, 2, 3, 0][..],
+                "zero value shareholder",
+            ),
+            (
+                HandoffKind::CommitteeUnchanged,
+                &[1, 2, 3, 4][..],
+                &[1, 2, 3][..],
+                "new committee too small: expected at least 4, got 3",
+            ),
+            (
+                HandoffKind::CommitteeUnchanged,
+                &[1, 2, 3, 4][..],
+                &[1, 2, 3, 5][..],
+                "committee overlap too small: expected at least 4, got 3",
+            ),
+            (
+                HandoffKind::CommitteeUnchanged,
+                &[1, 1, 2, 3][..],
+                &[1, 2, 3, 4][..],
+                "duplicate shareholder",
+            ),
+            (
+                HandoffKind::CommitteeChanged,
+                &[1, 2][..],
+                &[1, 2, 3, 4, 5][..],
+                "old committee too small: expected at least 3, got 2",
+            ),
+            (
+                HandoffKind::CommitteeChanged,
+                &[1, 2, 3][..],
+                &[4, 5, 6, 7][..],
+                "new committee too small: expected at least 5, got 4",
+            ),
+            (
+                HandoffKind::CommitteeChanged,
+                &[1, 2, 3][..],
+                &[4, 5, 6, 7, 7][..],
+                "duplicate shareholder",
+            ),
+            (
+                HandoffKind::Emergency,
+                &[1, 2, 3][..],
+                &[4, 5, 6, 7][..],
+                "new committee too small: expected at least 5, got 4",
+            ),
+            (
+                HandoffKind::Emergency,
+                &[1, 2, 3][..],
+                &[3, 4, 5, 6, 7][..],
+                "fresh committee required: 1 shareholders of the old committee remain",
+            ),
+        ];
+
+        for (kind, old, new, err) in tests {
+            assert_eq!(validate(kind, old, new).unwrap_err(), err);
+        }
+    }
 }
@@ -0,0 +1,225 @@
+//! Protection of memory holding sensitive data.
+//!
+//! With the `mlock` feature enabled on Unix, the pages holding shares
+//! and derived secrets are locked into memory, so that they are never
+//! swapped to disk, and core dumps can be disabled for the process.
+//! Otherwise, the protections are no-ops, e.g. inside SGX enclaves,
+//! where enclave memory is never swapped in plaintext.
+
+use anyhow::Result;
+
+#[cfg(all(feature = "mlock", unix))]
+use std::{
+    collections::BTreeMap,
+    ops::Range,
+    sync::{Mutex, OnceLock},
+};
+
+#[cfg(all(feature = "mlock", unix))]
+use super::Error;
+
+/// The number of locks holding each locked page, keyed by page address.
+///
+/// Since `munlock` doesn't nest, a page is only unlocked once the last
+/// lock holding it is released, so that buffers sharing a page don't
+/// unlock each other.
+#[cfg(all(feature = "mlock", unix))]
+static LOCKED_PAGES: Mutex<BTreeMap<usize, usize>> = Mutex::new(BTreeMap::new());
+
+/// Returns the page size of the system.
+#[cfg(all(feature = "mlock", unix))]
+fn page_size() -> usize {
+    static PAGE_SIZE: OnceLock<usize> = OnceLock::new();
+
+    // SAFETY: The name is a valid configuration variable.
+    *PAGE_SIZE.get_or_init(|| match unsafe { libc::sysconf(libc::_SC_PAGESIZE) } {
+        size if size > 0 => size as usize,
+        _ => 4096,
+    })
+}
+
+/// Returns the addresses of the pages spanning the given buffer.
+#[cfg(all(feature = "mlock", unix))]
+fn pages(ptr: usize, len: usize) -> impl Iterator<Item = usize> {
+    let size = page_size();
+    let start = ptr - ptr % size;
+    let end = ptr + len;
+
+    (start..end).step_by(size)
+}
+
+/// A lock of the pages holding a buffer, which is released when dropped.
+///
+/// Locking is best-effort, as the amount of memory that can be locked
+/// is usually limited, e.g. by `RLIMIT_MEMLOCK`, so whether the pages
+/// were locked can be queried with [`MemoryLock::is_locked`]. Locks are
+/// reference-counted per page, so overlapping locks can be released
+/// in any order.
+pub(crate) struct MemoryLock {
+    /// The addresses of the locked pages, empty if locking failed.
+    #[cfg(all(feature = "mlock", unix))]
+    pages: Range<usize>,
+    /// True if and only if all pages holding the buffer are locked.
+    locked: bool,
+}
+
+impl MemoryLock {
+    /// Locks the pages holding the given buffer.
+    ///
+    /// The buffer must not be reallocated while the lock is held.
+    #[cfg_attr(not(all(feature = "mlock", unix)), allow(unused_variables))]
+    pub(crate) fn new<T>(data: &[T]) -> Self {
+        #[cfg(all(feature = "mlock", unix))]
+        {
+            let len = std::mem::size_of_val(data);
+            if len == 0 {
+                return Self {
+                    pages: 0..0,
+                    locked: true,
+                };
+            }
+
+            let ptr = data.as_ptr() as usize;
+            let size = page_size();
+            let mut counts = LOCKED_PAGES.lock().unwrap();
+
+            let mut locked = Vec::new();
+            for page in pages(ptr, len) {
+                let count = counts.entry(page).or_insert(0);
+                // SAFETY: The page belongs to a valid allocation.
+                if *count == 0 && unsafe { libc::mlock(page as *const libc::c_void, size) } != 0 {
+                    counts.remove(&page);
+                    Self::release(&mut counts, locked);
+                    return Self {
+                        pages: 0..0,
+                        locked: false,
+                    };
+                }
+                *counts.get_mut(&page).unwrap() += 1;
+                locked.push(page);
+            }
+
+            let start = locked[0];
+            Self {
+                pages: start..start + locked.len() * size,
+                locked: true,
+            }
+        }
+
+        #[cfg(not(all(feature = "mlock", unix)))]
+        Self { locked: false }
+    }
+
+    /// Returns true if and only if all pages holding the buffer are locked
+    /// into memory.
+    pub(crate) fn is_locked(&self) -> bool {
+        self.locked
+    }
+
+    /// Decrements the lock counts of the given pages, unlocking the pages
+    /// which are no longer held by any lock.
+    #[cfg(all(feature = "mlock", unix))]
+    fn release(counts: &mut BTreeMap<usize, usize>, pages: impl IntoIterator<Item = usize>) {
+        for page in pages {
+            let count = counts.get_mut(&page).expect("page should be locked");
+            *count -= 1;
+            if *count == 0 {
+                counts.remove(&page);
+                // SAFETY: Unlocking pages doesn't access their contents.
+                unsafe {
+                    libc::munlock(page as *const libc::c_void, page_size());
+                }
+            }
+        }
+    }
+}
+
+impl Drop for MemoryLock {
+    fn drop(&mut self) {
+        #[cfg(all(feature = "mlock", unix))]
+        if !self.pages.is_empty() {
+            let mut counts = LOCKED_PAGES.lock().unwrap();
+            Self::release(&mut counts, self.pages.clone().step_by(page_size()));
+        }
+    }
+}
+
+/// Disables core dumps for the current process, so that shares
+/// and derived secrets cannot leak through them.
+///
+/// This is a no-op unless the `mlock` feature is enabled on Unix.
+pub fn disable_core_dumps() -> Result<()> {
+    #[cfg(all(feature = "mlock", unix))]
+    {
+        let limit = libc::rlimit {
+            rlim_cur: 0,
+            rlim_max: 0,
+        };
+        // SAFETY: The limit is a valid structure.
+        if unsafe { libc::setrlimit(libc::RLIMIT_CORE, &limit) } != 0 {
+            return Err(Error::MemoryProtectionFailed.into());
+        }
+
+        #[cfg(target_os = "linux")]
+        // SAFETY: The option doesn't take pointers.
+        if unsafe { libc::prctl(libc::PR_SET_DUMPABLE, 0, 0, 0, 0) } != 0 {
+            return Err(Error::MemoryProtectionFailed.into());
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MemoryLock;
+
+    #[test]
+    fn test_memory_lock() {
+        let data = vec![1u8; 100];
+        let lock = MemoryLock::new(&data);
+        drop(lock);
+
+        let lock = MemoryLock::new::<u8>(&[]);
+        drop(lock);
+    }
+
+    #[cfg(all(feature = "mlock", unix))]
+    #[test]
+    fn test_memory_lock_nested() {
+        use super::{page_size, LOCKED_PAGES};
+
+        // A buffer spanning two whole pages not shared with other tests.
+        let size = page_size();
+        let data = vec![0u8; 4 * size];
+        let offset = size - data.as_ptr() as usize % size;
+        let buffer = &data[offset..offset + 2 * size];
+        let (first, second) = buffer.split_at(size);
+        let page = first.as_ptr() as usize;
+
+        let count = |page: usize| LOCKED_PAGES.lock().unwrap().get(&page).copied();
+
+        // Overlapping locks can be released in any order.
+        let lock = MemoryLock::new(buffer);
+        if !lock.is_locked() {
+            // Locking is not permitted, e.g. due to RLIMIT_MEMLOCK.
+            assert_eq!(count(page), None);
+            return;
+        }
+        let first_lock = MemoryLock::new(&first[..10]);
+        let second_lock = MemoryLock::new(second);
+        assert_eq!(count(page), Some(2));
+        assert_eq!(count(page + size), Some(2));
+
+        drop(lock);
+        assert_eq!(count(page), Some(1));
+        assert_eq!(count(page + size), Some(1));
+
+        drop(second_lock);
+        assert_eq!(count(page), Some(1));
+        assert_eq!(count(page + size), None);
+
+        drop(first_lock);
+        assert_eq!(count(page), None);
+    }
+}
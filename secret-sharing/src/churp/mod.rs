@@ -1,11 +1,41 @@
 //! CHUrn-Robust Proactive secret sharing.
 
+mod aggregation;
+mod backup;
+mod batch;
+mod blinding;
+mod cache;
+mod complaint;
 mod dealer;
+mod decryption;
+mod ecies;
+mod encryption;
 mod errors;
+mod evidence;
 mod handoff;
+mod memory;
+mod package;
 mod player;
+mod players;
+mod possession;
+mod purpose;
+mod registry;
+mod repair;
+mod response;
+mod sensitive;
+mod session;
 mod shareholder;
+mod signing;
 mod switch;
+mod tagged;
+mod threshold;
+mod transcript;
 
 // Re-exports.
-pub use self::{dealer::*, errors::*, handoff::*, player::*, shareholder::*, switch::*};
+pub use self::{
+    aggregation::*, backup::*, batch::*, blinding::*, cache::*, complaint::*, dealer::*,
+    decryption::*, encryption::*, errors::*, evidence::*, handoff::*, memory::disable_core_dumps,
+    package::*, player::*, players::*, possession::*, purpose::*, registry::*, repair::*,
+    response::*, sensitive::*, session::*, shareholder::*, signing::*, switch::*, tagged::*,
+    threshold::*, transcript::*,
+};
@@ -0,0 +1,665 @@
+//! Dealer packages.
+
+use std::convert::TryInto;
+
+use anyhow::Result;
+use group::{ff::PrimeField, Group, GroupEncoding};
+use sha3::{Digest, Sha3_256};
+use zeroize::Zeroize;
+
+use crate::{suites::FieldDigest, vss::VerificationMatrix};
+
+use super::{
+    encode_shareholders,
+    signing::{signing_bytes, DEALER_PACKAGE_SIGNATURE_DST},
+    DealerSigner, DealerVerifier, EncryptedShare, Error, HandoffKind, SecretShare,
+};
+
+/// The size of a committee digest in bytes.
+pub const COMMITTEE_DIGEST_SIZE: usize = 32;
+
+/// Digest of the identities of the members of a committee.
+pub type CommitteeDigest = [u8; COMMITTEE_DIGEST_SIZE];
+
+/// Domain separation tag for committee digests.
+const COMMITTEE_DIGEST_DST: &[u8] = b"CHURP-CommitteeDigest";
+
+/// Computes the digest of the committee with the given shareholder IDs.
+///
+/// The IDs are sorted before hashing, so the digest doesn't depend
+/// on their order.
+///
+/// Fails if the IDs are not unique.
+pub fn committee_digest<I: AsRef<[u8]>>(ids: &[I]) -> Result<CommitteeDigest> {
+    let mut ids: Vec<&[u8]> = ids.iter().map(AsRef::as_ref).collect();
+    ids.sort_unstable();
+
+    if ids.windows(2).any(|w| w[0] == w[1]) {
+        return Err(Error::DuplicateShareholder.into());
+    }
+
+    let mut hasher = Sha3_256::new();
+    hasher.update(COMMITTEE_DIGEST_DST);
+    hasher.update((ids.len() as u32).to_le_bytes());
+    for id in ids {
+        hasher.update((id.len() as u32).to_le_bytes());
+        hasher.update(id);
+    }
+
+    Ok(hasher.finalize().into())
+}
+
+/// Dealer package is the unit that a dealer broadcasts during a handoff.
+///
+/// It bundles the verification matrix of the dealer with the shares
+/// of all shareholders, each encrypted to the public key of its recipient,
+/// together with the handoff kind and epoch, and an optional signature
+/// of the dealer.
+///
+/// The dealer can also bind the package to the intended committee
+/// by including the digest of its shareholder IDs, so that the shares
+/// cannot be silently redirected to a committee other than the approved one.
+///
+/// Encrypted shares are kept sorted by the byte representation of the
+/// shareholder identities, so that every package has exactly one byte
+/// representation. The signature, if any, is computed over the canonical
+/// byte representation of the package without the signature, returned
+/// by `signing_bytes`, prefixed with a domain separation tag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DealerPackage<G>
+where
+    G: Group + GroupEncoding,
+{
+    /// The handoff epoch.
+    epoch: u64,
+    /// The handoff kind.
+    kind: HandoffKind,
+    /// The digest of the intended committee.
+    committee: Option<CommitteeDigest>,
+    /// The verification matrix of the dealer.
+    vm: VerificationMatrix<G>,
+    /// Encrypted shares, sorted by shareholder identity.
+    shares: Vec<(G::Scalar, EncryptedShare<G>)>,
+    /// The signature of the dealer.
+    signature: Option<Vec<u8>>,
+}
+
+impl<G> DealerPackage<G>
+where
+    G: Group + GroupEncoding,
+    G::Scalar: Zeroize,
+{
+    /// Creates a new unsigned dealer package.
+    ///
+    /// Fails if the shareholder identities are not unique.
+    pub fn new(
+        epoch: u64,
+        kind: HandoffKind,
+        vm: VerificationMatrix<G>,
+        mut shares: Vec<(G::Scalar, EncryptedShare<G>)>,
+    ) -> Result<Self> {
+        shares.sort_by_cached_key(|(x, _)| x.to_repr().as_ref().to_vec());
+
+        if shares.windows(2).any(|w| w[0].0 == w[1].0) {
+            return Err(Error::DuplicateShareholder.into());
+        }
+
+        Ok(Self {
+            epoch,
+            kind,
+            committee: None,
+            vm,
+            shares,
+            signature: None,
+        })
+    }
+
+    /// Returns the handoff epoch.
+    pub fn epoch(&self) -> u64 {
+        self.epoch
+    }
+
+    /// Returns the handoff kind.
+    pub fn kind(&self) -> HandoffKind {
+        self.kind
+    }
+
+    /// Returns the verification matrix of the dealer.
+    pub fn verification_matrix(&self) -> &VerificationMatrix<G> {
+        &self.vm
+    }
+
+    /// Returns the encrypted shares, sorted by shareholder identity.
+    pub fn shares(&self) -> &[(G::Scalar, EncryptedShare<G>)] {
+        &self.shares
+    }
+
+    /// Returns the encrypted share of the given shareholder, if any.
+    pub fn share(&self, x: &G::Scalar) -> Option<&EncryptedShare<G>> {
+        self.shares.iter().find(|(xi, _)| xi == x).map(|(_, s)| s)
+    }
+
+    /// Decrypts the share of the given shareholder using its secret key.
+    ///
+    /// Fails if the package doesn't contain a share for the shareholder,
+    /// or if the share cannot be decrypted, or if the decrypted share
+    /// belongs to another shareholder.
+    pub fn decrypt_share(
+        &self,
+        x: &G::Scalar,
+        sk: &G::Scalar,
+        context: &[u8],
+    ) -> Result<SecretShare<G::Scalar>> {
+        let share = self
+            .share(x)
+            .ok_or(Error::UnknownShareholder)?
+            .decrypt(sk, context)?;
+
+        if share.x() != x {
+            return Err(Error::ShareholderIdentityMismatch.into());
+        }
+
+        Ok(share)
+    }
+
+    /// Returns the digest of the intended committee, if any.
+    pub fn committee(&self) -> Option<&CommitteeDigest> {
+        self.committee.as_ref()
+    }
+
+    /// Binds the package to the committee with the given shareholder IDs.
+    ///
+    /// Since the committee digest is signed, this removes the signature
+    /// of the dealer, if any.
+    ///
+    /// Fails if the IDs are not unique.
+    pub fn bind_committee<I: AsRef<[u8]>>(&mut self, ids: &[I]) -> Result<()> {
+        self.committee = Some(committee_digest(ids)?);
+        self.signature = None;
+        Ok(())
+    }
+
+    /// Verifies that the package is bound to the committee with the given
+    /// shareholder IDs, and that all shares are addressed to its members.
+    ///
+    /// The IDs are encoded to elements of the prime field using the given
+    /// domain separation tag.
+    pub fn verify_committee<H, I>(&self, ids: &[I], dst: &[u8]) -> Result<()>
+    where
+        H: FieldDigest<Output = G::Scalar>,
+        I: AsRef<[u8]>,
+    {
+        let committee = self.committee.ok_or(Error::CommitteeRequired)?;
+        if committee != committee_digest(ids)? {
+            return Err(Error::CommitteeMismatch.into());
+        }
+
+        let xs = encode_shareholders::<H, I>(ids, dst)?;
+        if self.shares.iter().any(|(x, _)| !xs.contains(x)) {
+            return Err(Error::UnknownShareholder.into());
+        }
+
+        Ok(())
+    }
+
+    /// Returns the signature of the dealer, if any.
+    pub fn signature(&self) -> Option<&[u8]> {
+        self.signature.as_deref()
+    }
+
+    /// Sets the signature of the dealer.
+    pub fn set_signature(&mut self, signature: Vec<u8>) {
+        self.signature = Some(signature);
+    }
+
+    /// Removes the signature of the dealer.
+    pub fn clear_signature(&mut self) {
+        self.signature = None;
+    }
+
+    /// Signs the package with the long-term key of the dealer.
+    ///
+    /// The signature is computed over the canonical byte representation
+    /// of the package without the signature, prefixed with a domain
+    /// separation tag.
+    pub fn sign(&mut self, signer: &impl DealerSigner) -> Result<()> {
+        let signature = signer.sign(&self.signed_message())?;
+        self.signature = Some(signature);
+        Ok(())
+    }
+
+    /// Verifies the signature of the dealer.
+    pub fn verify_signature(&self, verifier: &impl DealerVerifier) -> Result<()> {
+        let signature = self.signature.as_ref().ok_or(Error::SignatureRequired)?;
+
+        verifier
+            .verify(&self.signed_message(), signature)
+            .map_err(|_| Error::InvalidSignature.into())
+    }
+
+    /// Verifies the signature of the dealer and decrypts the share
+    /// of the given shareholder using its secret key.
+    ///
+    /// This is the entry point for shareholders accepting shares,
+    /// as it prevents a network adversary from substituting the package
+    /// of the dealer, e.g. the verification matrix.
+    pub fn open_share(
+        &self,
+        x: &G::Scalar,
+        sk: &G::Scalar,
+        context: &[u8],
+        verifier: &impl DealerVerifier,
+    ) -> Result<SecretShare<G::Scalar>> {
+        self.verify_signature(verifier)?;
+        self.decrypt_share(x, sk, context)
+    }
+
+    /// Returns the message signed by the dealer.
+    fn signed_message(&self) -> Vec<u8> {
+        signing_bytes(DEALER_PACKAGE_SIGNATURE_DST, &[], &self.signing_bytes())
+    }
+
+    /// Returns the canonical byte representation of the package without
+    /// the signature, which should be signed by the dealer.
+    pub fn signing_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&self.epoch.to_le_bytes());
+        bytes.push(encode_kind(self.kind));
+        match &self.committee {
+            Some(committee) => {
+                bytes.push(1);
+                bytes.extend_from_slice(committee);
+            }
+            None => bytes.push(0),
+        }
+        append_bytes(&mut bytes, &self.vm.to_bytes());
+
+        bytes.extend_from_slice(&(self.shares.len() as u32).to_le_bytes());
+        for (x, share) in self.shares.iter() {
+            bytes.extend_from_slice(x.to_repr().as_ref());
+            append_bytes(&mut bytes, &share.to_bytes());
+        }
+
+        bytes
+    }
+
+    /// Returns the canonical byte representation of the package.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = self.signing_bytes();
+        match &self.signature {
+            Some(signature) => {
+                bytes.push(1);
+                append_bytes(&mut bytes, signature);
+            }
+            None => bytes.push(0),
+        }
+
+        bytes
+    }
+
+    /// Attempts to create a package from its canonical byte representation.
+    ///
+    /// Returns `None` if the representation is malformed or not canonical.
+    ///
+    /// This method is not constant time since the package doesn't
+    /// contain sensitive information.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let mut reader = Reader { bytes };
+
+        let epoch = reader.take_u64()?;
+        let kind = decode_kind(reader.take(1)?[0])?;
+        let committee = match reader.take(1)?[0] {
+            0 => None,
+            1 => Some(reader.take(COMMITTEE_DIGEST_SIZE)?.try_into().ok()?),
+            _ => return None,
+        };
+        let vm = VerificationMatrix::from_bytes(reader.take_prefixed()?)?;
+
+        let n = reader.take_u32()? as usize;
+        let mut shares = Vec::with_capacity(n.min(bytes.len()));
+        let mut prev: Option<Vec<u8>> = None;
+        for _ in 0..n {
+            let mut repr = <G::Scalar as PrimeField>::Repr::default();
+            let size = repr.as_ref().len();
+            let chunk = reader.take(size)?;
+            if prev.as_deref().map_or(false, |prev| prev >= chunk) {
+                return None;
+            }
+            prev = Some(chunk.to_vec());
+
+            repr.as_mut().copy_from_slice(chunk);
+            let x = Option::from(G::Scalar::from_repr(repr))?;
+            let share = EncryptedShare::from_bytes(reader.take_prefixed()?)?;
+            shares.push((x, share));
+        }
+
+        let signature = match reader.take(1)?[0] {
+            0 => None,
+            1 => Some(reader.take_prefixed()?.to_vec()),
+            _ => return None,
+        };
+
+        if !reader.bytes.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            epoch,
+            kind,
+            committee,
+            vm,
+            shares,
+            signature,
+        })
+    }
+}
+
+/// Encodes the handoff kind as a byte.
+pub(crate) fn encode_kind(kind: HandoffKind) -> u8 {
+    match kind {
+        HandoffKind::DealingPhase => 0,
+        HandoffKind::CommitteeUnchanged => 1,
+        HandoffKind::CommitteeChanged => 2,
+        HandoffKind::Emergency => 3,
+    }
+}
+
+/// Decodes the handoff kind from a byte.
+pub(crate) fn decode_kind(byte: u8) -> Option<HandoffKind> {
+    match byte {
+        0 => Some(HandoffKind::DealingPhase),
+        1 => Some(HandoffKind::CommitteeUnchanged),
+        2 => Some(HandoffKind::CommitteeChanged),
+        3 => Some(HandoffKind::Emergency),
+        _ => None,
+    }
+}
+
+/// Appends the given bytes prefixed with their length.
+pub(crate) fn append_bytes(bytes: &mut Vec<u8>, data: &[u8]) {
+    bytes.extend_from_slice(&(data.len() as u32).to_le_bytes());
+    bytes.extend_from_slice(data);
+}
+
+/// Reader of length-prefixed byte representations.
+pub(crate) struct Reader<'a> {
+    pub(crate) bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    /// Consumes the given number of bytes.
+    pub(crate) fn take(&mut self, n: usize) -> Option<&'a [u8]> {
+        if self.bytes.len() < n {
+            return None;
+        }
+        let (head, tail) = self.bytes.split_at(n);
+        self.bytes = tail;
+        Some(head)
+    }
+
+    /// Consumes a little-endian 32-bit integer.
+    pub(crate) fn take_u32(&mut self) -> Option<u32> {
+        Some(u32::from_le_bytes(self.take(4)?.try_into().ok()?))
+    }
+
+    /// Consumes a little-endian 64-bit integer.
+    pub(crate) fn take_u64(&mut self) -> Option<u64> {
+        Some(u64::from_le_bytes(self.take(8)?.try_into().ok()?))
+    }
+
+    /// Consumes an element of the prime field in its canonical byte
+    /// representation.
+    pub(crate) fn take_scalar<F: PrimeField>(&mut self) -> Option<F> {
+        let mut repr = F::Repr::default();
+        let size = repr.as_ref().len();
+        repr.as_mut().copy_from_slice(self.take(size)?);
+        Option::from(F::from_repr(repr))
+    }
+
+    /// Consumes an element of the group in its canonical byte
+    /// representation.
+    pub(crate) fn take_element<G: GroupEncoding>(&mut self) -> Option<G> {
+        let mut repr = G::Repr::default();
+        let size = repr.as_ref().len();
+        repr.as_mut().copy_from_slice(self.take(size)?);
+        Option::from(G::from_bytes(&repr))
+    }
+
+    /// Consumes length-prefixed bytes.
+    pub(crate) fn take_prefixed(&mut self) -> Option<&'a [u8]> {
+        let n = self.take_u32()? as usize;
+        self.take(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use group::{ff::Field, Group as _};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::{
+        churp::{encode_shareholder, signing::tests::TestSigner, Dealer, HandoffKind},
+        suites::p384::Sha3_384,
+    };
+
+    use super::{committee_digest, DealerPackage};
+
+    type PrimeField = p384::Scalar;
+    type Group = p384::ProjectivePoint;
+
+    fn package(kind: HandoffKind) -> (DealerPackage<Group>, Vec<(PrimeField, PrimeField)>) {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let dealer = Dealer::<Group>::new(2, &mut rng).unwrap();
+
+        let keys: Vec<_> = (1..=3)
+            .rev()
+            .map(|x| (PrimeField::from_u64(x), PrimeField::random(&mut rng)))
+            .collect();
+        let recipients: Vec<_> = keys
+            .iter()
+            .map(|(x, sk)| (*x, Group::generator() * sk))
+            .collect();
+
+        let package = dealer
+            .make_package(7, kind, &recipients, b"context", &mut rng)
+            .unwrap();
+
+        (package, keys)
+    }
+
+    #[test]
+    fn test_package() {
+        let kind = HandoffKind::CommitteeChanged;
+        let (package, keys) = package(kind);
+        assert_eq!(package.epoch(), 7);
+        assert_eq!(package.kind(), kind);
+        assert_eq!(package.shares().len(), 3);
+        assert_eq!(package.signature(), None);
+
+        // Sorted by shareholder identity.
+        let xs: Vec<_> = package.shares().iter().map(|(x, _)| *x).collect();
+        assert_eq!(
+            xs,
+            vec![1, 2, 3]
+                .into_iter()
+                .map(PrimeField::from_u64)
+                .collect::<Vec<_>>()
+        );
+
+        for (x, sk) in keys.iter() {
+            let share = package.decrypt_share(x, sk, b"context").unwrap();
+            assert_eq!(share.x(), x);
+        }
+
+        // Unknown shareholder.
+        let (_, sk) = keys[0];
+        let result = package.decrypt_share(&PrimeField::from_u64(4), &sk, b"context");
+        assert!(result.is_err());
+        assert_eq!(result.err().unwrap().to_string(), "unknown shareholder");
+
+        // Duplicate shareholder.
+        let mut shares = package.shares().to_vec();
+        shares.push(shares[0].clone());
+        let vm = package.verification_matrix().clone();
+        let result = DealerPackage::new(7, kind, vm, shares);
+        assert!(result.is_err());
+        assert_eq!(result.err().unwrap().to_string(), "duplicate shareholder");
+    }
+
+    #[test]
+    fn test_serialization() {
+        for kind in [
+            HandoffKind::DealingPhase,
+            HandoffKind::CommitteeUnchanged,
+            HandoffKind::CommitteeChanged,
+        ] {
+            let (mut package, _) = package(kind);
+
+            let bytes = package.to_bytes();
+            let restored = DealerPackage::<Group>::from_bytes(&bytes).unwrap();
+            assert_eq!(package, restored);
+            assert_eq!(restored.to_bytes(), bytes);
+            assert_eq!(&bytes[..bytes.len() - 1], package.signing_bytes());
+
+            package.set_signature(b"signature".to_vec());
+            let signed = package.to_bytes();
+            assert!(signed.starts_with(&package.signing_bytes()));
+            let restored = DealerPackage::<Group>::from_bytes(&signed).unwrap();
+            assert_eq!(restored.signature(), Some(&b"signature"[..]));
+            assert_eq!(package, restored);
+
+            package.clear_signature();
+            assert_eq!(package.to_bytes(), bytes);
+
+            // Bound to a committee.
+            let mut bound = package.clone();
+            bound.bind_committee(&[&b"alice"[..], b"bob"]).unwrap();
+            assert_eq!(bound.signature(), None);
+            let restored = DealerPackage::<Group>::from_bytes(&bound.to_bytes()).unwrap();
+            assert_eq!(bound, restored);
+            assert_ne!(bound.signing_bytes(), package.signing_bytes());
+
+            // Truncated or extended.
+            assert!(DealerPackage::<Group>::from_bytes(&bytes[..bytes.len() - 1]).is_none());
+            let mut extended = bytes.clone();
+            extended.push(0);
+            assert!(DealerPackage::<Group>::from_bytes(&extended).is_none());
+
+            // Invalid kind.
+            let mut invalid = bytes.clone();
+            invalid[8] = 4;
+            assert!(DealerPackage::<Group>::from_bytes(&invalid).is_none());
+        }
+    }
+
+    #[test]
+    fn test_committee_digest() {
+        let a = committee_digest(&[&b"alice"[..], b"bob", b"carol"]).unwrap();
+        let b = committee_digest(&[&b"carol"[..], b"alice", b"bob"]).unwrap();
+        let c = committee_digest(&[&b"alice"[..], b"bob"]).unwrap();
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+
+        // Length-prefixed IDs.
+        let a = committee_digest(&[b"ab".to_vec(), b"c".to_vec()]).unwrap();
+        let b = committee_digest(&[b"a".to_vec(), b"bc".to_vec()]).unwrap();
+        assert_ne!(a, b);
+
+        let result = committee_digest(&[&b"alice"[..], b"bob", b"alice"]);
+        assert!(result.is_err());
+        assert_eq!(result.err().unwrap().to_string(), "duplicate shareholder");
+    }
+
+    #[test]
+    fn test_verify_committee() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let dealer = Dealer::<Group>::new(2, &mut rng).unwrap();
+        let kind = HandoffKind::DealingPhase;
+
+        let ids = vec![b"alice".to_vec(), b"bob".to_vec(), b"carol".to_vec()];
+        let recipients: Vec<_> = ids
+            .iter()
+            .map(|id| {
+                let x = encode_shareholder::<Sha3_384>(id, b"dst").unwrap();
+                (x, Group::random(&mut rng))
+            })
+            .collect();
+
+        let mut package = dealer
+            .make_package(7, kind, &recipients, b"context", &mut rng)
+            .unwrap();
+
+        // Unbound package.
+        let result = package.verify_committee::<Sha3_384, _>(&ids, b"dst");
+        assert!(result.is_err());
+        assert_eq!(result.err().unwrap().to_string(), "committee required");
+
+        // Bound package.
+        package.bind_committee(&ids).unwrap();
+        assert_eq!(package.committee(), Some(&committee_digest(&ids).unwrap()));
+        package
+            .verify_committee::<Sha3_384, _>(&ids, b"dst")
+            .unwrap();
+
+        // Different committee.
+        let other = vec![b"alice".to_vec(), b"bob".to_vec(), b"dave".to_vec()];
+        let result = package.verify_committee::<Sha3_384, _>(&other, b"dst");
+        assert!(result.is_err());
+        assert_eq!(result.err().unwrap().to_string(), "committee mismatch");
+
+        // Shares redirected to a non-member.
+        let mut redirected = package.clone();
+        redirected.bind_committee(&ids[..2]).unwrap();
+        let result = redirected.verify_committee::<Sha3_384, _>(&ids[..2], b"dst");
+        assert!(result.is_err());
+        assert_eq!(result.err().unwrap().to_string(), "unknown shareholder");
+    }
+
+    #[test]
+    fn test_signature() {
+        let (mut package, keys) = package(HandoffKind::CommitteeChanged);
+        let signer = TestSigner(b"key".to_vec());
+        let (x, sk) = keys[0];
+
+        // Unsigned package.
+        let result = package.verify_signature(&signer);
+        assert!(result.is_err());
+        assert_eq!(result.err().unwrap().to_string(), "signature required");
+        assert!(package.open_share(&x, &sk, b"context", &signer).is_err());
+
+        // Signed package.
+        package.sign(&signer).unwrap();
+        package.verify_signature(&signer).unwrap();
+        let share = package.open_share(&x, &sk, b"context", &signer).unwrap();
+        assert_eq!(share.x(), &x);
+
+        // Different key.
+        let result = package.verify_signature(&TestSigner(b"other key".to_vec()));
+        assert!(result.is_err());
+        assert_eq!(result.err().unwrap().to_string(), "invalid signature");
+
+        // Substituted matrix.
+        let mut rng: StdRng = SeedableRng::from_seed([2u8; 32]);
+        let other = Dealer::<Group>::new(2, &mut rng).unwrap();
+        let substituted = DealerPackage {
+            vm: other.verification_matrix().clone(),
+            ..package.clone()
+        };
+        let result = substituted.open_share(&x, &sk, b"context", &signer);
+        assert!(result.is_err());
+        assert_eq!(result.err().unwrap().to_string(), "invalid signature");
+    }
+
+    #[test]
+    fn test_non_canonical_order() {
+        let (package, _) = package(HandoffKind::DealingPhase);
+        let mut shares = package.shares().to_vec();
+        shares.swap(0, 1);
+        let unsorted = DealerPackage {
+            shares,
+            ..package.clone()
+        };
+
+        assert!(DealerPackage::<Group>::from_bytes(&unsorted.to_bytes()).is_none());
+    }
+}
@@ -1,21 +1,167 @@
+use std::{fmt::Write, sync::Mutex};
+
 use anyhow::{bail, Result};
-use group::ff::PrimeField;
+use group::{
+    ff::{Field, PrimeField},
+    Group, GroupEncoding,
+};
+use hkdf::Hkdf;
+use rand_core::RngCore;
+use sha2::Sha256;
 use zeroize::Zeroize;
 
-use crate::{kdc::KeyRecoverer, poly::lagrange};
+use crate::{
+    kdc::{KeyRecoverer, KeySharer},
+    poly::{lagrange, EncryptedPoint, Point, Polynomial},
+    suites::{FieldDigest, Suite},
+    vss::{MerkleNode, VerificationMatrix, VerificationVector},
+};
+
+use super::{
+    decryption, encode_shareholder,
+    evidence::EvidenceLog,
+    package::{append_bytes, decode_kind, encode_kind, Reader},
+    repair, threshold, validate_share_degree, Ciphertext, DealerSigner, DecryptionShare, Error,
+    EvidenceBundle, HandoffKind, KeyPurpose, MatrixCache, Misbehavior, PartialSignature,
+    PurposeShareholder, RepairContribution, SecretShare, Sensitive, SharePossessionProof, ShareTag,
+    Shareholder, SwitchPoint, TaggedShare, ThresholdSignature, VectorProof, VerifiableEvaluation,
+    VerifiableSecretShare,
+};
+
+/// The version of the byte representation of the player state.
+const PLAYER_STATE_VERSION: u8 = 1;
+
+/// Domain separation tag for key derivation.
+const KEY_DERIVATION_DST: &[u8] = b"CHURP-KeyDerivation";
+
+/// Label of the info used to expand recovered keys to secrets.
+const KEY_DERIVATION_INFO_LABEL: &[u8] = b"CHURP-KeyDerivation-Info";
+
+/// The size of secrets derived from the shared secret.
+pub const DERIVED_SECRET_SIZE: usize = 32;
+
+/// The context in which keys are derived from the shared secret,
+/// which scopes the derived keys to a suite, a runtime and a key ID.
+pub struct KeyDerivationContext<'a> {
+    /// The identifier of the suite, e.g. as used in the suite registry.
+    suite_id: &'a str,
+    /// The identifier of the runtime.
+    runtime_id: &'a [u8],
+    /// The identifier of the key.
+    key_id: &'a [u8],
+}
 
-use super::{HandoffKind, SecretShare};
+impl<'a> KeyDerivationContext<'a> {
+    /// Creates a new key derivation context.
+    pub fn new(suite_id: &'a str, runtime_id: &'a [u8], key_id: &'a [u8]) -> Self {
+        Self {
+            suite_id,
+            runtime_id,
+            key_id,
+        }
+    }
+
+    /// Returns the domain separation tag used to hash the key ID
+    /// to an element of the group.
+    fn dst(&self) -> Vec<u8> {
+        let mut bytes = KEY_DERIVATION_DST.to_vec();
+        append_bytes(&mut bytes, self.suite_id.as_bytes());
+        append_bytes(&mut bytes, self.runtime_id);
+        bytes
+    }
+
+    /// Returns the info used to expand the recovered key to a secret,
+    /// i.e. the label followed by the length-prefixed suite, runtime
+    /// and key IDs.
+    fn info(&self) -> Vec<u8> {
+        let mut bytes = KEY_DERIVATION_INFO_LABEL.to_vec();
+        append_bytes(&mut bytes, self.suite_id.as_bytes());
+        append_bytes(&mut bytes, self.runtime_id);
+        append_bytes(&mut bytes, self.key_id);
+        bytes
+    }
+}
 
 /// A constructor of the shared secret.
+///
+/// The player records hashes of invalid points and polynomials it receives
+/// in a bounded log, which can be exported as signed evidence of misbehavior.
 pub struct Player {
     threshold: u8,
     kind: HandoffKind,
+    epoch: u64,
+    evidence: Mutex<EvidenceLog>,
 }
 
 impl Player {
     /// Creates a new player.
     pub fn new(threshold: u8, kind: HandoffKind) -> Self {
-        Player { threshold, kind }
+        Self::new_with_epoch(threshold, kind, 0)
+    }
+
+    /// Creates a new player for the given epoch, which is recorded
+    /// in the evidence of misbehavior.
+    pub fn new_with_epoch(threshold: u8, kind: HandoffKind, epoch: u64) -> Self {
+        Player {
+            threshold,
+            kind,
+            epoch,
+            evidence: Mutex::new(EvidenceLog::default()),
+        }
+    }
+
+    /// Returns the epoch of the player.
+    pub fn epoch(&self) -> u64 {
+        self.epoch
+    }
+
+    /// Returns the kind of the handoff of the player.
+    pub fn kind(&self) -> HandoffKind {
+        self.kind
+    }
+
+    /// Returns the recorded evidence of misbehavior.
+    ///
+    /// At most [`MAX_EVIDENCE_RECORDS`](super::MAX_EVIDENCE_RECORDS) records are kept, and every sender
+    /// is recorded at most once per epoch and reason.
+    pub fn evidence(&self) -> Vec<Misbehavior> {
+        self.evidence.lock().unwrap().records().to_vec()
+    }
+
+    /// Removes and returns the recorded evidence of misbehavior.
+    pub fn drain_evidence(&self) -> Vec<Misbehavior> {
+        self.evidence.lock().unwrap().drain()
+    }
+
+    /// Removes the recorded evidence of misbehavior of epochs before
+    /// the given epoch, e.g. once it is no longer actionable.
+    pub fn prune_evidence(&self, epoch: u64) {
+        self.evidence.lock().unwrap().prune(epoch)
+    }
+
+    /// Signs the recorded evidence of misbehavior with the long-term key
+    /// of the node and clears the record.
+    ///
+    /// The record is kept if signing fails.
+    pub fn export_evidence(&self, signer: &impl DealerSigner) -> Result<EvidenceBundle> {
+        let mut evidence = self.evidence.lock().unwrap();
+        let bundle = EvidenceBundle::sign(evidence.records().to_vec(), signer)?;
+        evidence.drain();
+
+        Ok(bundle)
+    }
+
+    /// Records the given invalid payload received from the given sender.
+    pub(crate) fn record<F: PrimeField>(&self, sender: &F, payload: &[u8], error: &Error) {
+        self.record_at(self.epoch, sender, payload, error)
+    }
+
+    /// Records the given invalid payload received from the given sender
+    /// in the given epoch.
+    fn record_at<F: PrimeField>(&self, epoch: u64, sender: &F, payload: &[u8], error: &Error) {
+        let sender = sender.to_repr().as_ref().to_vec();
+        let record = Misbehavior::new(epoch, sender, payload, error.to_string());
+        self.evidence.lock().unwrap().record(record);
     }
 
     /// Recovers the secret from the provided shares.
@@ -28,26 +174,789 @@ impl Player {
         }
 
         let xs = shares.iter().map(|s| *s.x()).collect::<Vec<_>>();
-        let cs = lagrange::coefficients(&xs);
-        let mut secret = F::ZERO;
-        for (mut ci, share) in cs.into_iter().zip(shares) {
-            ci *= share.y();
-            secret += &ci;
-            ci.zeroize();
+        let ys = shares.iter().map(|s| s.y());
+
+        Ok(Self::interpolate(&xs, ys))
+    }
+
+    /// Combines the provided verifiable shares into the secret.
+    ///
+    /// Unlike [`Player::recover_secret`], every share is verified against
+    /// the verification matrix, which must be the same for all shares,
+    /// before the secret is interpolated.
+    ///
+    /// Fails if there are not enough shares, if the shares are not from
+    /// distinct shareholders, or if the verification data is inconsistent.
+    pub fn combine<G>(&self, shares: &[VerifiableSecretShare<G>]) -> Result<G::Scalar>
+    where
+        G: Group,
+        G::Scalar: Zeroize,
+    {
+        let shares: Vec<_> = shares.iter().collect();
+        self.combine_shares(&shares)
+    }
+
+    /// Combines the provided verifiable shares into the secret.
+    fn combine_shares<G>(&self, shares: &[&VerifiableSecretShare<G>]) -> Result<G::Scalar>
+    where
+        G: Group,
+        G::Scalar: Zeroize,
+    {
+        let expected = self.min_shares();
+        if shares.len() < expected {
+            return Err(Error::NotEnoughShares {
+                expected,
+                actual: shares.len(),
+            }
+            .into());
+        }
+
+        let vm = shares[0].verification_matrix();
+        for (i, share) in shares.iter().enumerate() {
+            if shares[..i].iter().any(|s| s.x() == share.x()) {
+                return Err(Error::DuplicateShareholder.into());
+            }
+            if share.verification_matrix() != vm {
+                return Err(Error::VerificationMatrixMismatch.into());
+            }
+            self.verify_share(share.secret_share(), vm)?;
+        }
+
+        let xs = shares.iter().map(|s| *s.x()).collect::<Vec<_>>();
+        let ys = shares.iter().map(|s| s.y());
+
+        Ok(Self::interpolate(&xs, ys))
+    }
+
+    /// Combines the provided tagged shares into the secret.
+    ///
+    /// Every share must be tagged with the given scheme instance and with
+    /// the epoch and the kind of the handoff of the player, so that stale
+    /// shares of a previous committee cannot be mixed with new ones.
+    /// Otherwise, this behaves like [`Player::combine`].
+    pub fn combine_tagged<G>(
+        &self,
+        scheme_id: &[u8],
+        shares: &[TaggedShare<G>],
+    ) -> Result<G::Scalar>
+    where
+        G: Group,
+        G::Scalar: Zeroize,
+    {
+        let tag = self.tag(scheme_id);
+        for share in shares {
+            share.tag().verify(&tag)?;
+        }
+
+        let shares: Vec<_> = shares.iter().map(|s| s.share()).collect();
+        self.combine_shares(&shares)
+    }
+
+    /// Verifies the given tagged share against its verification matrix.
+    ///
+    /// Fails if the share is not tagged with the given scheme instance
+    /// and with the epoch and the kind of the handoff of the player.
+    pub fn verify_tagged_share<G>(&self, scheme_id: &[u8], share: &TaggedShare<G>) -> Result<()>
+    where
+        G: Group,
+        G::Scalar: Zeroize,
+    {
+        share.tag().verify(&self.tag(scheme_id))?;
+        self.verify_share(
+            share.share().secret_share(),
+            share.share().verification_matrix(),
+        )
+    }
+
+    /// Returns the tag of shares of the given scheme instance which
+    /// the player accepts.
+    pub fn tag(&self, scheme_id: &[u8]) -> ShareTag {
+        ShareTag::new(scheme_id.to_vec(), self.epoch, self.kind)
+    }
+
+    /// Recovers the public key of the shared secret from the provided
+    /// public shares, without reconstructing the secret itself.
+    pub fn recover_public_key<G: Group>(&self, shares: &[EncryptedPoint<G>]) -> Result<G> {
+        if shares.len() < self.min_shares() {
+            bail!("not enough shares");
+        }
+        if !<Self as KeyRecoverer>::distinct_shares(shares) {
+            bail!("not distinct shares");
+        }
+
+        Ok(lagrange::lagrange_in_exponent(shares))
+    }
+
+    /// Verifies the given share against the verification matrix.
+    ///
+    /// On failure, the error names the shareholder and the coefficient
+    /// of the share that doesn't match the matrix.
+    pub fn verify_share<G>(
+        &self,
+        share: &SecretShare<G::Scalar>,
+        vm: &VerificationMatrix<G>,
+    ) -> Result<()>
+    where
+        G: Group,
+        G::Scalar: Zeroize,
+    {
+        self.verify_share_at(self.epoch, share, vm)
+    }
+
+    /// Verifies the given share of a previous epoch, which arrived after
+    /// the local verification matrix was replaced, against the matrix
+    /// of that epoch from the given cache.
+    ///
+    /// Failures are attributed to the shareholder in the epoch of the share.
+    pub fn verify_cached_share<G>(
+        &self,
+        scheme_id: &[u8],
+        epoch: u64,
+        share: &SecretShare<G::Scalar>,
+        cache: &MatrixCache<G>,
+    ) -> Result<()>
+    where
+        G: Group,
+        G::Scalar: Zeroize,
+    {
+        let vm = cache.require(scheme_id, epoch)?;
+        self.verify_share_at(epoch, share, &vm)
+    }
+
+    /// Verifies the given share of the given epoch against the verification
+    /// matrix.
+    fn verify_share_at<G>(
+        &self,
+        epoch: u64,
+        share: &SecretShare<G::Scalar>,
+        vm: &VerificationMatrix<G>,
+    ) -> Result<()>
+    where
+        G: Group,
+        G::Scalar: Zeroize,
+    {
+        self.verify_dimensions(vm)?;
+
+        let result = match self.kind {
+            HandoffKind::DealingPhase => vm.verify_x_detailed(share.x(), share.polynomial()),
+            HandoffKind::CommitteeUnchanged => vm.verify_x_detailed(share.x(), share.polynomial()),
+            HandoffKind::CommitteeChanged | HandoffKind::Emergency => {
+                vm.verify_y_detailed(share.x(), share.polynomial())
+            }
+        };
+
+        result.map_err(|failure| {
+            let error = Error::InvalidShare {
+                shareholder: Self::shareholder(share.x()),
+                failure,
+            };
+            self.record_at(epoch, share.x(), &share.polynomial().to_bytes(), &error);
+            error.into()
+        })
+    }
+
+    /// Verifies the given point on the secret-sharing univariate polynomial
+    /// B(x,0) or B(0,y) against the verification matrix.
+    ///
+    /// On failure, the error names the shareholder, i.e. the evaluation
+    /// point, whose y-coordinate doesn't match the matrix.
+    pub fn verify_point<G>(
+        &self,
+        point: &Point<G::Scalar>,
+        vm: &VerificationMatrix<G>,
+    ) -> Result<()>
+    where
+        G: Group,
+    {
+        self.verify_point_at(self.epoch, point, vm)
+    }
+
+    /// Verifies the given point of a previous epoch, which arrived after
+    /// the local verification matrix was replaced, against the matrix
+    /// of that epoch from the given cache.
+    ///
+    /// Failures are attributed to the shareholder in the epoch of the point.
+    pub fn verify_cached_point<G>(
+        &self,
+        scheme_id: &[u8],
+        epoch: u64,
+        point: &Point<G::Scalar>,
+        cache: &MatrixCache<G>,
+    ) -> Result<()>
+    where
+        G: Group,
+    {
+        let vm = cache.require(scheme_id, epoch)?;
+        self.verify_point_at(epoch, point, &vm)
+    }
+
+    /// Verifies the given point of the given epoch against the verification
+    /// matrix.
+    fn verify_point_at<G>(
+        &self,
+        epoch: u64,
+        point: &Point<G::Scalar>,
+        vm: &VerificationMatrix<G>,
+    ) -> Result<()>
+    where
+        G: Group,
+    {
+        self.verify_dimensions(vm)?;
+
+        let vv = self.secret_sharing_vector(vm);
+        if !vv.verify(point.x(), point.y()) {
+            let error = Error::InvalidSharePoint {
+                shareholder: Self::shareholder(point.x()),
+            };
+            let payload = [point.x().to_repr().as_ref(), point.y().to_repr().as_ref()].concat();
+            self.record_at(epoch, point.x(), &payload, &error);
+            return Err(error.into());
         }
 
-        Ok(secret)
+        Ok(())
+    }
+
+    /// Proactivizes the share of the given shareholder with the given
+    /// update shares, e.g. received from the dealers of a proactivization
+    /// round.
+    ///
+    /// Every update must belong to the shareholder, be zero-hole, and
+    /// verify against its verification matrix. The updates are applied
+    /// atomically, i.e. either all of them or none, and the shareholder
+    /// of the new epoch is returned, with the updated verification matrix.
+    pub fn proactivize<G>(
+        &self,
+        shareholder: &Shareholder<G>,
+        updates: &[VerifiableSecretShare<G>],
+    ) -> Result<Shareholder<G>>
+    where
+        G: Group,
+        G::Scalar: Zeroize,
+    {
+        let (first, rest) = updates.split_first().ok_or(Error::NotEnoughDealers)?;
+        let x = shareholder.verifiable_share().x();
+
+        for update in updates {
+            if update.x() != x {
+                return Err(Error::ShareholderIdentityMismatch.into());
+            }
+            if !update.verification_matrix().is_zero_hole() {
+                return Err(Error::VerificationMatrixZeroHoleMismatch.into());
+            }
+            self.verify_share(update.secret_share(), update.verification_matrix())?;
+        }
+
+        let mut p = first.polynomial().clone();
+        let mut vm = first.verification_matrix().clone();
+        for update in rest {
+            p += update.polynomial();
+            vm += update.verification_matrix();
+        }
+
+        let result = shareholder.proactivize(&p, &vm);
+        p.zeroize();
+
+        result
+    }
+
+    /// Computes the switch point for the shareholder with the given ID
+    /// by evaluating the share of the given shareholder at the encoded ID.
+    ///
+    /// The switch point is returned together with the verification vector
+    /// of the share, which allows the requester to verify the point.
+    ///
+    /// Fails if the ID cannot be encoded, or if the degree of the share
+    /// doesn't match the kind of the handoff.
+    pub fn switch_point<H, G>(
+        &self,
+        shareholder: &Shareholder<G>,
+        id: &[u8],
+        dst: &[u8],
+    ) -> Result<SwitchPointResponse<G>>
+    where
+        H: FieldDigest<Output = G::Scalar>,
+        G: Group,
+        G::Scalar: Zeroize,
+    {
+        let share = shareholder.verifiable_share();
+        validate_share_degree(share.polynomial(), self.threshold, self.kind)?;
+
+        let x = encode_shareholder::<H>(id, dst)?;
+        let y = shareholder.switch_point(&x);
+        let point = SwitchPoint::new(*share.x(), y);
+        let verification_vector = share.verification_vector(!self.kind.changes_committee());
+
+        Ok(SwitchPointResponse {
+            point,
+            verification_vector,
+        })
+    }
+
+    /// Derives the key share of the given shareholder in the given key
+    /// derivation context.
+    ///
+    /// Key shares of distinct shareholders can be combined into a secret
+    /// using [`Player::derive_secret`].
+    pub fn derive_key_share<S>(
+        &self,
+        shareholder: &Shareholder<S::Group>,
+        context: &KeyDerivationContext<'_>,
+    ) -> Result<EncryptedPoint<S::Group>>
+    where
+        S: Suite,
+    {
+        shareholder.make_key_share::<S>(context.key_id, &context.dst())
+    }
+
+    /// Recovers the key from the provided key shares and derives a secret
+    /// from it in the given key derivation context.
+    ///
+    /// The key shares must have been derived in the same context,
+    /// as otherwise the derived secret is meaningless.
+    pub fn derive_secret<S>(
+        &self,
+        key_shares: &[EncryptedPoint<S::Group>],
+        context: &KeyDerivationContext<'_>,
+    ) -> Result<Sensitive<[u8; DERIVED_SECRET_SIZE]>>
+    where
+        S: Suite,
+    {
+        let mut key = self.recover_key(key_shares)?;
+        let mut ikm = key.to_bytes();
+        key.zeroize();
+
+        let hkdf = Hkdf::<Sha256>::new(Some(KEY_DERIVATION_DST), ikm.as_ref());
+        ikm.as_mut().zeroize();
+
+        let mut secret = [0u8; DERIVED_SECRET_SIZE];
+        hkdf.expand(&context.info(), &mut secret)
+            .expect("secret size should be valid");
+
+        Ok(Sensitive::new(secret))
+    }
+
+    /// Evaluates the blinded element of a client using the share of the given
+    /// shareholder, returning the blinded key share.
+    ///
+    /// Since the element is blinded, the player doesn't learn which key
+    /// was requested, and the share is not revealed. The shared key must
+    /// be dedicated to evaluations, as the client can choose any element.
+    ///
+    /// Fails if the blinded element is the identity, or if the key is
+    /// dedicated to another purpose.
+    pub fn evaluate_blinded<G>(
+        &self,
+        shareholder: &PurposeShareholder<G>,
+        blinded: &G,
+    ) -> Result<EncryptedPoint<G>>
+    where
+        G: Group,
+        G::Scalar: Zeroize,
+    {
+        let shareholder = shareholder.scoped(KeyPurpose::Evaluation)?;
+        if blinded.is_identity().into() {
+            return Err(Error::InvalidBlindedElement.into());
+        }
+
+        let share = shareholder.verifiable_share();
+        let point = EncryptedPoint::new(*share.x(), *blinded * share.y());
+
+        Ok(point)
+    }
+
+    /// Evaluates the share of the given shareholder at the given base point,
+    /// e.g. a blinded element, and attaches a proof which ties the evaluation
+    /// to the verification matrix of the share. The shared key must be
+    /// dedicated to evaluations, as the requester can choose any base point.
+    ///
+    /// Fails if the base point is the identity, or if the key is dedicated
+    /// to another purpose.
+    pub fn evaluate_verifiable<G>(
+        &self,
+        shareholder: &PurposeShareholder<G>,
+        base: &G,
+        rng: &mut impl RngCore,
+    ) -> Result<VerifiableEvaluation<G>>
+    where
+        G: Group + GroupEncoding,
+        G::Scalar: Zeroize,
+    {
+        let shareholder = shareholder.scoped(KeyPurpose::Evaluation)?;
+        self.evaluate_at(shareholder, base, rng)
+    }
+
+    /// Evaluates the share of the given shareholder at the given base point
+    /// and attaches a proof which ties the evaluation to the verification
+    /// matrix of the share.
+    fn evaluate_at<G>(
+        &self,
+        shareholder: &Shareholder<G>,
+        base: &G,
+        rng: &mut impl RngCore,
+    ) -> Result<VerifiableEvaluation<G>>
+    where
+        G: Group + GroupEncoding,
+        G::Scalar: Zeroize,
+    {
+        if base.is_identity().into() {
+            return Err(Error::InvalidBlindedElement.into());
+        }
+
+        let share = shareholder.verifiable_share();
+        let evaluation = DecryptionShare::new(share.x(), share.y(), base, rng);
+        let vector = VectorProof::new(share.verification_matrix(), self.kind);
+
+        Ok(VerifiableEvaluation::new(evaluation, vector))
+    }
+
+    /// Derives the key share of the given shareholder in the given key
+    /// derivation context, and attaches a proof which ties the key share
+    /// to the verification matrix of the share.
+    pub fn derive_verifiable_key_share<S>(
+        &self,
+        shareholder: &Shareholder<S::Group>,
+        context: &KeyDerivationContext<'_>,
+        rng: &mut impl RngCore,
+    ) -> Result<VerifiableEvaluation<S::Group>>
+    where
+        S: Suite,
+    {
+        let base = S::hash_to_group(context.key_id, &context.dst())?;
+        self.evaluate_at(shareholder, &base, rng)
+    }
+
+    /// Verifies the given evaluation at the given base point against
+    /// the Merkle root of the verification matrix, without the matrix
+    /// itself.
+    pub fn verify_evaluation<G>(
+        &self,
+        evaluation: &VerifiableEvaluation<G>,
+        root: &MerkleNode,
+        base: &G,
+    ) -> Result<()>
+    where
+        G: Group + GroupEncoding,
+        G::Scalar: Zeroize,
+    {
+        let threshold = self.threshold as usize;
+        let expected = (threshold + 1, 2 * threshold + 1);
+        let actual = evaluation.vector_proof().dimensions();
+        if actual != expected {
+            return Err(Error::UnexpectedVerificationMatrixDimensions { expected, actual }.into());
+        }
+
+        let x = evaluation.point().x();
+        if !evaluation.verify(root, self.kind, base) {
+            let error = Error::InvalidEvaluation {
+                shareholder: Self::shareholder(x),
+            };
+            self.record(x, evaluation.point().z().to_bytes().as_ref(), &error);
+            return Err(error.into());
+        }
+
+        Ok(())
+    }
+
+    /// Verifies the given key share in the given key derivation context
+    /// against the Merkle root of the verification matrix.
+    pub fn verify_key_share<S>(
+        &self,
+        key_share: &VerifiableEvaluation<S::Group>,
+        root: &MerkleNode,
+        context: &KeyDerivationContext<'_>,
+    ) -> Result<()>
+    where
+        S: Suite,
+    {
+        let base = S::hash_to_group(context.key_id, &context.dst())?;
+        self.verify_evaluation(key_share, root, &base)
+    }
+
+    /// Proves that the given shareholder holds a valid share for its
+    /// identity, without revealing the share.
+    ///
+    /// The proof is bound to the given context, e.g. the epoch and
+    /// a nonce chosen by the auditor.
+    pub fn prove_share_possession<G>(
+        &self,
+        shareholder: &Shareholder<G>,
+        context: &[u8],
+        rng: &mut impl RngCore,
+    ) -> SharePossessionProof<G>
+    where
+        G: Group + GroupEncoding,
+        G::Scalar: Zeroize,
+    {
+        let share = shareholder.verifiable_share();
+        SharePossessionProof::prove(share.x(), share.y(), context, rng)
+    }
+
+    /// Verifies that the shareholder with the given identity holds a valid
+    /// share, using only the public verification matrix.
+    pub fn verify_share_possession<G>(
+        &self,
+        x: &G::Scalar,
+        vm: &VerificationMatrix<G>,
+        proof: &SharePossessionProof<G>,
+        context: &[u8],
+    ) -> Result<()>
+    where
+        G: Group + GroupEncoding,
+    {
+        self.verify_dimensions(vm)?;
+
+        let public = self.secret_sharing_vector(vm).eval(x);
+        if !proof.verify(x, &public, context) {
+            return Err(Error::InvalidSharePossessionProof {
+                shareholder: Self::shareholder(x),
+            }
+            .into());
+        }
+
+        Ok(())
+    }
+
+    /// Signs the given message using the share of the given shareholder
+    /// and its share of a one-time nonce, e.g. from a separate dealing.
+    ///
+    /// The nonce must never be used to sign another message, as otherwise
+    /// the share can be extracted from the partial signatures.
+    ///
+    /// Fails if the nonce share doesn't belong to the shareholder.
+    pub fn sign_partial<G>(
+        &self,
+        shareholder: &Shareholder<G>,
+        nonce: &Shareholder<G>,
+        message: &[u8],
+    ) -> Result<PartialSignature<G>>
+    where
+        G: Group + GroupEncoding,
+        G::Scalar: Zeroize,
+    {
+        threshold::sign_partial(self, shareholder, nonce, message)
+    }
+
+    /// Verifies the given partial signature of the given message against
+    /// the verification matrices of the secret and of the nonce.
+    pub fn verify_partial_signature<G>(
+        &self,
+        partial: &PartialSignature<G>,
+        vm: &VerificationMatrix<G>,
+        nonce_vm: &VerificationMatrix<G>,
+        message: &[u8],
+    ) -> Result<()>
+    where
+        G: Group + GroupEncoding,
+    {
+        threshold::verify_partial_signature(self, partial, vm, nonce_vm, message)
+    }
+
+    /// Combines the provided partial signatures of the given message into
+    /// a signature which verifies against the public key of the committee.
+    ///
+    /// Every partial signature is verified before the response
+    /// is interpolated.
+    pub fn combine_signatures<G>(
+        &self,
+        partials: &[PartialSignature<G>],
+        vm: &VerificationMatrix<G>,
+        nonce_vm: &VerificationMatrix<G>,
+        message: &[u8],
+    ) -> Result<ThresholdSignature<G>>
+    where
+        G: Group + GroupEncoding,
+        G::Scalar: Zeroize,
+    {
+        threshold::combine_signatures(self, partials, vm, nonce_vm, message)
+    }
+
+    /// Computes the decryption share of the given shareholder for the given
+    /// ciphertext, together with a proof of its correctness.
+    ///
+    /// Fails if the ephemeral key of the ciphertext is the identity,
+    /// or if the key is not dedicated to decryption.
+    pub fn decrypt_partial<G>(
+        &self,
+        shareholder: &PurposeShareholder<G>,
+        ciphertext: &Ciphertext<G>,
+        rng: &mut impl RngCore,
+    ) -> Result<DecryptionShare<G>>
+    where
+        G: Group + GroupEncoding,
+        G::Scalar: Zeroize,
+    {
+        decryption::decrypt_partial(shareholder, ciphertext, rng)
+    }
+
+    /// Verifies the given decryption share for the given ciphertext against
+    /// the verification matrix.
+    pub fn verify_decryption_share<G>(
+        &self,
+        share: &DecryptionShare<G>,
+        ciphertext: &Ciphertext<G>,
+        vm: &VerificationMatrix<G>,
+    ) -> Result<()>
+    where
+        G: Group + GroupEncoding,
+        G::Scalar: Zeroize,
+    {
+        decryption::verify_decryption_share(self, share, ciphertext, vm)
+    }
+
+    /// Combines the provided decryption shares and decrypts the given
+    /// ciphertext, without reconstructing the secret.
+    ///
+    /// Every decryption share is verified before the shared secret
+    /// is interpolated in the exponent.
+    pub fn combine_decryption_shares<G>(
+        &self,
+        shares: &[DecryptionShare<G>],
+        ciphertext: &Ciphertext<G>,
+        vm: &VerificationMatrix<G>,
+        context: &[u8],
+    ) -> Result<Vec<u8>>
+    where
+        G: Group + GroupEncoding,
+        G::Scalar: Zeroize,
+    {
+        decryption::combine_decryption_shares(self, shares, ciphertext, vm, context)
+    }
+
+    /// Generates random masks which the given shareholder sends privately
+    /// to each of the other helpers repairing a lost share, in the order
+    /// of the given helpers.
+    pub fn repair_masks<G>(
+        &self,
+        shareholder: &Shareholder<G>,
+        helpers: &[G::Scalar],
+        rng: &mut impl RngCore,
+    ) -> Vec<Polynomial<G::Scalar>>
+    where
+        G: Group,
+        G::Scalar: Zeroize,
+    {
+        repair::repair_masks(shareholder, helpers, rng)
+    }
+
+    /// Computes the contribution of the given shareholder to the repair
+    /// of the share of the lost shareholder, using the masks it sent to
+    /// and received from the other helpers.
+    ///
+    /// Fails if the shareholder is not one of the helpers, if there are
+    /// not enough distinct helpers, if the lost shareholder is a helper,
+    /// or if the masks don't match the share.
+    pub fn repair_contribution<G>(
+        &self,
+        shareholder: &Shareholder<G>,
+        lost: &G::Scalar,
+        helpers: &[G::Scalar],
+        sent: &[Polynomial<G::Scalar>],
+        received: &[Polynomial<G::Scalar>],
+    ) -> Result<RepairContribution<G>>
+    where
+        G: Group,
+        G::Scalar: Zeroize,
+    {
+        repair::repair_contribution(self, shareholder, lost, helpers, sent, received)
+    }
+
+    /// Repairs the share of the lost shareholder from the contributions
+    /// of the helpers.
+    ///
+    /// Every contribution is verified against the verification matrix
+    /// and the verification vector of its mask, and the masks must cancel
+    /// out, so that the repaired share is guaranteed to be valid.
+    pub fn repair_share<G>(
+        &self,
+        lost: &G::Scalar,
+        contributions: &[RepairContribution<G>],
+        vm: &VerificationMatrix<G>,
+    ) -> Result<Shareholder<G>>
+    where
+        G: Group,
+        G::Scalar: Zeroize,
+    {
+        repair::repair_share(self, lost, contributions, vm)
+    }
+
+    /// Returns the public key of the secret committed to by the given
+    /// verification matrix.
+    pub(crate) fn public_key<G: Group>(&self, vm: &VerificationMatrix<G>) -> G {
+        self.secret_sharing_vector(vm).eval(&G::Scalar::ZERO)
+    }
+
+    /// Returns the verification vector of the secret-sharing univariate
+    /// polynomial B(x,0) or B(0,y), on which the shares of the handoff lie.
+    pub(crate) fn secret_sharing_vector<G: Group>(
+        &self,
+        vm: &VerificationMatrix<G>,
+    ) -> VerificationVector<G> {
+        match self.kind {
+            HandoffKind::DealingPhase => vm.verification_vector_for_x(&G::Scalar::ZERO),
+            HandoffKind::CommitteeUnchanged => vm.verification_vector_for_x(&G::Scalar::ZERO),
+            HandoffKind::CommitteeChanged | HandoffKind::Emergency => {
+                vm.verification_vector_for_y(&G::Scalar::ZERO)
+            }
+        }
+    }
+
+    /// Verifies that the verification matrix has the dimensions expected
+    /// for the threshold.
+    pub(crate) fn verify_dimensions<G: Group>(&self, vm: &VerificationMatrix<G>) -> Result<()> {
+        let threshold = self.threshold as usize;
+        let expected = (threshold + 1, 2 * threshold + 1);
+        let actual = vm.dimensions();
+
+        if actual != expected {
+            return Err(Error::UnexpectedVerificationMatrixDimensions { expected, actual }.into());
+        }
+
+        Ok(())
+    }
+
+    /// Returns the hex-encoded identity of the given shareholder.
+    pub(crate) fn shareholder<F: PrimeField>(x: &F) -> String {
+        Self::hex(x)
+    }
+
+    /// Returns the hex encoding of the given field element.
+    pub(crate) fn hex<F: PrimeField>(value: &F) -> String {
+        value
+            .to_repr()
+            .as_ref()
+            .iter()
+            .fold(String::new(), |mut hex, b| {
+                let _ = write!(hex, "{:02x}", b);
+                hex
+            })
     }
 
     /// Returns the minimum number of shares required to recover the secret.
-    fn min_shares(&self) -> usize {
+    pub(crate) fn min_shares(&self) -> usize {
         let threshold = self.threshold as usize;
-        if self.kind == HandoffKind::CommitteeChanged {
+        if self.kind.changes_committee() {
             return 2 * threshold + 1;
         }
         threshold + 1
     }
 
+    /// Interpolates the secret from the given points on the secret-sharing
+    /// univariate polynomial.
+    pub(crate) fn interpolate<'a, F>(xs: &[F], ys: impl Iterator<Item = &'a F>) -> F
+    where
+        F: PrimeField + Zeroize,
+    {
+        let cs = lagrange::coefficients(xs);
+        let mut secret = F::ZERO;
+        for (mut ci, yi) in cs.into_iter().zip(ys) {
+            ci *= yi;
+            secret += &ci;
+            ci.zeroize();
+        }
+
+        secret
+    }
+
     /// Returns true if shares are from distinct shareholders.
     fn distinct_shares<F: PrimeField + Zeroize>(shares: &[SecretShare<F>]) -> bool {
         // For a small number of shareholders, a brute-force approach should
@@ -63,6 +972,213 @@ impl Player {
     }
 }
 
+/// A switch point computed for a requesting shareholder, together with
+/// the data required to verify it.
+pub struct SwitchPointResponse<G>
+where
+    G: Group,
+    G::Scalar: Zeroize,
+{
+    /// The switch point, i.e. the share of the responding shareholder
+    /// evaluated at the identity of the requester.
+    point: SwitchPoint<G::Scalar>,
+
+    /// The verification vector of the share of the responding shareholder.
+    verification_vector: VerificationVector<G>,
+}
+
+impl<G> SwitchPointResponse<G>
+where
+    G: Group,
+    G::Scalar: Zeroize,
+{
+    /// Returns the switch point.
+    pub fn point(&self) -> &SwitchPoint<G::Scalar> {
+        &self.point
+    }
+
+    /// Returns the verification vector of the share of the responding
+    /// shareholder.
+    pub fn verification_vector(&self) -> &VerificationVector<G> {
+        &self.verification_vector
+    }
+
+    /// Verifies the switch point for the requester with the given identity.
+    ///
+    /// The verification vector should be checked against the verification
+    /// matrix, unless it comes from a trusted source.
+    pub fn verify(&self, me: &G::Scalar) -> bool {
+        self.verification_vector.verify(me, self.point.y())
+    }
+
+    /// Returns the switch point, consuming the response.
+    pub fn into_point(self) -> SwitchPoint<G::Scalar> {
+        self.point
+    }
+}
+
+/// A snapshot of the state of a player, i.e. its current share, epoch,
+/// and the switch points received during an in-progress handoff, which
+/// allows nodes to restart mid-epoch without losing the share or
+/// the handoff.
+///
+/// The verification matrix is stored together with the share.
+pub struct PlayerState<G>
+where
+    G: Group,
+    G::Scalar: Zeroize,
+{
+    /// The threshold of the secret sharing.
+    threshold: u8,
+    /// The kind of the handoff in which the share was received.
+    kind: HandoffKind,
+    /// The epoch of the share.
+    epoch: u64,
+    /// The current share, if any.
+    shareholder: Option<Shareholder<G>>,
+    /// Switch points received during the in-progress handoff.
+    switch_points: Vec<SwitchPoint<G::Scalar>>,
+}
+
+impl<G> PlayerState<G>
+where
+    G: Group,
+    G::Scalar: Zeroize,
+{
+    /// Creates a new snapshot of the state of the given player.
+    pub fn new(
+        player: &Player,
+        shareholder: Option<Shareholder<G>>,
+        switch_points: Vec<SwitchPoint<G::Scalar>>,
+    ) -> Self {
+        Self {
+            threshold: player.threshold,
+            kind: player.kind,
+            epoch: player.epoch,
+            shareholder,
+            switch_points,
+        }
+    }
+
+    /// Returns the player.
+    pub fn player(&self) -> Player {
+        Player::new_with_epoch(self.threshold, self.kind, self.epoch)
+    }
+
+    /// Returns the epoch of the share.
+    pub fn epoch(&self) -> u64 {
+        self.epoch
+    }
+
+    /// Returns the current share, if any.
+    pub fn shareholder(&self) -> Option<&Shareholder<G>> {
+        self.shareholder.as_ref()
+    }
+
+    /// Returns the switch points received during the in-progress handoff.
+    pub fn switch_points(&self) -> &[SwitchPoint<G::Scalar>] {
+        &self.switch_points
+    }
+
+    /// Returns the versioned byte representation of the state.
+    ///
+    /// The representation contains the secret share, so it must only be
+    /// stored after being sealed.
+    pub fn to_bytes(&self) -> Sensitive<Vec<u8>>
+    where
+        G: GroupEncoding,
+    {
+        let mut bytes = vec![PLAYER_STATE_VERSION, self.threshold, encode_kind(self.kind)];
+        bytes.extend_from_slice(&self.epoch.to_le_bytes());
+
+        match &self.shareholder {
+            Some(shareholder) => {
+                let share = shareholder.verifiable_share();
+                bytes.push(1);
+                bytes.extend_from_slice(share.x().to_repr().as_ref());
+                append_bytes(&mut bytes, &share.polynomial().to_bytes());
+                append_bytes(&mut bytes, &share.verification_matrix().to_bytes());
+            }
+            None => bytes.push(0),
+        }
+
+        bytes.extend_from_slice(&(self.switch_points.len() as u32).to_le_bytes());
+        for point in self.switch_points.iter() {
+            bytes.extend_from_slice(point.x().to_repr().as_ref());
+            bytes.extend_from_slice(point.y().to_repr().as_ref());
+        }
+
+        Sensitive::new(bytes)
+    }
+
+    /// Restores the state from its versioned byte representation.
+    ///
+    /// Fails if the version is not supported, if the representation
+    /// is malformed, or if the degree of the share doesn't match
+    /// the threshold.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self>
+    where
+        G: GroupEncoding,
+    {
+        let version = *bytes.first().ok_or(Error::InvalidState)?;
+        if version != PLAYER_STATE_VERSION {
+            return Err(Error::UnsupportedStateVersion { version }.into());
+        }
+
+        let state = Self::decode(&bytes[1..]).ok_or(Error::InvalidState)?;
+        if let Some(shareholder) = &state.shareholder {
+            let share = shareholder.verifiable_share();
+            validate_share_degree(share.polynomial(), state.threshold, state.kind)?;
+        }
+
+        Ok(state)
+    }
+
+    /// Decodes the unversioned byte representation of the state.
+    fn decode(bytes: &[u8]) -> Option<Self>
+    where
+        G: GroupEncoding,
+    {
+        let mut reader = Reader { bytes };
+
+        let threshold = reader.take(1)?[0];
+        let kind = decode_kind(reader.take(1)?[0])?;
+        let epoch = reader.take_u64()?;
+
+        let shareholder = match reader.take(1)?[0] {
+            0 => None,
+            1 => {
+                let x = reader.take_scalar()?;
+                let p = Polynomial::from_bytes(reader.take_prefixed()?)?;
+                let vm = VerificationMatrix::from_bytes(reader.take_prefixed()?)?;
+                let share = SecretShare::new(x, p);
+                Some(VerifiableSecretShare::new(share, vm).into())
+            }
+            _ => return None,
+        };
+
+        let n = reader.take_u32()? as usize;
+        let mut switch_points = Vec::with_capacity(n.min(bytes.len()));
+        for _ in 0..n {
+            let x = reader.take_scalar()?;
+            let y = reader.take_scalar()?;
+            switch_points.push(SwitchPoint::new(x, y));
+        }
+
+        if !reader.bytes.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            threshold,
+            kind,
+            epoch,
+            shareholder,
+            switch_points,
+        })
+    }
+}
+
 impl KeyRecoverer for Player {
     fn min_shares(&self) -> usize {
         self.min_shares()
@@ -71,15 +1187,21 @@ impl KeyRecoverer for Player {
 
 #[cfg(test)]
 mod tests {
+    use group::{ff::PrimeField as _, Group as _};
     use rand_core::OsRng;
 
     use crate::{
-        churp::{self, HandoffKind, Shareholder, VerifiableSecretShare},
+        churp::{
+            self, signing::tests::TestSigner, Blinding, HandoffKind, KeyPurpose, MatrixCache,
+            Misbehavior, PurposeShareholder, SecretShare, ShareTag, Shareholder, SwitchPoint,
+            TaggedShare, VerifiableSecretShare,
+        },
         kdc::{KeyRecoverer, KeySharer},
+        poly::Point,
         suites::{self, p384, GroupDigest},
     };
 
-    use super::Player;
+    use super::{KeyDerivationContext, Player, PlayerState};
 
     // Types used in tests.
     type Suite = p384::Sha3_384;
@@ -137,6 +1259,778 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_combine() {
+        let test_cases = vec![
+            HandoffKind::DealingPhase,
+            HandoffKind::CommitteeUnchanged,
+            HandoffKind::CommitteeChanged,
+        ];
+
+        for kind in test_cases.into_iter() {
+            let threshold = 2;
+            let secret = PrimeField::from_u64(100);
+            let dealer = Dealer::new_with_secret(threshold, secret, &mut OsRng).unwrap();
+            let vm = dealer.verification_matrix();
+            let player = Player::new(threshold, kind);
+            let min_shares = player.min_shares() as u64;
+
+            let verifiable_shares = |xs: Vec<u64>| -> Vec<_> {
+                let xs = xs.into_iter().map(PrimeField::from_u64).collect();
+                dealer
+                    .make_shares(xs, kind)
+                    .into_iter()
+                    .map(|share| VerifiableSecretShare::new(share, vm.clone()))
+                    .collect()
+            };
+
+            // Exact number of shares.
+            let shares = verifiable_shares((1..=min_shares).collect());
+            assert_eq!(player.combine(&shares).unwrap(), secret);
+
+            // Too many shares.
+            let shares = verifiable_shares((1..=min_shares + 10).collect());
+            assert_eq!(player.combine(&shares).unwrap(), secret);
+
+            // Not enough shares.
+            let shares = verifiable_shares((1..min_shares).collect());
+            let result = player.combine(&shares);
+            assert!(result.is_err());
+            assert_eq!(
+                result.unwrap_err().to_string(),
+                format!(
+                    "not enough shares: expected at least {}, got {}",
+                    min_shares,
+                    min_shares - 1
+                )
+            );
+
+            // Duplicate shares.
+            let mut xs: Vec<_> = (1..=min_shares).collect();
+            xs.push(1);
+            let shares = verifiable_shares(xs);
+            let result = player.combine(&shares);
+            assert!(result.is_err());
+            assert_eq!(result.unwrap_err().to_string(), "duplicate shareholder");
+
+            // Different verification matrices.
+            let other = Dealer::new(threshold, &mut OsRng).unwrap();
+            let mut shares = verifiable_shares((1..min_shares).collect());
+            let x = PrimeField::from_u64(min_shares);
+            shares.push(VerifiableSecretShare::new(
+                other.make_share(x, kind),
+                other.verification_matrix().clone(),
+            ));
+            let result = player.combine(&shares);
+            assert!(result.is_err());
+            assert_eq!(
+                result.unwrap_err().to_string(),
+                "verification matrix mismatch"
+            );
+
+            // Invalid share.
+            let mut shares = verifiable_shares((1..min_shares).collect());
+            shares.push(VerifiableSecretShare::new(
+                other.make_share(x, kind),
+                vm.clone(),
+            ));
+            let result = player.combine(&shares);
+            assert!(result.is_err());
+            assert!(result
+                .unwrap_err()
+                .to_string()
+                .starts_with("invalid share of shareholder"));
+        }
+    }
+
+    #[test]
+    fn test_combine_tagged() {
+        let threshold = 2;
+        let kind = HandoffKind::CommitteeUnchanged;
+        let secret = PrimeField::from_u64(100);
+        let dealer = Dealer::new_with_secret(threshold, secret, &mut OsRng).unwrap();
+        let vm = dealer.verification_matrix();
+        let player = Player::new_with_epoch(threshold, kind, 2);
+
+        let tagged_shares = |tag: &ShareTag, xs: Vec<u64>| -> Vec<_> {
+            let xs = xs.into_iter().map(PrimeField::from_u64).collect();
+            dealer
+                .make_shares(xs, kind)
+                .into_iter()
+                .map(|share| {
+                    TaggedShare::new(tag.clone(), VerifiableSecretShare::new(share, vm.clone()))
+                })
+                .collect()
+        };
+
+        let tag = player.tag(b"scheme");
+        let shares = tagged_shares(&tag, (1..=3).collect());
+        assert_eq!(player.combine_tagged(b"scheme", &shares).unwrap(), secret);
+        player.verify_tagged_share(b"scheme", &shares[0]).unwrap();
+
+        // Another scheme instance.
+        let result = player.combine_tagged(b"other", &shares);
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().to_string(), "scheme mismatch");
+
+        // Stale share of a previous epoch.
+        let mut shares = tagged_shares(&tag, (1..=2).collect());
+        let stale = ShareTag::new(b"scheme".to_vec(), 1, kind);
+        shares.extend(tagged_shares(&stale, vec![3]));
+        let result = player.combine_tagged(b"scheme", &shares);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "epoch mismatch: expected 2, got 1"
+        );
+        let result = player.verify_tagged_share(b"scheme", &shares[2]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_recover_public_key() {
+        let test_cases = vec![
+            HandoffKind::DealingPhase,
+            HandoffKind::CommitteeUnchanged,
+            HandoffKind::CommitteeChanged,
+        ];
+
+        for kind in test_cases.into_iter() {
+            let threshold = 2;
+            let dealer = Dealer::new(threshold, &mut OsRng).unwrap();
+            let vm = dealer.verification_matrix();
+            let key = *vm.element(0, 0).unwrap();
+            let player = Player::new(threshold, kind);
+            let min_shares = player.min_shares() as u64;
+
+            let public_shares = |n: u64| -> Vec<_> {
+                let xs = (1..=n).map(PrimeField::from_u64).collect();
+                dealer
+                    .make_shares(xs, kind)
+                    .into_iter()
+                    .map(|share| VerifiableSecretShare::new(share, vm.clone()).public_share())
+                    .collect()
+            };
+
+            // Not enough shares.
+            let shares = public_shares(min_shares - 1);
+            let result = player.recover_public_key(&shares);
+            assert!(result.is_err());
+            assert_eq!(result.unwrap_err().to_string(), "not enough shares");
+
+            // Duplicate shares.
+            let mut shares = public_shares(min_shares);
+            shares.push(shares[0].clone());
+            let result = player.recover_public_key(&shares);
+            assert!(result.is_err());
+            assert_eq!(result.unwrap_err().to_string(), "not distinct shares");
+
+            // Exact number of shares.
+            let shares = public_shares(min_shares);
+            let recovered = player.recover_public_key(&shares).unwrap();
+            assert_eq!(key, recovered);
+
+            // Too many shares.
+            let shares = public_shares(min_shares + 10);
+            let recovered = player.recover_public_key(&shares).unwrap();
+            assert_eq!(key, recovered);
+        }
+    }
+
+    #[test]
+    fn test_verify_share() {
+        let test_cases = vec![
+            HandoffKind::DealingPhase,
+            HandoffKind::CommitteeUnchanged,
+            HandoffKind::CommitteeChanged,
+        ];
+
+        for kind in test_cases.into_iter() {
+            let threshold = 2;
+            let dealer = Dealer::new(threshold, &mut OsRng).unwrap();
+            let vm = dealer.verification_matrix();
+            let player = Player::new(threshold, kind);
+            let x = PrimeField::from_u64(1);
+            let share = dealer.make_share(x, kind);
+
+            player.verify_share(&share, vm).unwrap();
+            player.verify_point(&Point::new(x, *share.y()), vm).unwrap();
+
+            // Invalid coefficient.
+            let mut p = share.polynomial().clone();
+            p.set_coefficient(1, PrimeField::ONE);
+            let invalid = SecretShare::new(x, p);
+            let result = player.verify_share(&invalid, vm);
+            assert!(result.is_err());
+            let share_kind = match kind {
+                HandoffKind::CommitteeChanged => "reduced share",
+                _ => "full share",
+            };
+            assert_eq!(
+                result.unwrap_err().to_string(),
+                format!(
+                    "invalid share of shareholder {}: invalid coefficient 1 of the {}",
+                    "0".repeat(94) + "01",
+                    share_kind,
+                )
+            );
+
+            // Invalid size.
+            let other_kind = match kind {
+                HandoffKind::CommitteeChanged => HandoffKind::DealingPhase,
+                _ => HandoffKind::CommitteeChanged,
+            };
+            let invalid = dealer.make_share(x, other_kind);
+            let result = player.verify_share(&invalid, vm);
+            assert!(result.is_err());
+            assert!(result
+                .unwrap_err()
+                .to_string()
+                .ends_with(&format!("invalid size of the {}", share_kind)));
+
+            // Invalid point.
+            let result = player.verify_point(&Point::new(x, PrimeField::ONE), vm);
+            assert!(result.is_err());
+            assert_eq!(
+                result.unwrap_err().to_string(),
+                format!(
+                    "invalid share point of shareholder {}",
+                    "0".repeat(94) + "01"
+                )
+            );
+
+            // Unexpected dimensions.
+            let other = Dealer::new(threshold + 1, &mut OsRng).unwrap();
+            let result = player.verify_share(&share, other.verification_matrix());
+            assert!(result.is_err());
+            assert_eq!(
+                result.unwrap_err().to_string(),
+                "unexpected verification matrix dimensions: expected (3, 5), got (4, 7)"
+            );
+        }
+    }
+
+    #[test]
+    fn test_proactivize() {
+        let test_cases = vec![
+            HandoffKind::DealingPhase,
+            HandoffKind::CommitteeUnchanged,
+            HandoffKind::CommitteeChanged,
+        ];
+
+        for kind in test_cases.into_iter() {
+            let threshold = 2;
+            let secret = PrimeField::from_u64(100);
+            let dealer = Dealer::new_with_secret(threshold, secret, &mut OsRng).unwrap();
+            let player = Player::new(threshold, kind);
+            let xs: Vec<_> = (1..=player.min_shares() as u64)
+                .map(PrimeField::from_u64)
+                .collect();
+
+            let updaters: Vec<_> = (0..2)
+                .map(|_| Dealer::new_proactive(threshold, &mut OsRng).unwrap())
+                .collect();
+            let updates = |x: PrimeField| -> Vec<_> {
+                updaters
+                    .iter()
+                    .map(|d| {
+                        VerifiableSecretShare::new(
+                            d.make_share(x, kind),
+                            d.verification_matrix().clone(),
+                        )
+                    })
+                    .collect()
+            };
+
+            let mut shares = Vec::new();
+            for x in xs.iter() {
+                let shareholder: Shareholder<Group> = VerifiableSecretShare::new(
+                    dealer.make_share(*x, kind),
+                    dealer.verification_matrix().clone(),
+                )
+                .into();
+                let proactivized = player.proactivize(&shareholder, &updates(*x)).unwrap();
+
+                let vm = updaters
+                    .iter()
+                    .fold(dealer.verification_matrix().clone(), |vm, d| {
+                        vm + d.verification_matrix()
+                    });
+                let share = proactivized.verifiable_share();
+                assert_eq!(share.verification_matrix(), &vm);
+                assert_ne!(share.y(), shareholder.verifiable_share().y());
+                player.verify_share(share.secret_share(), &vm).unwrap();
+
+                shares.push(SecretShare::new(*x, share.polynomial().clone()));
+            }
+
+            // The secret doesn't change.
+            assert_eq!(player.recover_secret(&shares).unwrap(), secret);
+
+            let x = xs[0];
+            let shareholder: Shareholder<Group> = VerifiableSecretShare::new(
+                dealer.make_share(x, kind),
+                dealer.verification_matrix().clone(),
+            )
+            .into();
+
+            // No updates.
+            let result = player.proactivize(&shareholder, &[]);
+            assert!(result.is_err());
+            assert_eq!(result.err().unwrap().to_string(), "not enough dealers");
+
+            // Update of another shareholder.
+            let mut invalid = updates(x);
+            invalid.extend(updates(xs[1]));
+            let result = player.proactivize(&shareholder, &invalid);
+            assert!(result.is_err());
+            assert_eq!(
+                result.err().unwrap().to_string(),
+                "shareholder identity mismatch"
+            );
+
+            // Update which is not zero-hole.
+            let other = Dealer::new(threshold, &mut OsRng).unwrap();
+            let mut invalid = updates(x);
+            invalid.push(VerifiableSecretShare::new(
+                other.make_share(x, kind),
+                other.verification_matrix().clone(),
+            ));
+            let result = player.proactivize(&shareholder, &invalid);
+            assert!(result.is_err());
+            assert_eq!(
+                result.err().unwrap().to_string(),
+                "verification matrix zero-hole mismatch"
+            );
+        }
+    }
+
+    #[test]
+    fn test_switch_point() {
+        let test_cases = vec![
+            HandoffKind::DealingPhase,
+            HandoffKind::CommitteeUnchanged,
+            HandoffKind::CommitteeChanged,
+        ];
+
+        for kind in test_cases.into_iter() {
+            let threshold = 2;
+            let dealer = Dealer::new(threshold, &mut OsRng).unwrap();
+            let vm = dealer.verification_matrix();
+            let player = Player::new(threshold, kind);
+            let x = PrimeField::from_u64(1);
+            let shareholder: Shareholder<Group> =
+                VerifiableSecretShare::new(dealer.make_share(x, kind), vm.clone()).into();
+
+            let me = churp::encode_shareholder::<Suite>(b"bob", b"dst").unwrap();
+            let response = player
+                .switch_point::<Suite, _>(&shareholder, b"bob", b"dst")
+                .unwrap();
+            assert_eq!(response.point().x(), &x);
+            assert!(response.verify(&me));
+            assert!(!response.verify(&x));
+
+            // The switch point is a point on the share of the requester.
+            let bp = dealer.bivariate_polynomial();
+            let expected = match kind {
+                HandoffKind::CommitteeChanged => bp.eval(&me, &x),
+                _ => bp.eval(&x, &me),
+            };
+            assert_eq!(response.into_point().y(), &expected);
+
+            // Share of another kind.
+            let other_kind = match kind {
+                HandoffKind::CommitteeChanged => HandoffKind::DealingPhase,
+                _ => HandoffKind::CommitteeChanged,
+            };
+            let shareholder: Shareholder<Group> =
+                VerifiableSecretShare::new(dealer.make_share(x, other_kind), vm.clone()).into();
+            let result = player.switch_point::<Suite, _>(&shareholder, b"bob", b"dst");
+            assert!(result.is_err());
+        }
+    }
+
+    #[test]
+    fn test_player_state() {
+        let threshold = 2;
+        let kind = HandoffKind::CommitteeUnchanged;
+        let dealer = Dealer::new(threshold, &mut OsRng).unwrap();
+        let vm = dealer.verification_matrix();
+        let player = Player::new_with_epoch(threshold, kind, 5);
+        let x = PrimeField::from_u64(1);
+        let share = dealer.make_share(x, kind);
+        let y = *share.y();
+        let shareholder: Shareholder<Group> = VerifiableSecretShare::new(share, vm.clone()).into();
+        let switch_points = (2..4)
+            .map(|i| SwitchPoint::new(PrimeField::from_u64(i), PrimeField::from_u64(i + 10)))
+            .collect();
+
+        let state = PlayerState::new(&player, Some(shareholder), switch_points);
+        let bytes = state.to_bytes();
+        let restored = PlayerState::<Group>::from_bytes(bytes.expose()).unwrap();
+        assert_eq!(restored.epoch(), 5);
+        assert_eq!(restored.player().epoch(), 5);
+        assert_eq!(restored.player().min_shares(), player.min_shares());
+        let share = restored.shareholder().unwrap().verifiable_share();
+        assert_eq!(share.x(), &x);
+        assert_eq!(share.y(), &y);
+        assert_eq!(share.verification_matrix(), vm);
+        assert_eq!(restored.switch_points().len(), 2);
+        assert_eq!(restored.switch_points()[1].x(), &PrimeField::from_u64(3));
+        assert_eq!(restored.switch_points()[1].y(), &PrimeField::from_u64(13));
+        assert_eq!(restored.to_bytes().expose(), bytes.expose());
+
+        // No share.
+        let player = Player::new_with_epoch(threshold, kind, 6);
+        let state = PlayerState::<Group>::new(&player, None, vec![]);
+        let restored = PlayerState::<Group>::from_bytes(state.to_bytes().expose()).unwrap();
+        assert_eq!(restored.epoch(), 6);
+        assert!(restored.shareholder().is_none());
+        assert!(restored.switch_points().is_empty());
+
+        // Unsupported version.
+        let mut invalid = bytes.expose().clone();
+        invalid[0] = 2;
+        let result = PlayerState::<Group>::from_bytes(&invalid);
+        assert!(result.is_err());
+        assert_eq!(
+            result.err().unwrap().to_string(),
+            "unsupported state version 2"
+        );
+
+        // Truncated and trailing bytes.
+        let len = bytes.expose().len();
+        let result = PlayerState::<Group>::from_bytes(&bytes.expose()[..len - 1]);
+        assert!(result.is_err());
+        assert_eq!(result.err().unwrap().to_string(), "invalid state");
+
+        let mut invalid = bytes.expose().clone();
+        invalid.push(0);
+        assert!(PlayerState::<Group>::from_bytes(&invalid).is_err());
+
+        // Share of another degree.
+        let mut invalid = bytes.expose().clone();
+        invalid[1] = 3;
+        assert!(PlayerState::<Group>::from_bytes(&invalid).is_err());
+    }
+
+    #[test]
+    fn test_derive_secret() {
+        let threshold = 2;
+        let kind = HandoffKind::DealingPhase;
+        let secret = PrimeField::from_u64(100);
+        let dealer = Dealer::new_with_secret(threshold, secret, &mut OsRng).unwrap();
+        let vm = dealer.verification_matrix();
+        let player = Player::new(threshold, kind);
+        let shareholders: Vec<Shareholder<Group>> = (1..=5)
+            .map(PrimeField::from_u64)
+            .map(|x| VerifiableSecretShare::new(dealer.make_share(x, kind), vm.clone()).into())
+            .collect();
+
+        let derive = |shareholders: &[Shareholder<Group>], context: &KeyDerivationContext| {
+            let key_shares: Vec<_> = shareholders
+                .iter()
+                .map(|sh| player.derive_key_share::<Suite>(sh, context).unwrap())
+                .collect();
+            *player
+                .derive_secret::<Suite>(&key_shares, context)
+                .unwrap()
+                .expose()
+        };
+
+        let context = KeyDerivationContext::new("p384", b"runtime", b"key id");
+        let derived = derive(&shareholders[..3], &context);
+
+        // Any subset of shareholders derives the same secret.
+        assert_eq!(derive(&shareholders[2..], &context), derived);
+
+        // Secrets are scoped to the suite, the runtime and the key ID.
+        let contexts = [
+            KeyDerivationContext::new("p256", b"runtime", b"key id"),
+            KeyDerivationContext::new("p384", b"other runtime", b"key id"),
+            KeyDerivationContext::new("p384", b"runtime", b"other key id"),
+        ];
+        for context in contexts.iter() {
+            assert_ne!(derive(&shareholders[..3], context), derived);
+        }
+
+        // Not enough key shares.
+        let key_share = player
+            .derive_key_share::<Suite>(&shareholders[0], &context)
+            .unwrap();
+        let result = player.derive_secret::<Suite>(&[key_share], &context);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_key_derivation_context() {
+        let context = KeyDerivationContext::new("p384", b"runtime", b"key id");
+        let info = context.info();
+        assert!(info.starts_with(b"CHURP-KeyDerivation-Info"));
+        assert!(!info.starts_with(&context.dst()));
+
+        // Identifiers are length-prefixed, so moving bytes between them
+        // changes both the domain separation tag and the info.
+        let shifted = KeyDerivationContext::new("p384", b"runtimek", b"ey id");
+        assert_ne!(shifted.dst(), context.dst());
+        assert_ne!(shifted.info(), info);
+
+        let shifted = KeyDerivationContext::new("p384r", b"untime", b"key id");
+        assert_ne!(shifted.dst(), context.dst());
+        assert_ne!(shifted.info(), info);
+    }
+
+    #[test]
+    fn test_evaluate_blinded() {
+        let threshold = 2;
+        let kind = HandoffKind::DealingPhase;
+        let secret = PrimeField::from_u64(100);
+        let dealer = Dealer::new_with_secret(threshold, secret, &mut OsRng).unwrap();
+        let vm = dealer.verification_matrix();
+        let player = Player::new(threshold, kind);
+        let shareholders: Vec<PurposeShareholder<Group>> = (1..=3)
+            .map(PrimeField::from_u64)
+            .map(|x| VerifiableSecretShare::new(dealer.make_share(x, kind), vm.clone()).into())
+            .map(|sh| PurposeShareholder::new(KeyPurpose::Evaluation, sh))
+            .collect();
+
+        let (blinding, blinded) =
+            Blinding::<Group>::blind::<Suite>(b"key id", b"dst", &mut OsRng).unwrap();
+        let hash = Suite::hash_to_group(b"key id", b"dst").unwrap();
+        assert_ne!(blinded, hash);
+
+        let key_shares: Vec<_> = shareholders
+            .iter()
+            .map(|sh| player.evaluate_blinded(sh, &blinded).unwrap())
+            .collect();
+        let blinded_key = player.recover_key(&key_shares).unwrap();
+        assert_eq!(blinding.unblind(&blinded_key), hash * secret);
+
+        // Identity.
+        let result = player.evaluate_blinded(&shareholders[0], &Group::identity());
+        assert!(result.is_err());
+        assert_eq!(result.err().unwrap().to_string(), "invalid blinded element");
+    }
+
+    #[test]
+    fn test_verifiable_key_share() {
+        let test_cases = vec![
+            HandoffKind::DealingPhase,
+            HandoffKind::CommitteeUnchanged,
+            HandoffKind::CommitteeChanged,
+        ];
+
+        for kind in test_cases.into_iter() {
+            let threshold = 2;
+            let secret = PrimeField::from_u64(100);
+            let dealer = Dealer::new_with_secret(threshold, secret, &mut OsRng).unwrap();
+            let vm = dealer.verification_matrix();
+            let root = vm.merkle_root();
+            let player = Player::new(threshold, kind);
+            let min_shares = player.min_shares() as u64;
+            let context = KeyDerivationContext::new("p384", b"runtime", b"key id");
+
+            let key_shares: Vec<_> = (1..=min_shares)
+                .map(PrimeField::from_u64)
+                .map(|x| {
+                    let shareholder: Shareholder<Group> =
+                        VerifiableSecretShare::new(dealer.make_share(x, kind), vm.clone()).into();
+                    player
+                        .derive_verifiable_key_share::<Suite>(&shareholder, &context, &mut OsRng)
+                        .unwrap()
+                })
+                .collect();
+
+            for key_share in key_shares.iter() {
+                player
+                    .verify_key_share::<Suite>(key_share, &root, &context)
+                    .unwrap();
+            }
+
+            // The verified key shares derive the secret.
+            let points: Vec<_> = key_shares.into_iter().map(|k| k.into_point()).collect();
+            player.derive_secret::<Suite>(&points, &context).unwrap();
+
+            let x = PrimeField::from_u64(1);
+            let shareholder: Shareholder<Group> =
+                VerifiableSecretShare::new(dealer.make_share(x, kind), vm.clone()).into();
+            let key_share = player
+                .derive_verifiable_key_share::<Suite>(&shareholder, &context, &mut OsRng)
+                .unwrap();
+
+            // Another context.
+            let other = KeyDerivationContext::new("p384", b"runtime", b"other key id");
+            let result = player.verify_key_share::<Suite>(&key_share, &root, &other);
+            assert!(result.is_err());
+            assert_eq!(
+                result.unwrap_err().to_string(),
+                format!(
+                    "invalid evaluation of shareholder {}",
+                    "0".repeat(94) + "01"
+                )
+            );
+
+            // Another matrix.
+            let other = Dealer::new(threshold, &mut OsRng).unwrap();
+            let root = other.verification_matrix().merkle_root();
+            let result = player.verify_key_share::<Suite>(&key_share, &root, &context);
+            assert!(result.is_err());
+        }
+    }
+
+    #[test]
+    fn test_share_possession() {
+        let test_cases = vec![
+            HandoffKind::DealingPhase,
+            HandoffKind::CommitteeUnchanged,
+            HandoffKind::CommitteeChanged,
+        ];
+
+        for kind in test_cases.into_iter() {
+            let threshold = 2;
+            let dealer = Dealer::new(threshold, &mut OsRng).unwrap();
+            let vm = dealer.verification_matrix();
+            let player = Player::new(threshold, kind);
+            let x = PrimeField::from_u64(1);
+            let shareholder: Shareholder<Group> =
+                VerifiableSecretShare::new(dealer.make_share(x, kind), vm.clone()).into();
+
+            let proof = player.prove_share_possession(&shareholder, b"epoch 1", &mut OsRng);
+            player
+                .verify_share_possession(&x, vm, &proof, b"epoch 1")
+                .unwrap();
+
+            // Different context.
+            let result = player.verify_share_possession(&x, vm, &proof, b"epoch 2");
+            assert!(result.is_err());
+
+            // Different shareholder.
+            let result =
+                player.verify_share_possession(&PrimeField::from_u64(2), vm, &proof, b"epoch 1");
+            assert!(result.is_err());
+            assert_eq!(
+                result.unwrap_err().to_string(),
+                format!(
+                    "invalid share possession proof of shareholder {}",
+                    "0".repeat(94) + "02"
+                )
+            );
+
+            // Share of another dealing.
+            let other = Dealer::new(threshold, &mut OsRng).unwrap();
+            let result =
+                player.verify_share_possession(&x, other.verification_matrix(), &proof, b"epoch 1");
+            assert!(result.is_err());
+        }
+    }
+
+    #[test]
+    fn test_evidence() {
+        let threshold = 2;
+        let kind = HandoffKind::DealingPhase;
+        let dealer = Dealer::new(threshold, &mut OsRng).unwrap();
+        let vm = dealer.verification_matrix();
+        let player = Player::new_with_epoch(threshold, kind, 3);
+        let signer = TestSigner(b"key".to_vec());
+        let x = PrimeField::from_u64(1);
+        let share = dealer.make_share(x, kind);
+
+        // Valid payloads are not recorded.
+        player.verify_share(&share, vm).unwrap();
+        player.verify_point(&Point::new(x, *share.y()), vm).unwrap();
+        assert!(player.evidence().is_empty());
+
+        // Invalid payloads are recorded.
+        let mut p = share.polynomial().clone();
+        p.set_coefficient(0, PrimeField::ONE);
+        let invalid = SecretShare::new(x, p);
+        let share_error = player.verify_share(&invalid, vm).unwrap_err();
+        let point_error = player
+            .verify_point(&Point::new(x, PrimeField::ONE), vm)
+            .unwrap_err();
+
+        let evidence = player.evidence();
+        assert_eq!(evidence.len(), 2);
+        assert_eq!(evidence[0].epoch, 3);
+        assert_eq!(evidence[0].sender, x.to_repr().to_vec());
+        assert_eq!(evidence[0].reason, share_error.to_string());
+        assert_eq!(
+            evidence[0],
+            Misbehavior::new(
+                3,
+                x.to_repr().to_vec(),
+                &invalid.polynomial().to_bytes(),
+                share_error.to_string()
+            )
+        );
+        assert_eq!(evidence[1].reason, point_error.to_string());
+
+        // Repeated payloads of the same sender are recorded once.
+        player.verify_share(&invalid, vm).unwrap_err();
+        assert_eq!(player.evidence().len(), 2);
+
+        // Export.
+        let bundle = player.export_evidence(&signer).unwrap();
+        assert_eq!(bundle.records(), &evidence[..]);
+        bundle.verify(&signer).unwrap();
+        assert!(player.evidence().is_empty());
+
+        // Evidence of old epochs can be pruned.
+        player.verify_share(&invalid, vm).unwrap_err();
+        player.prune_evidence(3);
+        assert_eq!(player.evidence().len(), 1);
+        player.prune_evidence(4);
+        assert!(player.evidence().is_empty());
+    }
+
+    #[test]
+    fn test_verify_cached() {
+        let threshold = 2;
+        let kind = HandoffKind::CommitteeUnchanged;
+        let cache = MatrixCache::new(2);
+        let player = Player::new_with_epoch(threshold, kind, 5);
+        let x = PrimeField::from_u64(1);
+
+        // The matrix of the previous epoch was replaced locally.
+        let previous = Dealer::new(threshold, &mut OsRng).unwrap();
+        let current = Dealer::new(threshold, &mut OsRng).unwrap();
+        cache.insert(b"scheme", 4, previous.verification_matrix().clone());
+        cache.insert(b"scheme", 5, current.verification_matrix().clone());
+
+        // Late payloads of the previous epoch can still be verified.
+        let share = previous.make_share(x, kind);
+        let point = Point::new(x, *share.y());
+        assert!(player
+            .verify_share(&share, current.verification_matrix())
+            .is_err());
+        player
+            .verify_cached_share(b"scheme", 4, &share, &cache)
+            .unwrap();
+        player
+            .verify_cached_point(b"scheme", 4, &point, &cache)
+            .unwrap();
+
+        // Invalid late payloads are attributed to their epoch.
+        player.drain_evidence();
+        let invalid = Point::new(x, PrimeField::ONE);
+        let error = player
+            .verify_cached_point(b"scheme", 4, &invalid, &cache)
+            .unwrap_err();
+        let evidence = player.evidence();
+        assert_eq!(evidence.len(), 1);
+        assert_eq!(evidence[0].epoch, 4);
+        assert_eq!(evidence[0].reason, error.to_string());
+
+        // Payloads of unknown epochs can't be verified.
+        let error = player
+            .verify_cached_point(b"scheme", 3, &point, &cache)
+            .unwrap_err();
+        assert_eq!(error.to_string(), "unknown epoch 3");
+        assert!(player
+            .verify_cached_point(b"other", 4, &point, &cache)
+            .is_err());
+    }
+
     #[test]
     fn test_kdc() {
         let test_cases = vec![
@@ -0,0 +1,197 @@
+//! Players of concurrent CHURP instances.
+
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, Mutex},
+};
+
+use anyhow::Result;
+use group::Group;
+use zeroize::Zeroize;
+
+use super::{Error, Player, Shareholder};
+
+/// A player of a single CHURP instance together with its current share.
+pub struct PlayerInstance<G>
+where
+    G: Group,
+    G::Scalar: Zeroize,
+{
+    /// The player of the instance.
+    player: Player,
+    /// The current share of the instance, if any.
+    shareholder: Mutex<Option<Arc<Shareholder<G>>>>,
+}
+
+impl<G> PlayerInstance<G>
+where
+    G: Group,
+    G::Scalar: Zeroize,
+{
+    /// Creates a new instance for the given player without a share.
+    pub fn new(player: Player) -> Self {
+        Self {
+            player,
+            shareholder: Mutex::new(None),
+        }
+    }
+
+    /// Returns the player of the instance.
+    pub fn player(&self) -> &Player {
+        &self.player
+    }
+
+    /// Returns the current share of the instance, if any.
+    pub fn shareholder(&self) -> Option<Arc<Shareholder<G>>> {
+        self.shareholder.lock().unwrap().clone()
+    }
+
+    /// Replaces the current share of the instance, e.g. after a handoff.
+    pub fn set_shareholder(&self, shareholder: Shareholder<G>) {
+        *self.shareholder.lock().unwrap() = Some(Arc::new(shareholder));
+    }
+
+    /// Returns the current share of the instance, failing if there is none.
+    pub fn require_shareholder(&self) -> Result<Arc<Shareholder<G>>> {
+        self.shareholder()
+            .ok_or_else(|| Error::ShareholderIdentityRequired.into())
+    }
+}
+
+/// A set of players of concurrent CHURP instances, e.g. of different
+/// schemes or runtimes, which allows one process to hold shares
+/// of multiple instances.
+///
+/// Instances are isolated from each other, i.e. each has its own player,
+/// share and evidence of misbehavior, and requests are routed to them
+/// by instance ID.
+pub struct PlayerSet<G>
+where
+    G: Group,
+    G::Scalar: Zeroize,
+{
+    instances: Mutex<BTreeMap<Vec<u8>, Arc<PlayerInstance<G>>>>,
+}
+
+impl<G> PlayerSet<G>
+where
+    G: Group,
+    G::Scalar: Zeroize,
+{
+    /// Creates a new empty set.
+    pub fn new() -> Self {
+        Self {
+            instances: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    /// Adds a new instance with the given ID and player.
+    ///
+    /// Fails if an instance with the same ID already exists.
+    pub fn add(&self, id: &[u8], player: Player) -> Result<Arc<PlayerInstance<G>>> {
+        let mut instances = self.instances.lock().unwrap();
+        if instances.contains_key(id) {
+            return Err(Error::DuplicateInstance.into());
+        }
+
+        let instance = Arc::new(PlayerInstance::new(player));
+        instances.insert(id.to_vec(), instance.clone());
+
+        Ok(instance)
+    }
+
+    /// Returns the instance with the given ID.
+    pub fn get(&self, id: &[u8]) -> Result<Arc<PlayerInstance<G>>> {
+        self.instances
+            .lock()
+            .unwrap()
+            .get(id)
+            .cloned()
+            .ok_or_else(|| Error::UnknownInstance.into())
+    }
+
+    /// Removes the instance with the given ID, e.g. when the scheme
+    /// is retired, and returns it.
+    pub fn remove(&self, id: &[u8]) -> Result<Arc<PlayerInstance<G>>> {
+        self.instances
+            .lock()
+            .unwrap()
+            .remove(id)
+            .ok_or_else(|| Error::UnknownInstance.into())
+    }
+
+    /// Returns true if an instance with the given ID exists.
+    pub fn contains(&self, id: &[u8]) -> bool {
+        self.instances.lock().unwrap().contains_key(id)
+    }
+
+    /// Returns the IDs of all instances in sorted order.
+    pub fn ids(&self) -> Vec<Vec<u8>> {
+        self.instances.lock().unwrap().keys().cloned().collect()
+    }
+}
+
+impl<G> Default for PlayerSet<G>
+where
+    G: Group,
+    G::Scalar: Zeroize,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::churp::{HandoffKind, Player, VerifiableSecretShare};
+
+    use super::PlayerSet;
+
+    type PrimeField = p384::Scalar;
+    type Group = p384::ProjectivePoint;
+    type Dealer = crate::churp::Dealer<Group>;
+
+    #[test]
+    fn test_player_set() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let kind = HandoffKind::DealingPhase;
+        let set = PlayerSet::<Group>::new();
+
+        let alice = set.add(b"alice", Player::new(1, kind)).unwrap();
+        let bob = set.add(b"bob", Player::new(2, kind)).unwrap();
+        assert_eq!(set.ids(), vec![b"alice".to_vec(), b"bob".to_vec()]);
+
+        // Duplicate instance.
+        let result = set.add(b"alice", Player::new(1, kind));
+        assert!(result.is_err());
+        assert_eq!(result.err().unwrap().to_string(), "duplicate instance");
+
+        // Instances are isolated.
+        let x = PrimeField::from_u64(1);
+        let dealer = Dealer::new(1, &mut rng).unwrap();
+        let share = dealer.make_share(x, kind);
+        let vm = dealer.verification_matrix().clone();
+        alice.set_shareholder(VerifiableSecretShare::new(share, vm).into());
+
+        let instance = set.get(b"alice").unwrap();
+        assert_eq!(instance.player().epoch(), 0);
+        let shareholder = instance.require_shareholder().unwrap();
+        assert_eq!(shareholder.verifiable_share().x(), &x);
+        assert!(bob.shareholder().is_none());
+        let result = set.get(b"bob").unwrap().require_shareholder();
+        assert!(result.is_err());
+
+        // Unknown instance.
+        let result = set.get(b"carol");
+        assert!(result.is_err());
+        assert_eq!(result.err().unwrap().to_string(), "unknown instance");
+
+        // Removal.
+        set.remove(b"alice").unwrap();
+        assert!(!set.contains(b"alice"));
+        assert!(set.contains(b"bob"));
+        assert!(set.remove(b"alice").is_err());
+    }
+}
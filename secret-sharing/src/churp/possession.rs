@@ -0,0 +1,154 @@
+//! Proofs of share possession.
+
+use group::{
+    ff::{Field, PrimeField},
+    Group, GroupEncoding,
+};
+use rand_core::RngCore;
+use zeroize::Zeroize;
+
+use crate::poly::XofRng;
+
+/// Domain separation tag for the challenge of share possession proofs.
+const SHARE_POSSESSION_PROOF_DST: &[u8] = b"CHURP-SharePossessionProof";
+
+/// Non-interactive zero-knowledge proof that a shareholder holds a valid
+/// share for its identity.
+///
+/// The public value `Y = y_i * G` of the share of the shareholder `x_i`
+/// can be computed by anyone from the verification matrix, so the holder
+/// proves knowledge of its discrete logarithm `y_i` using a Schnorr proof
+/// made non-interactive with the Fiat-Shamir transform:
+/// ```text
+///     R = k * G
+///     c = Hash(x_i || Y || R || context)
+///     s = k + c * y_i
+/// ```
+/// The proof is valid iff `s * G = R + c * Y`. Since it binds the identity
+/// of the shareholder and the given context, e.g. the epoch and a nonce
+/// chosen by the auditor, proofs cannot be replayed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SharePossessionProof<G>
+where
+    G: Group,
+{
+    /// The commitment to the nonce `R = k * G`.
+    r: G,
+    /// The response `s = k + c * y_i`.
+    s: G::Scalar,
+}
+
+impl<G> SharePossessionProof<G>
+where
+    G: Group + GroupEncoding,
+{
+    /// Proves knowledge of the share point `(x, y)`.
+    pub fn prove(x: &G::Scalar, y: &G::Scalar, context: &[u8], rng: &mut impl RngCore) -> Self
+    where
+        G::Scalar: Zeroize,
+    {
+        let public = G::generator() * y;
+
+        let mut k = G::Scalar::random(rng);
+        let r = G::generator() * k;
+        let c = Self::challenge(x, &public, &r, context);
+        let s = k + c * y;
+        k.zeroize();
+
+        Self { r, s }
+    }
+
+    /// Verifies that the shareholder with the given identity knows
+    /// the discrete logarithm of the given public value of its share.
+    pub fn verify(&self, x: &G::Scalar, public: &G, context: &[u8]) -> bool {
+        let c = Self::challenge(x, public, &self.r, context);
+        let diff = G::generator() * self.s - self.r - *public * c;
+
+        diff.is_identity().into()
+    }
+
+    /// Returns the byte representation of the proof.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(Self::byte_size());
+        bytes.extend_from_slice(self.r.to_bytes().as_ref());
+        bytes.extend_from_slice(self.s.to_repr().as_ref());
+        bytes
+    }
+
+    /// Attempts to create a proof from its byte representation.
+    ///
+    /// This method is not constant time since the proof doesn't contain
+    /// sensitive information.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != Self::byte_size() {
+            return None;
+        }
+
+        let (r_bytes, s_bytes) = bytes.split_at(Self::group_byte_size());
+        let mut r_repr: G::Repr = Default::default();
+        r_repr.as_mut().copy_from_slice(r_bytes);
+        let mut s_repr = <G::Scalar as PrimeField>::Repr::default();
+        s_repr.as_mut().copy_from_slice(s_bytes);
+
+        let r = Option::from(G::from_bytes(&r_repr))?;
+        let s = Option::from(G::Scalar::from_repr(s_repr))?;
+
+        Some(Self { r, s })
+    }
+
+    /// Returns the size of the byte representation of the proof.
+    pub fn byte_size() -> usize {
+        Self::group_byte_size() + <G::Scalar as PrimeField>::Repr::default().as_ref().len()
+    }
+
+    /// Returns the size of the byte representation of a group element.
+    fn group_byte_size() -> usize {
+        G::Repr::default().as_ref().len()
+    }
+
+    /// Computes the Fiat-Shamir challenge.
+    fn challenge(x: &G::Scalar, public: &G, r: &G, context: &[u8]) -> G::Scalar {
+        let mut transcript = Vec::new();
+        transcript.extend_from_slice(x.to_repr().as_ref());
+        for p in [public, r] {
+            transcript.extend_from_slice(p.to_bytes().as_ref());
+        }
+        transcript.extend_from_slice(context);
+
+        G::Scalar::random(XofRng::new(&transcript, SHARE_POSSESSION_PROOF_DST))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use group::{ff::Field, Group as _};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::SharePossessionProof;
+
+    type PrimeField = p384::Scalar;
+    type Group = p384::ProjectivePoint;
+
+    #[test]
+    fn test_share_possession_proof() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let x = PrimeField::from_u64(1);
+        let y = PrimeField::random(&mut rng);
+        let public = Group::generator() * y;
+
+        let proof = SharePossessionProof::<Group>::prove(&x, &y, b"context", &mut rng);
+        assert!(proof.verify(&x, &public, b"context"));
+
+        // Different context, identity or public value.
+        assert!(!proof.verify(&x, &public, b"other context"));
+        assert!(!proof.verify(&PrimeField::from_u64(2), &public, b"context"));
+        assert!(!proof.verify(&x, &(public + Group::generator()), b"context"));
+
+        // Serialization.
+        let bytes = proof.to_bytes();
+        assert_eq!(bytes.len(), SharePossessionProof::<Group>::byte_size());
+        let decoded = SharePossessionProof::<Group>::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded, proof);
+        assert!(SharePossessionProof::<Group>::from_bytes(&bytes[1..]).is_none());
+    }
+}
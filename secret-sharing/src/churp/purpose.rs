@@ -0,0 +1,77 @@
+//! Shares of keys dedicated to a single purpose.
+
+use anyhow::Result;
+use group::Group;
+use zeroize::Zeroize;
+
+use super::{Error, Shareholder};
+
+/// The purpose of a shared key which is used by evaluations at elements
+/// chosen by the requester.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyPurpose {
+    /// Blinded and verifiable evaluations, e.g. of an oblivious
+    /// pseudorandom function.
+    Evaluation,
+    /// Threshold decryption.
+    Decryption,
+}
+
+/// A shareholder of a key dedicated to a single purpose.
+///
+/// Evaluating a share at an element chosen by the requester hands out
+/// a Diffie-Hellman oracle for the shared key. If the key were the master
+/// secret, a requester could obtain key shares for any key identifier
+/// without a key derivation context. The shared key must therefore be
+/// dealt separately for every purpose, e.g. as an additional secret
+/// of a [`super::BatchHandoff`], and never be reused for another one.
+pub struct PurposeShareholder<G>
+where
+    G: Group,
+    G::Scalar: Zeroize,
+{
+    /// The purpose of the shared key.
+    purpose: KeyPurpose,
+    /// The shareholder of the shared key.
+    shareholder: Shareholder<G>,
+}
+
+impl<G> PurposeShareholder<G>
+where
+    G: Group,
+    G::Scalar: Zeroize,
+{
+    /// Creates a new shareholder of a key dedicated to the given purpose.
+    pub fn new(purpose: KeyPurpose, shareholder: Shareholder<G>) -> Self {
+        Self {
+            purpose,
+            shareholder,
+        }
+    }
+
+    /// Returns the purpose of the shared key.
+    pub fn purpose(&self) -> KeyPurpose {
+        self.purpose
+    }
+
+    /// Returns the shareholder of the shared key.
+    pub fn shareholder(&self) -> &Shareholder<G> {
+        &self.shareholder
+    }
+
+    /// Returns the shareholder of the shared key if the key is dedicated
+    /// to the given purpose.
+    ///
+    /// Fails if the key is dedicated to another purpose.
+    pub fn scoped(&self, purpose: KeyPurpose) -> Result<&Shareholder<G>> {
+        if self.purpose != purpose {
+            return Err(Error::KeyPurposeMismatch {
+                expected: purpose,
+                actual: self.purpose,
+            }
+            .into());
+        }
+
+        Ok(&self.shareholder)
+    }
+}
@@ -0,0 +1,438 @@
+//! Runtime registry of CHURP cipher suites.
+
+use std::{collections::BTreeMap, marker::PhantomData};
+
+use anyhow::Result;
+use rand_core::RngCore;
+
+use crate::{
+    poly::Polynomial,
+    suites::{p384, Suite},
+    vss::VerificationMatrix,
+};
+
+#[cfg(feature = "bls12_381_g1")]
+use crate::suites::bls12_381_g1;
+#[cfg(feature = "bn254")]
+use crate::suites::bn254;
+#[cfg(feature = "ed25519")]
+use crate::suites::ed25519;
+#[cfg(feature = "jubjub")]
+use crate::suites::jubjub;
+#[cfg(feature = "k256")]
+use crate::suites::k256;
+#[cfg(feature = "p256")]
+use crate::suites::p256;
+#[cfg(feature = "p521")]
+use crate::suites::p521;
+#[cfg(feature = "pasta")]
+use crate::suites::pallas;
+#[cfg(feature = "ristretto255")]
+use crate::suites::ristretto255;
+#[cfg(feature = "pasta")]
+use crate::suites::vesta;
+
+use super::{
+    encode_shareholder, Dealer, Error, HandoffKind, Player, SecretShare, VerifiableSecretShare,
+};
+
+/// A constructor of a type-erased cipher suite.
+pub type SuiteConstructor = fn() -> Box<dyn DynSuite>;
+
+/// An object-safe facade over a cipher suite, exposing the common CHURP
+/// operations on byte-encoded field and group elements.
+///
+/// Field elements are encoded using their canonical representation,
+/// polynomials and verification matrices using their `to_bytes` methods.
+pub trait DynSuite: Send + Sync {
+    /// Returns the stable identifier of the suite.
+    fn id(&self) -> &'static str;
+
+    /// Encodes the given shareholder ID to a non-zero element of the prime
+    /// field and returns its byte representation.
+    fn encode_shareholder(&self, id: &[u8], dst: &[u8]) -> Result<Vec<u8>>;
+
+    /// Hashes an arbitrary-length byte string to an element of the group
+    /// and returns its byte representation.
+    fn hash_to_group(&self, msg: &[u8], dst: &[u8]) -> Result<Vec<u8>>;
+
+    /// Creates a new dealer of secret bivariate shares, which can be used
+    /// to recover a randomly selected shared secret.
+    fn new_dealer(&self, threshold: u8, rng: &mut dyn RngCore) -> Result<Box<dyn DynDealer>>;
+
+    /// Creates a new dealer of secret proactive bivariate shares, which can
+    /// be used to randomize a shared secret.
+    fn new_proactive_dealer(
+        &self,
+        threshold: u8,
+        rng: &mut dyn RngCore,
+    ) -> Result<Box<dyn DynDealer>>;
+
+    /// Verifies the encoded secret share against the verification matrix
+    /// in its canonical byte representation.
+    fn verify_share(
+        &self,
+        threshold: u8,
+        kind: HandoffKind,
+        x: &[u8],
+        p: &[u8],
+        vm: &[u8],
+    ) -> Result<()>;
+
+    /// Recovers the secret from the provided encoded shares, given
+    /// as pairs of shareholder coordinates and polynomials.
+    fn recover_secret(
+        &self,
+        threshold: u8,
+        kind: HandoffKind,
+        shares: &[(&[u8], &[u8])],
+    ) -> Result<Vec<u8>>;
+}
+
+/// An object-safe facade over a dealer.
+pub trait DynDealer: Send + Sync {
+    /// Returns the canonical byte representation of the verification matrix.
+    fn verification_matrix(&self) -> Vec<u8>;
+
+    /// Generates a share of the secret for the given encoded shareholder
+    /// and returns the byte representation of the share's polynomial.
+    fn make_share(&self, x: &[u8], kind: HandoffKind) -> Result<Vec<u8>>;
+}
+
+/// Adapter implementing [`DynSuite`] for a statically known suite.
+pub struct SuiteAdapter<S> {
+    id: &'static str,
+    _suite: PhantomData<fn() -> S>,
+}
+
+impl<S> SuiteAdapter<S> {
+    /// Creates a new adapter with the given identifier.
+    pub fn new(id: &'static str) -> Self {
+        Self {
+            id,
+            _suite: PhantomData,
+        }
+    }
+}
+
+impl<S: Suite> SuiteAdapter<S> {
+    /// Decodes an encoded shareholder using the suite's scalar codec.
+    fn decode_shareholder(x: &[u8]) -> Result<S::PrimeField> {
+        S::scalar_from_bytes(x).map_err(|_| Error::InvalidShareholder.into())
+    }
+}
+
+/// Adapter implementing [`DynDealer`] for a dealer of a statically known
+/// suite.
+struct SuiteDealer<S: Suite> {
+    dealer: Dealer<S::Group>,
+}
+
+impl<S> DynSuite for SuiteAdapter<S>
+where
+    S: Suite + 'static,
+    S::Group: Send + Sync,
+{
+    fn id(&self) -> &'static str {
+        self.id
+    }
+
+    fn encode_shareholder(&self, id: &[u8], dst: &[u8]) -> Result<Vec<u8>> {
+        let x = encode_shareholder::<S>(id, dst)?;
+        Ok(S::scalar_to_bytes(&x))
+    }
+
+    fn hash_to_group(&self, msg: &[u8], dst: &[u8]) -> Result<Vec<u8>> {
+        let p = S::hash_to_group(msg, dst)?;
+        Ok(S::group_to_bytes(&p))
+    }
+
+    fn new_dealer(&self, threshold: u8, mut rng: &mut dyn RngCore) -> Result<Box<dyn DynDealer>> {
+        let dealer = Dealer::<S::Group>::new(threshold, &mut rng)?;
+        Ok(Box::new(SuiteDealer::<S> { dealer }))
+    }
+
+    fn new_proactive_dealer(
+        &self,
+        threshold: u8,
+        mut rng: &mut dyn RngCore,
+    ) -> Result<Box<dyn DynDealer>> {
+        let dealer = Dealer::<S::Group>::new_proactive(threshold, &mut rng)?;
+        Ok(Box::new(SuiteDealer::<S> { dealer }))
+    }
+
+    fn verify_share(
+        &self,
+        threshold: u8,
+        kind: HandoffKind,
+        x: &[u8],
+        p: &[u8],
+        vm: &[u8],
+    ) -> Result<()> {
+        let x = Self::decode_shareholder(x)?;
+        let p = Polynomial::from_bytes(p).ok_or(Error::InvalidPolynomial)?;
+        let vm = VerificationMatrix::<S::Group>::from_canonical_bytes(vm)
+            .ok_or(Error::InvalidVerificationMatrix)?;
+        let full_share = !kind.changes_committee();
+        let share = SecretShare::new(x, p);
+        let verifiable_share = VerifiableSecretShare::new(share, vm);
+        verifiable_share.verify(threshold, kind.require_zero_hole(), full_share)
+    }
+
+    fn recover_secret(
+        &self,
+        threshold: u8,
+        kind: HandoffKind,
+        shares: &[(&[u8], &[u8])],
+    ) -> Result<Vec<u8>> {
+        let shares = shares
+            .iter()
+            .map(|(x, p)| {
+                let x = Self::decode_shareholder(x)?;
+                let p = Polynomial::from_bytes(p).ok_or(Error::InvalidPolynomial)?;
+                Ok(SecretShare::new(x, p))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let player = Player::new(threshold, kind);
+        let secret = player.recover_secret(&shares)?;
+        Ok(S::scalar_to_bytes(&secret))
+    }
+}
+
+impl<S> DynDealer for SuiteDealer<S>
+where
+    S: Suite + 'static,
+    S::Group: Send + Sync,
+{
+    fn verification_matrix(&self) -> Vec<u8> {
+        self.dealer.verification_matrix().to_canonical_bytes()
+    }
+
+    fn make_share(&self, x: &[u8], kind: HandoffKind) -> Result<Vec<u8>> {
+        let x = SuiteAdapter::<S>::decode_shareholder(x)?;
+        let share = self.dealer.make_share(x, kind);
+        Ok(share.polynomial().to_bytes())
+    }
+}
+
+/// A registry mapping stable suite identifiers to suite constructors,
+/// allowing suites to be selected at runtime, e.g. from configuration.
+pub struct SuiteRegistry {
+    constructors: BTreeMap<&'static str, SuiteConstructor>,
+}
+
+impl SuiteRegistry {
+    /// Creates a new empty registry.
+    pub fn new() -> Self {
+        Self {
+            constructors: BTreeMap::new(),
+        }
+    }
+
+    /// Registers a suite constructor under the given identifier, replacing
+    /// any previously registered constructor.
+    pub fn register(&mut self, id: &'static str, constructor: SuiteConstructor) {
+        self.constructors.insert(id, constructor);
+    }
+
+    /// Returns the suite registered under the given identifier.
+    pub fn get(&self, id: &str) -> Result<Box<dyn DynSuite>> {
+        let constructor = self.constructors.get(id).ok_or(Error::UnknownSuite)?;
+        Ok(constructor())
+    }
+
+    /// Returns true if a suite is registered under the given identifier.
+    pub fn contains(&self, id: &str) -> bool {
+        self.constructors.contains_key(id)
+    }
+
+    /// Returns the identifiers of all registered suites in sorted order.
+    pub fn ids(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.constructors.keys().copied()
+    }
+}
+
+impl Default for SuiteRegistry {
+    /// Creates a new registry containing all built-in suites.
+    fn default() -> Self {
+        let mut registry = Self::new();
+        #[cfg(feature = "bls12_381_g1")]
+        registry.register(BLS12_381_G1_SHA256, || {
+            Box::new(SuiteAdapter::<bls12_381_g1::Sha256>::new(
+                BLS12_381_G1_SHA256,
+            ))
+        });
+        #[cfg(feature = "bn254")]
+        registry.register(BN254_KECCAK256, || {
+            Box::new(SuiteAdapter::<bn254::Keccak256>::new(BN254_KECCAK256))
+        });
+        #[cfg(feature = "bn254")]
+        registry.register(BN254_SHA256, || {
+            Box::new(SuiteAdapter::<bn254::Sha256>::new(BN254_SHA256))
+        });
+        #[cfg(feature = "ed25519")]
+        registry.register(ED25519_SHA512, || {
+            Box::new(SuiteAdapter::<ed25519::Sha512>::new(ED25519_SHA512))
+        });
+        #[cfg(feature = "jubjub")]
+        registry.register(JUBJUB_BLAKE2B, || {
+            Box::new(SuiteAdapter::<jubjub::Blake2b>::new(JUBJUB_BLAKE2B))
+        });
+        #[cfg(feature = "k256")]
+        registry.register(K256_SHA3_256, || {
+            Box::new(SuiteAdapter::<k256::Sha3_256>::new(K256_SHA3_256))
+        });
+        #[cfg(feature = "p256")]
+        registry.register(P256_SHA3_256, || {
+            Box::new(SuiteAdapter::<p256::Sha3_256>::new(P256_SHA3_256))
+        });
+        #[cfg(feature = "blake3")]
+        registry.register(P384_BLAKE3, || {
+            Box::new(SuiteAdapter::<p384::Blake3>::new(P384_BLAKE3))
+        });
+        registry.register(P384_SHA3_384, || {
+            Box::new(SuiteAdapter::<p384::Sha3_384>::new(P384_SHA3_384))
+        });
+        registry.register(P384_SHA512, || {
+            Box::new(SuiteAdapter::<p384::Sha512>::new(P384_SHA512))
+        });
+        #[cfg(feature = "p521")]
+        registry.register(P521_SHA3_512, || {
+            Box::new(SuiteAdapter::<p521::Sha3_512>::new(P521_SHA3_512))
+        });
+        #[cfg(feature = "pasta")]
+        registry.register(PALLAS_BLAKE2B, || {
+            Box::new(SuiteAdapter::<pallas::Blake2b>::new(PALLAS_BLAKE2B))
+        });
+        #[cfg(feature = "ristretto255")]
+        registry.register(RISTRETTO255_SHA512, || {
+            Box::new(SuiteAdapter::<ristretto255::Sha512>::new(
+                RISTRETTO255_SHA512,
+            ))
+        });
+        #[cfg(feature = "pasta")]
+        registry.register(VESTA_BLAKE2B, || {
+            Box::new(SuiteAdapter::<vesta::Blake2b>::new(VESTA_BLAKE2B))
+        });
+        registry
+    }
+}
+
+/// Identifier of the BLS12-381 G1 suite with SHA-256.
+pub const BLS12_381_G1_SHA256: &str = "churp-bls12-381-g1-sha256";
+/// Identifier of the BN254 suite with Keccak-256.
+pub const BN254_KECCAK256: &str = "churp-bn254-keccak256";
+/// Identifier of the BN254 suite with SHA-256.
+pub const BN254_SHA256: &str = "churp-bn254-sha256";
+/// Identifier of the Ed25519 suite with SHA-512.
+pub const ED25519_SHA512: &str = "churp-ed25519-sha512";
+/// Identifier of the Jubjub suite with BLAKE2b.
+pub const JUBJUB_BLAKE2B: &str = "churp-jubjub-blake2b";
+/// Identifier of the secp256k1 suite with SHA3-256.
+pub const K256_SHA3_256: &str = "churp-k256-sha3-256";
+/// Identifier of the NIST P-256 suite with SHA3-256.
+pub const P256_SHA3_256: &str = "churp-p256-sha3-256";
+/// Identifier of the NIST P-384 suite with BLAKE3.
+pub const P384_BLAKE3: &str = "churp-p384-blake3";
+/// Identifier of the NIST P-384 suite with SHA3-384.
+pub const P384_SHA3_384: &str = "churp-p384-sha3-384";
+/// Identifier of the NIST P-384 suite with SHA-512.
+pub const P384_SHA512: &str = "churp-p384-sha512";
+/// Identifier of the NIST P-521 suite with SHA3-512.
+pub const P521_SHA3_512: &str = "churp-p521-sha3-512";
+/// Identifier of the Pallas suite with BLAKE2b.
+pub const PALLAS_BLAKE2B: &str = "churp-pallas-blake2b";
+/// Identifier of the ristretto255 suite with SHA-512.
+pub const RISTRETTO255_SHA512: &str = "churp-ristretto255-sha512";
+/// Identifier of the Vesta suite with BLAKE2b.
+pub const VESTA_BLAKE2B: &str = "churp-vesta-blake2b";
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::churp::HandoffKind;
+
+    use super::{Error, SuiteRegistry, P384_SHA3_384};
+
+    #[test]
+    fn test_registry() {
+        let registry = SuiteRegistry::default();
+        assert!(registry.contains(P384_SHA3_384));
+        assert!(!registry.contains("churp-unknown"));
+        assert!(registry.get("churp-unknown").is_err());
+
+        for id in registry.ids() {
+            let suite = registry.get(id).unwrap();
+            assert_eq!(suite.id(), id);
+        }
+
+        let mut registry = SuiteRegistry::new();
+        assert_eq!(registry.ids().count(), 0);
+        registry.register("custom", || {
+            SuiteRegistry::default().get(P384_SHA3_384).unwrap()
+        });
+        assert!(registry.get("custom").is_ok());
+    }
+
+    #[test]
+    fn test_dyn_suite() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let registry = SuiteRegistry::default();
+        let threshold = 2;
+
+        for id in registry.ids() {
+            let suite = registry.get(id).unwrap();
+
+            let dealer = suite.new_dealer(threshold, &mut rng).unwrap();
+            let vm = dealer.verification_matrix();
+            let xs = (0..=threshold)
+                .map(|i| suite.encode_shareholder(&[i], b"dst").unwrap())
+                .collect::<Vec<_>>();
+            let ps = xs
+                .iter()
+                .map(|x| dealer.make_share(x, HandoffKind::DealingPhase).unwrap())
+                .collect::<Vec<_>>();
+
+            for (x, p) in xs.iter().zip(&ps) {
+                suite
+                    .verify_share(threshold, HandoffKind::DealingPhase, x, p, &vm)
+                    .expect("share should be valid");
+            }
+
+            // Shares verified against the wrong kind are rejected.
+            suite
+                .verify_share(
+                    threshold,
+                    HandoffKind::CommitteeChanged,
+                    &xs[0],
+                    &ps[0],
+                    &vm,
+                )
+                .expect_err("share should be invalid");
+
+            let shares = xs
+                .iter()
+                .zip(&ps)
+                .map(|(x, p)| (&x[..], &p[..]))
+                .collect::<Vec<_>>();
+            let secret = suite
+                .recover_secret(threshold, HandoffKind::DealingPhase, &shares)
+                .unwrap();
+            suite
+                .recover_secret(threshold, HandoffKind::DealingPhase, &shares[1..])
+                .expect_err("recovery should fail");
+            assert!(!secret.is_empty());
+            assert!(suite.hash_to_group(b"msg", b"dst").is_ok());
+
+            // Shareholders with invalid or non-canonical encodings are rejected.
+            for x in [vec![0xff; xs[0].len()], xs[0][1..].to_vec()] {
+                let err = dealer
+                    .make_share(&x, HandoffKind::DealingPhase)
+                    .err()
+                    .unwrap();
+                assert_eq!(err.to_string(), Error::InvalidShareholder.to_string());
+            }
+        }
+    }
+}
@@ -0,0 +1,324 @@
+//! Collaborative repair of lost shares.
+
+use anyhow::Result;
+use group::{ff::PrimeField, Group};
+use rand_core::RngCore;
+use zeroize::Zeroize;
+
+use crate::{
+    poly::{lagrange::BarycentricInterpolator, Polynomial},
+    vss::{VerificationMatrix, VerificationVector},
+};
+
+use super::{Error, Player, SecretShare, Shareholder, VerifiableSecretShare};
+
+/// A contribution of a helper to the repair of the share of a shareholder
+/// who lost its state.
+///
+/// The helper `x_j` scales its share `p_j` by the Lagrange coefficient
+/// `λ_j(x_l)` of the lost shareholder `x_l`, and masks it with the sum of
+/// the random polynomials it sent to the other helpers minus the sum of
+/// the polynomials it received from them:
+/// ```text
+///     c_j = λ_j(x_l) * p_j + m_j,    m_j = \sum_k r_{j,k} - \sum_k r_{k,j}
+/// ```
+/// Since the masks cancel out, the lost share is the sum of all
+/// contributions, while a single contribution reveals nothing about
+/// the share of its helper. The contribution is accompanied by
+/// the verification vector of the mask, which allows the owner to verify
+/// it against the verification matrix.
+pub struct RepairContribution<G>
+where
+    G: Group,
+    G::Scalar: Zeroize,
+{
+    /// The identity of the helper.
+    x: G::Scalar,
+    /// The masked and scaled share of the helper.
+    p: Polynomial<G::Scalar>,
+    /// The verification vector of the mask.
+    mask: VerificationVector<G>,
+}
+
+impl<G> RepairContribution<G>
+where
+    G: Group,
+    G::Scalar: Zeroize,
+{
+    /// Creates a new contribution.
+    pub(crate) fn new(x: G::Scalar, p: Polynomial<G::Scalar>, mask: VerificationVector<G>) -> Self {
+        Self { x, p, mask }
+    }
+
+    /// Returns the identity of the helper.
+    pub fn x(&self) -> &G::Scalar {
+        &self.x
+    }
+
+    /// Returns the masked and scaled share of the helper.
+    pub fn polynomial(&self) -> &Polynomial<G::Scalar> {
+        &self.p
+    }
+
+    /// Returns the verification vector of the mask.
+    pub fn mask(&self) -> &VerificationVector<G> {
+        &self.mask
+    }
+}
+
+impl<G> Drop for RepairContribution<G>
+where
+    G: Group,
+    G::Scalar: Zeroize,
+{
+    fn drop(&mut self) {
+        self.p.zeroize();
+    }
+}
+
+/// Implements [`Player::repair_masks`].
+pub(crate) fn repair_masks<G>(
+    shareholder: &Shareholder<G>,
+    helpers: &[G::Scalar],
+    rng: &mut impl RngCore,
+) -> Vec<Polynomial<G::Scalar>>
+where
+    G: Group,
+    G::Scalar: Zeroize,
+{
+    let share = shareholder.verifiable_share();
+    let deg = share.polynomial().size() as u8 - 1;
+
+    helpers
+        .iter()
+        .filter(|x| *x != share.x())
+        .map(|_| Polynomial::random(deg, rng))
+        .collect()
+}
+
+/// Implements [`Player::repair_contribution`].
+pub(crate) fn repair_contribution<G>(
+    player: &Player,
+    shareholder: &Shareholder<G>,
+    lost: &G::Scalar,
+    helpers: &[G::Scalar],
+    sent: &[Polynomial<G::Scalar>],
+    received: &[Polynomial<G::Scalar>],
+) -> Result<RepairContribution<G>>
+where
+    G: Group,
+    G::Scalar: Zeroize,
+{
+    let share = shareholder.verifiable_share();
+    let index = helpers
+        .iter()
+        .position(|x| x == share.x())
+        .ok_or(Error::UnknownShareholder)?;
+    let lambdas = repair_coefficients(player, lost, helpers)?;
+
+    let size = share.polynomial().size();
+    let others = helpers.len() - 1;
+    if sent.len() != others || received.len() != others {
+        return Err(Error::RepairMaskMismatch.into());
+    }
+    if sent.iter().chain(received).any(|m| m.size() != size) {
+        return Err(Error::RepairMaskMismatch.into());
+    }
+
+    let mut mask = Polynomial::zero(size as u8 - 1);
+    for m in sent {
+        mask += m;
+    }
+    for m in received {
+        mask -= m;
+    }
+
+    let mut p = share.polynomial() * lambdas[index];
+    p += &mask;
+    let vv = VerificationVector::from(&mask);
+    mask.zeroize();
+
+    Ok(RepairContribution::new(*share.x(), p, vv))
+}
+
+/// Implements [`Player::repair_share`].
+pub(crate) fn repair_share<G>(
+    player: &Player,
+    lost: &G::Scalar,
+    contributions: &[RepairContribution<G>],
+    vm: &VerificationMatrix<G>,
+) -> Result<Shareholder<G>>
+where
+    G: Group,
+    G::Scalar: Zeroize,
+{
+    player.verify_dimensions(vm)?;
+
+    let helpers: Vec<_> = contributions.iter().map(|c| *c.x()).collect();
+    let lambdas = repair_coefficients(player, lost, &helpers)?;
+    let full_share = !player.kind().changes_committee();
+
+    let mut masks: Option<Vec<G>> = None;
+    for (contribution, lambda) in contributions.iter().zip(lambdas.iter()) {
+        let x = contribution.x();
+        let vv = match full_share {
+            true => vm.verification_vector_for_y(x),
+            false => vm.verification_vector_for_x(x),
+        };
+        let mask = contribution.mask().elements();
+        let p = contribution.polynomial();
+
+        let valid = mask.len() == vv.elements().len()
+            && p.size() == mask.len()
+            && vv
+                .elements()
+                .iter()
+                .zip(mask)
+                .zip(p.a.iter())
+                .all(|((v, m), c)| {
+                    bool::from((*v * lambda + m - G::generator() * c).is_identity())
+                });
+        if !valid {
+            let error = Error::InvalidRepairContribution {
+                shareholder: Player::shareholder(x),
+            };
+            player.record(x, &p.to_bytes(), &error);
+            return Err(error.into());
+        }
+
+        match masks.as_mut() {
+            Some(masks) => masks.iter_mut().zip(mask).for_each(|(a, m)| *a += m),
+            None => masks = Some(mask.to_vec()),
+        }
+    }
+
+    let masks = masks.ok_or(Error::NotEnoughShareholders)?;
+    if masks.iter().any(|m| !bool::from(m.is_identity())) {
+        return Err(Error::RepairMaskMismatch.into());
+    }
+
+    let mut p = contributions[0].polynomial().clone();
+    for contribution in &contributions[1..] {
+        p += contribution.polynomial();
+    }
+    let share = SecretShare::new(*lost, p);
+
+    Ok(VerifiableSecretShare::new(share, vm.clone()).into())
+}
+
+/// Returns the Lagrange coefficients of the given helpers at the lost
+/// shareholder.
+fn repair_coefficients<F: PrimeField>(player: &Player, lost: &F, helpers: &[F]) -> Result<Vec<F>> {
+    let expected = player.min_shares();
+    if helpers.len() < expected {
+        return Err(Error::NotEnoughShares {
+            expected,
+            actual: helpers.len(),
+        }
+        .into());
+    }
+    for (i, x) in helpers.iter().enumerate() {
+        if helpers[..i].contains(x) || x == lost {
+            return Err(Error::DuplicateShareholder.into());
+        }
+    }
+
+    Ok(BarycentricInterpolator::new(helpers.to_vec()).coefficients_at(lost))
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::OsRng;
+
+    use crate::{
+        churp::{self, HandoffKind, Player, Shareholder, VerifiableSecretShare},
+        poly::Polynomial,
+        suites::{self, p384},
+    };
+
+    // Types used in tests.
+    type Suite = p384::Sha3_384;
+    type PrimeField = <Suite as suites::Suite>::PrimeField;
+    type Group = <Suite as suites::Suite>::Group;
+    type Dealer = churp::Dealer<Group>;
+
+    #[test]
+    fn test_repair_share() {
+        let test_cases = vec![
+            HandoffKind::DealingPhase,
+            HandoffKind::CommitteeUnchanged,
+            HandoffKind::CommitteeChanged,
+        ];
+
+        for kind in test_cases.into_iter() {
+            let threshold = 2;
+            let dealer = Dealer::new(threshold, &mut OsRng).unwrap();
+            let vm = dealer.verification_matrix();
+            let player = Player::new(threshold, kind);
+            let helpers: Vec<_> = (1..=player.min_shares() as u64)
+                .map(PrimeField::from_u64)
+                .collect();
+            let shareholders: Vec<Shareholder<Group>> = helpers
+                .iter()
+                .map(|x| VerifiableSecretShare::new(dealer.make_share(*x, kind), vm.clone()).into())
+                .collect();
+            let lost = PrimeField::from_u64(10);
+
+            // Every helper sends a mask to every other helper.
+            let masks: Vec<Vec<_>> = shareholders
+                .iter()
+                .map(|sh| player.repair_masks(sh, &helpers, &mut OsRng))
+                .collect();
+            let received = |j: usize| -> Vec<_> {
+                (0..helpers.len())
+                    .filter(|k| *k != j)
+                    .map(|k| masks[k][if j < k { j } else { j - 1 }].clone())
+                    .collect()
+            };
+            let mut contributions: Vec<_> = shareholders
+                .iter()
+                .enumerate()
+                .map(|(j, sh)| {
+                    player
+                        .repair_contribution(sh, &lost, &helpers, &masks[j], &received(j))
+                        .unwrap()
+                })
+                .collect();
+
+            // A single contribution doesn't reveal the share of its helper.
+            assert!(
+                contributions[0].polynomial() != shareholders[0].verifiable_share().polynomial()
+            );
+
+            let repaired = player.repair_share(&lost, &contributions, vm).unwrap();
+            let share = repaired.verifiable_share();
+            assert_eq!(share.x(), &lost);
+            assert!(share.polynomial() == dealer.make_share(lost, kind).polynomial());
+
+            // Masks which don't cancel out.
+            let j = helpers.len() - 1;
+            let mut sent = masks[j].clone();
+            sent[0] = Polynomial::random(sent[0].degree() as u8, &mut OsRng);
+            contributions[j] = player
+                .repair_contribution(&shareholders[j], &lost, &helpers, &sent, &received(j))
+                .unwrap();
+            let result = player.repair_share(&lost, &contributions, vm);
+            assert!(result.is_err());
+            assert_eq!(result.err().unwrap().to_string(), "repair mask mismatch");
+
+            // Invalid contribution.
+            let other = Dealer::new(threshold, &mut OsRng).unwrap();
+            let result = player.repair_share(&lost, &contributions, other.verification_matrix());
+            assert!(result.is_err());
+            assert!(result
+                .err()
+                .unwrap()
+                .to_string()
+                .starts_with("invalid repair contribution of shareholder"));
+
+            // Not enough helpers.
+            let result = player.repair_share(&lost, &contributions[1..], vm);
+            assert!(result.is_err());
+        }
+    }
+}
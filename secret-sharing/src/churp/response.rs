@@ -0,0 +1,198 @@
+//! Responses verifiable against the root of the verification matrix.
+
+use group::{Group, GroupEncoding};
+use zeroize::Zeroize;
+
+use crate::{
+    poly::EncryptedPoint,
+    vss::{MerkleNode, MerkleProof, VerificationMatrix, VerificationVector},
+};
+
+use super::{DecryptionShare, HandoffKind};
+
+/// The elements of the verification matrix which commit to the
+/// secret-sharing univariate polynomial B(x,0) or B(0,y), together with
+/// their Merkle proofs.
+///
+/// For full shares, these are the elements of the first column,
+/// and for reduced shares, the elements of the first row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VectorProof<G>
+where
+    G: Group,
+{
+    /// The dimensions of the verification matrix.
+    dimensions: (usize, usize),
+    /// The elements of the first column or row.
+    elements: Vec<G>,
+    /// The Merkle proofs of the elements.
+    proofs: Vec<MerkleProof>,
+}
+
+impl<G> VectorProof<G>
+where
+    G: Group + GroupEncoding,
+{
+    /// Creates a new proof of the secret-sharing vector of the given
+    /// verification matrix for the given kind of handoff.
+    pub(crate) fn new(vm: &VerificationMatrix<G>, kind: HandoffKind) -> Self {
+        let dimensions = vm.dimensions();
+        let tree = vm.merkle_tree();
+
+        let (elements, proofs) = Self::indices(dimensions, kind)
+            .map(|(i, j)| {
+                let element = *vm.element(i, j).expect("element should exist");
+                let index = VerificationMatrix::<G>::merkle_leaf_index(dimensions.1, i, j);
+                let proof = tree.proof(index).expect("proof should exist");
+                (element, proof)
+            })
+            .unzip();
+
+        Self {
+            dimensions,
+            elements,
+            proofs,
+        }
+    }
+
+    /// Returns the dimensions of the verification matrix.
+    pub fn dimensions(&self) -> (usize, usize) {
+        self.dimensions
+    }
+
+    /// Verifies the elements against the given Merkle root of
+    /// the verification matrix and returns the verification vector
+    /// of the secret-sharing polynomial.
+    pub fn verify(&self, root: &MerkleNode, kind: HandoffKind) -> Option<VerificationVector<G>> {
+        let indices: Vec<_> = Self::indices(self.dimensions, kind).collect();
+        if indices.len() != self.elements.len() || indices.len() != self.proofs.len() {
+            return None;
+        }
+
+        for (((i, j), element), proof) in indices.into_iter().zip(&self.elements).zip(&self.proofs)
+        {
+            let verified = VerificationMatrix::verify_merkle_proof(
+                root,
+                self.dimensions,
+                i,
+                j,
+                element,
+                proof,
+            );
+            if !verified {
+                return None;
+            }
+        }
+
+        Some(VerificationVector::new(self.elements.clone()))
+    }
+
+    /// Returns the indices of the elements of the secret-sharing vector.
+    fn indices(
+        (rows, cols): (usize, usize),
+        kind: HandoffKind,
+    ) -> Box<dyn Iterator<Item = (usize, usize)>> {
+        match kind {
+            HandoffKind::CommitteeChanged | HandoffKind::Emergency => {
+                Box::new((0..cols).map(|j| (0, j)))
+            }
+            _ => Box::new((0..rows).map(|i| (i, 0))),
+        }
+    }
+}
+
+/// An evaluation `z_i = y_i * P` of the share of a shareholder at a base
+/// point `P`, e.g. a hashed key ID or a blinded element, which can be
+/// verified by requesters that only hold the Merkle root of the
+/// verification matrix.
+///
+/// The evaluation carries a Chaum-Pedersen proof, as used for decryption
+/// shares, that it uses the same share as the public value `Y_i = y_i * G`,
+/// and the elements of the verification matrix from which `Y_i`
+/// is computed, together with their Merkle proofs.
+#[derive(Clone)]
+pub struct VerifiableEvaluation<G>
+where
+    G: Group,
+{
+    /// The evaluation with the proof of its correctness.
+    evaluation: DecryptionShare<G>,
+    /// The proof of the secret-sharing vector.
+    vector: VectorProof<G>,
+}
+
+impl<G> VerifiableEvaluation<G>
+where
+    G: Group + GroupEncoding,
+    G::Scalar: Zeroize,
+{
+    /// Creates a new verifiable evaluation.
+    pub(crate) fn new(evaluation: DecryptionShare<G>, vector: VectorProof<G>) -> Self {
+        Self { evaluation, vector }
+    }
+
+    /// Returns the evaluation.
+    pub fn point(&self) -> &EncryptedPoint<G> {
+        self.evaluation.point()
+    }
+
+    /// Returns the proof of the secret-sharing vector.
+    pub fn vector_proof(&self) -> &VectorProof<G> {
+        &self.vector
+    }
+
+    /// Verifies the evaluation at the given base point against the given
+    /// Merkle root of the verification matrix.
+    ///
+    /// The dimensions of the matrix should be checked separately.
+    pub fn verify(&self, root: &MerkleNode, kind: HandoffKind, base: &G) -> bool {
+        let vv = match self.vector.verify(root, kind) {
+            Some(vv) => vv,
+            None => return false,
+        };
+        let public = vv.eval(self.point().x());
+
+        self.evaluation.verify(&public, base)
+    }
+
+    /// Returns the evaluation, consuming the response.
+    pub fn into_point(self) -> EncryptedPoint<G> {
+        self.evaluation.point().clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::churp::HandoffKind;
+
+    use super::VectorProof;
+
+    type PrimeField = p384::Scalar;
+    type Group = p384::ProjectivePoint;
+    type Dealer = crate::churp::Dealer<Group>;
+
+    #[test]
+    fn test_vector_proof() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let dealer = Dealer::new(2, &mut rng).unwrap();
+        let vm = dealer.verification_matrix();
+        let root = vm.merkle_root();
+        let zero = PrimeField::ZERO;
+
+        let proof = VectorProof::new(vm, HandoffKind::DealingPhase);
+        let vv = proof.verify(&root, HandoffKind::DealingPhase).unwrap();
+        assert_eq!(vv, vm.verification_vector_for_x(&zero));
+        assert!(proof.verify(&root, HandoffKind::CommitteeChanged).is_none());
+
+        let proof = VectorProof::new(vm, HandoffKind::CommitteeChanged);
+        let vv = proof.verify(&root, HandoffKind::CommitteeChanged).unwrap();
+        assert_eq!(vv, vm.verification_vector_for_y(&zero));
+
+        // Another matrix.
+        let other = Dealer::new(2, &mut rng).unwrap();
+        let root = other.verification_matrix().merkle_root();
+        assert!(proof.verify(&root, HandoffKind::CommitteeChanged).is_none());
+    }
+}
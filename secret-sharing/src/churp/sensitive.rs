@@ -0,0 +1,82 @@
+//! Wrapper for sensitive data.
+
+use std::fmt;
+
+use zeroize::Zeroize;
+
+use super::memory::MemoryLock;
+
+/// Wrapper for sensitive data, e.g. the serialized state of a dealer,
+/// which must only be stored after being sealed.
+///
+/// The wrapped data is zeroized when dropped and redacted when formatted,
+/// and can only be accessed explicitly. With the `mlock` feature,
+/// the pages holding the data are also locked into memory.
+pub struct Sensitive<T>
+where
+    T: Zeroize,
+{
+    /// The lock of the pages holding the data, released after the data
+    /// has been zeroized.
+    _lock: MemoryLock,
+    /// The sensitive data, boxed so that it doesn't move.
+    data: Box<T>,
+}
+
+impl<T> Sensitive<T>
+where
+    T: Zeroize,
+{
+    /// Wraps the given sensitive data.
+    pub fn new(data: T) -> Self
+    where
+        T: AsRef<[u8]>,
+    {
+        let data = Box::new(data);
+        let _lock = MemoryLock::new((*data).as_ref());
+
+        Self { _lock, data }
+    }
+
+    /// Returns the sensitive data.
+    pub fn expose(&self) -> &T {
+        &self.data
+    }
+
+    /// Returns true if and only if the pages holding the data are locked
+    /// into memory, which requires the `mlock` feature and may fail, e.g.
+    /// when exceeding `RLIMIT_MEMLOCK`.
+    pub fn is_locked(&self) -> bool {
+        self._lock.is_locked()
+    }
+}
+
+impl<T> fmt::Debug for Sensitive<T>
+where
+    T: Zeroize,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Sensitive(<redacted>)")
+    }
+}
+
+impl<T> Drop for Sensitive<T>
+where
+    T: Zeroize,
+{
+    fn drop(&mut self) {
+        self.data.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Sensitive;
+
+    #[test]
+    fn test_sensitive() {
+        let sensitive = Sensitive::new(vec![1u8, 2, 3]);
+        assert_eq!(sensitive.expose(), &vec![1, 2, 3]);
+        assert_eq!(format!("{:?}", sensitive), "Sensitive(<redacted>)");
+    }
+}
@@ -0,0 +1,804 @@
+//! Resumable handoffs with deadlines and retries.
+
+use std::sync::{Arc, Mutex};
+
+use anyhow::Result;
+use group::{ff::PrimeField, Group, GroupEncoding};
+use zeroize::Zeroize;
+
+use crate::{poly::Polynomial, vss::VerificationMatrix};
+
+use super::{
+    package::{append_bytes, decode_kind, encode_kind, Reader},
+    CommitteeChanged, CommitteeUnchanged, DealingPhase, Error, Handoff, HandoffKind, SecretShare,
+    Sensitive, Shareholder, SwitchPoint, SwitchStatus, VerifiableSecretShare,
+};
+
+/// The version of the byte representation of the handoff snapshot.
+const HANDOFF_SNAPSHOT_VERSION: u8 = 1;
+
+/// Phase of a handoff.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HandoffPhase {
+    /// The handoff is waiting for the verification matrix or the shareholder
+    /// from the previous handoff.
+    Setup,
+    /// The new committee is collecting share reduction switch points
+    /// from the old committee.
+    ShareReduction,
+    /// The committee is collecting bivariate shares from the dealers.
+    Proactivization,
+    /// The new committee is collecting full share distribution switch
+    /// points from each other.
+    FullShareDistribution,
+    /// The handoff has completed.
+    Completed,
+}
+
+/// Policy for re-requesting missing contributions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// The maximum number of requests sent to a participant in one phase.
+    pub max_attempts: u32,
+    /// The minimum time between two requests sent to the same participant.
+    pub interval: u64,
+}
+
+/// Deadlines of the handoff phases.
+///
+/// The deadlines are absolute and expressed in the same units as the time
+/// passed to [`HandoffSession::poll`], e.g. seconds or block heights.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PhaseDeadlines {
+    /// The deadline of the share reduction phase.
+    pub share_reduction: u64,
+    /// The deadline of the proactivization phase.
+    pub proactivization: u64,
+    /// The deadline of the full share distribution phase.
+    pub full_share_distribution: u64,
+}
+
+impl PhaseDeadlines {
+    /// Returns the deadline of the given phase, if any.
+    fn get(&self, phase: HandoffPhase) -> Option<u64> {
+        match phase {
+            HandoffPhase::Setup => None,
+            HandoffPhase::ShareReduction => Some(self.share_reduction),
+            HandoffPhase::Proactivization => Some(self.proactivization),
+            HandoffPhase::FullShareDistribution => Some(self.full_share_distribution),
+            HandoffPhase::Completed => None,
+        }
+    }
+}
+
+/// The progress of a handoff session.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HandoffStatus<F> {
+    /// The current phase of the handoff.
+    pub phase: HandoffPhase,
+    /// The deadline of the current phase, if any.
+    pub deadline: Option<u64>,
+    /// The progress of the dimension switch of the current phase.
+    pub switch: SwitchStatus<F>,
+    /// The participants whose contributions are still needed in the current
+    /// phase.
+    pub pending: Vec<F>,
+}
+
+/// An input accepted by the handoff, recorded so that the handoff can be
+/// replayed after a restart.
+enum HandoffInput<G>
+where
+    G: Group,
+    G::Scalar: Zeroize,
+{
+    VerificationMatrix(VerificationMatrix<G>),
+    Shareholder(Arc<Shareholder<G>>),
+    ShareReductionSwitchPoint(SwitchPoint<G::Scalar>),
+    FullShareDistributionSwitchPoint(SwitchPoint<G::Scalar>),
+    BivariateShare(G::Scalar, VerifiableSecretShare<G>),
+}
+
+/// A request sent to a participant for its missing contribution.
+struct Attempt<F> {
+    /// The phase of the handoff in which the request was sent.
+    phase: HandoffPhase,
+    /// The identity of the participant.
+    x: F,
+    /// The number of requests sent.
+    count: u32,
+    /// The time of the last request.
+    last: u64,
+}
+
+/// A handoff which can be persisted and resumed.
+///
+/// The session records all inputs accepted by the handoff, so that it can be
+/// restored from a snapshot by replaying them, e.g. after a restart. It also
+/// tracks per-phase deadlines and decides which participants should be asked
+/// again for their missing contributions, so that a single slow participant
+/// doesn't force the handoff to be abandoned when others can stand in for it.
+pub struct HandoffSession<G>
+where
+    G: Group,
+    G::Scalar: Zeroize,
+{
+    /// The degree of the secret-sharing polynomial.
+    threshold: u8,
+    /// The kind of the handoff.
+    kind: HandoffKind,
+    /// The encoded identity.
+    me: G::Scalar,
+    /// The new committee.
+    shareholders: Vec<G::Scalar>,
+    /// The old committee, providing share reduction switch points.
+    previous_shareholders: Vec<G::Scalar>,
+    /// The policy for re-requesting missing contributions.
+    policy: RetryPolicy,
+    /// The deadlines of the phases.
+    deadlines: PhaseDeadlines,
+    /// The underlying handoff.
+    handoff: Box<dyn Handoff<G>>,
+    /// The inputs accepted by the handoff.
+    inputs: Mutex<Vec<HandoffInput<G>>>,
+    /// The requests sent for missing contributions.
+    attempts: Mutex<Vec<Attempt<G::Scalar>>>,
+}
+
+impl<G> HandoffSession<G>
+where
+    G: Group,
+    G::Scalar: Zeroize,
+{
+    /// Creates a new session of the given kind of handoff.
+    ///
+    /// The previous shareholders are only needed when the committee changes.
+    /// The committees are validated before the handoff is created, see
+    /// `HandoffKind::validate_committees`.
+    pub fn new(
+        threshold: u8,
+        kind: HandoffKind,
+        me: G::Scalar,
+        shareholders: Vec<G::Scalar>,
+        previous_shareholders: Vec<G::Scalar>,
+        policy: RetryPolicy,
+        deadlines: PhaseDeadlines,
+    ) -> Result<Self> {
+        // Unless given, the old committee of an unchanged committee is
+        // the new committee.
+        let old_committee = match kind {
+            HandoffKind::CommitteeUnchanged if previous_shareholders.is_empty() => &shareholders,
+            _ => &previous_shareholders,
+        };
+        kind.validate_committees(threshold, old_committee, &shareholders)?;
+
+        let handoff: Box<dyn Handoff<G>> = match kind {
+            HandoffKind::DealingPhase => {
+                Box::new(DealingPhase::new(threshold, me, shareholders.clone())?)
+            }
+            HandoffKind::CommitteeUnchanged => Box::new(CommitteeUnchanged::new(
+                threshold,
+                me,
+                shareholders.clone(),
+            )?),
+            HandoffKind::CommitteeChanged | HandoffKind::Emergency => {
+                Box::new(CommitteeChanged::new(threshold, me, shareholders.clone())?)
+            }
+        };
+
+        Ok(Self {
+            threshold,
+            kind,
+            me,
+            shareholders,
+            previous_shareholders,
+            policy,
+            deadlines,
+            handoff,
+            inputs: Mutex::new(Vec::new()),
+            attempts: Mutex::new(Vec::new()),
+        })
+    }
+
+    /// Returns the underlying handoff.
+    pub fn handoff(&self) -> &dyn Handoff<G> {
+        self.handoff.as_ref()
+    }
+
+    /// Returns the current phase of the handoff.
+    pub fn phase(&self) -> HandoffPhase {
+        let handoff = self.handoff();
+
+        if handoff.get_full_shareholder().is_ok() {
+            return HandoffPhase::Completed;
+        }
+        if handoff.needs_verification_matrix().unwrap_or(false)
+            || handoff.needs_shareholder().unwrap_or(false)
+        {
+            return HandoffPhase::Setup;
+        }
+        if handoff.needs_share_reduction_switch_point(&self.me).is_ok() {
+            return HandoffPhase::ShareReduction;
+        }
+        if handoff.needs_bivariate_share(&self.me).is_ok() {
+            return HandoffPhase::Proactivization;
+        }
+
+        HandoffPhase::FullShareDistribution
+    }
+
+    /// Returns the participants whose contributions are still needed
+    /// in the current phase.
+    pub fn pending(&self) -> Vec<G::Scalar> {
+        let handoff = self.handoff();
+
+        match self.phase() {
+            HandoffPhase::ShareReduction => Self::needed(&self.previous_shareholders, |x| {
+                handoff.needs_share_reduction_switch_point(x)
+            }),
+            HandoffPhase::Proactivization => {
+                Self::needed(&self.shareholders, |x| handoff.needs_bivariate_share(x))
+            }
+            HandoffPhase::FullShareDistribution => Self::needed(&self.shareholders, |x| {
+                handoff.needs_full_share_distribution_switch_point(x)
+            }),
+            HandoffPhase::Setup | HandoffPhase::Completed => Vec::new(),
+        }
+    }
+
+    /// Returns the progress of the handoff, e.g. to find out why it is stuck.
+    pub fn status(&self) -> Result<HandoffStatus<G::Scalar>> {
+        let phase = self.phase();
+
+        Ok(HandoffStatus {
+            phase,
+            deadline: self.deadlines.get(phase),
+            switch: self.handoff.status()?,
+            pending: self.pending(),
+        })
+    }
+
+    /// Returns the given participants whose contributions are needed.
+    fn needed(xs: &[G::Scalar], needs: impl Fn(&G::Scalar) -> Result<bool>) -> Vec<G::Scalar> {
+        xs.iter()
+            .filter(|x| needs(x).unwrap_or(false))
+            .copied()
+            .collect()
+    }
+
+    /// Returns the participants which should be (re)requested to send their
+    /// missing contributions at the given time, according to the retry
+    /// policy.
+    ///
+    /// Participants that exhausted their attempts are no longer requested,
+    /// leaving the others to complete the phase if they can. Fails if the
+    /// deadline of the current phase has passed.
+    pub fn poll(&self, now: u64) -> Result<Vec<G::Scalar>> {
+        let phase = self.phase();
+        if let Some(deadline) = self.deadlines.get(phase) {
+            if now > deadline {
+                return Err(Error::DeadlineExceeded { phase }.into());
+            }
+        }
+
+        let mut attempts = self.attempts.lock().unwrap();
+        let mut requests = Vec::new();
+
+        for x in self.pending() {
+            let attempt = attempts.iter_mut().find(|a| a.phase == phase && a.x == x);
+            match attempt {
+                None => attempts.push(Attempt {
+                    phase,
+                    x,
+                    count: 1,
+                    last: now,
+                }),
+                Some(attempt) => {
+                    if attempt.count >= self.policy.max_attempts
+                        || now < attempt.last.saturating_add(self.policy.interval)
+                    {
+                        continue;
+                    }
+                    attempt.count += 1;
+                    attempt.last = now;
+                }
+            }
+            requests.push(x);
+        }
+
+        Ok(requests)
+    }
+
+    /// Sets the verification matrix from the previous handoff.
+    pub fn set_verification_matrix(&self, vm: VerificationMatrix<G>) -> Result<()> {
+        self.handoff.set_verification_matrix(vm.clone())?;
+        self.record(HandoffInput::VerificationMatrix(vm));
+        Ok(())
+    }
+
+    /// Sets the shareholder from the previous handoff.
+    pub fn set_shareholder(&self, shareholder: Arc<Shareholder<G>>) -> Result<()> {
+        self.handoff.set_shareholder(shareholder.clone())?;
+        self.record(HandoffInput::Shareholder(shareholder));
+        Ok(())
+    }
+
+    /// Adds the given switch point to share reduction.
+    pub fn add_share_reduction_switch_point(&self, point: SwitchPoint<G::Scalar>) -> Result<bool> {
+        let copy = SwitchPoint::new(*point.x(), *point.y());
+        let done = self.handoff.add_share_reduction_switch_point(point)?;
+        self.record(HandoffInput::ShareReductionSwitchPoint(copy));
+        Ok(done)
+    }
+
+    /// Adds the given switch point to full share distribution.
+    pub fn add_full_share_distribution_switch_point(
+        &self,
+        point: SwitchPoint<G::Scalar>,
+    ) -> Result<bool> {
+        let copy = SwitchPoint::new(*point.x(), *point.y());
+        let done = self
+            .handoff
+            .add_full_share_distribution_switch_point(point)?;
+        self.record(HandoffInput::FullShareDistributionSwitchPoint(copy));
+        Ok(done)
+    }
+
+    /// Adds the given bivariate share.
+    pub fn add_bivariate_share(
+        &self,
+        x: &G::Scalar,
+        verifiable_share: VerifiableSecretShare<G>,
+    ) -> Result<bool> {
+        let copy = Self::copy_share(&verifiable_share);
+        let done = self.handoff.add_bivariate_share(x, verifiable_share)?;
+        self.record(HandoffInput::BivariateShare(*x, copy));
+        Ok(done)
+    }
+
+    /// Records the given accepted input.
+    fn record(&self, input: HandoffInput<G>) {
+        self.inputs.lock().unwrap().push(input);
+    }
+
+    /// Returns a copy of the given verifiable share.
+    fn copy_share(share: &VerifiableSecretShare<G>) -> VerifiableSecretShare<G> {
+        let copy = SecretShare::new(*share.x(), share.polynomial().clone());
+        VerifiableSecretShare::new(copy, share.verification_matrix().clone())
+    }
+
+    /// Returns the versioned byte representation of the session.
+    ///
+    /// The retry bookkeeping is not included, so missing contributions
+    /// are requested anew after the session is restored. The representation
+    /// contains secret shares, so it must only be stored after being sealed.
+    pub fn snapshot(&self) -> Sensitive<Vec<u8>>
+    where
+        G: GroupEncoding,
+    {
+        let mut bytes = vec![
+            HANDOFF_SNAPSHOT_VERSION,
+            self.threshold,
+            encode_kind(self.kind),
+        ];
+        bytes.extend_from_slice(self.me.to_repr().as_ref());
+        for xs in [&self.shareholders, &self.previous_shareholders] {
+            bytes.extend_from_slice(&(xs.len() as u32).to_le_bytes());
+            for x in xs.iter() {
+                bytes.extend_from_slice(x.to_repr().as_ref());
+            }
+        }
+        bytes.extend_from_slice(&self.policy.max_attempts.to_le_bytes());
+        bytes.extend_from_slice(&self.policy.interval.to_le_bytes());
+        bytes.extend_from_slice(&self.deadlines.share_reduction.to_le_bytes());
+        bytes.extend_from_slice(&self.deadlines.proactivization.to_le_bytes());
+        bytes.extend_from_slice(&self.deadlines.full_share_distribution.to_le_bytes());
+
+        let inputs = self.inputs.lock().unwrap();
+        bytes.extend_from_slice(&(inputs.len() as u32).to_le_bytes());
+        for input in inputs.iter() {
+            match input {
+                HandoffInput::VerificationMatrix(vm) => {
+                    bytes.push(0);
+                    append_bytes(&mut bytes, &vm.to_bytes());
+                }
+                HandoffInput::Shareholder(shareholder) => {
+                    bytes.push(1);
+                    Self::write_share(&mut bytes, shareholder.verifiable_share());
+                }
+                HandoffInput::ShareReductionSwitchPoint(point) => {
+                    bytes.push(2);
+                    bytes.extend_from_slice(point.x().to_repr().as_ref());
+                    bytes.extend_from_slice(point.y().to_repr().as_ref());
+                }
+                HandoffInput::FullShareDistributionSwitchPoint(point) => {
+                    bytes.push(3);
+                    bytes.extend_from_slice(point.x().to_repr().as_ref());
+                    bytes.extend_from_slice(point.y().to_repr().as_ref());
+                }
+                HandoffInput::BivariateShare(x, share) => {
+                    bytes.push(4);
+                    bytes.extend_from_slice(x.to_repr().as_ref());
+                    Self::write_share(&mut bytes, share);
+                }
+            }
+        }
+
+        Sensitive::new(bytes)
+    }
+
+    /// Restores the session from its versioned byte representation
+    /// by replaying the recorded inputs.
+    ///
+    /// Fails if the version is not supported, if the representation
+    /// is malformed, or if any of the inputs is rejected.
+    pub fn restore(bytes: &[u8]) -> Result<Self>
+    where
+        G: GroupEncoding,
+    {
+        let version = *bytes.first().ok_or(Error::InvalidState)?;
+        if version != HANDOFF_SNAPSHOT_VERSION {
+            return Err(Error::UnsupportedSnapshotVersion { version }.into());
+        }
+
+        let mut reader = Reader { bytes: &bytes[1..] };
+        let session = Self::decode_session(&mut reader).ok_or(Error::InvalidState)?;
+
+        let n = reader.take_u32().ok_or(Error::InvalidState)?;
+        for _ in 0..n {
+            match reader.take(1).ok_or(Error::InvalidState)?[0] {
+                0 => {
+                    let vm = reader
+                        .take_prefixed()
+                        .and_then(VerificationMatrix::from_bytes)
+                        .ok_or(Error::InvalidState)?;
+                    session.set_verification_matrix(vm)?;
+                }
+                1 => {
+                    let share = Self::read_share(&mut reader).ok_or(Error::InvalidState)?;
+                    session.set_shareholder(Arc::new(share.into()))?;
+                }
+                2 => {
+                    let point = Self::read_point(&mut reader).ok_or(Error::InvalidState)?;
+                    session.add_share_reduction_switch_point(point)?;
+                }
+                3 => {
+                    let point = Self::read_point(&mut reader).ok_or(Error::InvalidState)?;
+                    session.add_full_share_distribution_switch_point(point)?;
+                }
+                4 => {
+                    let x = reader.take_scalar().ok_or(Error::InvalidState)?;
+                    let share = Self::read_share(&mut reader).ok_or(Error::InvalidState)?;
+                    session.add_bivariate_share(&x, share)?;
+                }
+                _ => return Err(Error::InvalidState.into()),
+            }
+        }
+
+        if !reader.bytes.is_empty() {
+            return Err(Error::InvalidState.into());
+        }
+
+        Ok(session)
+    }
+
+    /// Decodes the parameters of the session and creates a new one.
+    fn decode_session(reader: &mut Reader<'_>) -> Option<Self> {
+        let threshold = reader.take(1)?[0];
+        let kind = decode_kind(reader.take(1)?[0])?;
+        let me = reader.take_scalar()?;
+
+        let mut committees = Vec::with_capacity(2);
+        for _ in 0..2 {
+            let n = reader.take_u32()? as usize;
+            let mut xs = Vec::with_capacity(n.min(reader.bytes.len()));
+            for _ in 0..n {
+                xs.push(reader.take_scalar()?);
+            }
+            committees.push(xs);
+        }
+        let previous_shareholders = committees.pop()?;
+        let shareholders = committees.pop()?;
+
+        let policy = RetryPolicy {
+            max_attempts: reader.take_u32()?,
+            interval: reader.take_u64()?,
+        };
+        let deadlines = PhaseDeadlines {
+            share_reduction: reader.take_u64()?,
+            proactivization: reader.take_u64()?,
+            full_share_distribution: reader.take_u64()?,
+        };
+
+        Self::new(
+            threshold,
+            kind,
+            me,
+            shareholders,
+            previous_shareholders,
+            policy,
+            deadlines,
+        )
+        .ok()
+    }
+
+    /// Appends the byte representation of the given verifiable share.
+    fn write_share(bytes: &mut Vec<u8>, share: &VerifiableSecretShare<G>)
+    where
+        G: GroupEncoding,
+    {
+        bytes.extend_from_slice(share.x().to_repr().as_ref());
+        append_bytes(bytes, &share.polynomial().to_bytes());
+        append_bytes(bytes, &share.verification_matrix().to_bytes());
+    }
+
+    /// Consumes the byte representation of a verifiable share.
+    fn read_share(reader: &mut Reader<'_>) -> Option<VerifiableSecretShare<G>>
+    where
+        G: GroupEncoding,
+    {
+        let x = reader.take_scalar()?;
+        let p = Polynomial::from_bytes(reader.take_prefixed()?)?;
+        let vm = VerificationMatrix::from_bytes(reader.take_prefixed()?)?;
+
+        Some(VerifiableSecretShare::new(SecretShare::new(x, p), vm))
+    }
+
+    /// Consumes the byte representation of a switch point.
+    fn read_point(reader: &mut Reader<'_>) -> Option<SwitchPoint<G::Scalar>> {
+        let x = reader.take_scalar()?;
+        let y = reader.take_scalar()?;
+
+        Some(SwitchPoint::new(x, y))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::{
+        churp::{self, HandoffKind, SwitchPoint, SwitchState, VerifiableSecretShare},
+        suites::{self, p384},
+    };
+
+    use super::{HandoffPhase, PhaseDeadlines, RetryPolicy};
+
+    // Types used in tests.
+    type Suite = p384::Sha3_384;
+    type PrimeField = <Suite as suites::Suite>::PrimeField;
+    type Group = <Suite as suites::Suite>::Group;
+    type Dealer = churp::Dealer<Group>;
+    type Shareholder = churp::Shareholder<Group>;
+    type HandoffSession = churp::HandoffSession<Group>;
+
+    const POLICY: RetryPolicy = RetryPolicy {
+        max_attempts: 2,
+        interval: 10,
+    };
+
+    const DEADLINES: PhaseDeadlines = PhaseDeadlines {
+        share_reduction: 100,
+        proactivization: 200,
+        full_share_distribution: 300,
+    };
+
+    fn prepare_shareholders(ids: &[u64]) -> Vec<PrimeField> {
+        ids.iter().map(|&id| id.into()).collect()
+    }
+
+    #[test]
+    fn test_retries() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let threshold = 2;
+        let kind = HandoffKind::DealingPhase;
+        let committee = prepare_shareholders(&[1, 2, 3, 4]);
+        let dealer = Dealer::new(threshold, &mut rng).unwrap();
+        let session = HandoffSession::new(
+            threshold,
+            kind,
+            committee[0],
+            committee.clone(),
+            vec![],
+            POLICY,
+            DEADLINES,
+        )
+        .unwrap();
+
+        assert_eq!(session.phase(), HandoffPhase::Proactivization);
+        assert_eq!(session.pending(), committee);
+
+        // Everyone is requested once.
+        assert_eq!(session.poll(0).unwrap(), committee);
+        assert!(session.poll(5).unwrap().is_empty());
+
+        // Missing contributions are requested again after the interval.
+        let share = dealer.make_share(committee[0], kind);
+        let vm = dealer.verification_matrix().clone();
+        session
+            .add_bivariate_share(&committee[1], VerifiableSecretShare::new(share, vm))
+            .unwrap();
+        let expected = vec![committee[0], committee[2], committee[3]];
+        assert_eq!(session.pending(), expected);
+        assert_eq!(session.poll(10).unwrap(), expected);
+
+        // Until the attempts are exhausted.
+        assert!(session.poll(100).unwrap().is_empty());
+
+        // The deadline of the phase.
+        assert!(session.poll(200).is_ok());
+        let result = session.poll(201);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "deadline of the Proactivization phase exceeded"
+        );
+    }
+
+    #[test]
+    fn test_status() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let threshold = 2;
+        let kind = HandoffKind::DealingPhase;
+        let committee = prepare_shareholders(&[1, 2, 3, 4]);
+        let dealer = Dealer::new(threshold, &mut rng).unwrap();
+        let session = HandoffSession::new(
+            threshold,
+            kind,
+            committee[0],
+            committee.clone(),
+            vec![],
+            POLICY,
+            DEADLINES,
+        )
+        .unwrap();
+
+        let status = session.status().unwrap();
+        assert_eq!(status.phase, HandoffPhase::Proactivization);
+        assert_eq!(status.deadline, Some(DEADLINES.proactivization));
+        assert_eq!(status.switch.state, SwitchState::Merging);
+        assert!(status.switch.contributors.is_empty());
+        assert_eq!(status.switch.remaining, committee.len());
+        assert_eq!(status.pending, committee);
+
+        // One valid and one invalid bivariate share.
+        let vm = dealer.verification_matrix().clone();
+        let share = dealer.make_share(committee[0], kind);
+        session
+            .add_bivariate_share(&committee[1], VerifiableSecretShare::new(share, vm.clone()))
+            .unwrap();
+        let share = dealer.make_share(committee[1], kind);
+        assert!(session
+            .add_bivariate_share(&committee[2], VerifiableSecretShare::new(share, vm))
+            .is_err());
+
+        let status = session.status().unwrap();
+        assert_eq!(status.switch.contributors, vec![committee[1]]);
+        assert_eq!(status.switch.rejected, vec![committee[2]]);
+        assert_eq!(status.switch.remaining, 3);
+        assert_eq!(
+            status.pending,
+            vec![committee[0], committee[2], committee[3]]
+        );
+    }
+
+    #[test]
+    fn test_snapshot() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let threshold = 1;
+        let kind = HandoffKind::CommitteeChanged;
+
+        // The old committee holds full shares of the previous handoff.
+        let old_committee = prepare_shareholders(&[1, 2, 3]);
+        let old_dealer = Dealer::new(threshold, &mut rng).unwrap();
+        let vm = old_dealer.verification_matrix().clone();
+        let old_shareholders: Vec<Shareholder> = old_committee
+            .iter()
+            .map(|x| {
+                let share = old_dealer.make_share(*x, HandoffKind::CommitteeUnchanged);
+                VerifiableSecretShare::new(share, vm.clone()).into()
+            })
+            .collect();
+
+        let committee = prepare_shareholders(&[4, 5, 6]);
+        let me = committee[0];
+        let dealers: Vec<_> = committee
+            .iter()
+            .map(|_| Dealer::new_proactive(threshold, &mut rng).unwrap())
+            .collect();
+        let session = HandoffSession::new(
+            threshold,
+            kind,
+            me,
+            committee.clone(),
+            old_committee.clone(),
+            POLICY,
+            DEADLINES,
+        )
+        .unwrap();
+        assert_eq!(session.phase(), HandoffPhase::Setup);
+        assert!(session.pending().is_empty());
+
+        // Share reduction.
+        session.set_verification_matrix(vm.clone()).unwrap();
+        assert_eq!(session.phase(), HandoffPhase::ShareReduction);
+        assert_eq!(session.pending(), old_committee);
+
+        let point = |i: usize| {
+            let x = old_committee[i];
+            SwitchPoint::new(x, old_shareholders[i].switch_point(&me))
+        };
+        session.add_share_reduction_switch_point(point(0)).unwrap();
+
+        // Restart in the middle of share reduction.
+        let snapshot = session.snapshot();
+        let session = HandoffSession::restore(snapshot.expose()).unwrap();
+        assert_eq!(session.phase(), HandoffPhase::ShareReduction);
+        assert_eq!(session.pending(), old_committee[1..].to_vec());
+
+        // The slow old shareholder is replaced by another one.
+        assert!(session.add_share_reduction_switch_point(point(2)).unwrap());
+        assert_eq!(session.phase(), HandoffPhase::Proactivization);
+
+        // Proactivization.
+        for (x, dealer) in committee.iter().zip(dealers.iter()) {
+            let share = dealer.make_share(me, kind);
+            let vm = dealer.verification_matrix().clone();
+            session
+                .add_bivariate_share(x, VerifiableSecretShare::new(share, vm))
+                .unwrap();
+
+            // Restart after every share.
+            let snapshot = session.snapshot();
+            let restored = HandoffSession::restore(snapshot.expose()).unwrap();
+            assert_eq!(restored.pending(), session.pending());
+        }
+        assert_eq!(session.phase(), HandoffPhase::FullShareDistribution);
+        let reduced = session.handoff().get_reduced_shareholder().unwrap();
+
+        let snapshot = session.snapshot();
+        let restored = HandoffSession::restore(snapshot.expose()).unwrap();
+        let restored_reduced = restored.handoff().get_reduced_shareholder().unwrap();
+        assert!(
+            reduced.verifiable_share().polynomial()
+                == restored_reduced.verifiable_share().polynomial()
+        );
+
+        // Unsupported version.
+        let mut bytes = snapshot.expose().clone();
+        bytes[0] = 0;
+        let result = HandoffSession::restore(&bytes);
+        assert!(result.is_err());
+        assert_eq!(
+            result.err().unwrap().to_string(),
+            "unsupported snapshot version 0"
+        );
+
+        // Malformed snapshot.
+        let result = HandoffSession::restore(&snapshot.expose()[..bytes.len() - 1]);
+        assert!(result.is_err());
+
+        // Previous shareholders can be restored too.
+        let session = HandoffSession::new(
+            threshold,
+            HandoffKind::CommitteeUnchanged,
+            old_committee[0],
+            old_committee.clone(),
+            vec![],
+            POLICY,
+            DEADLINES,
+        )
+        .unwrap();
+        let shareholder: Shareholder = VerifiableSecretShare::new(
+            old_dealer.make_share(old_committee[0], HandoffKind::CommitteeUnchanged),
+            vm,
+        )
+        .into();
+        session.set_shareholder(Arc::new(shareholder)).unwrap();
+        let snapshot = session.snapshot();
+        let restored = HandoffSession::restore(snapshot.expose()).unwrap();
+        assert_eq!(restored.phase(), HandoffPhase::Proactivization);
+    }
+}
@@ -7,13 +7,18 @@ use group::{
     ff::{Field, PrimeField},
     Group,
 };
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 use zeroize::Zeroize;
 
 use crate::{
-    kdc::PointShareholder, poly::Polynomial, suites::FieldDigest, vss::VerificationMatrix,
+    kdc::PointShareholder,
+    poly::{EncryptedPoint, Polynomial},
+    suites::FieldDigest,
+    vss::{VerificationMatrix, VerificationVector},
 };
 
-use super::Error;
+use super::{memory::MemoryLock, Error, HandoffKind};
 
 /// Encodes the given shareholder ID to a non-zero element of the prime field.
 pub fn encode_shareholder<H: FieldDigest>(id: &[u8], dst: &[u8]) -> Result<H::Output> {
@@ -26,14 +31,93 @@ pub fn encode_shareholder<H: FieldDigest>(id: &[u8], dst: &[u8]) -> Result<H::Ou
     Ok(s)
 }
 
+/// Encodes the given shareholder IDs to non-zero elements of the prime field.
+///
+/// When the `parallel` feature is enabled, the IDs are encoded concurrently.
+///
+/// Fails if any of the IDs cannot be encoded.
+pub fn encode_shareholders<H, I>(ids: &[I], dst: &[u8]) -> Result<Vec<H::Output>>
+where
+    H: FieldDigest,
+    I: AsRef<[u8]>,
+{
+    #[cfg(not(feature = "parallel"))]
+    let xs = ids
+        .iter()
+        .map(|id| encode_shareholder::<H>(id.as_ref(), dst))
+        .collect();
+
+    #[cfg(feature = "parallel")]
+    let xs = ids
+        .iter()
+        .map(AsRef::as_ref)
+        .collect::<Vec<_>>()
+        .into_par_iter()
+        .map(|id| encode_shareholder::<H>(id, dst))
+        .collect();
+
+    xs
+}
+
+/// Validates that the degree of the given polynomial, received as a share
+/// in a handoff of the given kind, matches the degree of the secret bivariate
+/// polynomial in the corresponding variable.
+///
+/// Full shares `B(x_i, y)`, distributed in the dealing phase and in handoffs
+/// where the committee doesn't change, must have degree `2t` in the variable
+/// `y`, while reduced shares `B(x, x_i)`, distributed when the committee
+/// changes, must have degree `t` in the variable `x`.
+pub fn validate_share_degree<F: PrimeField>(
+    p: &Polynomial<F>,
+    threshold: u8,
+    kind: HandoffKind,
+) -> Result<()> {
+    let full_share = match kind {
+        HandoffKind::DealingPhase => true,
+        HandoffKind::CommitteeUnchanged => true,
+        HandoffKind::CommitteeChanged | HandoffKind::Emergency => false,
+    };
+    validate_degree(p, threshold, full_share)
+}
+
+/// Validates the degree of a full or reduced share polynomial.
+fn validate_degree<F: PrimeField>(
+    p: &Polynomial<F>,
+    threshold: u8,
+    full_share: bool,
+) -> Result<()> {
+    let t = threshold as usize;
+    let actual = p.size() - 1;
+
+    match full_share {
+        true if actual != 2 * t => Err(Error::InvalidDegreeY {
+            expected: 2 * t,
+            actual,
+        }
+        .into()),
+        false if actual != t => Err(Error::InvalidDegreeX {
+            expected: t,
+            actual,
+        }
+        .into()),
+        _ => Ok(()),
+    }
+}
+
 /// Shareholder is responsible for deriving key shares and generating
 /// switch points during handoffs when the committee is trying
 /// to switch to the other dimension.
+///
+/// With the `mlock` feature, the pages holding the share are locked
+/// into memory.
 pub struct Shareholder<G>
 where
     G: Group,
     G::Scalar: Zeroize,
 {
+    /// The lock of the pages holding the share, released after the share
+    /// has been zeroized.
+    _lock: MemoryLock,
     /// Verifiable secret (full or reduced) share of the shared secret.
     verifiable_share: VerifiableSecretShare<G>,
 }
@@ -48,6 +132,13 @@ where
         &self.verifiable_share
     }
 
+    /// Returns true if and only if the pages holding the share are locked
+    /// into memory, which requires the `mlock` feature and may fail, e.g.
+    /// when exceeding `RLIMIT_MEMLOCK`.
+    pub fn is_locked(&self) -> bool {
+        self._lock.is_locked()
+    }
+
     /// Computes switch point for the given shareholder.
     pub fn switch_point(&self, x: &G::Scalar) -> G::Scalar {
         self.verifiable_share.p.eval(x)
@@ -84,8 +175,31 @@ where
     G: Group,
     G::Scalar: Zeroize,
 {
-    fn from(verifiable_share: VerifiableSecretShare<G>) -> Shareholder<G> {
-        Shareholder { verifiable_share }
+    fn from(mut verifiable_share: VerifiableSecretShare<G>) -> Shareholder<G> {
+        // Copy the coefficients into a fixed boxed slice, so that the locked
+        // allocation holds exactly the coefficients, and zeroize the original.
+        // The boxed slice is never reallocated, as the shareholder doesn't
+        // modify the share.
+        let p = &mut verifiable_share.share.p;
+        let a: Box<[G::Scalar]> = p.a.iter().copied().collect();
+        p.zeroize();
+        let _lock = MemoryLock::new(&a);
+        p.a = a.into_vec();
+
+        Shareholder {
+            _lock,
+            verifiable_share,
+        }
+    }
+}
+
+impl<G> Drop for Shareholder<G>
+where
+    G: Group,
+    G::Scalar: Zeroize,
+{
+    fn drop(&mut self) {
+        self.verifiable_share.share.p.zeroize();
     }
 }
 
@@ -179,6 +293,93 @@ where
     }
 }
 
+/// Full share `B(x_i, y)` of the shared secret, of degree `2t` in the
+/// variable `y`, distributed in the dealing phase and in handoffs where
+/// the committee doesn't change.
+pub struct FullShare<F>(SecretShare<F>)
+where
+    F: PrimeField + Zeroize;
+
+impl<F> FullShare<F>
+where
+    F: PrimeField + Zeroize,
+{
+    /// Converts the given share into a full share.
+    ///
+    /// Fails if the degree of the share doesn't match the threshold.
+    pub fn new(share: SecretShare<F>, threshold: u8) -> Result<Self> {
+        validate_degree(share.polynomial(), threshold, true)?;
+        Ok(Self(share))
+    }
+
+    /// Wraps the given share without validating its degree.
+    pub(crate) fn new_unchecked(share: SecretShare<F>) -> Self {
+        Self(share)
+    }
+
+    /// Returns the underlying secret share.
+    pub fn secret_share(&self) -> &SecretShare<F> {
+        &self.0
+    }
+
+    /// Returns the underlying secret share, consuming the full share.
+    pub fn into_secret_share(self) -> SecretShare<F> {
+        self.0
+    }
+}
+
+impl<F> From<FullShare<F>> for SecretShare<F>
+where
+    F: PrimeField + Zeroize,
+{
+    fn from(share: FullShare<F>) -> Self {
+        share.into_secret_share()
+    }
+}
+
+/// Reduced share `B(x, x_i)` of the shared secret, of degree `t` in the
+/// variable `x`, distributed in handoffs where the committee changes.
+pub struct ReducedShare<F>(SecretShare<F>)
+where
+    F: PrimeField + Zeroize;
+
+impl<F> ReducedShare<F>
+where
+    F: PrimeField + Zeroize,
+{
+    /// Converts the given share into a reduced share.
+    ///
+    /// Fails if the degree of the share doesn't match the threshold.
+    pub fn new(share: SecretShare<F>, threshold: u8) -> Result<Self> {
+        validate_degree(share.polynomial(), threshold, false)?;
+        Ok(Self(share))
+    }
+
+    /// Wraps the given share without validating its degree.
+    pub(crate) fn new_unchecked(share: SecretShare<F>) -> Self {
+        Self(share)
+    }
+
+    /// Returns the underlying secret share.
+    pub fn secret_share(&self) -> &SecretShare<F> {
+        &self.0
+    }
+
+    /// Returns the underlying secret share, consuming the reduced share.
+    pub fn into_secret_share(self) -> SecretShare<F> {
+        self.0
+    }
+}
+
+impl<F> From<ReducedShare<F>> for SecretShare<F>
+where
+    F: PrimeField + Zeroize,
+{
+    fn from(share: ReducedShare<F>) -> Self {
+        share.into_secret_share()
+    }
+}
+
 /// Verifiable secret share of the shared secret.
 pub struct VerifiableSecretShare<G>
 where
@@ -214,6 +415,28 @@ where
         &self.vm
     }
 
+    /// Returns the verification vector of the secret share, which allows
+    /// verifying the share without the full verification matrix.
+    ///
+    /// For full shares `B(x_i, y)`, the vector is derived from the columns
+    /// of the matrix, and for reduced shares `B(x, x_i)` from its rows.
+    pub fn verification_vector(&self, full_share: bool) -> VerificationVector<G> {
+        match full_share {
+            true => self.vm.verification_vector_for_y(&self.x),
+            false => self.vm.verification_vector_for_x(&self.x),
+        }
+    }
+
+    /// Returns the public share, i.e. the secret share point with
+    /// the y-coordinate encrypted with the group generator.
+    ///
+    /// Public shares of distinct shareholders can be combined using
+    /// Lagrange interpolation in the exponent to obtain the public key
+    /// of the shared secret, without reconstructing the secret itself.
+    pub fn public_share(&self) -> EncryptedPoint<G> {
+        EncryptedPoint::new(self.share.x, G::generator() * self.share.y())
+    }
+
     /// Verifies the secret share and the verification matrix.
     pub fn verify(&self, threshold: u8, zero_hole: bool, full_share: bool) -> Result<()> {
         self.verify_verification_matrix(threshold, zero_hole)?;
@@ -261,22 +484,14 @@ where
 
     /// Verifies the secret share.
     fn verify_secret_share(&self, threshold: u8, full_share: bool) -> Result<()> {
-        let (rows, cols) = Self::calculate_dimensions(threshold);
-
-        if full_share {
-            if self.share.p.size() != cols {
-                return Err(Error::PolynomialDegreeMismatch.into());
-            }
-            if !self.vm.verify_x(&self.x, &self.p) {
-                return Err(Error::InvalidPolynomial.into());
-            }
-        } else {
-            if self.p.size() != rows {
-                return Err(Error::PolynomialDegreeMismatch.into());
-            }
-            if !self.vm.verify_y(&self.x, &self.p) {
-                return Err(Error::InvalidPolynomial.into());
-            }
+        validate_degree(&self.p, threshold, full_share)?;
+
+        let verified = match full_share {
+            true => self.vm.verify_x(&self.x, &self.p),
+            false => self.vm.verify_y(&self.x, &self.p),
+        };
+        if !verified {
+            return Err(Error::InvalidPolynomial.into());
         }
 
         Ok(())
@@ -324,3 +539,100 @@ where
         self.vm += &rhs.vm;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::{
+        churp::{Dealer, HandoffKind, SecretShare, VerifiableSecretShare},
+        poly::Polynomial,
+    };
+
+    use super::{validate_share_degree, FullShare, ReducedShare};
+
+    type PrimeField = p384::Scalar;
+    type Group = p384::ProjectivePoint;
+
+    #[test]
+    fn test_typed_shares() {
+        let threshold = 2;
+        let x = PrimeField::from_u64(1);
+        let full = || SecretShare::new(x, Polynomial::<PrimeField>::zero(4));
+        let reduced = || SecretShare::new(x, Polynomial::<PrimeField>::zero(2));
+
+        let share = FullShare::new(full(), threshold).unwrap();
+        assert_eq!(share.secret_share().x(), &x);
+        let share: SecretShare<_> = share.into();
+        assert_eq!(share.polynomial().size(), 5);
+        let result = FullShare::new(reduced(), threshold);
+        assert!(result.is_err());
+        assert_eq!(
+            result.err().unwrap().to_string(),
+            "invalid polynomial degree in y: expected 4, got 2"
+        );
+
+        let share = ReducedShare::new(reduced(), threshold).unwrap();
+        assert_eq!(share.into_secret_share().polynomial().size(), 3);
+        let result = ReducedShare::new(full(), threshold);
+        assert!(result.is_err());
+        assert_eq!(
+            result.err().unwrap().to_string(),
+            "invalid polynomial degree in x: expected 2, got 4"
+        );
+    }
+
+    #[test]
+    fn test_validate_share_degree() {
+        let threshold = 2;
+        let full = Polynomial::<PrimeField>::zero(4);
+        let reduced = Polynomial::<PrimeField>::zero(2);
+
+        for kind in [HandoffKind::DealingPhase, HandoffKind::CommitteeUnchanged] {
+            assert!(validate_share_degree(&full, threshold, kind).is_ok());
+            let result = validate_share_degree(&reduced, threshold, kind);
+            assert_eq!(
+                result.unwrap_err().to_string(),
+                "invalid polynomial degree in y: expected 4, got 2"
+            );
+        }
+
+        let kind = HandoffKind::CommitteeChanged;
+        assert!(validate_share_degree(&reduced, threshold, kind).is_ok());
+        let result = validate_share_degree(&full, threshold, kind);
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "invalid polynomial degree in x: expected 2, got 4"
+        );
+    }
+
+    #[test]
+    fn test_verify_short_share() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let threshold = 2;
+        let dealer = Dealer::<Group>::new(threshold, &mut rng).unwrap();
+        let x = PrimeField::from_u64(1);
+
+        // Truncated full share.
+        let share = dealer.make_share(x, HandoffKind::CommitteeUnchanged);
+        let p = Polynomial::with_coefficients(share.polynomial().coefficients()[..4].to_vec());
+        let share = SecretShare::new(x, p);
+        let vss = VerifiableSecretShare::new(share, dealer.verification_matrix().clone());
+        let result = vss.verify(threshold, false, true);
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "invalid polynomial degree in y: expected 4, got 3"
+        );
+
+        // Truncated reduced share.
+        let share = dealer.make_share(x, HandoffKind::CommitteeChanged);
+        let p = Polynomial::with_coefficients(share.polynomial().coefficients()[..2].to_vec());
+        let share = SecretShare::new(x, p);
+        let vss = VerifiableSecretShare::new(share, dealer.verification_matrix().clone());
+        let result = vss.verify(threshold, false, false);
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "invalid polynomial degree in x: expected 2, got 1"
+        );
+    }
+}
@@ -0,0 +1,162 @@
+//! Dealer signatures.
+
+use anyhow::Result;
+use group::{Group, GroupEncoding};
+
+use crate::vss::VerificationMatrix;
+
+use super::Error;
+
+/// Domain separation tag for signatures of verification matrices.
+const VERIFICATION_MATRIX_SIGNATURE_DST: &[u8] = b"CHURP-VerificationMatrixSignature";
+
+/// Domain separation tag for signatures of dealer packages.
+pub(crate) const DEALER_PACKAGE_SIGNATURE_DST: &[u8] = b"CHURP-DealerPackageSignature";
+
+/// A trait for signing dealer commitments with a long-term key.
+pub trait DealerSigner {
+    /// Signs the given message.
+    fn sign(&self, message: &[u8]) -> Result<Vec<u8>>;
+}
+
+/// A trait for verifying signatures of dealer commitments against
+/// the long-term public key of the dealer.
+pub trait DealerVerifier {
+    /// Verifies the signature of the given message.
+    fn verify(&self, message: &[u8], signature: &[u8]) -> Result<()>;
+}
+
+/// Returns the message which should be signed to authenticate the given
+/// verification matrix in the given context, e.g. the handoff epoch.
+pub fn verification_matrix_signing_bytes<G>(vm: &VerificationMatrix<G>, context: &[u8]) -> Vec<u8>
+where
+    G: Group + GroupEncoding,
+{
+    signing_bytes(VERIFICATION_MATRIX_SIGNATURE_DST, context, &vm.to_bytes())
+}
+
+/// Signs the given verification matrix in the given context.
+pub fn sign_verification_matrix<G>(
+    vm: &VerificationMatrix<G>,
+    context: &[u8],
+    signer: &impl DealerSigner,
+) -> Result<Vec<u8>>
+where
+    G: Group + GroupEncoding,
+{
+    signer.sign(&verification_matrix_signing_bytes(vm, context))
+}
+
+/// Verifies the signature of the given verification matrix in the given
+/// context.
+///
+/// Shares must not be accepted unless this check succeeds, as otherwise
+/// a network adversary could substitute the matrix of the dealer.
+pub fn verify_verification_matrix_signature<G>(
+    vm: &VerificationMatrix<G>,
+    context: &[u8],
+    signature: &[u8],
+    verifier: &impl DealerVerifier,
+) -> Result<()>
+where
+    G: Group + GroupEncoding,
+{
+    verifier
+        .verify(&verification_matrix_signing_bytes(vm, context), signature)
+        .map_err(|_| Error::InvalidSignature.into())
+}
+
+/// Concatenates the given domain separation tag, length-prefixed context
+/// and data.
+pub(crate) fn signing_bytes(dst: &[u8], context: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(dst.len() + 4 + context.len() + data.len());
+    bytes.extend_from_slice(dst);
+    bytes.extend_from_slice(&(context.len() as u32).to_le_bytes());
+    bytes.extend_from_slice(context);
+    bytes.extend_from_slice(data);
+    bytes
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use anyhow::{bail, Result};
+    use rand::{rngs::StdRng, SeedableRng};
+    use sha3::{Digest, Sha3_256};
+
+    use crate::churp::Dealer;
+
+    use super::{
+        sign_verification_matrix, verify_verification_matrix_signature, DealerSigner,
+        DealerVerifier,
+    };
+
+    type Group = p384::ProjectivePoint;
+
+    /// Keyed hash standing in for a signature scheme in tests.
+    pub(crate) struct TestSigner(pub(crate) Vec<u8>);
+
+    impl TestSigner {
+        fn mac(&self, message: &[u8]) -> Vec<u8> {
+            let mut hasher = Sha3_256::new();
+            hasher.update(&self.0);
+            hasher.update(message);
+            hasher.finalize().to_vec()
+        }
+    }
+
+    impl DealerSigner for TestSigner {
+        fn sign(&self, message: &[u8]) -> Result<Vec<u8>> {
+            Ok(self.mac(message))
+        }
+    }
+
+    impl DealerVerifier for TestSigner {
+        fn verify(&self, message: &[u8], signature: &[u8]) -> Result<()> {
+            if self.mac(message) != signature {
+                bail!("invalid signature");
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_verification_matrix_signature() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let signer = TestSigner(b"key".to_vec());
+
+        let dealer = Dealer::<Group>::new(2, &mut rng).unwrap();
+        let vm = dealer.verification_matrix();
+        let signature = dealer
+            .sign_verification_matrix(&signer, b"epoch 1")
+            .unwrap();
+        assert_eq!(
+            signature,
+            sign_verification_matrix(vm, b"epoch 1", &signer).unwrap()
+        );
+        verify_verification_matrix_signature(vm, b"epoch 1", &signature, &signer).unwrap();
+
+        // Different context.
+        let result = verify_verification_matrix_signature(vm, b"epoch 2", &signature, &signer);
+        assert!(result.is_err());
+        assert_eq!(result.err().unwrap().to_string(), "invalid signature");
+
+        // Substituted matrix.
+        let other = Dealer::<Group>::new(2, &mut rng).unwrap();
+        let result = verify_verification_matrix_signature(
+            other.verification_matrix(),
+            b"epoch 1",
+            &signature,
+            &signer,
+        );
+        assert!(result.is_err());
+
+        // Different key.
+        let result = verify_verification_matrix_signature(
+            vm,
+            b"epoch 1",
+            &signature,
+            &TestSigner(b"other key".to_vec()),
+        );
+        assert!(result.is_err());
+    }
+}
@@ -1,10 +1,12 @@
 use std::{
+    collections::BTreeMap,
     ops::Deref,
     sync::{Arc, Mutex},
 };
 
 use anyhow::Result;
 use group::{ff::PrimeField, Group};
+use rand_core::RngCore;
 use zeroize::Zeroize;
 
 use crate::{
@@ -12,7 +14,16 @@ use crate::{
     vss::{VerificationMatrix, VerificationVector},
 };
 
-use super::{Error, SecretShare, Shareholder, VerifiableSecretShare};
+use super::{
+    Accusation, Error, Revelation, SecretShare, Shareholder, Verdict, VerifiableSecretShare,
+};
+
+/// The maximum number of shareholders whose rejected switch points
+/// are recorded by an accumulator.
+///
+/// Switch points are accepted from anyone, so the record is bounded
+/// to prevent strangers from exhausting memory.
+pub const MAX_REJECTED_SWITCH_POINTS: usize = 1024;
 
 /// A simple wrapper around point that is zeroized when dropped.
 pub struct SwitchPoint<F>(Point<F>)
@@ -58,6 +69,98 @@ where
     }
 }
 
+/// The outcome of adding a switch point which passed verification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SwitchPointOutcome {
+    /// The point was added and counts towards the quorum.
+    Accepted,
+    /// An identical point from the same sender was received before,
+    /// so the point was ignored.
+    Duplicate,
+}
+
+/// The results of adding a batch of switch points.
+pub struct SwitchPointReport<F> {
+    /// The identities of the senders together with the results of adding
+    /// their switch points, in the order of submission.
+    pub results: Vec<(F, Result<SwitchPointOutcome>)>,
+    /// Indicates whether enough points have been received and the switch
+    /// transitioned to the next state.
+    pub done: bool,
+}
+
+impl<F> SwitchPointReport<F> {
+    /// Returns the number of accepted switch points, excluding duplicates.
+    pub fn accepted(&self) -> usize {
+        self.count(SwitchPointOutcome::Accepted)
+    }
+
+    /// Returns the number of ignored duplicate switch points.
+    pub fn duplicates(&self) -> usize {
+        self.count(SwitchPointOutcome::Duplicate)
+    }
+
+    /// Returns the number of switch points with the given outcome.
+    fn count(&self, outcome: SwitchPointOutcome) -> usize {
+        self.results
+            .iter()
+            .filter(|(_, r)| matches!(r, Ok(o) if *o == outcome))
+            .count()
+    }
+
+    /// Returns the senders of the rejected switch points together with
+    /// the reasons for rejection.
+    pub fn rejected(&self) -> impl Iterator<Item = (&F, &anyhow::Error)> {
+        self.results
+            .iter()
+            .filter_map(|(x, r)| r.as_ref().err().map(|err| (x, err)))
+    }
+}
+
+/// The state of a dimension switch, see `DimensionSwitchState`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SwitchState {
+    /// The switch is waiting for the verification matrix.
+    WaitingForVerificationMatrix,
+    /// The switch is accumulating switch points.
+    Accumulating,
+    /// The switch is waiting for the shareholder to be proactivized.
+    WaitingForShareholder,
+    /// The switch is merging bivariate shares.
+    Merging,
+    /// The switch is completed.
+    Serving,
+}
+
+/// The progress of a dimension switch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SwitchStatus<F> {
+    /// The state of the switch.
+    pub state: SwitchState,
+    /// The shareholders whose switch points or bivariate shares have been
+    /// accepted in the current state.
+    pub contributors: Vec<F>,
+    /// The shareholders whose switch points or bivariate shares failed
+    /// verification in the current state, of which at most
+    /// `MAX_REJECTED_SWITCH_POINTS` are recorded for switch points.
+    pub rejected: Vec<F>,
+    /// The number of switch points or bivariate shares still needed
+    /// to complete the current state.
+    pub remaining: usize,
+}
+
+impl<F> SwitchStatus<F> {
+    /// Creates a status of a state which doesn't accept contributions.
+    fn idle(state: SwitchState) -> Self {
+        Self {
+            state,
+            contributors: Vec::new(),
+            rejected: Vec::new(),
+            remaining: 0,
+        }
+    }
+}
+
 /// Dimension switch state.
 enum DimensionSwitchState<G>
 where
@@ -116,6 +219,10 @@ where
     /// If empty, proactivization is skipped.
     shareholders: Vec<G::Scalar>,
 
+    /// Indicates whether merging of bivariate shares completes only after
+    /// the complaint phase is closed.
+    complaints: bool,
+
     /// Current state of the switch.
     state: Mutex<DimensionSwitchState<G>>,
 }
@@ -170,10 +277,18 @@ where
             full_share,
             me,
             shareholders,
+            complaints: false,
             state,
         })
     }
 
+    /// Enables the complaint phase, in which dealers of invalid bivariate
+    /// shares can be accused and disqualified before merging completes.
+    pub(crate) fn with_complaints(mut self, complaints: bool) -> Self {
+        self.complaints = complaints;
+        self
+    }
+
     /// Checks if the switch is waiting for the verification matrix.
     pub(crate) fn is_waiting_for_verification_matrix(&self) -> bool {
         let state = self.state.lock().unwrap();
@@ -233,6 +348,41 @@ where
 
         sp.add_point(point)?;
 
+        self.finish_accumulating(&mut state)
+    }
+
+    /// Verifies and adds the given batch of switch points from multiple
+    /// shareholders, using a single batched verification.
+    ///
+    /// Invalid points don't prevent valid ones from being added,
+    /// the outcome for every sender is reported separately.
+    pub(crate) fn add_switch_points(
+        &self,
+        points: Vec<SwitchPoint<G::Scalar>>,
+        rng: &mut impl RngCore,
+    ) -> Result<SwitchPointReport<G::Scalar>> {
+        let mut state = self.state.lock().unwrap();
+        let sp = match &mut *state {
+            DimensionSwitchState::Accumulating(sp) => sp,
+            _ => return Err(Error::InvalidState.into()),
+        };
+
+        let results = sp.add_points(points, rng);
+        let done = self.finish_accumulating(&mut state)?;
+
+        Ok(SwitchPointReport { results, done })
+    }
+
+    /// Reconstructs the shareholder once enough switch points have been
+    /// received, and transitions the switch to the next state.
+    ///
+    /// Returns true if the switch transitioned to the next state.
+    fn finish_accumulating(&self, state: &mut DimensionSwitchState<G>) -> Result<bool> {
+        let sp = match state {
+            DimensionSwitchState::Accumulating(sp) => sp,
+            _ => return Err(Error::InvalidState.into()),
+        };
+
         if sp.needs_points() {
             return Ok(false);
         }
@@ -243,7 +393,7 @@ where
         if self.shareholders.is_empty() {
             *state = DimensionSwitchState::Serving(shareholder);
         } else {
-            let bs = BivariateShares::new(
+            let mut bs = BivariateShares::new(
                 self.threshold,
                 self.zero_hole,
                 self.full_share,
@@ -251,6 +401,7 @@ where
                 self.shareholders.clone(),
                 Some(shareholder),
             )?;
+            bs.complaint_phase = self.complaints;
             *state = DimensionSwitchState::Merging(bs);
         }
 
@@ -272,7 +423,7 @@ where
             _ => return Err(Error::InvalidState.into()),
         };
 
-        let bs = BivariateShares::new(
+        let mut bs = BivariateShares::new(
             self.threshold,
             self.zero_hole,
             self.full_share,
@@ -280,6 +431,7 @@ where
             self.shareholders.clone(),
             shareholder,
         )?;
+        bs.complaint_phase = self.complaints;
         *state = DimensionSwitchState::Merging(bs);
 
         Ok(())
@@ -322,6 +474,61 @@ where
         Ok(done)
     }
 
+    /// Accuses the given dealer of sending an invalid bivariate share
+    /// and returns the accusation to be published.
+    pub(crate) fn accuse_dealer(
+        &self,
+        x: &G::Scalar,
+        vm: VerificationMatrix<G>,
+    ) -> Result<Accusation<G>> {
+        let mut state = self.state.lock().unwrap();
+        let shares = match &mut *state {
+            DimensionSwitchState::Merging(bs) => bs,
+            _ => return Err(Error::InvalidState.into()),
+        };
+
+        shares.accuse(x)?;
+
+        Ok(Accusation::new(self.me, *x, vm))
+    }
+
+    /// Resolves the given accusation, disqualifying the accused dealer
+    /// or adopting the revealed share if we are the accuser.
+    pub(crate) fn resolve_accusation(
+        &self,
+        accusation: &Accusation<G>,
+        revelation: Option<&Revelation<G>>,
+    ) -> Result<Verdict> {
+        let mut state = self.state.lock().unwrap();
+        let shares = match &mut *state {
+            DimensionSwitchState::Merging(bs) => bs,
+            _ => return Err(Error::InvalidState.into()),
+        };
+
+        shares.resolve(accusation, revelation)
+    }
+
+    /// Closes the complaint phase.
+    ///
+    /// Returns true if all shares have been received and the switch
+    /// transitioned to the next state.
+    pub(crate) fn close_complaints(&self) -> Result<bool> {
+        let mut state = self.state.lock().unwrap();
+        let shares = match &mut *state {
+            DimensionSwitchState::Merging(bs) => bs,
+            _ => return Err(Error::InvalidState.into()),
+        };
+
+        let done = shares.close_complaints()?;
+        if done {
+            let shareholder = shares.proactivize_shareholder()?;
+            let shareholder = Arc::new(shareholder);
+            *state = DimensionSwitchState::Serving(shareholder);
+        }
+
+        Ok(done)
+    }
+
     /// Returns the shareholder if the switch has completed.
     pub(crate) fn get_shareholder(&self) -> Result<Arc<Shareholder<G>>> {
         let state = self.state.lock().unwrap();
@@ -332,6 +539,22 @@ where
 
         Ok(shareholder)
     }
+
+    /// Returns the progress of the switch.
+    pub(crate) fn status(&self) -> SwitchStatus<G::Scalar> {
+        let state = self.state.lock().unwrap();
+        match &*state {
+            DimensionSwitchState::WaitingForVerificationMatrix => {
+                SwitchStatus::idle(SwitchState::WaitingForVerificationMatrix)
+            }
+            DimensionSwitchState::Accumulating(sp) => sp.status(),
+            DimensionSwitchState::WaitingForShareholder => {
+                SwitchStatus::idle(SwitchState::WaitingForShareholder)
+            }
+            DimensionSwitchState::Merging(bs) => bs.status(),
+            DimensionSwitchState::Serving(_) => SwitchStatus::idle(SwitchState::Serving),
+        }
+    }
 }
 
 /// An accumulator for switch points.
@@ -365,6 +588,10 @@ where
 
     /// A list of received switch points.
     points: Vec<SwitchPoint<G::Scalar>>,
+
+    /// A bounded set of shareholders whose switch points were rejected,
+    /// keyed by their encoding.
+    rejected: BTreeMap<Vec<u8>, G::Scalar>,
 }
 
 impl<G> SwitchPoints<G>
@@ -408,9 +635,29 @@ where
             vm,
             vv,
             points,
+            rejected: BTreeMap::new(),
         })
     }
 
+    /// Returns the progress of the accumulation.
+    fn status(&self) -> SwitchStatus<G::Scalar> {
+        SwitchStatus {
+            state: SwitchState::Accumulating,
+            contributors: self.points.iter().map(|p| p.x).collect(),
+            rejected: self.rejected.values().copied().collect(),
+            remaining: self.n.saturating_sub(self.points.len()),
+        }
+    }
+
+    /// Records that a switch point of the given shareholder was rejected,
+    /// unless the record is full.
+    fn reject(&mut self, x: &G::Scalar) {
+        if self.rejected.len() >= MAX_REJECTED_SWITCH_POINTS {
+            return;
+        }
+        self.rejected.insert(x.to_repr().as_ref().to_vec(), *x);
+    }
+
     /// Checks if a switch point has already been received from the given shareholder.
     fn has_point(&self, x: &G::Scalar) -> bool {
         self.points.iter().any(|p| &p.x == x)
@@ -426,23 +673,58 @@ where
         self.points.len() < self.n
     }
 
+    /// Checks if the given switch point has already been received
+    /// from the same shareholder, either among the accepted points
+    /// or among the given pending ones.
+    ///
+    /// Returns true if an identical point has been received, and fails
+    /// with the conflicting values if the shareholder sent a different one.
+    fn is_resubmitted(
+        &self,
+        point: &SwitchPoint<G::Scalar>,
+        pending: &[SwitchPoint<G::Scalar>],
+    ) -> Result<bool> {
+        let received = self
+            .points
+            .iter()
+            .chain(pending.iter())
+            .find(|p| p.x == point.x);
+
+        match received {
+            None => Ok(false),
+            Some(p) if p.y == point.y => Ok(true),
+            Some(p) => Err(Error::ConflictingSwitchPoints {
+                shareholder: point.x.to_repr().as_ref().to_vec(),
+                accepted: p.y.to_repr().as_ref().to_vec(),
+                conflicting: point.y.to_repr().as_ref().to_vec(),
+            }
+            .into()),
+        }
+    }
+
     /// Verifies and adds the given switch point.
     ///
-    /// Returns true if enough points have been received; otherwise,
-    /// it returns false.
+    /// Re-submitting an identical point is a no-op, so the point is counted
+    /// towards the quorum only once.
     fn add_point(&mut self, point: SwitchPoint<G::Scalar>) -> Result<()> {
+        match self.is_resubmitted(&point, &[]) {
+            Ok(true) => return Ok(()),
+            Ok(false) => (),
+            Err(err) => {
+                self.reject(&point.x);
+                return Err(err);
+            }
+        }
         if self.points.len() >= self.n {
             return Err(Error::TooManySwitchPoints.into());
         }
-        if self.has_point(&point.x) {
-            return Err(Error::DuplicateShareholder.into());
-        }
 
         // The identity of the shareholder doesn't require verification.
         // If the point is valid, it doesn't matter if it came from a stranger.
         // However, since verification is costly, one could check if the point
         // came from a legitimate shareholder.
         if !self.vv.verify(&point.x, &point.y) {
+            self.reject(&point.x);
             return Err(Error::InvalidSwitchPoint.into());
         }
 
@@ -451,6 +733,68 @@ where
         Ok(())
     }
 
+    /// Verifies and adds the given switch points.
+    ///
+    /// All points are verified with a single batched check. Only if the check
+    /// fails, the points are verified one by one to find the invalid ones.
+    /// Re-submitted points are ignored, as in `add_point`, and reported
+    /// as duplicates.
+    ///
+    /// Returns the senders and the results of adding their switch points,
+    /// in the order of submission.
+    fn add_points(
+        &mut self,
+        points: Vec<SwitchPoint<G::Scalar>>,
+        rng: &mut impl RngCore,
+    ) -> Vec<(G::Scalar, Result<SwitchPointOutcome>)> {
+        let mut results: Vec<(G::Scalar, Option<Result<SwitchPointOutcome>>)> =
+            Vec::with_capacity(points.len());
+        let mut candidates = Vec::with_capacity(points.len());
+
+        for point in points {
+            let x = point.x;
+            match self.is_resubmitted(&point, &candidates) {
+                Ok(true) => results.push((x, Some(Ok(SwitchPointOutcome::Duplicate)))),
+                Ok(false) => {
+                    results.push((x, None));
+                    candidates.push(point);
+                }
+                Err(err) => {
+                    self.reject(&x);
+                    results.push((x, Some(Err(err))));
+                }
+            }
+        }
+
+        let pairs: Vec<_> = candidates.iter().map(|p| (p.x, p.y)).collect();
+        let verified = self.vv.verify_batch(&pairs, rng);
+
+        let mut candidates = candidates.into_iter();
+        for (_, result) in results.iter_mut().filter(|(_, r)| r.is_none()) {
+            let point = candidates
+                .next()
+                .expect("every candidate should have a result");
+
+            if !verified && !self.vv.verify(&point.x, &point.y) {
+                self.reject(&point.x);
+                *result = Some(Err(Error::InvalidSwitchPoint.into()));
+                continue;
+            }
+            if self.points.len() >= self.n {
+                *result = Some(Err(Error::TooManySwitchPoints.into()));
+                continue;
+            }
+
+            self.points.push(point);
+            *result = Some(Ok(SwitchPointOutcome::Accepted));
+        }
+
+        results
+            .into_iter()
+            .map(|(x, result)| (x, result.expect("every point should have a result")))
+            .collect()
+    }
+
     /// Reconstructs the shareholder from the received switch points.
     ///
     /// The shareholder can be reconstructed only once, which avoids copying
@@ -502,11 +846,23 @@ where
     /// A set of shareholders whose bivariate share still needs to be received.
     pending_shareholders: Vec<G::Scalar>,
 
+    /// A set of shareholders accused of dealing us an invalid bivariate
+    /// share, whose accusations haven't been resolved yet.
+    accused_shareholders: Vec<G::Scalar>,
+
+    /// Indicates whether the complaint phase is open, in which case
+    /// the shares can't be combined yet.
+    complaint_phase: bool,
+
     /// The shareholder to be proactivized with bivariate shares.
     shareholder: Option<Arc<Shareholder<G>>>,
 
-    /// The sum of the received verifiable bivariate shares.
-    combined_share: Option<VerifiableSecretShare<G>>,
+    /// The received verifiable bivariate shares, kept separately so that
+    /// the shares of disqualified dealers can be excluded.
+    shares: Vec<(G::Scalar, VerifiableSecretShare<G>)>,
+
+    /// A set of shareholders whose bivariate shares were rejected.
+    rejected_shareholders: Vec<G::Scalar>,
 }
 
 impl<G> BivariateShares<G>
@@ -536,11 +892,24 @@ where
             me,
             shareholders,
             pending_shareholders,
+            accused_shareholders: Vec::new(),
+            complaint_phase: false,
             shareholder,
-            combined_share: None,
+            shares: Vec::new(),
+            rejected_shareholders: Vec::new(),
         })
     }
 
+    /// Returns the progress of the merging.
+    fn status(&self) -> SwitchStatus<G::Scalar> {
+        SwitchStatus {
+            state: SwitchState::Merging,
+            contributors: self.shares.iter().map(|(x, _)| *x).collect(),
+            rejected: self.rejected_shareholders.clone(),
+            remaining: self.pending_shareholders.len() + self.accused_shareholders.len(),
+        }
+    }
+
     /// Checks if a bivariate share can be received from the given shareholder.
     fn has_bivariate_share(&self, x: &G::Scalar) -> bool {
         self.shareholders.contains(x)
@@ -568,16 +937,16 @@ where
         }
 
         if verifiable_share.x() != &self.me {
+            self.reject(x);
             return Err(Error::ShareholderIdentityMismatch.into());
         }
-        verifiable_share.verify(self.threshold, self.zero_hole, self.full_share)?;
-
-        if let Some(ref mut cs) = self.combined_share {
-            *cs += &verifiable_share;
-        } else {
-            self.combined_share = Some(verifiable_share);
+        if let Err(err) = verifiable_share.verify(self.threshold, self.zero_hole, self.full_share) {
+            self.reject(x);
+            return Err(err);
         }
 
+        self.shares.push((*x, verifiable_share));
+
         let index = self
             .pending_shareholders
             .iter()
@@ -585,22 +954,134 @@ where
             .unwrap();
         self.pending_shareholders.swap_remove(index);
 
-        let done = self.pending_shareholders.is_empty();
+        Ok(self.is_done())
+    }
 
-        Ok(done)
+    /// Records that the bivariate share of the given shareholder
+    /// was rejected.
+    fn reject(&mut self, x: &G::Scalar) {
+        if !self.rejected_shareholders.contains(x) {
+            self.rejected_shareholders.push(*x);
+        }
+    }
+
+    /// Checks if all shares have been received and all accusations
+    /// resolved after the complaint phase.
+    fn is_done(&self) -> bool {
+        self.pending_shareholders.is_empty()
+            && self.accused_shareholders.is_empty()
+            && !self.complaint_phase
+    }
+
+    /// Accuses the given shareholder of dealing an invalid bivariate share.
+    ///
+    /// The share stops being pending until the accusation is resolved.
+    fn accuse(&mut self, x: &G::Scalar) -> Result<()> {
+        if !self.complaint_phase {
+            return Err(Error::InvalidState.into());
+        }
+        if !self.has_bivariate_share(x) {
+            return Err(Error::UnknownShareholder.into());
+        }
+        if !self.needs_bivariate_share(x) {
+            return Err(Error::DuplicateShareholder.into());
+        }
+
+        self.pending_shareholders.retain(|y| y != x);
+        self.accused_shareholders.push(*x);
+
+        Ok(())
+    }
+
+    /// Resolves the given accusation.
+    ///
+    /// A disqualified dealer is removed from the set of shareholders
+    /// and its share excluded, even if we received a valid one. If the
+    /// accusation is dismissed and we are the accuser, the revealed share
+    /// is adopted instead.
+    fn resolve(
+        &mut self,
+        accusation: &Accusation<G>,
+        revelation: Option<&Revelation<G>>,
+    ) -> Result<Verdict> {
+        if !self.complaint_phase {
+            return Err(Error::InvalidState.into());
+        }
+
+        let dealer = accusation.dealer();
+        if !self.has_bivariate_share(dealer) {
+            return Err(Error::UnknownShareholder.into());
+        }
+
+        let verdict =
+            accusation.resolve(revelation, self.threshold, self.zero_hole, self.full_share)?;
+
+        match verdict {
+            Verdict::Disqualified => {
+                self.shareholders.retain(|y| y != dealer);
+                self.pending_shareholders.retain(|y| y != dealer);
+                self.accused_shareholders.retain(|y| y != dealer);
+                self.shares.retain(|(y, _)| y != dealer);
+
+                // Enough dealers must remain, so that the combined bivariate
+                // polynomial still can't be known by any single party.
+                let min_dealers = match self.zero_hole {
+                    true => self.threshold as usize + 1,
+                    false => self.threshold as usize + 2,
+                };
+                if self.shareholders.len() < min_dealers {
+                    return Err(Error::NotEnoughDealers.into());
+                }
+            }
+            Verdict::Dismissed if accusation.accuser() == &self.me => {
+                if !self.accused_shareholders.contains(dealer) {
+                    return Err(Error::DuplicateShareholder.into());
+                }
+
+                // The revelation is known to be present and valid.
+                let verifiable_share = revelation
+                    .ok_or(Error::InvalidState)?
+                    .verifiable_share(accusation.verification_matrix());
+                self.accused_shareholders.retain(|y| y != dealer);
+                self.shares.push((*dealer, verifiable_share));
+            }
+            Verdict::Dismissed => (),
+        }
+
+        Ok(verdict)
+    }
+
+    /// Closes the complaint phase.
+    ///
+    /// Returns true if all shares have been received; otherwise,
+    /// it returns false. Fails if any of our accusations is unresolved.
+    fn close_complaints(&mut self) -> Result<bool> {
+        if !self.complaint_phase {
+            return Err(Error::InvalidState.into());
+        }
+        if !self.accused_shareholders.is_empty() {
+            return Err(Error::UnresolvedAccusations.into());
+        }
+
+        self.complaint_phase = false;
+
+        Ok(self.is_done())
     }
 
     /// Proactivizes the shareholder with the combined polynomial
     /// and verification matrix.
     fn proactivize_shareholder(&mut self) -> Result<Shareholder<G>> {
-        if !self.pending_shareholders.is_empty() {
+        if !self.is_done() {
             return Err(Error::NotEnoughBivariateShares.into());
         }
 
-        let verifiable_share = self
-            .combined_share
-            .take()
+        let mut shares = self.shares.drain(..).map(|(_, share)| share);
+        let mut verifiable_share = shares
+            .next()
             .ok_or(Error::ShareholderProactivizationCompleted)?;
+        for share in shares {
+            verifiable_share += share;
+        }
 
         let shareholder = match &self.shareholder {
             Some(shareholder) => {
@@ -622,6 +1103,7 @@ where
 #[cfg(test)]
 mod tests {
     use anyhow::Result;
+    use group::ff::PrimeField as _;
     use rand::{rngs::StdRng, SeedableRng};
 
     use crate::{
@@ -631,7 +1113,10 @@ mod tests {
         vss,
     };
 
-    use super::{BivariateShares, Error, SwitchPoint, SwitchPoints};
+    use super::{
+        BivariateShares, DimensionSwitch, Error, SwitchPoint, SwitchPointOutcome, SwitchPoints,
+        SwitchState, MAX_REJECTED_SWITCH_POINTS,
+    };
 
     type Suite = p384::Sha3_384;
     type Group = <Suite as suites::Suite>::Group;
@@ -701,12 +1186,29 @@ mod tests {
             assert!(res.is_ok());
             assert!(!res.unwrap());
 
+            // Re-submitting the same point is a no-op.
             let res = add_point(me, sh, &bp, &mut sp, full_share);
-            assert!(res.is_err());
-            assert_eq!(
-                res.unwrap_err().to_string(),
-                Error::DuplicateShareholder.to_string()
-            );
+            assert!(res.is_ok());
+            assert!(!res.unwrap());
+            assert_eq!(sp.points.len(), 2);
+
+            // Submitting a conflicting point fails with both values.
+            let x = prepare_shareholder(sh);
+            let accepted = sp.points[1].y;
+            let res = sp.add_point(SwitchPoint::new(x, PrimeField::ONE));
+            match res.unwrap_err().downcast_ref::<Error>() {
+                Some(Error::ConflictingSwitchPoints {
+                    shareholder,
+                    accepted: first,
+                    conflicting: second,
+                }) => {
+                    assert_eq!(shareholder, &x.to_repr().to_vec());
+                    assert_eq!(first, &accepted.to_repr().to_vec());
+                    assert_eq!(second, &PrimeField::ONE.to_repr().to_vec());
+                }
+                _ => panic!("switch points should conflict"),
+            }
+            assert_eq!(sp.points.len(), 2);
 
             assert!(!sp.needs_point(&prepare_shareholder(sh)));
             sh += 1;
@@ -754,6 +1256,116 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_switch_points() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+
+        let threshold = 2;
+        let bp = BivariatePolynomial::random(threshold, 2 * threshold, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+        let me = prepare_shareholder(1);
+        let point = |x: u64, y: u64| {
+            let x = prepare_shareholder(x);
+            let y = prepare_shareholder(y);
+            SwitchPoint::new(x, bp.eval(&x, &y))
+        };
+        let errors = |results: Vec<(PrimeField, Result<SwitchPointOutcome>)>| -> Vec<_> {
+            results
+                .into_iter()
+                .map(|(_, r)| r.err().map(|err| err.to_string()))
+                .collect()
+        };
+
+        // Invalid and conflicting points are reported per sender,
+        // while re-submitted points are ignored.
+        let mut sp = SwitchPoints::<Group>::new(threshold, false, me, vm.clone()).unwrap();
+        let results = sp.add_points(
+            vec![point(2, 1), point(1, 3), point(2, 1), point(2, 3)],
+            &mut rng,
+        );
+        let results = errors(results);
+        assert_eq!(
+            results[..3],
+            [None, Some(Error::InvalidSwitchPoint.to_string()), None]
+        );
+        assert!(results[3]
+            .as_ref()
+            .unwrap()
+            .starts_with("conflicting switch points of shareholder"));
+        assert_eq!(sp.points.len(), 1);
+
+        // Points beyond the quorum are rejected.
+        let results = sp.add_points(
+            vec![point(2, 1), point(3, 1), point(4, 1), point(5, 1)],
+            &mut rng,
+        );
+        assert_eq!(
+            errors(results),
+            vec![
+                None,
+                None,
+                None,
+                Some(Error::TooManySwitchPoints.to_string()),
+            ]
+        );
+        assert!(!sp.needs_points());
+        assert!(sp.reconstruct_shareholder().is_ok());
+
+        // Rejections are recorded once per sender, up to a bound.
+        let mut sp = SwitchPoints::<Group>::new(threshold, false, me, vm.clone()).unwrap();
+        for x in 1..=MAX_REJECTED_SWITCH_POINTS as u64 + 10 {
+            sp.reject(&prepare_shareholder(x));
+            sp.reject(&prepare_shareholder(1));
+        }
+        let rejected = sp.status().rejected;
+        assert_eq!(rejected.len(), MAX_REJECTED_SWITCH_POINTS);
+        assert!(rejected.contains(&prepare_shareholder(1)));
+        assert!(!rejected.contains(&prepare_shareholder(MAX_REJECTED_SWITCH_POINTS as u64 + 1)));
+
+        // The switch transitions once enough points have been added.
+        let switch =
+            DimensionSwitch::<Group>::new_share_reduction(threshold, true, me, vec![]).unwrap();
+        switch.start_accumulating(vm).unwrap();
+
+        let report = switch
+            .add_switch_points(vec![point(2, 1), point(1, 3)], &mut rng)
+            .unwrap();
+        assert!(!report.done);
+        assert_eq!(report.accepted(), 1);
+        let rejected: Vec<_> = report.rejected().map(|(x, _)| *x).collect();
+        assert_eq!(rejected, vec![prepare_shareholder(1)]);
+
+        let status = switch.status();
+        assert_eq!(status.state, SwitchState::Accumulating);
+        assert_eq!(status.contributors, vec![prepare_shareholder(2)]);
+        assert_eq!(status.rejected, rejected);
+        assert_eq!(status.remaining, 2);
+
+        // Re-submitted points are reported as duplicates and don't count
+        // towards the quorum again.
+        let report = switch
+            .add_switch_points(vec![point(2, 1)], &mut rng)
+            .unwrap();
+        assert!(!report.done);
+        assert_eq!(report.accepted(), 0);
+        assert_eq!(report.duplicates(), 1);
+        assert_eq!(report.rejected().count(), 0);
+        assert_eq!(switch.status().contributors, vec![prepare_shareholder(2)]);
+        assert_eq!(switch.status().remaining, 2);
+
+        let report = switch
+            .add_switch_points(vec![point(3, 1), point(4, 1)], &mut rng)
+            .unwrap();
+        assert!(report.done);
+        assert_eq!(report.accepted(), 2);
+        assert!(switch.get_shareholder().is_ok());
+        assert_eq!(switch.status().state, SwitchState::Serving);
+
+        // No more points are accepted.
+        let result = switch.add_switch_points(vec![point(5, 1)], &mut rng);
+        assert!(result.is_err());
+    }
+
     fn add_bivariate_shares(
         threshold: u8,
         zero_hole: bool,
@@ -0,0 +1,174 @@
+//! Shares tagged with the instance of the scheme they belong to.
+
+use std::cmp::Ordering;
+
+use anyhow::Result;
+use group::Group;
+use zeroize::Zeroize;
+
+use super::{Error, HandoffKind, VerifiableSecretShare};
+
+/// A tag identifying the instance of the scheme, the epoch and the kind
+/// of the handoff in which a share was created.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShareTag {
+    /// The identifier of the scheme instance.
+    pub scheme_id: Vec<u8>,
+    /// The epoch of the share.
+    pub epoch: u64,
+    /// The kind of the handoff in which the share was created.
+    pub kind: HandoffKind,
+}
+
+impl ShareTag {
+    /// Creates a new tag.
+    pub fn new(scheme_id: Vec<u8>, epoch: u64, kind: HandoffKind) -> Self {
+        Self {
+            scheme_id,
+            epoch,
+            kind,
+        }
+    }
+
+    /// Verifies that the tag matches the expected one.
+    ///
+    /// Fails if the shares were created by another scheme instance,
+    /// in another epoch, or in another kind of handoff.
+    pub fn verify(&self, expected: &ShareTag) -> Result<()> {
+        if self.scheme_id != expected.scheme_id {
+            return Err(Error::SchemeMismatch.into());
+        }
+        if self.epoch != expected.epoch {
+            return Err(Error::EpochMismatch {
+                expected: expected.epoch,
+                actual: self.epoch,
+            }
+            .into());
+        }
+        if self.kind != expected.kind {
+            return Err(Error::InvalidKind.into());
+        }
+
+        Ok(())
+    }
+
+    /// Verifies that the share wasn't created before the emergency handoff
+    /// of the given epoch, which replaced a possibly compromised committee.
+    ///
+    /// Fails if the share is from an earlier epoch, or from the same epoch
+    /// but not from the emergency handoff.
+    pub fn verify_incident(&self, epoch: u64) -> Result<()> {
+        let stale = match self.epoch.cmp(&epoch) {
+            Ordering::Less => true,
+            Ordering::Equal => self.kind != HandoffKind::Emergency,
+            Ordering::Greater => false,
+        };
+        if stale {
+            return Err(Error::StaleShare { epoch: self.epoch }.into());
+        }
+
+        Ok(())
+    }
+}
+
+/// A verifiable secret share tagged with the scheme instance, the epoch
+/// and the kind of the handoff, which prevents stale shares of a previous
+/// committee from being combined with new ones.
+pub struct TaggedShare<G>
+where
+    G: Group,
+    G::Scalar: Zeroize,
+{
+    /// The tag of the share.
+    tag: ShareTag,
+    /// The verifiable secret share.
+    share: VerifiableSecretShare<G>,
+}
+
+impl<G> TaggedShare<G>
+where
+    G: Group,
+    G::Scalar: Zeroize,
+{
+    /// Creates a new tagged share.
+    pub fn new(tag: ShareTag, share: VerifiableSecretShare<G>) -> Self {
+        Self { tag, share }
+    }
+
+    /// Returns the tag of the share.
+    pub fn tag(&self) -> &ShareTag {
+        &self.tag
+    }
+
+    /// Returns the verifiable secret share.
+    pub fn share(&self) -> &VerifiableSecretShare<G> {
+        &self.share
+    }
+
+    /// Returns the verifiable secret share, consuming the tagged share.
+    pub fn into_share(self) -> VerifiableSecretShare<G> {
+        self.share
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::churp::HandoffKind;
+
+    use super::ShareTag;
+
+    #[test]
+    fn test_share_tag() {
+        let kind = HandoffKind::CommitteeUnchanged;
+        let tag = ShareTag::new(b"scheme".to_vec(), 2, kind);
+        tag.verify(&tag.clone()).unwrap();
+
+        let other = ShareTag::new(b"other".to_vec(), 2, kind);
+        let result = tag.verify(&other);
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().to_string(), "scheme mismatch");
+
+        let other = ShareTag::new(b"scheme".to_vec(), 3, kind);
+        let result = tag.verify(&other);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "epoch mismatch: expected 3, got 2"
+        );
+
+        let other = ShareTag::new(b"scheme".to_vec(), 2, HandoffKind::CommitteeChanged);
+        let result = tag.verify(&other);
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().to_string(), "invalid handoff kind");
+    }
+
+    #[test]
+    fn test_incident() {
+        let scheme = b"scheme".to_vec();
+
+        // Shares of the emergency handoff and of later handoffs are fresh.
+        for (epoch, kind) in [
+            (2, HandoffKind::Emergency),
+            (3, HandoffKind::CommitteeUnchanged),
+            (3, HandoffKind::CommitteeChanged),
+        ] {
+            let tag = ShareTag::new(scheme.clone(), epoch, kind);
+            tag.verify_incident(2).unwrap();
+        }
+
+        // Shares created before the incident are stale.
+        for (epoch, kind) in [
+            (1, HandoffKind::Emergency),
+            (1, HandoffKind::CommitteeUnchanged),
+            (2, HandoffKind::CommitteeChanged),
+        ] {
+            let tag = ShareTag::new(scheme.clone(), epoch, kind);
+            let result = tag.verify_incident(2);
+            assert!(result.is_err());
+            assert_eq!(
+                result.unwrap_err().to_string(),
+                format!("stale share from epoch {epoch}")
+            );
+        }
+    }
+}
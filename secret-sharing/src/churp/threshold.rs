@@ -0,0 +1,311 @@
+//! Threshold Schnorr signatures.
+
+use anyhow::Result;
+use group::{
+    ff::{Field, PrimeField},
+    Group, GroupEncoding,
+};
+use zeroize::Zeroize;
+
+use crate::{poly::XofRng, vss::VerificationMatrix};
+
+use super::{Error, Player, Shareholder};
+
+/// Domain separation tag for the challenge of threshold signatures.
+const THRESHOLD_SIGNATURE_DST: &[u8] = b"CHURP-ThresholdSignature";
+
+/// A partial signature produced by a shareholder using its share `y_i`
+/// of the secret and its share `k_i` of a one-time nonce:
+/// ```text
+///     z_i = k_i + c * y_i
+/// ```
+/// where `c` is the challenge of the full signature.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartialSignature<G>
+where
+    G: Group,
+{
+    /// The identity of the shareholder.
+    x: G::Scalar,
+    /// The partial response `z_i = k_i + c * y_i`.
+    z: G::Scalar,
+}
+
+impl<G> PartialSignature<G>
+where
+    G: Group,
+{
+    /// Creates a new partial signature.
+    pub(crate) fn new(x: G::Scalar, z: G::Scalar) -> Self {
+        Self { x, z }
+    }
+
+    /// Returns the identity of the shareholder.
+    pub fn x(&self) -> &G::Scalar {
+        &self.x
+    }
+
+    /// Returns the partial response.
+    pub fn z(&self) -> &G::Scalar {
+        &self.z
+    }
+}
+
+/// A Schnorr signature produced by a quorum of shareholders.
+///
+/// The nonce `k` and the secret `s` are both shared among the committee,
+/// so the signature is computed by interpolating the partial responses:
+/// ```text
+///     R = k * G
+///     c = Hash(PK || R || message)
+///     z = k + c * s
+/// ```
+/// The signature is valid iff `z * G = R + c * PK`, where `PK = s * G`
+/// is the public key of the committee, i.e. the first element
+/// of the verification matrix.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ThresholdSignature<G>
+where
+    G: Group,
+{
+    /// The commitment to the nonce `R = k * G`.
+    r: G,
+    /// The response `z = k + c * s`.
+    z: G::Scalar,
+}
+
+impl<G> ThresholdSignature<G>
+where
+    G: Group + GroupEncoding,
+{
+    /// Creates a new signature.
+    pub(crate) fn new(r: G, z: G::Scalar) -> Self {
+        Self { r, z }
+    }
+
+    /// Returns the commitment to the nonce.
+    pub fn r(&self) -> &G {
+        &self.r
+    }
+
+    /// Returns the response.
+    pub fn z(&self) -> &G::Scalar {
+        &self.z
+    }
+
+    /// Verifies the signature of the given message against the public key
+    /// of the committee.
+    pub fn verify(&self, public_key: &G, message: &[u8]) -> bool {
+        let c = Self::challenge(public_key, &self.r, message);
+        let diff = G::generator() * self.z - self.r - *public_key * c;
+
+        diff.is_identity().into()
+    }
+
+    /// Computes the Fiat-Shamir challenge.
+    pub(crate) fn challenge(public_key: &G, r: &G, message: &[u8]) -> G::Scalar {
+        let mut transcript = Vec::new();
+        for p in [public_key, r] {
+            transcript.extend_from_slice(p.to_bytes().as_ref());
+        }
+        transcript.extend_from_slice(message);
+
+        G::Scalar::random(XofRng::new(&transcript, THRESHOLD_SIGNATURE_DST))
+    }
+}
+
+/// Implements [`Player::sign_partial`].
+pub(crate) fn sign_partial<G>(
+    player: &Player,
+    shareholder: &Shareholder<G>,
+    nonce: &Shareholder<G>,
+    message: &[u8],
+) -> Result<PartialSignature<G>>
+where
+    G: Group + GroupEncoding,
+    G::Scalar: Zeroize,
+{
+    let share = shareholder.verifiable_share();
+    let nonce = nonce.verifiable_share();
+    if share.x() != nonce.x() {
+        return Err(Error::ShareholderIdentityMismatch.into());
+    }
+
+    let public_key = player.public_key(share.verification_matrix());
+    let r = player.public_key(nonce.verification_matrix());
+    let c = ThresholdSignature::challenge(&public_key, &r, message);
+    let z = *nonce.y() + c * share.y();
+
+    Ok(PartialSignature::new(*share.x(), z))
+}
+
+/// Implements [`Player::verify_partial_signature`].
+pub(crate) fn verify_partial_signature<G>(
+    player: &Player,
+    partial: &PartialSignature<G>,
+    vm: &VerificationMatrix<G>,
+    nonce_vm: &VerificationMatrix<G>,
+    message: &[u8],
+) -> Result<()>
+where
+    G: Group + GroupEncoding,
+{
+    player.verify_dimensions(vm)?;
+    player.verify_dimensions(nonce_vm)?;
+
+    let public_key = player.public_key(vm);
+    let r = player.public_key(nonce_vm);
+    let c = ThresholdSignature::challenge(&public_key, &r, message);
+
+    let x = partial.x();
+    let public = player.secret_sharing_vector(vm).eval(x);
+    let ri = player.secret_sharing_vector(nonce_vm).eval(x);
+    let diff = G::generator() * partial.z() - ri - public * c;
+
+    if !bool::from(diff.is_identity()) {
+        let error = Error::InvalidPartialSignature {
+            shareholder: Player::shareholder(x),
+        };
+        player.record(x, partial.z().to_repr().as_ref(), &error);
+        return Err(error.into());
+    }
+
+    Ok(())
+}
+
+/// Implements [`Player::combine_signatures`].
+pub(crate) fn combine_signatures<G>(
+    player: &Player,
+    partials: &[PartialSignature<G>],
+    vm: &VerificationMatrix<G>,
+    nonce_vm: &VerificationMatrix<G>,
+    message: &[u8],
+) -> Result<ThresholdSignature<G>>
+where
+    G: Group + GroupEncoding,
+    G::Scalar: Zeroize,
+{
+    let expected = player.min_shares();
+    if partials.len() < expected {
+        return Err(Error::NotEnoughShares {
+            expected,
+            actual: partials.len(),
+        }
+        .into());
+    }
+
+    for (i, partial) in partials.iter().enumerate() {
+        if partials[..i].iter().any(|p| p.x() == partial.x()) {
+            return Err(Error::DuplicateShareholder.into());
+        }
+        player.verify_partial_signature(partial, vm, nonce_vm, message)?;
+    }
+
+    let xs = partials.iter().map(|p| *p.x()).collect::<Vec<_>>();
+    let zs = partials.iter().map(|p| p.z());
+    let z = Player::interpolate(&xs, zs);
+    let r = player.public_key(nonce_vm);
+
+    Ok(ThresholdSignature::new(r, z))
+}
+
+#[cfg(test)]
+mod tests {
+    use group::{ff::Field, Group as _};
+    use rand::{rngs::StdRng, SeedableRng};
+    use rand_core::OsRng;
+
+    use crate::churp::{self, HandoffKind, Player, Shareholder, VerifiableSecretShare};
+
+    use super::ThresholdSignature;
+
+    type PrimeField = p384::Scalar;
+    type Group = p384::ProjectivePoint;
+    type Dealer = churp::Dealer<Group>;
+
+    #[test]
+    fn test_threshold_signature() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let s = PrimeField::random(&mut rng);
+        let k = PrimeField::random(&mut rng);
+        let public_key = Group::generator() * s;
+        let r = Group::generator() * k;
+
+        let c = ThresholdSignature::<Group>::challenge(&public_key, &r, b"message");
+        let signature = ThresholdSignature::<Group>::new(r, k + c * s);
+        assert!(signature.verify(&public_key, b"message"));
+
+        // Different message or public key.
+        assert!(!signature.verify(&public_key, b"other message"));
+        assert!(!signature.verify(&(public_key + Group::generator()), b"message"));
+    }
+
+    #[test]
+    fn test_partial_signatures() {
+        let test_cases = vec![
+            HandoffKind::DealingPhase,
+            HandoffKind::CommitteeUnchanged,
+            HandoffKind::CommitteeChanged,
+        ];
+
+        for kind in test_cases.into_iter() {
+            let threshold = 2;
+            let dealer = Dealer::new(threshold, &mut OsRng).unwrap();
+            let nonce_dealer = Dealer::new(threshold, &mut OsRng).unwrap();
+            let vm = dealer.verification_matrix();
+            let nonce_vm = nonce_dealer.verification_matrix();
+            let public_key = *vm.element(0, 0).unwrap();
+            let player = Player::new(threshold, kind);
+            let min_shares = player.min_shares() as u64;
+
+            let shareholder = |dealer: &Dealer, x: u64| -> Shareholder<Group> {
+                let x = PrimeField::from_u64(x);
+                VerifiableSecretShare::new(
+                    dealer.make_share(x, kind),
+                    dealer.verification_matrix().clone(),
+                )
+                .into()
+            };
+            let partials: Vec<_> = (1..=min_shares)
+                .map(|x| {
+                    player
+                        .sign_partial(
+                            &shareholder(&dealer, x),
+                            &shareholder(&nonce_dealer, x),
+                            b"message",
+                        )
+                        .unwrap()
+                })
+                .collect();
+
+            let signature = player
+                .combine_signatures(&partials, vm, nonce_vm, b"message")
+                .unwrap();
+            assert!(signature.verify(&public_key, b"message"));
+            assert!(!signature.verify(&public_key, b"other message"));
+
+            // Not enough partial signatures.
+            let result = player.combine_signatures(&partials[1..], vm, nonce_vm, b"message");
+            assert!(result.is_err());
+
+            // Partial signature of another message.
+            let result = player.combine_signatures(&partials, vm, nonce_vm, b"other message");
+            assert!(result.is_err());
+            assert_eq!(
+                result.unwrap_err().to_string(),
+                format!(
+                    "invalid partial signature of shareholder {}",
+                    "0".repeat(94) + "01"
+                )
+            );
+
+            // Nonce share of another shareholder.
+            let result = player.sign_partial(
+                &shareholder(&dealer, 1),
+                &shareholder(&nonce_dealer, 2),
+                b"message",
+            );
+            assert!(result.is_err());
+        }
+    }
+}
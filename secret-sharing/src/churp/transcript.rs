@@ -0,0 +1,746 @@
+//! Archival and offline verification of handoff transcripts.
+
+use anyhow::Result;
+use group::{ff::PrimeField, Group, GroupEncoding};
+use zeroize::Zeroize;
+
+use crate::vss::VerificationMatrix;
+
+use super::{
+    package::{append_bytes, decode_kind, encode_kind, Reader},
+    DealerPackage, DealerVerifier, Error, HandoffKind, Player,
+};
+
+/// The version of the byte representation of the handoff transcript.
+const HANDOFF_TRANSCRIPT_VERSION: u8 = 1;
+
+/// A public commitment `B(a,b) * G` to a switch point sent from one
+/// shareholder to another during a handoff.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PointCommitment<G>
+where
+    G: Group,
+{
+    /// The identity of the sender.
+    pub sender: G::Scalar,
+    /// The identity of the recipient.
+    pub recipient: G::Scalar,
+    /// The commitment to the switch point.
+    pub commitment: G,
+}
+
+/// A record of the public messages of a completed handoff, i.e. the dealer
+/// packages of the committee and the resulting verification matrix,
+/// together with the verification matrix of the previous handoff,
+/// the commitments to the exchanged switch points and the dealers
+/// disqualified in the complaint phase.
+///
+/// The transcript contains no secrets, so it can be published, archived
+/// in its canonical byte representation, and verified by auditors after
+/// the handoff has finished.
+pub struct HandoffTranscript<G>
+where
+    G: Group + GroupEncoding,
+{
+    /// The threshold of the secret sharing.
+    threshold: u8,
+    /// The handoff epoch.
+    epoch: u64,
+    /// The handoff kind.
+    kind: HandoffKind,
+    /// The identities of the shareholders of the committee.
+    shareholders: Vec<G::Scalar>,
+    /// The verification matrix of the previous handoff, if any.
+    previous: Option<VerificationMatrix<G>>,
+    /// The packages of the dealers, one per shareholder, in the same order.
+    packages: Vec<DealerPackage<G>>,
+    /// The resulting verification matrix.
+    result: VerificationMatrix<G>,
+    /// The commitments to the share reduction switch points.
+    share_reduction_points: Vec<PointCommitment<G>>,
+    /// The commitments to the full share distribution switch points.
+    full_share_distribution_points: Vec<PointCommitment<G>>,
+    /// The dealers disqualified in the complaint phase.
+    disqualified: Vec<G::Scalar>,
+}
+
+impl<G> HandoffTranscript<G>
+where
+    G: Group + GroupEncoding,
+    G::Scalar: Zeroize,
+{
+    /// Creates a new transcript.
+    pub fn new(
+        threshold: u8,
+        epoch: u64,
+        kind: HandoffKind,
+        shareholders: Vec<G::Scalar>,
+        previous: Option<VerificationMatrix<G>>,
+        packages: Vec<DealerPackage<G>>,
+        result: VerificationMatrix<G>,
+    ) -> Self {
+        Self {
+            threshold,
+            epoch,
+            kind,
+            shareholders,
+            previous,
+            packages,
+            result,
+            share_reduction_points: Vec::new(),
+            full_share_distribution_points: Vec::new(),
+            disqualified: Vec::new(),
+        }
+    }
+
+    /// Records the commitment to a share reduction switch point `B(i,j)`
+    /// sent by the old shareholder `i` to the new shareholder `j`.
+    pub fn add_share_reduction_point(&mut self, point: PointCommitment<G>) {
+        self.share_reduction_points.push(point);
+    }
+
+    /// Records the commitment to a full share distribution switch point
+    /// `B(j,i)` sent by the shareholder `i` to the shareholder `j`.
+    pub fn add_full_share_distribution_point(&mut self, point: PointCommitment<G>) {
+        self.full_share_distribution_points.push(point);
+    }
+
+    /// Records the disqualification of the given dealer, whose package
+    /// is excluded from the resulting verification matrix.
+    pub fn disqualify(&mut self, x: G::Scalar) {
+        self.disqualified.push(x);
+    }
+
+    /// Returns the threshold of the secret sharing.
+    pub fn threshold(&self) -> u8 {
+        self.threshold
+    }
+
+    /// Returns the handoff epoch.
+    pub fn epoch(&self) -> u64 {
+        self.epoch
+    }
+
+    /// Returns the handoff kind.
+    pub fn kind(&self) -> HandoffKind {
+        self.kind
+    }
+
+    /// Returns the identities of the shareholders of the committee.
+    pub fn shareholders(&self) -> &[G::Scalar] {
+        &self.shareholders
+    }
+
+    /// Returns the verification matrix of the previous handoff, if any.
+    pub fn previous_verification_matrix(&self) -> Option<&VerificationMatrix<G>> {
+        self.previous.as_ref()
+    }
+
+    /// Returns the commitments to the share reduction switch points.
+    pub fn share_reduction_points(&self) -> &[PointCommitment<G>] {
+        &self.share_reduction_points
+    }
+
+    /// Returns the commitments to the full share distribution switch points.
+    pub fn full_share_distribution_points(&self) -> &[PointCommitment<G>] {
+        &self.full_share_distribution_points
+    }
+
+    /// Returns the dealers disqualified in the complaint phase.
+    pub fn disqualified(&self) -> &[G::Scalar] {
+        &self.disqualified
+    }
+
+    /// Returns the dealer packages.
+    pub fn packages(&self) -> &[DealerPackage<G>] {
+        &self.packages
+    }
+
+    /// Returns the resulting verification matrix.
+    pub fn verification_matrix(&self) -> &VerificationMatrix<G> {
+        &self.result
+    }
+
+    /// Verifies that the handoff was executed correctly, using only
+    /// the public messages.
+    ///
+    /// Every shareholder must have dealt exactly one package for the epoch
+    /// and the kind of the handoff, with shares for the whole committee
+    /// and a verification matrix of the expected dimensions, which
+    /// is zero-hole if and only if the handoff proactivizes an existing
+    /// secret. The resulting matrix must be the sum of the dealt matrices
+    /// and of the previous matrix, if any, which guarantees that
+    /// a proactivized secret didn't change.
+    ///
+    /// Disqualified dealers must belong to the committee and enough dealers
+    /// must remain, and the switch point commitments, which can only be
+    /// exchanged when the committee changes, must match the previous
+    /// and the resulting matrix, respectively.
+    ///
+    /// The signatures of the dealers can be verified separately using
+    /// [`HandoffTranscript::verify_signatures`].
+    pub fn verify(&self) -> Result<()> {
+        if self.packages.len() < self.shareholders.len() {
+            return Err(Error::NotEnoughDealers.into());
+        }
+        if self.packages.len() > self.shareholders.len() {
+            return Err(Error::CommitteeMismatch.into());
+        }
+
+        let mut committee: Vec<_> = self.shareholders.clone();
+        committee.sort_by_cached_key(|x| x.to_repr().as_ref().to_vec());
+        if committee.windows(2).any(|w| w[0] == w[1]) {
+            return Err(Error::DuplicateShareholder.into());
+        }
+
+        for (i, x) in self.disqualified.iter().enumerate() {
+            if !self.shareholders.contains(x) {
+                return Err(Error::UnknownShareholder.into());
+            }
+            if self.disqualified[..i].contains(x) {
+                return Err(Error::DuplicateShareholder.into());
+            }
+        }
+        let min_dealers = match self.kind {
+            HandoffKind::DealingPhase => self.threshold as usize + 2,
+            _ => self.threshold as usize + 1,
+        };
+        if self.shareholders.len() - self.disqualified.len() < min_dealers {
+            return Err(Error::NotEnoughDealers.into());
+        }
+
+        let zero_hole = self.kind.require_zero_hole();
+        let mut vm = match (self.kind, &self.previous) {
+            (HandoffKind::DealingPhase, _) => None,
+            (_, Some(previous)) => {
+                self.verify_dimensions(previous)?;
+                Some(previous.clone())
+            }
+            (_, None) => return Err(Error::VerificationMatrixRequired.into()),
+        };
+
+        for (x, package) in self.shareholders.iter().zip(self.packages.iter()) {
+            if package.epoch() != self.epoch || package.kind() != self.kind {
+                return Err(Error::DealerPackageMismatch.into());
+            }
+            if !package.shares().iter().map(|(x, _)| x).eq(committee.iter()) {
+                return Err(Error::CommitteeMismatch.into());
+            }
+
+            let package_vm = package.verification_matrix();
+            self.verify_dimensions(package_vm)?;
+            if package_vm.is_zero_hole() != zero_hole {
+                return Err(Error::VerificationMatrixZeroHoleMismatch.into());
+            }
+
+            if self.disqualified.contains(x) {
+                continue;
+            }
+
+            match vm.as_mut() {
+                Some(vm) => *vm += package_vm,
+                None => vm = Some(package_vm.clone()),
+            }
+        }
+
+        let vm = vm.ok_or(Error::NotEnoughDealers)?;
+        if vm != self.result {
+            return Err(Error::VerificationMatrixMismatch.into());
+        }
+        if self.result.is_zero_hole() {
+            return Err(Error::ZeroValueSecret.into());
+        }
+
+        self.verify_points()
+    }
+
+    /// Verifies the switch point commitments against the previous
+    /// and the resulting verification matrix.
+    fn verify_points(&self) -> Result<()> {
+        if self.share_reduction_points.is_empty() && self.full_share_distribution_points.is_empty()
+        {
+            return Ok(());
+        }
+        if !self.kind.changes_committee() {
+            return Err(Error::InvalidKind.into());
+        }
+
+        let previous = self
+            .previous
+            .as_ref()
+            .ok_or(Error::VerificationMatrixRequired)?;
+
+        // Share reduction points B(i,j) are evaluations of the full shares
+        // of the old committee, full share distribution points B(j,i)
+        // evaluations of the reduced shares of the new one.
+        let reduction = self
+            .share_reduction_points
+            .iter()
+            .map(|p| (p, previous, &p.sender, &p.recipient));
+        let distribution = self
+            .full_share_distribution_points
+            .iter()
+            .map(|p| (p, &self.result, &p.recipient, &p.sender));
+
+        for (point, vm, x, y) in reduction.chain(distribution) {
+            if vm.verification_vector_for_y(x).eval(y) != point.commitment {
+                return Err(Error::InvalidSharePoint {
+                    shareholder: Player::shareholder(&point.sender),
+                }
+                .into());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the canonical byte representation of the transcript.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![
+            HANDOFF_TRANSCRIPT_VERSION,
+            self.threshold,
+            encode_kind(self.kind),
+        ];
+        bytes.extend_from_slice(&self.epoch.to_le_bytes());
+
+        Self::write_scalars(&mut bytes, &self.shareholders);
+        match &self.previous {
+            Some(previous) => {
+                bytes.push(1);
+                append_bytes(&mut bytes, &previous.to_bytes());
+            }
+            None => bytes.push(0),
+        }
+        bytes.extend_from_slice(&(self.packages.len() as u32).to_le_bytes());
+        for package in self.packages.iter() {
+            append_bytes(&mut bytes, &package.to_bytes());
+        }
+        append_bytes(&mut bytes, &self.result.to_bytes());
+
+        for points in [
+            &self.share_reduction_points,
+            &self.full_share_distribution_points,
+        ] {
+            bytes.extend_from_slice(&(points.len() as u32).to_le_bytes());
+            for point in points.iter() {
+                bytes.extend_from_slice(point.sender.to_repr().as_ref());
+                bytes.extend_from_slice(point.recipient.to_repr().as_ref());
+                bytes.extend_from_slice(point.commitment.to_bytes().as_ref());
+            }
+        }
+        Self::write_scalars(&mut bytes, &self.disqualified);
+
+        bytes
+    }
+
+    /// Attempts to create a transcript from its canonical byte representation.
+    ///
+    /// Returns `None` if the version is not supported or if the representation
+    /// is malformed. The transcript is not verified.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let mut reader = Reader { bytes };
+
+        if reader.take(1)?[0] != HANDOFF_TRANSCRIPT_VERSION {
+            return None;
+        }
+        let threshold = reader.take(1)?[0];
+        let kind = decode_kind(reader.take(1)?[0])?;
+        let epoch = reader.take_u64()?;
+
+        let shareholders = Self::read_scalars(&mut reader)?;
+        let previous = match reader.take(1)?[0] {
+            0 => None,
+            1 => Some(VerificationMatrix::from_bytes(reader.take_prefixed()?)?),
+            _ => return None,
+        };
+        let n = reader.take_u32()? as usize;
+        let mut packages = Vec::with_capacity(n.min(bytes.len()));
+        for _ in 0..n {
+            packages.push(DealerPackage::from_bytes(reader.take_prefixed()?)?);
+        }
+        let result = VerificationMatrix::from_bytes(reader.take_prefixed()?)?;
+
+        let mut transcript = Self::new(
+            threshold,
+            epoch,
+            kind,
+            shareholders,
+            previous,
+            packages,
+            result,
+        );
+
+        for i in 0..2 {
+            let n = reader.take_u32()? as usize;
+            for _ in 0..n {
+                let point = PointCommitment {
+                    sender: reader.take_scalar()?,
+                    recipient: reader.take_scalar()?,
+                    commitment: reader.take_element()?,
+                };
+                match i {
+                    0 => transcript.add_share_reduction_point(point),
+                    _ => transcript.add_full_share_distribution_point(point),
+                }
+            }
+        }
+        transcript.disqualified = Self::read_scalars(&mut reader)?;
+
+        if !reader.bytes.is_empty() {
+            return None;
+        }
+
+        Some(transcript)
+    }
+
+    /// Appends the given field elements prefixed with their count.
+    fn write_scalars(bytes: &mut Vec<u8>, xs: &[G::Scalar]) {
+        bytes.extend_from_slice(&(xs.len() as u32).to_le_bytes());
+        for x in xs.iter() {
+            bytes.extend_from_slice(x.to_repr().as_ref());
+        }
+    }
+
+    /// Consumes field elements prefixed with their count.
+    fn read_scalars(reader: &mut Reader<'_>) -> Option<Vec<G::Scalar>> {
+        let n = reader.take_u32()? as usize;
+        let mut xs = Vec::with_capacity(n.min(reader.bytes.len()));
+        for _ in 0..n {
+            xs.push(reader.take_scalar()?);
+        }
+
+        Some(xs)
+    }
+
+    /// Verifies the signatures of the dealer packages using the given
+    /// verifiers, given in the same order as the packages.
+    pub fn verify_signatures<V: DealerVerifier>(&self, verifiers: &[V]) -> Result<()> {
+        if verifiers.len() != self.packages.len() {
+            return Err(Error::CommitteeMismatch.into());
+        }
+
+        for (package, verifier) in self.packages.iter().zip(verifiers) {
+            package.verify_signature(verifier)?;
+        }
+
+        Ok(())
+    }
+
+    /// Verifies that the verification matrix has the dimensions expected
+    /// for the threshold.
+    fn verify_dimensions(&self, vm: &VerificationMatrix<G>) -> Result<()> {
+        let threshold = self.threshold as usize;
+        let expected = (threshold + 1, 2 * threshold + 1);
+        let actual = vm.dimensions();
+
+        if actual != expected {
+            return Err(Error::UnexpectedVerificationMatrixDimensions { expected, actual }.into());
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use group::{ff::Field, Group as _};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::churp::{signing::tests::TestSigner, DealerPackage, HandoffKind};
+
+    use super::{HandoffTranscript, PointCommitment};
+
+    type PrimeField = p384::Scalar;
+    type Group = p384::ProjectivePoint;
+    type Dealer = crate::churp::Dealer<Group>;
+
+    #[test]
+    fn test_handoff_transcript() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let threshold = 1;
+        let shareholders: Vec<_> = (1..=3).map(PrimeField::from_u64).collect();
+        let recipients: Vec<_> = shareholders
+            .iter()
+            .map(|x| (*x, Group::generator() * PrimeField::random(&mut rng)))
+            .collect();
+
+        let deal = |epoch: u64, kind: HandoffKind, rng: &mut StdRng| {
+            let packages: Vec<DealerPackage<Group>> = (0..shareholders.len())
+                .map(|_| {
+                    let dealer = match kind {
+                        HandoffKind::DealingPhase => Dealer::new(threshold, rng).unwrap(),
+                        _ => Dealer::new_proactive(threshold, rng).unwrap(),
+                    };
+                    dealer
+                        .make_package(epoch, kind, &recipients, b"context", rng)
+                        .unwrap()
+                })
+                .collect();
+            packages
+        };
+
+        // Dealing phase.
+        let kind = HandoffKind::DealingPhase;
+        let packages = deal(1, kind, &mut rng);
+        let vm = packages
+            .iter()
+            .map(|p| p.verification_matrix().clone())
+            .reduce(|a, b| a + b)
+            .unwrap();
+        let transcript = HandoffTranscript::new(
+            threshold,
+            1,
+            kind,
+            shareholders.clone(),
+            None,
+            packages.clone(),
+            vm.clone(),
+        );
+        transcript.verify().unwrap();
+
+        // Archival.
+        let bytes = transcript.to_bytes();
+        let restored = HandoffTranscript::<Group>::from_bytes(&bytes).unwrap();
+        assert_eq!(restored.to_bytes(), bytes);
+        assert_eq!(restored.epoch(), 1);
+        assert_eq!(restored.shareholders(), &shareholders[..]);
+        restored.verify().unwrap();
+        assert!(HandoffTranscript::<Group>::from_bytes(&bytes[..bytes.len() - 1]).is_none());
+
+        // Proactivization.
+        let kind = HandoffKind::CommitteeUnchanged;
+        let updates = deal(2, kind, &mut rng);
+        let next = updates
+            .iter()
+            .fold(vm.clone(), |vm, p| vm + p.verification_matrix());
+        let transcript = HandoffTranscript::new(
+            threshold,
+            2,
+            kind,
+            shareholders.clone(),
+            Some(vm.clone()),
+            updates.clone(),
+            next.clone(),
+        );
+        transcript.verify().unwrap();
+
+        // Signatures.
+        let signers: Vec<_> = (0..3).map(|i| TestSigner(vec![i])).collect();
+        let mut signed = updates.clone();
+        for (package, signer) in signed.iter_mut().zip(signers.iter()) {
+            package.sign(signer).unwrap();
+        }
+        let transcript = HandoffTranscript::new(
+            threshold,
+            2,
+            kind,
+            shareholders.clone(),
+            Some(vm.clone()),
+            signed,
+            next.clone(),
+        );
+        transcript.verify_signatures(&signers).unwrap();
+        let reversed: Vec<_> = signers
+            .iter()
+            .rev()
+            .map(|s| TestSigner(s.0.clone()))
+            .collect();
+        assert!(transcript.verify_signatures(&reversed).is_err());
+
+        // The secret changed.
+        let transcript = HandoffTranscript::new(
+            threshold,
+            2,
+            kind,
+            shareholders.clone(),
+            Some(vm.clone()),
+            updates.clone(),
+            next.clone() + &vm,
+        );
+        let result = transcript.verify();
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "verification matrix mismatch"
+        );
+
+        // Missing previous matrix.
+        let transcript = HandoffTranscript::new(
+            threshold,
+            2,
+            kind,
+            shareholders.clone(),
+            None,
+            updates.clone(),
+            next.clone(),
+        );
+        let result = transcript.verify();
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "verification matrix required"
+        );
+
+        // Package which is not zero-hole.
+        let mut invalid = updates.clone();
+        invalid[0] = packages[0].clone();
+        let transcript = HandoffTranscript::new(
+            threshold,
+            2,
+            kind,
+            shareholders.clone(),
+            Some(vm.clone()),
+            invalid,
+            next.clone(),
+        );
+        assert!(transcript.verify().is_err());
+
+        // Missing dealer.
+        let transcript = HandoffTranscript::new(
+            threshold,
+            2,
+            kind,
+            shareholders.clone(),
+            Some(vm.clone()),
+            updates[1..].to_vec(),
+            next.clone(),
+        );
+        let result = transcript.verify();
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().to_string(), "not enough dealers");
+
+        // Package for another committee.
+        let transcript = HandoffTranscript::new(
+            threshold,
+            2,
+            kind,
+            (2..=4).map(PrimeField::from_u64).collect(),
+            Some(vm),
+            updates,
+            next,
+        );
+        let result = transcript.verify();
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().to_string(), "committee mismatch");
+    }
+
+    #[test]
+    fn test_committee_changed_transcript() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let threshold = 1;
+        let kind = HandoffKind::CommitteeChanged;
+        let old_committee: Vec<_> = (1..=3).map(PrimeField::from_u64).collect();
+        let committee: Vec<_> = (4..=6).map(PrimeField::from_u64).collect();
+        let recipients: Vec<_> = committee
+            .iter()
+            .map(|x| (*x, Group::generator() * PrimeField::random(&mut rng)))
+            .collect();
+
+        let old_dealer = Dealer::new(threshold, &mut rng).unwrap();
+        let previous = old_dealer.verification_matrix().clone();
+        let dealers: Vec<_> = committee
+            .iter()
+            .map(|_| Dealer::new_proactive(threshold, &mut rng).unwrap())
+            .collect();
+        let packages: Vec<DealerPackage<Group>> = dealers
+            .iter()
+            .map(|dealer| {
+                dealer
+                    .make_package(2, kind, &recipients, b"context", &mut rng)
+                    .unwrap()
+            })
+            .collect();
+
+        // The last dealer is disqualified.
+        let qualified = &dealers[..2];
+        let result = qualified
+            .iter()
+            .fold(previous.clone(), |vm, d| vm + d.verification_matrix());
+
+        let mut transcript = HandoffTranscript::new(
+            threshold,
+            2,
+            kind,
+            committee.clone(),
+            Some(previous.clone()),
+            packages.clone(),
+            result.clone(),
+        );
+        transcript.disqualify(committee[2]);
+
+        // Share reduction points B(i,j) and full share distribution
+        // points B'(j,i).
+        let commit = |y: PrimeField| Group::generator() * y;
+        for i in old_committee.iter() {
+            let full = old_dealer.make_share(*i, HandoffKind::CommitteeUnchanged);
+            for j in committee.iter() {
+                transcript.add_share_reduction_point(PointCommitment {
+                    sender: *i,
+                    recipient: *j,
+                    commitment: commit(full.polynomial().eval(j)),
+                });
+            }
+        }
+        for i in committee.iter() {
+            let reduced = qualified.iter().fold(
+                old_dealer.make_share(*i, kind).polynomial().clone(),
+                |p, d| p + d.make_share(*i, kind).polynomial(),
+            );
+            for j in committee.iter() {
+                transcript.add_full_share_distribution_point(PointCommitment {
+                    sender: *i,
+                    recipient: *j,
+                    commitment: commit(reduced.eval(j)),
+                });
+            }
+        }
+        transcript.verify().unwrap();
+
+        // Archival.
+        let bytes = transcript.to_bytes();
+        let restored = HandoffTranscript::<Group>::from_bytes(&bytes).unwrap();
+        assert_eq!(restored.to_bytes(), bytes);
+        assert_eq!(restored.disqualified(), &committee[2..]);
+        assert_eq!(restored.share_reduction_points().len(), 9);
+        assert_eq!(restored.full_share_distribution_points().len(), 9);
+        restored.verify().unwrap();
+
+        // Invalid point commitment.
+        let mut invalid = HandoffTranscript::<Group>::from_bytes(&bytes).unwrap();
+        invalid.add_share_reduction_point(PointCommitment {
+            sender: old_committee[0],
+            recipient: committee[0],
+            commitment: Group::generator(),
+        });
+        let result = invalid.verify();
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .starts_with("invalid share point of shareholder"));
+
+        // The disqualified dealer is included in the result.
+        let mut invalid = HandoffTranscript::<Group>::from_bytes(&bytes).unwrap();
+        invalid.disqualified.clear();
+        assert!(invalid.verify().is_err());
+
+        // Too many disqualified dealers.
+        let mut invalid = HandoffTranscript::<Group>::from_bytes(&bytes).unwrap();
+        invalid.disqualify(committee[1]);
+        let result = invalid.verify();
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().to_string(), "not enough dealers");
+
+        // Unknown disqualified dealer.
+        let mut invalid = HandoffTranscript::<Group>::from_bytes(&bytes).unwrap();
+        invalid.disqualified = vec![old_committee[0]];
+        let result = invalid.verify();
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().to_string(), "unknown shareholder");
+
+        // Unsupported version.
+        let mut unsupported = bytes.clone();
+        unsupported[0] = 0;
+        assert!(HandoffTranscript::<Group>::from_bytes(&unsupported).is_none());
+    }
+}
@@ -1,11 +1,20 @@
+use std::{
+    cmp::max,
+    io::{self, Read, Write},
+    iter::Sum,
+    ops::{Add, AddAssign, Mul, MulAssign},
+};
+
 use group::ff::PrimeField;
 use rand_core::RngCore;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 use subtle::{Choice, CtOption};
 use zeroize::Zeroize;
 
 use crate::poly::powers;
 
-use super::Polynomial;
+use super::{xof::XofRng, Polynomial};
 
 /// Bivariate polynomial over a non-binary prime field.
 ///
@@ -57,6 +66,40 @@ where
         Self { b, deg_x, deg_y }
     }
 
+    /// Creates a bivariate polynomial with random coefficients and the given
+    /// coefficient `b_{0,0}` of the constant term.
+    ///
+    /// This method is not constant time as some prime field implementations
+    /// may generate uniformly random elements using rejection sampling.
+    pub fn random_with_constant(deg_x: u8, deg_y: u8, constant: F, rng: &mut impl RngCore) -> Self {
+        let mut bp = Self::random(deg_x, deg_y, rng);
+        bp.b[0][0] = constant;
+        bp
+    }
+
+    /// Creates a bivariate polynomial with pseudo-random coefficients
+    /// deterministically derived from the given seed and domain separation
+    /// tag.
+    ///
+    /// The seed is expanded using the SHAKE256 extendable-output function,
+    /// so the same seed, tag and degrees always result in the same polynomial.
+    /// This allows persisting a compact seed instead of all coefficients.
+    /// Note that the derivation depends on how the prime field implementation
+    /// samples random elements, so polynomials should be re-derived only
+    /// with the same implementation.
+    ///
+    /// The seed must be uniformly random and kept secret.
+    pub fn from_seed(deg_x: u8, deg_y: u8, seed: &[u8; 32], dst: &[u8]) -> Self {
+        let mut input = Vec::with_capacity(seed.len() + 2);
+        input.extend_from_slice(seed);
+        input.extend_from_slice(&[deg_x, deg_y]);
+
+        let mut rng = XofRng::new(&input, dst);
+        input.zeroize();
+
+        Self::random(deg_x, deg_y, &mut rng)
+    }
+
     /// Creates a bivariate polynomial with the given coefficients.
     ///
     /// # Panics
@@ -110,6 +153,43 @@ where
         self.b.get(i).and_then(|bi| bi.get(j))
     }
 
+    /// Returns a mutable reference to the coefficient `b_{i,j}`
+    /// of the bivariate polynomial.
+    pub fn coefficient_mut(&mut self, i: usize, j: usize) -> Option<&mut F> {
+        self.b.get_mut(i).and_then(|bi| bi.get_mut(j))
+    }
+
+    /// Returns the coefficient `b_{0,0}` of the constant term.
+    pub fn constant(&self) -> &F {
+        &self.b[0][0]
+    }
+
+    /// Returns a mutable reference to the coefficient `b_{0,0}`
+    /// of the constant term.
+    pub fn constant_mut(&mut self) -> &mut F {
+        &mut self.b[0][0]
+    }
+
+    /// Returns an iterator over the coefficients `b_{i,j}` of the bivariate
+    /// polynomial and their indices, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &F)> {
+        self.b
+            .iter()
+            .enumerate()
+            .flat_map(|(i, bi)| bi.iter().enumerate().map(move |(j, bij)| ((i, j), bij)))
+    }
+
+    /// Returns an iterator that allows modifying the coefficients `b_{i,j}`
+    /// of the bivariate polynomial, row by row.
+    ///
+    /// The number of coefficients cannot be changed.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = ((usize, usize), &mut F)> {
+        self.b
+            .iter_mut()
+            .enumerate()
+            .flat_map(|(i, bi)| bi.iter_mut().enumerate().map(move |(j, bij)| ((i, j), bij)))
+    }
+
     /// Returns the byte representation of the bivariate polynomial.
     pub fn to_bytes(&self) -> Vec<u8> {
         let cap = Self::byte_size(self.deg_x, self.deg_y);
@@ -170,6 +250,72 @@ where
         res.into()
     }
 
+    /// Writes the byte representation of the bivariate polynomial
+    /// to the given writer, one coefficient at a time.
+    ///
+    /// The output is identical to the output of [`Self::to_bytes`],
+    /// but the full representation is never held in memory.
+    pub fn write_to<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(&[self.deg_x as u8, self.deg_y as u8])?;
+        for bi in &self.b {
+            for bij in bi {
+                let mut repr = bij.to_repr();
+                let res = writer.write_all(repr.as_ref());
+                repr.as_mut().zeroize();
+                res?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Attempts to read a bivariate polynomial from its byte representation
+    /// from the given reader, one coefficient at a time.
+    ///
+    /// Returns an error of kind [`io::ErrorKind::InvalidData`] if any
+    /// of the coefficients is invalid.
+    pub fn read_from<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let mut degs = [0u8; 2];
+        reader.read_exact(&mut degs)?;
+        let deg_x = degs[0] as usize;
+        let deg_y = degs[1] as usize;
+
+        // Don't short-circuit this loop to avoid revealing which coefficient
+        // failed to decode.
+        let mut b = Vec::with_capacity(deg_x + 1);
+        let mut failed = Choice::from(0);
+        let mut repr: F::Repr = Default::default();
+
+        for _ in 0..=deg_x {
+            let mut bi = Vec::with_capacity(deg_y + 1);
+
+            for _ in 0..=deg_y {
+                if let Err(err) = reader.read_exact(repr.as_mut()) {
+                    repr.as_mut().zeroize();
+                    return Err(err);
+                }
+
+                let maybe_bij = F::from_repr(repr);
+                failed |= maybe_bij.is_none();
+
+                let bij = maybe_bij.unwrap_or(Default::default());
+                bi.push(bij);
+            }
+
+            b.push(bi);
+        }
+        repr.as_mut().zeroize();
+
+        if bool::from(failed) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "invalid coefficient",
+            ));
+        }
+
+        Ok(Self::with_coefficients(b))
+    }
+
     /// Returns the size of the byte representation of a coefficient.
     pub const fn coefficient_byte_size() -> usize {
         F::NUM_BITS.saturating_add(7) as usize / 8
@@ -181,16 +327,22 @@ where
     }
 
     /// Evaluates the bivariate polynomial.
+    ///
+    /// The polynomial is evaluated directly using nested Horner's rule
+    /// ```text
+    /// B(x,y) = (..(B_{deg_x}(y) x + B_{deg_x-1}(y)) x + ..) x + B_0(y)
+    /// ```
+    /// where `B_i(y) = \sum_{j=0}^{deg_y} b_{i,j} y^j`, without materializing
+    /// an intermediate univariate polynomial or computing powers of `x`
+    /// and `y`.
     pub fn eval(&self, x: &F, y: &F) -> F {
-        let xpows = powers(x, self.deg_x); // [x^i]
-        let ypows = powers(y, self.deg_y); // [y^j]
         let mut v = F::ZERO;
-        for (i, xpow) in xpows.iter().enumerate() {
+        for bi in self.b.iter().rev() {
             let mut vi = F::ZERO;
-            for (j, ypow) in ypows.iter().enumerate() {
-                vi += self.b[i][j] * ypow //  b_{i,j} y^j
+            for bij in bi.iter().rev() {
+                vi = vi * y + bij; // B_i(y)
             }
-            v += vi * xpow // \sum_{j=0}^{deg_y} b_{i,j} x^i y^j
+            v = v * x + vi;
         }
 
         v
@@ -208,18 +360,49 @@ where
     /// ```
     pub fn eval_x(&self, x: &F) -> Polynomial<F> {
         let xpows = powers(x, self.deg_x); // [x^i]
-        let mut a = Vec::with_capacity(self.deg_y + 1);
-        for j in 0..=self.deg_y {
+        let aj = |j: usize| {
             let mut aj = F::ZERO;
             for (i, xpow) in xpows.iter().enumerate() {
                 aj += self.b[i][j] * xpow //  b_{i,j} x^i
             }
-            a.push(aj)
-        }
+            aj
+        };
+
+        #[cfg(not(feature = "parallel"))]
+        let a = (0..=self.deg_y).map(aj).collect();
+        #[cfg(feature = "parallel")]
+        let a = (0..=self.deg_y).into_par_iter().map(aj).collect();
 
         Polynomial::with_coefficients(a)
     }
 
+    /// Evaluates the bivariate polynomial with respect to the indeterminate x
+    /// at each of the given points.
+    ///
+    /// This is equivalent to calling `eval_x` for every point, but the powers
+    /// of each point are computed only once and the coefficients are
+    /// accumulated row by row, which is considerably faster when many
+    /// shares are generated from the same polynomial.
+    pub fn eval_x_batch(&self, xs: &[F]) -> Vec<Polynomial<F>> {
+        let eval = |x: &F| {
+            let xpows = powers(x, self.deg_x); // [x^i]
+            let mut a = vec![F::ZERO; self.deg_y + 1];
+            for (bi, xpow) in self.b.iter().zip(xpows.iter()) {
+                for (aj, bij) in a.iter_mut().zip(bi.iter()) {
+                    *aj += *bij * xpow; // b_{i,j} x^i
+                }
+            }
+            Polynomial::with_coefficients(a)
+        };
+
+        #[cfg(not(feature = "parallel"))]
+        let ps = xs.iter().map(eval).collect();
+        #[cfg(feature = "parallel")]
+        let ps = xs.par_iter().map(eval).collect();
+
+        ps
+    }
+
     /// Evaluates the bivariate polynomial with respect to the indeterminate y.
     ///
     /// Returned polynomial:
@@ -232,17 +415,307 @@ where
     /// ```
     pub fn eval_y(&self, y: &F) -> Polynomial<F> {
         let ypows = powers(y, self.deg_y); // [y^j]
-        let mut a = Vec::with_capacity(self.deg_x + 1);
-        for i in 0..=self.deg_x {
+        let ai = |i: usize| {
             let mut ai = F::ZERO;
             for (j, ypow) in ypows.iter().enumerate() {
                 ai += self.b[i][j] * ypow // b_{i,j} y^j
             }
-            a.push(ai)
-        }
+            ai
+        };
+
+        #[cfg(not(feature = "parallel"))]
+        let a = (0..=self.deg_x).map(ai).collect();
+        #[cfg(feature = "parallel")]
+        let a = (0..=self.deg_x).into_par_iter().map(ai).collect();
 
         Polynomial::with_coefficients(a)
     }
+
+    /// Evaluates the bivariate polynomial with respect to the indeterminate y
+    /// at each of the given points.
+    ///
+    /// This is equivalent to calling `eval_y` for every point, but the powers
+    /// of each point are computed only once.
+    pub fn eval_y_batch(&self, ys: &[F]) -> Vec<Polynomial<F>> {
+        let eval = |y: &F| {
+            let ypows = powers(y, self.deg_y); // [y^j]
+            let a = self
+                .b
+                .iter()
+                .map(|bi| {
+                    bi.iter()
+                        .zip(ypows.iter())
+                        .fold(F::ZERO, |ai, (bij, ypow)| ai + *bij * ypow) // b_{i,j} y^j
+                })
+                .collect();
+            Polynomial::with_coefficients(a)
+        };
+
+        #[cfg(not(feature = "parallel"))]
+        let ps = ys.iter().map(eval).collect();
+        #[cfg(feature = "parallel")]
+        let ps = ys.par_iter().map(eval).collect();
+
+        ps
+    }
+
+    /// Returns the formal partial derivative of the bivariate polynomial
+    /// with respect to the indeterminate x.
+    ///
+    /// Returned polynomial:
+    /// ```text
+    /// dB/dx(x,y) = \sum_{i=1}^{deg_x} \sum_{j=0}^{deg_y} i b_{i,j} x^{i-1} y^j
+    /// ```
+    ///
+    /// The degree in the x variable is decreased by one, unless it is zero.
+    pub fn derivative_x(&self) -> BivariatePolynomial<F> {
+        let b: Vec<Vec<F>> = self
+            .b
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, bi)| {
+                let i = F::from_u128(i as u128);
+                bi.iter().map(|bij| i * bij).collect() // i b_{i,j}
+            })
+            .collect();
+
+        match b.is_empty() {
+            true => Self::zero(0, self.deg_y as u8),
+            false => Self::with_coefficients(b),
+        }
+    }
+
+    /// Returns the formal partial derivative of the bivariate polynomial
+    /// with respect to the indeterminate y.
+    ///
+    /// Returned polynomial:
+    /// ```text
+    /// dB/dy(x,y) = \sum_{i=0}^{deg_x} \sum_{j=1}^{deg_y} j b_{i,j} x^i y^{j-1}
+    /// ```
+    ///
+    /// The degree in the y variable is decreased by one, unless it is zero.
+    pub fn derivative_y(&self) -> BivariatePolynomial<F> {
+        if self.deg_y == 0 {
+            return Self::zero(self.deg_x as u8, 0);
+        }
+
+        let b = self
+            .b
+            .iter()
+            .map(|bi| {
+                bi.iter()
+                    .enumerate()
+                    .skip(1)
+                    .map(|(j, bij)| F::from_u128(j as u128) * bij) // j b_{i,j}
+                    .collect()
+            })
+            .collect();
+
+        Self::with_coefficients(b)
+    }
+}
+
+impl<F> Add for BivariatePolynomial<F>
+where
+    F: PrimeField,
+{
+    type Output = BivariatePolynomial<F>;
+
+    #[inline]
+    fn add(self, rhs: BivariatePolynomial<F>) -> BivariatePolynomial<F> {
+        &self + &rhs
+    }
+}
+
+impl<F> Add<&BivariatePolynomial<F>> for BivariatePolynomial<F>
+where
+    F: PrimeField,
+{
+    type Output = BivariatePolynomial<F>;
+
+    #[inline]
+    fn add(self, rhs: &BivariatePolynomial<F>) -> BivariatePolynomial<F> {
+        &self + rhs
+    }
+}
+
+impl<F> Add<BivariatePolynomial<F>> for &BivariatePolynomial<F>
+where
+    F: PrimeField,
+{
+    type Output = BivariatePolynomial<F>;
+
+    #[inline]
+    fn add(self, rhs: BivariatePolynomial<F>) -> BivariatePolynomial<F> {
+        self + &rhs
+    }
+}
+
+impl<F> Add for &BivariatePolynomial<F>
+where
+    F: PrimeField,
+{
+    type Output = BivariatePolynomial<F>;
+
+    fn add(self, rhs: &BivariatePolynomial<F>) -> BivariatePolynomial<F> {
+        let deg_x = max(self.deg_x, rhs.deg_x);
+        let deg_y = max(self.deg_y, rhs.deg_y);
+        let mut b = Vec::with_capacity(deg_x + 1);
+
+        for i in 0..=deg_x {
+            let mut bi = Vec::with_capacity(deg_y + 1);
+
+            for j in 0..=deg_y {
+                let mut bij = F::ZERO;
+                if let Some(b1) = self.coefficient(i, j) {
+                    bij += b1;
+                }
+                if let Some(b2) = rhs.coefficient(i, j) {
+                    bij += b2;
+                }
+                bi.push(bij);
+            }
+
+            b.push(bi);
+        }
+
+        BivariatePolynomial { deg_x, deg_y, b }
+    }
+}
+
+impl<F> AddAssign for BivariatePolynomial<F>
+where
+    F: PrimeField + Zeroize,
+{
+    #[inline]
+    fn add_assign(&mut self, rhs: BivariatePolynomial<F>) {
+        *self += &rhs
+    }
+}
+
+impl<F> AddAssign<&BivariatePolynomial<F>> for BivariatePolynomial<F>
+where
+    F: PrimeField + Zeroize,
+{
+    fn add_assign(&mut self, rhs: &BivariatePolynomial<F>) {
+        if self.deg_x < rhs.deg_x || self.deg_y < rhs.deg_y {
+            let sum = &*self + rhs;
+            self.zeroize();
+            *self = sum;
+            return;
+        }
+
+        for i in 0..=rhs.deg_x {
+            for j in 0..=rhs.deg_y {
+                self.b[i][j] += rhs.b[i][j];
+            }
+        }
+    }
+}
+
+impl<F> Mul<F> for BivariatePolynomial<F>
+where
+    F: PrimeField,
+{
+    type Output = BivariatePolynomial<F>;
+
+    #[allow(clippy::op_ref)]
+    #[inline]
+    fn mul(self, scalar: F) -> BivariatePolynomial<F> {
+        &self * &scalar
+    }
+}
+
+impl<F> Mul<&F> for BivariatePolynomial<F>
+where
+    F: PrimeField,
+{
+    type Output = BivariatePolynomial<F>;
+
+    #[inline]
+    fn mul(self, scalar: &F) -> BivariatePolynomial<F> {
+        &self * scalar
+    }
+}
+
+impl<F> Mul<F> for &BivariatePolynomial<F>
+where
+    F: PrimeField,
+{
+    type Output = BivariatePolynomial<F>;
+
+    #[allow(clippy::op_ref)]
+    #[inline]
+    fn mul(self, scalar: F) -> BivariatePolynomial<F> {
+        self * &scalar
+    }
+}
+
+impl<F> Mul<&F> for &BivariatePolynomial<F>
+where
+    F: PrimeField,
+{
+    type Output = BivariatePolynomial<F>;
+
+    fn mul(self, scalar: &F) -> BivariatePolynomial<F> {
+        let b = self
+            .b
+            .iter()
+            .map(|bi| bi.iter().map(|bij| *bij * scalar).collect())
+            .collect();
+
+        BivariatePolynomial {
+            deg_x: self.deg_x,
+            deg_y: self.deg_y,
+            b,
+        }
+    }
+}
+
+impl<F> MulAssign<F> for BivariatePolynomial<F>
+where
+    F: PrimeField,
+{
+    #[inline]
+    fn mul_assign(&mut self, scalar: F) {
+        *self *= &scalar
+    }
+}
+
+impl<F> MulAssign<&F> for BivariatePolynomial<F>
+where
+    F: PrimeField,
+{
+    fn mul_assign(&mut self, scalar: &F) {
+        for bi in self.b.iter_mut() {
+            for bij in bi.iter_mut() {
+                *bij *= scalar;
+            }
+        }
+    }
+}
+
+impl<F> Sum for BivariatePolynomial<F>
+where
+    F: PrimeField + Zeroize,
+{
+    fn sum<I: Iterator<Item = BivariatePolynomial<F>>>(iter: I) -> BivariatePolynomial<F> {
+        let mut sum = BivariatePolynomial::zero(0, 0);
+        iter.for_each(|bp| sum += bp);
+        sum
+    }
+}
+
+impl<'a, F> Sum<&'a BivariatePolynomial<F>> for BivariatePolynomial<F>
+where
+    F: PrimeField + Zeroize,
+{
+    fn sum<I: Iterator<Item = &'a BivariatePolynomial<F>>>(iter: I) -> BivariatePolynomial<F> {
+        let mut sum = BivariatePolynomial::zero(0, 0);
+        iter.for_each(|bp| sum += bp);
+        sum
+    }
 }
 
 impl<F> Zeroize for BivariatePolynomial<F>
@@ -260,8 +733,9 @@ where
 
 #[cfg(test)]
 mod tests {
-    use std::panic;
+    use std::{io, panic};
 
+    use group::ff::Field;
     use rand::{rngs::StdRng, SeedableRng};
 
     type PrimeField = p384::Scalar;
@@ -328,6 +802,22 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_random_with_constant() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+
+        let bp = BivariatePolynomial::random_with_constant(0, 0, scalar(7), &mut rng);
+        assert_eq!(bp.b, vec![scalars(&[7])]);
+
+        let bp = BivariatePolynomial::random_with_constant(2, 3, scalar(7), &mut rng);
+        assert_eq!(bp.deg_x, 2);
+        assert_eq!(bp.deg_y, 3);
+        assert_eq!(bp.constant(), &scalar(7));
+        assert_eq!(bp.eval(&scalar(0), &scalar(0)), scalar(7));
+        assert_ne!(bp.b[0][1], scalar(0)); // Zero with negligible probability.
+        assert_ne!(bp.b[1][0], scalar(0)); // Zero with negligible probability.
+    }
+
     #[test]
     fn test_random() {
         let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
@@ -350,6 +840,24 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_from_seed() {
+        let seed = [1u8; 32];
+        let dst = b"test from seed";
+        let bp = BivariatePolynomial::from_seed(2, 3, &seed, dst);
+        assert_eq!(bp.deg_x, 2);
+        assert_eq!(bp.deg_y, 3);
+
+        // Deterministic.
+        assert!(bp == BivariatePolynomial::from_seed(2, 3, &seed, dst));
+
+        // Different seeds, tags and degrees.
+        assert!(bp != BivariatePolynomial::from_seed(2, 3, &[2u8; 32], dst));
+        assert!(bp != BivariatePolynomial::from_seed(2, 3, &seed, b"other"));
+        let other = BivariatePolynomial::from_seed(2, 4, &seed, dst);
+        assert_ne!(other.coefficient(0, 0), bp.coefficient(0, 0));
+    }
+
     #[test]
     fn test_set_coefficient() {
         let mut bp = BivariatePolynomial::zero(2, 3);
@@ -359,6 +867,33 @@ mod tests {
         assert_eq!(bp.b[0][0], scalar(1));
     }
 
+    #[test]
+    fn test_coefficient_access() {
+        let b = vec![scalars(&[1, 2, 3]), scalars(&[4, 5, 6])];
+        let mut bp = BivariatePolynomial::with_coefficients(b.clone());
+        assert_eq!(bp.constant(), &scalar(1));
+
+        let indices: Vec<_> = bp.iter().map(|(ij, _)| ij).collect();
+        assert_eq!(
+            indices,
+            vec![(0, 0), (0, 1), (0, 2), (1, 0), (1, 1), (1, 2)]
+        );
+        for ((i, j), bij) in bp.iter() {
+            assert_eq!(bij, &b[i][j]);
+        }
+
+        *bp.constant_mut() = scalar(7);
+        *bp.coefficient_mut(1, 2).unwrap() = scalar(8);
+        assert!(bp.coefficient_mut(2, 0).is_none());
+        assert!(bp.coefficient_mut(0, 3).is_none());
+        assert_eq!(bp.b, vec![scalars(&[7, 2, 3]), scalars(&[4, 5, 8])]);
+
+        for ((i, j), bij) in bp.iter_mut() {
+            *bij = scalar((10 * i + j) as i64);
+        }
+        assert_eq!(bp.b, vec![scalars(&[0, 1, 2]), scalars(&[10, 11, 12])]);
+    }
+
     #[test]
     fn test_to_zero_hole() {
         let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
@@ -414,6 +949,33 @@ mod tests {
         assert!(bp == restored);
     }
 
+    #[test]
+    fn test_streaming_serialization() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(2, 3, &mut rng);
+
+        let mut bytes = Vec::new();
+        bp.write_to(&mut bytes)
+            .expect("serialization should succeed");
+        assert_eq!(bytes, bp.to_bytes());
+
+        let restored = BivariatePolynomial::read_from(&mut &bytes[..])
+            .expect("deserialization should succeed");
+        assert!(bp == restored);
+
+        // Truncated input.
+        let res = BivariatePolynomial::read_from(&mut &bytes[..bytes.len() - 1]);
+        assert_eq!(res.err().unwrap().kind(), io::ErrorKind::UnexpectedEof);
+        let res = BivariatePolynomial::read_from(&mut &bytes[..1]);
+        assert_eq!(res.err().unwrap().kind(), io::ErrorKind::UnexpectedEof);
+
+        // Invalid coefficient.
+        let mut invalid = bytes.clone();
+        invalid[2..50].fill(0xFF);
+        let res = BivariatePolynomial::read_from(&mut &invalid[..]);
+        assert_eq!(res.err().unwrap().kind(), io::ErrorKind::InvalidData);
+    }
+
     #[test]
     fn test_coefficient_byte_size() {
         let size = BivariatePolynomial::coefficient_byte_size();
@@ -462,4 +1024,189 @@ mod tests {
         let expected = Polynomial::with_coefficients(scalars(&[586, 242, 298]));
         assert!(result == expected);
     }
+
+    #[test]
+    fn test_eval_consistency() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let x = PrimeField::random(&mut rng);
+        let y = PrimeField::random(&mut rng);
+
+        for (deg_x, deg_y) in [(0, 0), (0, 3), (3, 0), (2, 4), (5, 3)] {
+            let bp = BivariatePolynomial::random(deg_x, deg_y, &mut rng);
+            let expected = bp.eval_x(&x).eval(&y);
+            assert_eq!(bp.eval(&x, &y), expected);
+            assert_eq!(bp.eval_y(&y).eval(&x), expected);
+        }
+    }
+
+    #[test]
+    fn test_eval_x_batch() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let xs: Vec<_> = (0..5).map(|_| PrimeField::random(&mut rng)).collect();
+
+        for (deg_x, deg_y) in [(0, 0), (0, 3), (3, 0), (2, 4), (5, 3)] {
+            let bp = BivariatePolynomial::random(deg_x, deg_y, &mut rng);
+            let ps = bp.eval_x_batch(&xs);
+            assert_eq!(ps.len(), xs.len());
+            for (x, p) in xs.iter().zip(ps.iter()) {
+                assert!(p == &bp.eval_x(x));
+            }
+        }
+
+        let bp = BivariatePolynomial::random(2, 4, &mut rng);
+        assert!(bp.eval_x_batch(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_eval_y_batch() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let ys: Vec<_> = (0..5).map(|_| PrimeField::random(&mut rng)).collect();
+
+        for (deg_x, deg_y) in [(0, 0), (0, 3), (3, 0), (2, 4), (5, 3)] {
+            let bp = BivariatePolynomial::random(deg_x, deg_y, &mut rng);
+            let ps = bp.eval_y_batch(&ys);
+            assert_eq!(ps.len(), ys.len());
+            for (y, p) in ys.iter().zip(ps.iter()) {
+                assert!(p == &bp.eval_y(y));
+            }
+        }
+
+        let bp = BivariatePolynomial::random(2, 4, &mut rng);
+        assert!(bp.eval_y_batch(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_derivative() {
+        let b = vec![
+            scalars(&[1, 2, 3, 4]),
+            scalars(&[2, 3, 4, 1]),
+            scalars(&[3, 4, 1, 2]),
+        ];
+        let bp = BivariatePolynomial::with_coefficients(b);
+
+        let dx = bp.derivative_x();
+        assert_eq!(dx.deg_x, 1);
+        assert_eq!(dx.deg_y, 3);
+        assert_eq!(dx.b, vec![scalars(&[2, 3, 4, 1]), scalars(&[6, 8, 2, 4])]);
+
+        let dy = bp.derivative_y();
+        assert_eq!(dy.deg_x, 2);
+        assert_eq!(dy.deg_y, 2);
+        assert_eq!(
+            dy.b,
+            vec![
+                scalars(&[2, 6, 12]),
+                scalars(&[3, 8, 3]),
+                scalars(&[4, 2, 6])
+            ]
+        );
+
+        // Partial derivatives are consistent with univariate derivatives.
+        let x = scalar(5);
+        let y = scalar(2);
+        assert!(dx.eval_y(&y) == bp.eval_y(&y).derivative());
+        assert!(dy.eval_x(&x) == bp.eval_x(&x).derivative());
+
+        // Constants.
+        let bp = BivariatePolynomial::with_coefficients(vec![scalars(&[1])]);
+        assert_eq!(bp.derivative_x().b, vec![scalars(&[0])]);
+        assert_eq!(bp.derivative_y().b, vec![scalars(&[0])]);
+
+        let bp = BivariatePolynomial::with_coefficients(vec![scalars(&[1, 2])]);
+        assert_eq!(bp.derivative_x().b, vec![scalars(&[0, 0])]);
+        assert_eq!(bp.derivative_y().b, vec![scalars(&[2])]);
+    }
+
+    #[test]
+    fn test_add() {
+        let bp1 =
+            BivariatePolynomial::with_coefficients(vec![scalars(&[1, 2, 3]), scalars(&[4, 5, 6])]);
+        let bp2 = BivariatePolynomial::with_coefficients(vec![
+            scalars(&[1, 1]),
+            scalars(&[2, 2]),
+            scalars(&[3, 3]),
+        ]);
+        let expected = vec![
+            scalars(&[2, 3, 3]),
+            scalars(&[6, 7, 6]),
+            scalars(&[3, 3, 0]),
+        ];
+
+        // Test add.
+        let bp = bp1.clone() + bp2.clone();
+        assert_eq!(bp.deg_x, 2);
+        assert_eq!(bp.deg_y, 2);
+        assert_eq!(bp.b, expected);
+
+        let bp = bp1.clone() + &bp2;
+        assert_eq!(bp.b, expected);
+
+        let bp = &bp1 + bp2.clone();
+        assert_eq!(bp.b, expected);
+
+        let bp = &bp2 + &bp1;
+        assert_eq!(bp.b, expected);
+
+        // Test add assign.
+        let mut bp = bp1.clone();
+        bp += bp2.clone();
+        assert_eq!(bp.b, expected);
+
+        let mut bp = bp2.clone();
+        bp += &bp1;
+        assert_eq!(bp.b, expected);
+
+        let mut bp = bp.clone();
+        bp += &bp1;
+        assert_eq!(
+            bp.b,
+            vec![
+                scalars(&[3, 5, 6]),
+                scalars(&[10, 12, 12]),
+                scalars(&[3, 3, 0])
+            ]
+        );
+
+        // Test sum.
+        let bp: BivariatePolynomial = vec![bp1.clone(), bp2.clone()].into_iter().sum();
+        assert_eq!(bp.b, expected);
+
+        let bp: BivariatePolynomial = [bp1.clone(), bp2.clone()].iter().sum();
+        assert_eq!(bp.b, expected);
+
+        // Evaluations are additive.
+        let x = scalar(5);
+        let y = scalar(2);
+        assert_eq!(bp.eval(&x, &y), bp1.eval(&x, &y) + bp2.eval(&x, &y));
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn test_scalar_mul() {
+        let bp =
+            BivariatePolynomial::with_coefficients(vec![scalars(&[1, 2, 3]), scalars(&[4, 5, 6])]);
+        let s = scalar(2);
+        let expected = vec![scalars(&[2, 4, 6]), scalars(&[8, 10, 12])];
+
+        // Test mul.
+        assert_eq!((bp.clone() * s).b, expected);
+        assert_eq!((bp.clone() * &s).b, expected);
+        assert_eq!((&bp * s).b, expected);
+        assert_eq!((&bp * &s).b, expected);
+
+        // Test mul assign.
+        let mut v = bp.clone();
+        v *= s;
+        assert_eq!(v.b, expected);
+
+        let mut v = bp.clone();
+        v *= &s;
+        assert_eq!(v.b, expected);
+
+        // Zero.
+        let v = &bp * PrimeField::ZERO;
+        assert_eq!(v.deg_x, 1);
+        assert_eq!(v.deg_y, 2);
+        assert_eq!(v.b, vec![scalars(&[0, 0, 0]), scalars(&[0, 0, 0])]);
+    }
 }
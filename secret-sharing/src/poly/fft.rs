@@ -0,0 +1,232 @@
+use group::ff::PrimeField;
+
+use super::Polynomial;
+
+/// Multiplicative subgroup of a prime field of order `n = 2^k`, generated
+/// by a primitive `n`-th root of unity `ω`.
+///
+/// Evaluating a polynomial at all elements of the domain, and interpolating
+/// it from such evaluations, can be done in `O(n log n)` field operations
+/// using the radix-2 fast Fourier transform, instead of `O(n^2)` operations
+/// required by the naive approach or Lagrange interpolation.
+///
+/// The domain can only be constructed for prime fields with a high 2-adicity,
+/// i.e. fields `F` where `2^k` divides `|F| - 1` (e.g. BLS12-381 and Pasta
+/// scalar fields), so that the required root of unity exists.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EvaluationDomain<F: PrimeField> {
+    /// The size of the domain.
+    size: usize,
+    /// The primitive root of unity generating the domain.
+    omega: F,
+    /// The inverse of the generator.
+    omega_inv: F,
+    /// The inverse of the size of the domain.
+    size_inv: F,
+}
+
+impl<F> EvaluationDomain<F>
+where
+    F: PrimeField,
+{
+    /// Creates the smallest domain containing at least the given number
+    /// of elements.
+    ///
+    /// Returns `None` if the 2-adicity of the prime field is too small.
+    pub fn new(size: usize) -> Option<Self> {
+        let size = size.max(1).checked_next_power_of_two()?;
+        let log_size = size.trailing_zeros();
+        if log_size > F::S {
+            return None;
+        }
+
+        let mut omega = F::ROOT_OF_UNITY;
+        for _ in log_size..F::S {
+            omega = omega.square();
+        }
+        let omega_inv = omega.invert().unwrap();
+        let size_inv = F::from_u128(size as u128).invert().unwrap();
+
+        Some(Self {
+            size,
+            omega,
+            omega_inv,
+            size_inv,
+        })
+    }
+
+    /// Returns the size of the domain.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns the generator of the domain.
+    pub fn generator(&self) -> &F {
+        &self.omega
+    }
+
+    /// Returns the elements of the domain `[ω^0, ω^1, ..., ω^{n-1}]`.
+    ///
+    /// Note that the first element is one, which should not be used
+    /// as a shareholder identity if shares are also derived for `x = 0`.
+    pub fn elements(&self) -> Vec<F> {
+        let mut elements = Vec::with_capacity(self.size);
+        let mut e = F::ONE;
+        for _ in 0..self.size {
+            elements.push(e);
+            e *= self.omega;
+        }
+        elements
+    }
+
+    /// Evaluates the given polynomial at all elements of the domain,
+    /// i.e. returns `[A(ω^0), A(ω^1), ..., A(ω^{n-1})]`.
+    ///
+    /// Returns `None` if the polynomial has more coefficients than
+    /// the domain has elements.
+    pub fn evaluate(&self, p: &Polynomial<F>) -> Option<Vec<F>> {
+        if p.a.len() > self.size {
+            return None;
+        }
+
+        let mut a = p.a.clone();
+        a.resize(self.size, F::ZERO);
+        fft(&mut a, &self.omega);
+
+        Some(a)
+    }
+
+    /// Interpolates the polynomial of degree less than the size of the domain
+    /// from its evaluations at all elements of the domain.
+    ///
+    /// The returned polynomial always has as many coefficients as the domain
+    /// has elements, regardless of its degree.
+    ///
+    /// Returns `None` if the number of evaluations doesn't match the size
+    /// of the domain.
+    pub fn interpolate(&self, evaluations: &[F]) -> Option<Polynomial<F>> {
+        if evaluations.len() != self.size {
+            return None;
+        }
+
+        let mut a = evaluations.to_vec();
+        fft(&mut a, &self.omega_inv);
+        for ai in a.iter_mut() {
+            *ai *= self.size_inv;
+        }
+
+        Some(Polynomial::with_coefficients(a))
+    }
+}
+
+/// Computes the discrete Fourier transform of the given coefficients
+/// in place, using the iterative radix-2 Cooley-Tukey algorithm.
+///
+/// The number of coefficients must be a power of two, and the given
+/// root of unity must have the same order.
+fn fft<F: PrimeField>(a: &mut [F], omega: &F) {
+    let n = a.len();
+    debug_assert!(n.is_power_of_two());
+    if n <= 1 {
+        return;
+    }
+    let log_n = n.trailing_zeros();
+
+    // Permute the coefficients into bit-reversed order.
+    for k in 0..n {
+        let rk = k.reverse_bits() >> (usize::BITS - log_n);
+        if k < rk {
+            a.swap(k, rk);
+        }
+    }
+
+    // Combine the transforms of halves using butterflies.
+    let mut m = 1;
+    while m < n {
+        let mut w_m = *omega;
+        for _ in 0..(log_n - m.trailing_zeros() - 1) {
+            w_m = w_m.square();
+        }
+
+        for k in (0..n).step_by(2 * m) {
+            let mut w = F::ONE;
+            for j in 0..m {
+                let t = a[k + j + m] * w;
+                a[k + j + m] = a[k + j] - t;
+                a[k + j] += t;
+                w *= w_m;
+            }
+        }
+
+        m *= 2;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use group::ff::PrimeField;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::poly::Polynomial;
+
+    use super::EvaluationDomain;
+
+    fn test_evaluation_domain<F: PrimeField>() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+
+        for (size, expected) in [(0, 1), (1, 1), (2, 2), (3, 4), (7, 8), (33, 64)] {
+            let domain = EvaluationDomain::<F>::new(size).unwrap();
+            assert_eq!(domain.size(), expected);
+
+            let elements = domain.elements();
+            assert_eq!(elements.len(), expected);
+            assert_eq!(domain.generator().pow_vartime([expected as u64]), F::ONE);
+            for (i, ei) in elements.iter().enumerate() {
+                for ej in elements[..i].iter() {
+                    assert_ne!(ei, ej);
+                }
+            }
+
+            for deg in 0..domain.size() {
+                let p = Polynomial::<F>::random(deg as u8, &mut rng);
+                let evaluations = domain.evaluate(&p).unwrap();
+                let expected: Vec<_> = elements.iter().map(|x| p.eval(x)).collect();
+                assert_eq!(evaluations, expected);
+
+                let q = domain.interpolate(&evaluations).unwrap();
+                assert_eq!(q.size(), domain.size());
+                for i in 0..domain.size() {
+                    let expected = p.coefficient(i).copied().unwrap_or(F::ZERO);
+                    assert_eq!(q.coefficient(i), Some(&expected));
+                }
+            }
+
+            // Too many coefficients.
+            let p = Polynomial::<F>::random(domain.size() as u8, &mut rng);
+            assert!(domain.evaluate(&p).is_none());
+
+            // Invalid number of evaluations.
+            let evaluations = vec![F::ONE; domain.size() + 1];
+            assert!(domain.interpolate(&evaluations).is_none());
+        }
+    }
+
+    #[cfg(feature = "bls12_381_g1")]
+    #[test]
+    fn test_evaluation_domain_bls12_381() {
+        test_evaluation_domain::<bls12_381_plus::Scalar>();
+    }
+
+    #[cfg(feature = "pasta")]
+    #[test]
+    fn test_evaluation_domain_pallas() {
+        test_evaluation_domain::<pasta_curves::Fq>();
+    }
+
+    #[test]
+    fn test_low_two_adicity() {
+        // The order of the P-384 scalar field minus one is divisible only by 2.
+        assert!(EvaluationDomain::<p384::Scalar>::new(2).is_some());
+        assert!(EvaluationDomain::<p384::Scalar>::new(3).is_none());
+    }
+}
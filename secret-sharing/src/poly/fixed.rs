@@ -0,0 +1,318 @@
+use std::{
+    convert::TryInto,
+    ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign},
+};
+
+use group::ff::PrimeField;
+use rand_core::RngCore;
+use zeroize::Zeroize;
+
+use super::Polynomial;
+
+/// Univariate polynomial over a non-binary prime field with a fixed number
+/// of coefficients.
+///
+/// ```text
+/// A(x) = \sum_{i=0}^{N-1} a_i x^i
+/// ```
+///
+/// The coefficients are stored inline, so the polynomial can be used without
+/// any heap allocation, and its degree bound `N - 1` is known at compile time.
+/// This makes it suitable for small thresholds in constrained environments,
+/// e.g. in enclaves.
+///
+/// The number of coefficients must be at least one, which is checked
+/// at compile time.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct FixedPolynomial<F: PrimeField, const N: usize> {
+    pub(crate) a: [F; N],
+}
+
+impl<F, const N: usize> FixedPolynomial<F, N>
+where
+    F: PrimeField,
+{
+    /// The number of coefficients in the polynomial.
+    pub const SIZE: usize = {
+        assert!(N > 0, "polynomial should have at least one coefficient");
+        N
+    };
+
+    /// The maximum degree of the polynomial.
+    pub const DEGREE: usize = Self::SIZE - 1;
+
+    /// Creates a polynomial initialized to zero.
+    pub fn zero() -> Self {
+        Self::with_coefficients([F::ZERO; N])
+    }
+
+    /// Creates a polynomial with random coefficients.
+    ///
+    /// This method is not constant time as some prime field implementations
+    /// may generate uniformly random elements using rejection sampling.
+    pub fn random(rng: &mut impl RngCore) -> Self {
+        Self::with_coefficients(std::array::from_fn(|_| F::random(&mut *rng)))
+    }
+
+    /// Creates a polynomial with the given coefficients.
+    pub fn with_coefficients(a: [F; N]) -> Self {
+        let _ = Self::SIZE;
+        Self { a }
+    }
+
+    /// Attempts to create a polynomial from the given dynamically sized
+    /// polynomial.
+    ///
+    /// Returns `None` if the number of coefficients differs.
+    pub fn from_polynomial(p: &Polynomial<F>) -> Option<Self> {
+        let a = p.a.as_slice().try_into().ok()?;
+        Some(Self::with_coefficients(a))
+    }
+
+    /// Converts the polynomial into a dynamically sized polynomial.
+    pub fn to_polynomial(&self) -> Polynomial<F> {
+        Polynomial::with_coefficients(self.a.to_vec())
+    }
+
+    /// Sets the coefficient `a_i` that belongs to the term `x^i`.
+    ///
+    /// If the coefficient does not exist, this is a no-op.
+    pub fn set_coefficient(&mut self, i: usize, ai: F) -> bool {
+        if let Some(old_ai) = self.a.get_mut(i) {
+            *old_ai = ai;
+            return true;
+        }
+        false
+    }
+
+    /// Sets the coefficient `a_0` of the constant term to zero,
+    /// effectively creating a zero-hole univariate polynomial.
+    pub fn to_zero_hole(&mut self) {
+        self.a[0] = F::ZERO;
+    }
+
+    /// Returns true iff the coefficient `a_0` of the constant term is zero.
+    pub fn is_zero_hole(&self) -> bool {
+        self.a[0].is_zero().into()
+    }
+
+    /// Returns the number of coefficients in the polynomial.
+    pub fn size(&self) -> usize {
+        N
+    }
+
+    /// Returns the i-th coefficient of the polynomial.
+    pub fn coefficient(&self, i: usize) -> Option<&F> {
+        self.a.get(i)
+    }
+
+    /// Returns the coefficients of the polynomial, where the i-th element
+    /// is the coefficient of the term `x^i`.
+    pub fn coefficients(&self) -> &[F; N] {
+        &self.a
+    }
+
+    /// Evaluates the polynomial using Horner's method.
+    pub fn eval(&self, x: &F) -> F {
+        self.a.iter().rev().fold(F::ZERO, |r, ai| r * x + ai)
+    }
+}
+
+impl<F, const N: usize> Default for FixedPolynomial<F, N>
+where
+    F: PrimeField,
+{
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<F, const N: usize> From<FixedPolynomial<F, N>> for Polynomial<F>
+where
+    F: PrimeField,
+{
+    fn from(p: FixedPolynomial<F, N>) -> Self {
+        p.to_polynomial()
+    }
+}
+
+impl<F, const N: usize> Add for FixedPolynomial<F, N>
+where
+    F: PrimeField,
+{
+    type Output = FixedPolynomial<F, N>;
+
+    #[inline]
+    fn add(mut self, rhs: Self) -> FixedPolynomial<F, N> {
+        self += rhs;
+        self
+    }
+}
+
+impl<F, const N: usize> AddAssign for FixedPolynomial<F, N>
+where
+    F: PrimeField,
+{
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        for (ai, bi) in self.a.iter_mut().zip(rhs.a.iter()) {
+            *ai += bi;
+        }
+    }
+}
+
+impl<F, const N: usize> Sub for FixedPolynomial<F, N>
+where
+    F: PrimeField,
+{
+    type Output = FixedPolynomial<F, N>;
+
+    #[inline]
+    fn sub(mut self, rhs: Self) -> FixedPolynomial<F, N> {
+        self -= rhs;
+        self
+    }
+}
+
+impl<F, const N: usize> SubAssign for FixedPolynomial<F, N>
+where
+    F: PrimeField,
+{
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        for (ai, bi) in self.a.iter_mut().zip(rhs.a.iter()) {
+            *ai -= bi;
+        }
+    }
+}
+
+impl<F, const N: usize> Mul<F> for FixedPolynomial<F, N>
+where
+    F: PrimeField,
+{
+    type Output = FixedPolynomial<F, N>;
+
+    #[inline]
+    fn mul(mut self, rhs: F) -> FixedPolynomial<F, N> {
+        self *= rhs;
+        self
+    }
+}
+
+impl<F, const N: usize> MulAssign<F> for FixedPolynomial<F, N>
+where
+    F: PrimeField,
+{
+    #[inline]
+    fn mul_assign(&mut self, rhs: F) {
+        for ai in self.a.iter_mut() {
+            *ai *= rhs;
+        }
+    }
+}
+
+impl<F, const N: usize> Zeroize for FixedPolynomial<F, N>
+where
+    F: PrimeField + Zeroize,
+{
+    fn zeroize(&mut self) {
+        for ai in self.a.iter_mut() {
+            ai.zeroize();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use group::ff::Field;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    type PrimeField = p384::Scalar;
+    type Polynomial = super::Polynomial<PrimeField>;
+    type FixedPolynomial<const N: usize> = super::FixedPolynomial<PrimeField, N>;
+
+    fn scalars<const N: usize>(values: [u64; N]) -> [PrimeField; N] {
+        values.map(PrimeField::from_u64)
+    }
+
+    #[test]
+    fn test_zero() {
+        let p = FixedPolynomial::<3>::zero();
+        assert_eq!(p.size(), 3);
+        assert_eq!(FixedPolynomial::<3>::SIZE, 3);
+        assert_eq!(FixedPolynomial::<3>::DEGREE, 2);
+        assert!(p.is_zero_hole());
+        assert!(p.coefficients().iter().all(|ai| ai.is_zero().into()));
+        assert!(p == FixedPolynomial::<3>::default());
+    }
+
+    #[test]
+    fn test_random() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let p = FixedPolynomial::<4>::random(&mut rng);
+        assert!(p.coefficients().iter().all(|ai| !bool::from(ai.is_zero())));
+        assert!(p != FixedPolynomial::<4>::random(&mut rng));
+    }
+
+    #[test]
+    fn test_coefficients() {
+        let mut p = FixedPolynomial::with_coefficients(scalars([1, 2, 3]));
+        assert_eq!(p.coefficient(1), Some(&PrimeField::from_u64(2)));
+        assert_eq!(p.coefficient(3), None);
+
+        assert!(p.set_coefficient(2, PrimeField::from_u64(5)));
+        assert!(!p.set_coefficient(3, PrimeField::from_u64(5)));
+        assert_eq!(p.coefficients(), &scalars([1, 2, 5]));
+
+        assert!(!p.is_zero_hole());
+        p.to_zero_hole();
+        assert!(p.is_zero_hole());
+    }
+
+    #[test]
+    fn test_eval() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let x = PrimeField::random(&mut rng);
+
+        let p = FixedPolynomial::<1>::random(&mut rng);
+        assert_eq!(p.eval(&x), p.a[0]);
+
+        let p = FixedPolynomial::with_coefficients(scalars([1, 2, 3]));
+        assert_eq!(p.eval(&PrimeField::from_u64(2)), PrimeField::from_u64(17));
+
+        let p = FixedPolynomial::<5>::random(&mut rng);
+        assert_eq!(p.eval(&x), p.to_polynomial().eval(&x));
+    }
+
+    #[test]
+    fn test_conversion() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let p = FixedPolynomial::<4>::random(&mut rng);
+
+        let q: Polynomial = p.into();
+        assert_eq!(q.coefficients(), p.coefficients());
+        assert!(FixedPolynomial::<4>::from_polynomial(&q) == Some(p));
+        assert!(FixedPolynomial::<3>::from_polynomial(&q).is_none());
+        assert!(FixedPolynomial::<5>::from_polynomial(&q).is_none());
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let p = FixedPolynomial::with_coefficients(scalars([1, 2, 3]));
+        let q = FixedPolynomial::with_coefficients(scalars([4, 5, 6]));
+
+        assert_eq!((p + q).coefficients(), &scalars([5, 7, 9]));
+        assert_eq!((q - p).coefficients(), &scalars([3, 3, 3]));
+        assert_eq!(
+            (p * PrimeField::from_u64(2)).coefficients(),
+            &scalars([2, 4, 6])
+        );
+
+        let mut r = p;
+        r += q;
+        r -= p;
+        assert!(r == q);
+        r *= PrimeField::ZERO;
+        assert!(r == FixedPolynomial::zero());
+    }
+}
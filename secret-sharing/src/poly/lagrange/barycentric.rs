@@ -0,0 +1,281 @@
+use group::{ff::PrimeField, Group};
+
+/// Barycentric Lagrange interpolator for a fixed set of x-coordinates.
+///
+/// The interpolator precomputes the barycentric weights
+/// ```text
+/// w_i = 1 / \prod_{j=0,j≠i}^n (x_i - x_j)
+/// ```
+/// which depend only on the x-coordinates, so that the value of the Lagrange
+/// interpolation polynomial at any point can be computed in linear time
+/// using the (first form of the) barycentric formula:
+/// ```text
+/// L(x) = l(x) * \sum_{i=0}^n w_i / (x - x_i) * y_i,  l(x) = \prod_{j=0}^n (x - x_j)
+/// ```
+///
+/// Additionally, the Lagrange coefficients `L_i(0)` are cached, so that
+/// repeated reconstructions of secrets from shares of the same shareholders
+/// only cost a single inner product, instead of recomputing the coefficients
+/// in quadratic time every time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BarycentricInterpolator<F: PrimeField> {
+    /// The x-coordinates.
+    xs: Vec<F>,
+    /// The barycentric weights.
+    ws: Vec<F>,
+    /// The Lagrange coefficients `L_i(0)`.
+    cs: Vec<F>,
+}
+
+impl<F> BarycentricInterpolator<F>
+where
+    F: PrimeField,
+{
+    /// Creates a new interpolator for the given set of x-coordinates.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the x-coordinates are not unique.
+    pub fn new(xs: Vec<F>) -> Self {
+        let mut ws = Vec::with_capacity(xs.len());
+        for (i, xi) in xs.iter().enumerate() {
+            let mut denom = F::ONE;
+            for (j, xj) in xs.iter().enumerate() {
+                if j == i {
+                    continue;
+                }
+                denom *= *xi - xj; // (x_i - x_j)
+            }
+            let w = denom.invert().expect("values should be unique");
+            ws.push(w);
+        }
+
+        let mut interpolator = Self {
+            xs,
+            ws,
+            cs: Vec::new(),
+        };
+        interpolator.cs = interpolator.coefficients_at(&F::ZERO);
+
+        interpolator
+    }
+
+    /// Returns the x-coordinates.
+    pub fn xs(&self) -> &[F] {
+        &self.xs
+    }
+
+    /// Returns the barycentric weights.
+    pub fn weights(&self) -> &[F] {
+        &self.ws
+    }
+
+    /// Returns the Lagrange coefficients `L_i(0)`.
+    pub fn coefficients(&self) -> &[F] {
+        &self.cs
+    }
+
+    /// Returns the Lagrange coefficients `L_i(x)` at the given point.
+    pub fn coefficients_at(&self, x: &F) -> Vec<F> {
+        // If the point is one of the x-coordinates, the basis polynomials
+        // are trivially zero, except for one.
+        if let Some(k) = self.xs.iter().position(|xi| xi == x) {
+            let mut cs = vec![F::ZERO; self.xs.len()];
+            cs[k] = F::ONE;
+            return cs;
+        }
+
+        let mut ds: Vec<_> = self.xs.iter().map(|xi| *x - xi).collect(); // (x - x_i)
+        let l = ds.iter().fold(F::ONE, |acc, di| acc * di); // l(x)
+        batch_invert(&mut ds);
+
+        ds.iter()
+            .zip(self.ws.iter())
+            .map(|(di, wi)| l * wi * di) // l(x) * w_i / (x - x_i)
+            .collect()
+    }
+
+    /// Returns the value of the Lagrange interpolation polynomial
+    /// at zero for the given y-coordinates.
+    ///
+    /// Returns `None` if the number of y-coordinates doesn't match
+    /// the number of x-coordinates.
+    pub fn interpolate(&self, ys: &[F]) -> Option<F> {
+        if ys.len() != self.xs.len() {
+            return None;
+        }
+
+        let v = self
+            .cs
+            .iter()
+            .zip(ys)
+            .fold(F::ZERO, |acc, (ci, yi)| acc + *ci * yi);
+
+        Some(v)
+    }
+
+    /// Returns the value of the Lagrange interpolation polynomial
+    /// at the given point for the given y-coordinates.
+    ///
+    /// Returns `None` if the number of y-coordinates doesn't match
+    /// the number of x-coordinates.
+    pub fn interpolate_at(&self, x: &F, ys: &[F]) -> Option<F> {
+        if ys.len() != self.xs.len() {
+            return None;
+        }
+
+        let v = self
+            .coefficients_at(x)
+            .into_iter()
+            .zip(ys)
+            .fold(F::ZERO, |acc, (ci, yi)| acc + ci * yi);
+
+        Some(v)
+    }
+
+    /// Returns the value of the Lagrange interpolation polynomial
+    /// at zero in the exponent for the given encrypted y-coordinates.
+    ///
+    /// Returns `None` if the number of encrypted y-coordinates doesn't match
+    /// the number of x-coordinates.
+    pub fn interpolate_in_exponent<G>(&self, zs: &[G]) -> Option<G>
+    where
+        G: Group<Scalar = F>,
+    {
+        if zs.len() != self.xs.len() {
+            return None;
+        }
+
+        let v = self
+            .cs
+            .iter()
+            .zip(zs)
+            .fold(G::identity(), |acc, (ci, zi)| acc + *zi * ci);
+
+        Some(v)
+    }
+}
+
+/// Inverts the given non-zero field elements in place using Montgomery's
+/// trick, which requires a single field inversion.
+fn batch_invert<F: PrimeField>(values: &mut [F]) {
+    let mut acc = F::ONE;
+    let mut prefixes = Vec::with_capacity(values.len());
+    for v in values.iter() {
+        prefixes.push(acc);
+        acc *= v;
+    }
+
+    let mut acc_inv = acc.invert().expect("values should be non-zero");
+    for (v, prefix) in values.iter_mut().zip(prefixes).rev() {
+        let v_inv = acc_inv * prefix;
+        acc_inv *= *v;
+        *v = v_inv;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate test;
+
+    use self::test::Bencher;
+
+    use group::{ff::Field, Group as _};
+    use rand::{rngs::StdRng, RngCore, SeedableRng};
+
+    use crate::poly::{lagrange::coefficients, Polynomial};
+
+    use super::{batch_invert, BarycentricInterpolator};
+
+    type PrimeField = p384::Scalar;
+    type Group = p384::ProjectivePoint;
+
+    fn random_scalars(n: usize, rng: &mut impl RngCore) -> Vec<PrimeField> {
+        (0..n).map(|_| PrimeField::random(&mut *rng)).collect()
+    }
+
+    #[test]
+    fn test_batch_invert() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        for n in 0..5 {
+            let values = random_scalars(n, &mut rng);
+            let mut inverses = values.clone();
+            batch_invert(&mut inverses);
+            for (v, v_inv) in values.iter().zip(inverses) {
+                assert_eq!(*v * v_inv, PrimeField::ONE);
+            }
+        }
+    }
+
+    #[test]
+    fn test_barycentric_interpolator() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+
+        for n in 1..=10 {
+            let xs = random_scalars(n, &mut rng);
+            let interpolator = BarycentricInterpolator::new(xs.clone());
+            assert_eq!(interpolator.xs(), &xs[..]);
+            assert_eq!(interpolator.weights().len(), n);
+            assert_eq!(interpolator.coefficients(), &coefficients(&xs)[..]);
+
+            let p = Polynomial::<PrimeField>::random(n as u8 - 1, &mut rng);
+            let ys: Vec<_> = xs.iter().map(|x| p.eval(x)).collect();
+
+            // Zero.
+            let v = interpolator.interpolate(&ys).unwrap();
+            assert_eq!(v, p.eval(&PrimeField::ZERO));
+
+            // Random points.
+            for x in random_scalars(3, &mut rng) {
+                let v = interpolator.interpolate_at(&x, &ys).unwrap();
+                assert_eq!(v, p.eval(&x));
+            }
+
+            // Interpolation points.
+            for (x, y) in xs.iter().zip(ys.iter()) {
+                let v = interpolator.interpolate_at(x, &ys).unwrap();
+                assert_eq!(&v, y);
+            }
+
+            // In the exponent.
+            let zs: Vec<_> = ys.iter().map(|y| Group::generator() * y).collect();
+            let v = interpolator.interpolate_in_exponent(&zs).unwrap();
+            assert_eq!(v, Group::generator() * p.eval(&PrimeField::ZERO));
+
+            // Invalid number of values.
+            assert!(interpolator.interpolate(&ys[1..]).is_none());
+            assert!(interpolator.interpolate_at(&xs[0], &ys[1..]).is_none());
+            assert!(interpolator.interpolate_in_exponent(&zs[1..]).is_none());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "values should be unique")]
+    fn test_barycentric_interpolator_duplicates() {
+        BarycentricInterpolator::new(vec![PrimeField::ONE, PrimeField::ONE]);
+    }
+
+    #[bench]
+    fn bench_interpolate_coefficients_20(b: &mut Bencher) {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let xs = random_scalars(20, &mut rng);
+        let ys = random_scalars(20, &mut rng);
+
+        b.iter(|| {
+            let cs = coefficients(&xs);
+            let _v: PrimeField = cs.iter().zip(ys.iter()).map(|(c, y)| *c * y).sum();
+        });
+    }
+
+    #[bench]
+    fn bench_interpolate_barycentric_20(b: &mut Bencher) {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let xs = random_scalars(20, &mut rng);
+        let ys = random_scalars(20, &mut rng);
+        let interpolator = BarycentricInterpolator::new(xs);
+
+        b.iter(|| {
+            let _v = interpolator.interpolate(&ys);
+        });
+    }
+}
@@ -0,0 +1,216 @@
+use group::ff::PrimeField;
+use zeroize::Zeroize;
+
+use crate::poly::{BivariatePolynomial, Polynomial};
+
+use super::optimized::basis_polynomials;
+
+/// Reconstructs the bivariate polynomial `B(x,y)` of the given degree
+/// in x from full shares `B(x_k,y)` of shareholders with the given
+/// x-coordinates.
+///
+/// Each coefficient `b_{i,j}` is the coefficient of the term `x^i`
+/// of the Lagrange interpolation polynomial of the points
+/// `(x_k, a_{k,j})`, where `a_{k,j}` is the j-th coefficient of the k-th
+/// share. Since the interpolation polynomial of more than `deg_x + 1` points
+/// has higher degree unless all points lie on a polynomial of degree
+/// `deg_x`, the reconstruction also verifies that the shares are consistent.
+///
+/// Returns `None` if there are fewer than `deg_x + 1` shares, if the shares
+/// have different sizes, if the x-coordinates are not unique, or if
+/// the shares are inconsistent.
+pub fn lagrange_bivariate_x<F>(
+    xs: &[F],
+    shares: &[Polynomial<F>],
+    deg_x: usize,
+) -> Option<BivariatePolynomial<F>>
+where
+    F: PrimeField + Zeroize,
+{
+    let size = shares.first()?.size();
+    if shares.iter().any(|p| p.size() != size) {
+        return None;
+    }
+
+    let b = interpolate(xs, shares, deg_x)?;
+
+    // Rows of the interpolated matrix are indexed by the powers of x,
+    // columns by the coefficients of the shares.
+    Some(BivariatePolynomial::with_coefficients(b))
+}
+
+/// Reconstructs the bivariate polynomial `B(x,y)` of the given degree
+/// in y from reduced shares `B(x,x_k)` of shareholders with the given
+/// x-coordinates.
+///
+/// Each coefficient `b_{i,j}` is the coefficient of the term `y^j`
+/// of the Lagrange interpolation polynomial of the points
+/// `(x_k, a_{k,i})`, where `a_{k,i}` is the i-th coefficient of the k-th
+/// share. As with full shares, the reconstruction also verifies that
+/// the shares are consistent.
+///
+/// Returns `None` if there are fewer than `deg_y + 1` shares, if the shares
+/// have different sizes, if the x-coordinates are not unique, or if
+/// the shares are inconsistent.
+pub fn lagrange_bivariate_y<F>(
+    xs: &[F],
+    shares: &[Polynomial<F>],
+    deg_y: usize,
+) -> Option<BivariatePolynomial<F>>
+where
+    F: PrimeField + Zeroize,
+{
+    let size = shares.first()?.size();
+    if shares.iter().any(|p| p.size() != size) {
+        return None;
+    }
+
+    let mut c = interpolate(xs, shares, deg_y)?;
+
+    // Rows of the interpolated matrix are indexed by the powers of y,
+    // so it needs to be transposed.
+    let b = (0..size)
+        .map(|i| c.iter().map(|cj| cj[i]).collect())
+        .collect();
+    c.zeroize();
+
+    Some(BivariatePolynomial::with_coefficients(b))
+}
+
+/// Interpolates the coefficients of the given shares, returning a matrix
+/// whose i-th row contains the coefficients of the term `x^i` of
+/// the interpolation polynomials.
+fn interpolate<F>(xs: &[F], shares: &[Polynomial<F>], deg: usize) -> Option<Vec<Vec<F>>>
+where
+    F: PrimeField + Zeroize,
+{
+    if xs.len() != shares.len() || xs.len() <= deg {
+        return None;
+    }
+    for (k, xk) in xs.iter().enumerate() {
+        if xs[..k].contains(xk) {
+            return None;
+        }
+    }
+
+    // c_{i,j} = \sum_k l_{k,i} * a_{k,j}, where l_{k,i} is the coefficient
+    // of the term x^i of the k-th Lagrange basis polynomial.
+    let size = shares[0].size();
+    let ls = basis_polynomials(xs);
+    let mut c = vec![vec![F::ZERO; size]; xs.len()];
+    for (lk, pk) in ls.iter().zip(shares) {
+        for (ci, lki) in c.iter_mut().zip(lk.iter()) {
+            for (cij, akj) in ci.iter_mut().zip(pk.iter()) {
+                *cij += *lki * akj;
+            }
+        }
+    }
+
+    // Don't short-circuit this loop to avoid revealing which coefficient
+    // is non-zero.
+    let mut consistent = true;
+    for ci in c.iter().skip(deg + 1) {
+        for cij in ci.iter() {
+            consistent &= bool::from(cij.is_zero());
+        }
+    }
+    c.truncate(deg + 1);
+
+    if !consistent {
+        c.zeroize();
+        return None;
+    }
+
+    Some(c)
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::poly::BivariatePolynomial;
+
+    use super::{lagrange_bivariate_x, lagrange_bivariate_y};
+
+    type PrimeField = p384::Scalar;
+
+    fn xs(n: u64) -> Vec<PrimeField> {
+        (1..=n).map(PrimeField::from_u64).collect()
+    }
+
+    #[test]
+    fn test_lagrange_bivariate_x() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+
+        for (deg_x, deg_y) in [(0, 0), (0, 2), (2, 0), (2, 4), (3, 1)] {
+            let bp = BivariatePolynomial::<PrimeField>::random(deg_x, deg_y, &mut rng);
+
+            for n in deg_x as u64 + 1..deg_x as u64 + 4 {
+                let xs = xs(n);
+                let shares: Vec<_> = xs.iter().map(|x| bp.eval_x(x)).collect();
+                let restored = lagrange_bivariate_x(&xs, &shares, deg_x as usize).unwrap();
+                assert!(restored == bp);
+            }
+
+            // Not enough shares.
+            let xs = xs(deg_x as u64);
+            let shares: Vec<_> = xs.iter().map(|x| bp.eval_x(x)).collect();
+            assert!(lagrange_bivariate_x(&xs, &shares, deg_x as usize).is_none());
+        }
+    }
+
+    #[test]
+    fn test_lagrange_bivariate_y() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+
+        for (deg_x, deg_y) in [(0, 0), (0, 2), (2, 0), (2, 4), (3, 1)] {
+            let bp = BivariatePolynomial::<PrimeField>::random(deg_x, deg_y, &mut rng);
+
+            for n in deg_y as u64 + 1..deg_y as u64 + 4 {
+                let xs = xs(n);
+                let shares: Vec<_> = xs.iter().map(|x| bp.eval_y(x)).collect();
+                let restored = lagrange_bivariate_y(&xs, &shares, deg_y as usize).unwrap();
+                assert!(restored == bp);
+            }
+
+            // Not enough shares.
+            let xs = xs(deg_y as u64);
+            let shares: Vec<_> = xs.iter().map(|x| bp.eval_y(x)).collect();
+            assert!(lagrange_bivariate_y(&xs, &shares, deg_y as usize).is_none());
+        }
+    }
+
+    #[test]
+    fn test_invalid_shares() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::<PrimeField>::random(2, 4, &mut rng);
+        let xs = xs(5);
+        let full: Vec<_> = xs.iter().map(|x| bp.eval_x(x)).collect();
+        let reduced: Vec<_> = xs.iter().map(|x| bp.eval_y(x)).collect();
+
+        // Inconsistent shares.
+        let mut invalid = full.clone();
+        *invalid[4].constant_mut() += PrimeField::ONE;
+        assert!(lagrange_bivariate_x(&xs, &invalid, 2).is_none());
+        assert!(lagrange_bivariate_x(&xs[..3], &invalid[..3], 2).is_some());
+
+        let mut invalid = reduced.clone();
+        *invalid[0].coefficient_mut(2).unwrap() += PrimeField::ONE;
+        assert!(lagrange_bivariate_y(&xs, &invalid, 3).is_none());
+
+        // Shares of different sizes.
+        let mut invalid = full.clone();
+        invalid[1] = reduced[1].clone();
+        assert!(lagrange_bivariate_x(&xs, &invalid, 2).is_none());
+
+        // Duplicate x-coordinates.
+        let mut duplicates = xs.clone();
+        duplicates[3] = duplicates[0];
+        assert!(lagrange_bivariate_x(&duplicates, &full, 2).is_none());
+
+        // Length mismatch and no shares.
+        assert!(lagrange_bivariate_x(&xs[..4], &full, 2).is_none());
+        assert!(lagrange_bivariate_x::<PrimeField>(&[], &[], 0).is_none());
+        assert!(lagrange_bivariate_y::<PrimeField>(&[], &[], 0).is_none());
+    }
+}
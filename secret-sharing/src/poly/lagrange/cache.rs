@@ -0,0 +1,200 @@
+use std::collections::HashMap;
+
+use group::ff::PrimeField;
+
+use super::coefficients;
+
+/// Cache of Lagrange coefficients keyed by the set of x-coordinates.
+///
+/// Computing the Lagrange coefficients `L_i(0)` takes quadratic time
+/// in the number of shares, which dominates the cost of combining shares
+/// when the same quorum of shareholders responds over and over again.
+/// The cache memoizes the coefficients for the most recently used sets
+/// of x-coordinates, regardless of the order in which they are given,
+/// and evicts the least recently used set when full.
+#[derive(Debug, Clone)]
+pub struct LagrangeCache<F: PrimeField> {
+    /// The maximum number of cached sets.
+    capacity: usize,
+    /// The logical time of the last access.
+    clock: u64,
+    /// The cached coefficients, in the canonical order of the x-coordinates,
+    /// together with the time of their last access.
+    entries: HashMap<Vec<u8>, (Vec<F>, u64)>,
+}
+
+impl<F> LagrangeCache<F>
+where
+    F: PrimeField,
+{
+    /// Creates a new cache which holds coefficients for at most the given
+    /// number of sets of x-coordinates.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            clock: 0,
+            entries: HashMap::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the maximum number of cached sets.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of cached sets.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true iff the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Removes all cached coefficients.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Returns Lagrange coefficients for the given set of x-coordinates,
+    /// computing and caching them if needed.
+    ///
+    /// The i-th returned coefficient belongs to the i-th x-coordinate.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the x-coordinates are not unique.
+    pub fn coefficients(&mut self, xs: &[F]) -> Vec<F> {
+        if self.capacity == 0 {
+            return coefficients(xs);
+        }
+
+        // Sort x-coordinates by their canonical representation, so that
+        // permutations of the same set share the same entry.
+        let reprs: Vec<_> = xs.iter().map(|x| x.to_repr()).collect();
+        let mut order: Vec<_> = (0..xs.len()).collect();
+        order.sort_by(|&i, &j| reprs[i].as_ref().cmp(reprs[j].as_ref()));
+        let key: Vec<u8> = order
+            .iter()
+            .flat_map(|&i| reprs[i].as_ref().iter().copied())
+            .collect();
+
+        self.clock += 1;
+        let clock = self.clock;
+
+        let sorted = match self.entries.get_mut(&key) {
+            Some((cs, last_used)) => {
+                *last_used = clock;
+                cs.clone()
+            }
+            None => {
+                let sorted_xs: Vec<_> = order.iter().map(|&i| xs[i]).collect();
+                let cs = coefficients(&sorted_xs);
+                self.insert(key, cs.clone(), clock);
+                cs
+            }
+        };
+
+        // Restore the order of the given x-coordinates.
+        let mut cs = vec![F::ZERO; xs.len()];
+        for (&i, c) in order.iter().zip(sorted) {
+            cs[i] = c;
+        }
+
+        cs
+    }
+
+    /// Inserts the given coefficients, evicting the least recently used
+    /// entry if the cache is full.
+    fn insert(&mut self, key: Vec<u8>, cs: Vec<F>, clock: u64) {
+        if self.entries.len() >= self.capacity {
+            let lru = self
+                .entries
+                .iter()
+                .min_by_key(|(_, (_, last_used))| *last_used)
+                .map(|(key, _)| key.clone());
+            if let Some(lru) = lru {
+                self.entries.remove(&lru);
+            }
+        }
+
+        self.entries.insert(key, (cs, clock));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use group::ff::Field;
+    use rand::{rngs::StdRng, RngCore, SeedableRng};
+
+    use crate::poly::lagrange::coefficients;
+
+    use super::LagrangeCache;
+
+    type PrimeField = p384::Scalar;
+
+    fn random_scalars(n: usize, rng: &mut impl RngCore) -> Vec<PrimeField> {
+        (0..n).map(|_| PrimeField::random(&mut *rng)).collect()
+    }
+
+    #[test]
+    fn test_lagrange_cache() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let mut cache = LagrangeCache::new(2);
+        assert_eq!(cache.capacity(), 2);
+        assert!(cache.is_empty());
+
+        let xs = random_scalars(5, &mut rng);
+        let ys = random_scalars(5, &mut rng);
+        let zs = random_scalars(5, &mut rng);
+
+        // Cache misses.
+        assert_eq!(cache.coefficients(&xs), coefficients(&xs));
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache.coefficients(&ys), coefficients(&ys));
+        assert_eq!(cache.len(), 2);
+
+        // Cache hits, including permutations.
+        assert_eq!(cache.coefficients(&xs), coefficients(&xs));
+        let mut rev = xs.clone();
+        rev.reverse();
+        assert_eq!(cache.coefficients(&rev), coefficients(&rev));
+        assert_eq!(cache.len(), 2);
+
+        // Eviction of the least recently used set.
+        assert_eq!(cache.coefficients(&zs), coefficients(&zs));
+        assert_eq!(cache.len(), 2);
+        let key = |xs: &[PrimeField]| -> Vec<u8> {
+            let mut reprs: Vec<_> = xs.iter().map(|x| x.to_bytes().to_vec()).collect();
+            reprs.sort();
+            reprs.concat()
+        };
+        assert!(cache.entries.contains_key(&key(&xs)));
+        assert!(!cache.entries.contains_key(&key(&ys)));
+        assert!(cache.entries.contains_key(&key(&zs)));
+
+        // Subsets are different sets.
+        assert_eq!(cache.coefficients(&xs[1..]), coefficients(&xs[1..]));
+
+        cache.clear();
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_lagrange_cache_zero_capacity() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let mut cache = LagrangeCache::new(0);
+
+        let xs = random_scalars(5, &mut rng);
+        assert_eq!(cache.coefficients(&xs), coefficients(&xs));
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "values should be unique")]
+    fn test_lagrange_cache_duplicates() {
+        let mut cache = LagrangeCache::new(1);
+        cache.coefficients(&[PrimeField::ONE, PrimeField::ONE]);
+    }
+}
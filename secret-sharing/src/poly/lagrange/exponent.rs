@@ -0,0 +1,80 @@
+use group::Group;
+
+use crate::{poly::EncryptedPoint, vss::multiscalar_mul};
+
+use super::coefficients;
+
+/// Returns the value of the Lagrange interpolation polynomial at zero
+/// in the exponent, for the given set of encrypted points.
+///
+/// Given points `(x_i, y_i * P)` on a polynomial `L(x)`, the value `L(0) * P`
+/// is computed as:
+/// ```text
+/// L(0) * P = \sum_{i=0}^n L_i(0) * (y_i * P)
+/// ```
+/// where `L_i(0)` represents the i-th Lagrange coefficient. This allows
+/// anyone to learn the public key `s * G` of a shared secret `s` from public
+/// shares `s_i * G`, without ever reconstructing the secret.
+///
+/// This function is not constant time and must not be used when the encrypted
+/// y-coordinates should remain secret.
+///
+/// # Panics
+///
+/// Panics if the x-coordinates are not unique.
+pub fn lagrange_in_exponent<G: Group>(points: &[EncryptedPoint<G>]) -> G {
+    let (xs, zs): (Vec<_>, Vec<_>) = points.iter().map(|p| (p.x, p.z)).unzip();
+    let cs = coefficients(&xs);
+
+    multiscalar_mul(&zs, &cs)
+}
+
+#[cfg(test)]
+mod tests {
+    use group::{ff::Field, Group as _};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::poly::{EncryptedPoint, Polynomial};
+
+    use super::lagrange_in_exponent;
+
+    type PrimeField = p384::Scalar;
+    type Group = p384::ProjectivePoint;
+
+    #[test]
+    fn test_lagrange_in_exponent() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let base = Group::random(&mut rng);
+
+        for deg in 0..10 {
+            let p = Polynomial::<PrimeField>::random(deg, &mut rng);
+            let points: Vec<_> = (0..20)
+                .map(|_| PrimeField::random(&mut rng))
+                .map(|x| EncryptedPoint::new(x, base * p.eval(&x)))
+                .collect();
+            let expected = base * p.eval(&PrimeField::ZERO);
+
+            // Exact number of points.
+            let size = deg as usize + 1;
+            assert_eq!(lagrange_in_exponent(&points[..size]), expected);
+
+            // Too many points.
+            assert_eq!(lagrange_in_exponent(&points), expected);
+
+            // Not enough points.
+            if deg > 0 {
+                assert_ne!(lagrange_in_exponent(&points[..size - 1]), expected);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "values should be unique")]
+    fn test_lagrange_in_exponent_duplicates() {
+        let points = vec![
+            EncryptedPoint::new(PrimeField::ONE, Group::generator()),
+            EncryptedPoint::new(PrimeField::ONE, Group::generator()),
+        ];
+        lagrange_in_exponent(&points);
+    }
+}
@@ -1,8 +1,12 @@
 //! Lagrange interpolation.
 
+mod barycentric;
+mod bivariate;
+mod cache;
+mod exponent;
 mod multiplier;
 mod naive;
 mod optimized;
 
 // Re-exports.
-pub use self::{naive::*, optimized::*};
+pub use self::{barycentric::*, bivariate::*, cache::*, exponent::*, naive::*, optimized::*};
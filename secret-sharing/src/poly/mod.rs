@@ -2,16 +2,25 @@
 //!
 //! Features include:
 //!
-//! - Univariate and bivariate polynomials
+//! - Univariate and bivariate polynomials, in dense and sparse representations
+//! - Fixed-size, stack-allocated univariate polynomials
 //! - Evaluation of points on polynomials
 //! - Lagrange interpolation methods
+//! - FFT-based evaluation and interpolation
 
 mod arith;
 mod bivariate;
+mod fft;
+mod fixed;
 pub mod lagrange;
 mod point;
 mod scalar;
+mod sparse;
 mod univariate;
+mod xof;
 
 // Re-exports.
-pub use self::{arith::*, bivariate::*, point::*, scalar::*, univariate::*};
+pub(crate) use self::xof::XofRng;
+pub use self::{
+    arith::*, bivariate::*, fft::*, fixed::*, point::*, scalar::*, sparse::*, univariate::*,
+};
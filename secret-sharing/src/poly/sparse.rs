@@ -0,0 +1,559 @@
+use std::collections::BTreeMap;
+
+use group::ff::PrimeField;
+use zeroize::Zeroize;
+
+use super::{BivariatePolynomial, Polynomial};
+
+/// A polynomial is considered sparse if at most one in this many
+/// coefficients is non-zero.
+const SPARSITY_FACTOR: usize = 4;
+
+/// Univariate polynomial over a non-binary prime field, represented
+/// by its non-zero coefficients.
+///
+/// ```text
+/// A(x) = \sum_{i \in I} a_i x^i
+/// ```
+///
+/// Like the dense representation, the sparse polynomial keeps track
+/// of the number of its coefficients, so that conversions between
+/// the representations preserve the size of the polynomial.
+#[derive(Clone, PartialEq, Eq)]
+pub struct SparsePolynomial<F: PrimeField> {
+    /// The number of coefficients, including zero coefficients.
+    size: usize,
+    /// The non-zero coefficients, where `terms[i]` represents
+    /// the coefficient of the term `x^i`.
+    terms: BTreeMap<usize, F>,
+}
+
+impl<F> SparsePolynomial<F>
+where
+    F: PrimeField,
+{
+    /// Creates a sparse polynomial initialized to zero.
+    pub fn zero(deg: u8) -> Self {
+        Self {
+            size: deg as usize + 1,
+            terms: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the number of coefficients, including zero coefficients.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns the number of non-zero coefficients.
+    pub fn num_terms(&self) -> usize {
+        self.terms.len()
+    }
+
+    /// Returns the coefficient `a_i`, or `None` if the coefficient
+    /// doesn't exist.
+    pub fn coefficient(&self, i: usize) -> Option<F> {
+        if i >= self.size {
+            return None;
+        }
+        Some(self.terms.get(&i).copied().unwrap_or(F::ZERO))
+    }
+
+    /// Sets the coefficient `a_i` that belongs to the term `x^i`.
+    ///
+    /// If the coefficient does not exist, this is a no-op.
+    ///
+    /// This method is not constant time.
+    pub fn set_coefficient(&mut self, i: usize, ai: F) -> bool {
+        if i >= self.size {
+            return false;
+        }
+        match bool::from(ai.is_zero()) {
+            true => self.terms.remove(&i),
+            false => self.terms.insert(i, ai),
+        };
+        true
+    }
+
+    /// Returns an iterator over the non-zero coefficients and their
+    /// indices, in ascending order.
+    pub fn terms(&self) -> impl Iterator<Item = (usize, &F)> {
+        self.terms.iter().map(|(i, ai)| (*i, ai))
+    }
+
+    /// Evaluates the polynomial.
+    ///
+    /// Only the non-zero terms are evaluated, so the cost grows with
+    /// the number of non-zero coefficients rather than the degree.
+    pub fn eval(&self, x: &F) -> F {
+        let mut r = F::ZERO;
+        let mut xpow = F::ONE;
+        let mut last = 0;
+        for (i, ai) in self.terms.iter() {
+            xpow *= x.pow_vartime([(*i - last) as u64]); // x^i
+            last = *i;
+            r += *ai * xpow;
+        }
+
+        r
+    }
+
+    /// Converts the polynomial to the dense representation.
+    pub fn to_dense(&self) -> Polynomial<F> {
+        let mut a = vec![F::ZERO; self.size];
+        for (i, ai) in self.terms.iter() {
+            a[*i] = *ai;
+        }
+
+        Polynomial::with_coefficients(a)
+    }
+}
+
+impl<F> From<&Polynomial<F>> for SparsePolynomial<F>
+where
+    F: PrimeField,
+{
+    /// Converts the given polynomial to the sparse representation.
+    ///
+    /// This method is not constant time, as it reveals which coefficients
+    /// are zero.
+    fn from(p: &Polynomial<F>) -> Self {
+        let terms =
+            p.a.iter()
+                .enumerate()
+                .filter(|(_, ai)| !bool::from(ai.is_zero()))
+                .map(|(i, ai)| (i, *ai))
+                .collect();
+
+        Self {
+            size: p.a.len(),
+            terms,
+        }
+    }
+}
+
+impl<F> Zeroize for SparsePolynomial<F>
+where
+    F: PrimeField + Zeroize,
+{
+    fn zeroize(&mut self) {
+        for ai in self.terms.values_mut() {
+            ai.zeroize();
+        }
+        self.terms.clear();
+    }
+}
+
+/// Bivariate polynomial over a non-binary prime field, represented
+/// by its non-zero coefficients.
+///
+/// ```text
+/// B(x,y) = \sum_{(i,j) \in I} b_{i,j} x^i y^j
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct SparseBivariatePolynomial<F: PrimeField> {
+    /// The degree of the bivariate polynomial in the x variable.
+    deg_x: usize,
+    /// The degree of the bivariate polynomial in the y variable.
+    deg_y: usize,
+    /// The non-zero coefficients, where `terms[(i, j)]` represents
+    /// the coefficient of the term `x^i y^j`.
+    terms: BTreeMap<(usize, usize), F>,
+}
+
+impl<F> SparseBivariatePolynomial<F>
+where
+    F: PrimeField,
+{
+    /// Creates a sparse bivariate polynomial initialized to zero.
+    pub fn zero(deg_x: u8, deg_y: u8) -> Self {
+        Self {
+            deg_x: deg_x as usize,
+            deg_y: deg_y as usize,
+            terms: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the degrees of the bivariate polynomial in the x and y
+    /// variables.
+    pub fn degrees(&self) -> (usize, usize) {
+        (self.deg_x, self.deg_y)
+    }
+
+    /// Returns the number of non-zero coefficients.
+    pub fn num_terms(&self) -> usize {
+        self.terms.len()
+    }
+
+    /// Returns the coefficient `b_{i,j}`, or `None` if the coefficient
+    /// doesn't exist.
+    pub fn coefficient(&self, i: usize, j: usize) -> Option<F> {
+        if i > self.deg_x || j > self.deg_y {
+            return None;
+        }
+        Some(self.terms.get(&(i, j)).copied().unwrap_or(F::ZERO))
+    }
+
+    /// Sets the coefficient `b_{i,j}` that belongs to the term `x^i y^j`.
+    ///
+    /// If the coefficient does not exist, this is a no-op.
+    ///
+    /// This method is not constant time.
+    pub fn set_coefficient(&mut self, i: usize, j: usize, bij: F) -> bool {
+        if i > self.deg_x || j > self.deg_y {
+            return false;
+        }
+        match bool::from(bij.is_zero()) {
+            true => self.terms.remove(&(i, j)),
+            false => self.terms.insert((i, j), bij),
+        };
+        true
+    }
+
+    /// Returns an iterator over the non-zero coefficients and their
+    /// indices, in ascending order.
+    pub fn terms(&self) -> impl Iterator<Item = ((usize, usize), &F)> {
+        self.terms.iter().map(|(ij, bij)| (*ij, bij))
+    }
+
+    /// Evaluates the bivariate polynomial.
+    pub fn eval(&self, x: &F, y: &F) -> F {
+        self.terms.iter().fold(F::ZERO, |acc, ((i, j), bij)| {
+            acc + *bij * x.pow_vartime([*i as u64]) * y.pow_vartime([*j as u64])
+        })
+    }
+
+    /// Evaluates the bivariate polynomial with respect to the indeterminate x.
+    ///
+    /// The returned polynomial uses the dense representation.
+    pub fn eval_x(&self, x: &F) -> Polynomial<F> {
+        let mut a = vec![F::ZERO; self.deg_y + 1];
+        for ((i, j), bij) in self.terms.iter() {
+            a[*j] += *bij * x.pow_vartime([*i as u64]); // b_{i,j} x^i
+        }
+
+        Polynomial::with_coefficients(a)
+    }
+
+    /// Evaluates the bivariate polynomial with respect to the indeterminate y.
+    ///
+    /// The returned polynomial uses the dense representation.
+    pub fn eval_y(&self, y: &F) -> Polynomial<F> {
+        let mut a = vec![F::ZERO; self.deg_x + 1];
+        for ((i, j), bij) in self.terms.iter() {
+            a[*i] += *bij * y.pow_vartime([*j as u64]); // b_{i,j} y^j
+        }
+
+        Polynomial::with_coefficients(a)
+    }
+
+    /// Converts the bivariate polynomial to the dense representation.
+    pub fn to_dense(&self) -> BivariatePolynomial<F> {
+        let mut b = vec![vec![F::ZERO; self.deg_y + 1]; self.deg_x + 1];
+        for ((i, j), bij) in self.terms.iter() {
+            b[*i][*j] = *bij;
+        }
+
+        BivariatePolynomial::with_coefficients(b)
+    }
+}
+
+impl<F> From<&BivariatePolynomial<F>> for SparseBivariatePolynomial<F>
+where
+    F: PrimeField,
+{
+    /// Converts the given bivariate polynomial to the sparse representation.
+    ///
+    /// This method is not constant time, as it reveals which coefficients
+    /// are zero.
+    fn from(bp: &BivariatePolynomial<F>) -> Self {
+        let mut terms = BTreeMap::new();
+        for (i, bi) in bp.b.iter().enumerate() {
+            for (j, bij) in bi.iter().enumerate() {
+                if !bool::from(bij.is_zero()) {
+                    terms.insert((i, j), *bij);
+                }
+            }
+        }
+
+        Self {
+            deg_x: bp.deg_x,
+            deg_y: bp.deg_y,
+            terms,
+        }
+    }
+}
+
+impl<F> Zeroize for SparseBivariatePolynomial<F>
+where
+    F: PrimeField + Zeroize,
+{
+    fn zeroize(&mut self) {
+        for bij in self.terms.values_mut() {
+            bij.zeroize();
+        }
+        self.terms.clear();
+    }
+}
+
+/// Univariate polynomial which automatically uses the sparse representation
+/// when only a few of its coefficients are non-zero.
+#[derive(Clone, PartialEq, Eq)]
+pub enum CompactPolynomial<F: PrimeField> {
+    /// Dense representation.
+    Dense(Polynomial<F>),
+    /// Sparse representation.
+    Sparse(SparsePolynomial<F>),
+}
+
+impl<F> CompactPolynomial<F>
+where
+    F: PrimeField,
+{
+    /// Returns true iff the polynomial uses the sparse representation.
+    pub fn is_sparse(&self) -> bool {
+        matches!(self, Self::Sparse(_))
+    }
+
+    /// Evaluates the polynomial.
+    pub fn eval(&self, x: &F) -> F {
+        match self {
+            Self::Dense(p) => p.eval(x),
+            Self::Sparse(p) => p.eval(x),
+        }
+    }
+
+    /// Converts the polynomial to the dense representation.
+    pub fn to_dense(&self) -> Polynomial<F> {
+        match self {
+            Self::Dense(p) => p.clone(),
+            Self::Sparse(p) => p.to_dense(),
+        }
+    }
+}
+
+impl<F> From<Polynomial<F>> for CompactPolynomial<F>
+where
+    F: PrimeField,
+{
+    /// Selects the representation based on the density of the given
+    /// polynomial.
+    ///
+    /// This method is not constant time, as it reveals the number
+    /// of zero coefficients.
+    fn from(p: Polynomial<F>) -> Self {
+        let sparse = SparsePolynomial::from(&p);
+        match sparse.num_terms() * SPARSITY_FACTOR <= sparse.size() {
+            true => Self::Sparse(sparse),
+            false => Self::Dense(p),
+        }
+    }
+}
+
+/// Bivariate polynomial which automatically uses the sparse representation
+/// when only a few of its coefficients are non-zero.
+#[derive(Clone, PartialEq, Eq)]
+pub enum CompactBivariatePolynomial<F: PrimeField> {
+    /// Dense representation.
+    Dense(BivariatePolynomial<F>),
+    /// Sparse representation.
+    Sparse(SparseBivariatePolynomial<F>),
+}
+
+impl<F> CompactBivariatePolynomial<F>
+where
+    F: PrimeField,
+{
+    /// Returns true iff the bivariate polynomial uses the sparse
+    /// representation.
+    pub fn is_sparse(&self) -> bool {
+        matches!(self, Self::Sparse(_))
+    }
+
+    /// Evaluates the bivariate polynomial.
+    pub fn eval(&self, x: &F, y: &F) -> F {
+        match self {
+            Self::Dense(bp) => bp.eval(x, y),
+            Self::Sparse(bp) => bp.eval(x, y),
+        }
+    }
+
+    /// Evaluates the bivariate polynomial with respect to the indeterminate x.
+    pub fn eval_x(&self, x: &F) -> Polynomial<F> {
+        match self {
+            Self::Dense(bp) => bp.eval_x(x),
+            Self::Sparse(bp) => bp.eval_x(x),
+        }
+    }
+
+    /// Evaluates the bivariate polynomial with respect to the indeterminate y.
+    pub fn eval_y(&self, y: &F) -> Polynomial<F> {
+        match self {
+            Self::Dense(bp) => bp.eval_y(y),
+            Self::Sparse(bp) => bp.eval_y(y),
+        }
+    }
+
+    /// Converts the bivariate polynomial to the dense representation.
+    pub fn to_dense(&self) -> BivariatePolynomial<F> {
+        match self {
+            Self::Dense(bp) => bp.clone(),
+            Self::Sparse(bp) => bp.to_dense(),
+        }
+    }
+}
+
+impl<F> From<BivariatePolynomial<F>> for CompactBivariatePolynomial<F>
+where
+    F: PrimeField,
+{
+    /// Selects the representation based on the density of the given
+    /// bivariate polynomial.
+    ///
+    /// This method is not constant time, as it reveals the number
+    /// of zero coefficients.
+    fn from(bp: BivariatePolynomial<F>) -> Self {
+        let sparse = SparseBivariatePolynomial::from(&bp);
+        let size = (bp.deg_x + 1) * (bp.deg_y + 1);
+        match sparse.num_terms() * SPARSITY_FACTOR <= size {
+            true => Self::Sparse(sparse),
+            false => Self::Dense(bp),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use group::ff::Field;
+    use rand::{rngs::StdRng, SeedableRng};
+    use zeroize::Zeroize;
+
+    use crate::poly::{BivariatePolynomial, Polynomial};
+
+    use super::{
+        CompactBivariatePolynomial, CompactPolynomial, SparseBivariatePolynomial, SparsePolynomial,
+    };
+
+    type PrimeField = p384::Scalar;
+
+    #[test]
+    fn test_sparse_polynomial() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+
+        let mut p = SparsePolynomial::<PrimeField>::zero(100);
+        assert_eq!(p.size(), 101);
+        assert_eq!(p.num_terms(), 0);
+        assert!(p.set_coefficient(0, PrimeField::from_u64(3)));
+        assert!(p.set_coefficient(50, PrimeField::from_u64(2)));
+        assert!(p.set_coefficient(100, PrimeField::ONE));
+        assert!(!p.set_coefficient(101, PrimeField::ONE));
+        assert_eq!(p.num_terms(), 3);
+        assert_eq!(p.coefficient(50), Some(PrimeField::from_u64(2)));
+        assert_eq!(p.coefficient(51), Some(PrimeField::ZERO));
+        assert_eq!(p.coefficient(101), None);
+        let indices: Vec<_> = p.terms().map(|(i, _)| i).collect();
+        assert_eq!(indices, vec![0, 50, 100]);
+
+        // Evaluation.
+        let dense = p.to_dense();
+        assert_eq!(dense.size(), 101);
+        for _ in 0..5 {
+            let x = PrimeField::random(&mut rng);
+            assert_eq!(p.eval(&x), dense.eval(&x));
+        }
+
+        // Conversion.
+        assert!(SparsePolynomial::from(&dense) == p);
+
+        // Zero coefficients are removed.
+        assert!(p.set_coefficient(50, PrimeField::ZERO));
+        assert_eq!(p.num_terms(), 2);
+
+        p.zeroize();
+        assert_eq!(p.num_terms(), 0);
+        assert_eq!(p.eval(&PrimeField::ONE), PrimeField::ZERO);
+    }
+
+    #[test]
+    fn test_sparse_bivariate_polynomial() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+
+        let mut bp = SparseBivariatePolynomial::<PrimeField>::zero(10, 20);
+        assert_eq!(bp.degrees(), (10, 20));
+        assert!(bp.set_coefficient(0, 0, PrimeField::from_u64(7)));
+        assert!(bp.set_coefficient(3, 17, PrimeField::from_u64(5)));
+        assert!(bp.set_coefficient(10, 20, PrimeField::ONE));
+        assert!(!bp.set_coefficient(11, 0, PrimeField::ONE));
+        assert!(!bp.set_coefficient(0, 21, PrimeField::ONE));
+        assert_eq!(bp.num_terms(), 3);
+        assert_eq!(bp.coefficient(3, 17), Some(PrimeField::from_u64(5)));
+        assert_eq!(bp.coefficient(3, 16), Some(PrimeField::ZERO));
+        assert_eq!(bp.coefficient(11, 0), None);
+
+        // Evaluation.
+        let dense = bp.to_dense();
+        assert_eq!((dense.deg_x, dense.deg_y), (10, 20));
+        for _ in 0..5 {
+            let x = PrimeField::random(&mut rng);
+            let y = PrimeField::random(&mut rng);
+            assert_eq!(bp.eval(&x, &y), dense.eval(&x, &y));
+            assert!(bp.eval_x(&x) == dense.eval_x(&x));
+            assert!(bp.eval_y(&y) == dense.eval_y(&y));
+        }
+
+        // Conversion.
+        assert!(SparseBivariatePolynomial::from(&dense) == bp);
+
+        bp.zeroize();
+        assert_eq!(bp.num_terms(), 0);
+    }
+
+    #[test]
+    fn test_compact_polynomial() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let x = PrimeField::random(&mut rng);
+
+        // Dense.
+        let p = Polynomial::<PrimeField>::random(10, &mut rng);
+        let cp = CompactPolynomial::from(p.clone());
+        assert!(!cp.is_sparse());
+        assert_eq!(cp.eval(&x), p.eval(&x));
+        assert!(cp.to_dense() == p);
+
+        // Sparse.
+        let mut p = Polynomial::<PrimeField>::zero(10);
+        p.set_coefficient(3, PrimeField::ONE);
+        p.set_coefficient(7, PrimeField::ONE);
+        let cp = CompactPolynomial::from(p.clone());
+        assert!(cp.is_sparse());
+        assert_eq!(cp.eval(&x), p.eval(&x));
+        assert!(cp.to_dense() == p);
+    }
+
+    #[test]
+    fn test_compact_bivariate_polynomial() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let x = PrimeField::random(&mut rng);
+        let y = PrimeField::random(&mut rng);
+
+        // Dense.
+        let bp = BivariatePolynomial::<PrimeField>::random(2, 4, &mut rng);
+        let cbp = CompactBivariatePolynomial::from(bp.clone());
+        assert!(!cbp.is_sparse());
+        assert_eq!(cbp.eval(&x, &y), bp.eval(&x, &y));
+        assert!(cbp.eval_x(&x) == bp.eval_x(&x));
+        assert!(cbp.eval_y(&y) == bp.eval_y(&y));
+        assert!(cbp.to_dense() == bp);
+
+        // Sparse.
+        let mut bp = BivariatePolynomial::<PrimeField>::zero(2, 4);
+        bp.set_coefficient(0, 0, PrimeField::ONE);
+        bp.set_coefficient(2, 4, PrimeField::ONE);
+        let cbp = CompactBivariatePolynomial::from(bp.clone());
+        assert!(cbp.is_sparse());
+        assert_eq!(cbp.eval(&x, &y), bp.eval(&x, &y));
+        assert!(cbp.eval_x(&x) == bp.eval_x(&x));
+        assert!(cbp.eval_y(&y) == bp.eval_y(&y));
+        assert!(cbp.to_dense() == bp);
+    }
+}
@@ -56,6 +56,17 @@ where
         Self::with_coefficients(a)
     }
 
+    /// Creates a polynomial with random coefficients and the given
+    /// coefficient `a_0` of the constant term.
+    ///
+    /// This method is not constant time as some prime field implementations
+    /// may generate uniformly random elements using rejection sampling.
+    pub fn random_with_constant(deg: u8, constant: F, rng: &mut impl RngCore) -> Self {
+        let mut p = Self::random(deg, rng);
+        p.a[0] = constant;
+        p
+    }
+
     /// Creates a polynomial with the given coefficients.
     pub fn with_coefficients(a: Vec<F>) -> Self {
         if a.is_empty() {
@@ -98,6 +109,42 @@ where
         self.a.get(i)
     }
 
+    /// Returns a mutable reference to the i-th coefficient of the polynomial.
+    pub fn coefficient_mut(&mut self, i: usize) -> Option<&mut F> {
+        self.a.get_mut(i)
+    }
+
+    /// Returns the coefficient `a_0` of the constant term.
+    pub fn constant(&self) -> &F {
+        &self.a[0]
+    }
+
+    /// Returns a mutable reference to the coefficient `a_0` of the constant
+    /// term.
+    pub fn constant_mut(&mut self) -> &mut F {
+        &mut self.a[0]
+    }
+
+    /// Returns the coefficients of the polynomial, where the i-th element
+    /// is the coefficient of the term `x^i`.
+    pub fn coefficients(&self) -> &[F] {
+        &self.a
+    }
+
+    /// Returns an iterator over the coefficients of the polynomial,
+    /// starting with the constant term.
+    pub fn iter(&self) -> impl Iterator<Item = &F> {
+        self.a.iter()
+    }
+
+    /// Returns an iterator that allows modifying the coefficients
+    /// of the polynomial, starting with the constant term.
+    ///
+    /// The number of coefficients cannot be changed.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut F> {
+        self.a.iter_mut()
+    }
+
     /// Returns the byte representation of the polynomial.
     pub fn to_bytes(&self) -> Vec<u8> {
         let cap = Self::byte_size(self.a.len());
@@ -163,6 +210,116 @@ where
 
         r
     }
+
+    /// Returns true iff all coefficients of the polynomial are zero.
+    ///
+    /// This method is not constant time.
+    pub fn is_zero(&self) -> bool {
+        self.a.iter().all(|ai| ai.is_zero().into())
+    }
+
+    /// Returns the degree of the polynomial, ignoring trailing zero
+    /// coefficients.
+    ///
+    /// The degree of the zero polynomial is defined to be zero.
+    ///
+    /// This method is not constant time.
+    pub fn degree(&self) -> usize {
+        self.a
+            .iter()
+            .rposition(|ai| !bool::from(ai.is_zero()))
+            .unwrap_or(0)
+    }
+
+    /// Divides the polynomial by the given divisor using Euclidean division.
+    ///
+    /// Returns the quotient `Q(x)` and the remainder `R(x)` such that
+    /// ```text
+    /// A(x) = Q(x) * D(x) + R(x)
+    /// ```
+    /// where the degree of `R(x)` is smaller than the degree of `D(x)`.
+    /// The remainder has as many coefficients as the degree of the divisor,
+    /// or one if the divisor is a constant.
+    ///
+    /// Returns `None` if the divisor is the zero polynomial.
+    ///
+    /// This method is not constant time.
+    pub fn div_rem(&self, divisor: &Polynomial<F>) -> Option<(Polynomial<F>, Polynomial<F>)> {
+        if divisor.is_zero() {
+            return None;
+        }
+
+        let n = self.degree();
+        let m = divisor.degree();
+        let mut r = self.a[..=n].to_vec();
+
+        if n < m {
+            return Some((Self::zero(0), Self::with_coefficients(r)));
+        }
+
+        let lc_inv = divisor.a[m].invert().unwrap();
+        let mut q = vec![F::ZERO; n - m + 1];
+        for k in (0..=n - m).rev() {
+            let qk = r[m + k] * lc_inv;
+            for (j, dj) in divisor.a[..=m].iter().enumerate() {
+                r[j + k] -= qk * dj;
+            }
+            q[k] = qk;
+        }
+        r.truncate(max(m, 1));
+
+        Some((Self::with_coefficients(q), Self::with_coefficients(r)))
+    }
+
+    /// Returns the monic greatest common divisor of the two polynomials,
+    /// computed using the Euclidean algorithm.
+    ///
+    /// The returned polynomial has no trailing zero coefficients. The greatest
+    /// common divisor of two zero polynomials is the zero polynomial.
+    ///
+    /// This method is not constant time.
+    pub fn gcd(&self, other: &Polynomial<F>) -> Polynomial<F> {
+        let mut a = self.clone();
+        let mut b = other.clone();
+        while !b.is_zero() {
+            let (_, r) = a.div_rem(&b).expect("divisor should not be zero");
+            a = b;
+            b = r;
+        }
+
+        if a.is_zero() {
+            return Self::zero(0);
+        }
+
+        let deg = a.degree();
+        let lc_inv = a.a[deg].invert().unwrap();
+        a.a.truncate(deg + 1);
+        a *= lc_inv;
+
+        a
+    }
+
+    /// Returns the formal derivative of the polynomial.
+    ///
+    /// Returned polynomial:
+    /// ```text
+    /// A'(x) = \sum_{i=1}^{deg_x} i a_i x^{i-1}
+    /// ```
+    ///
+    /// The derivative has one coefficient less than the polynomial,
+    /// unless the polynomial is a constant, in which case the derivative
+    /// is the zero polynomial with one coefficient.
+    pub fn derivative(&self) -> Polynomial<F> {
+        let a = self
+            .a
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, ai)| F::from_u128(i as u128) * ai) // i a_i
+            .collect();
+
+        Self::with_coefficients(a)
+    }
 }
 
 impl<F> Default for Polynomial<F>
@@ -585,6 +742,20 @@ mod tests {
         assert_eq!(p.a, scalars(&[0, 0, 0]));
     }
 
+    #[test]
+    fn test_random_with_constant() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+
+        let p = Polynomial::random_with_constant(0, scalar(7), &mut rng);
+        assert_eq!(p.a, scalars(&[7]));
+
+        let p = Polynomial::random_with_constant(3, scalar(7), &mut rng);
+        assert_eq!(p.size(), 4);
+        assert_eq!(p.constant(), &scalar(7));
+        assert_eq!(p.eval(&scalar(0)), scalar(7));
+        assert!(p.a[1..].iter().all(|ai| ai != &scalar(0))); // Zero with negligible probability.
+    }
+
     #[test]
     fn test_with_coefficients() {
         let p = Polynomial::with_coefficients(vec![]);
@@ -605,6 +776,24 @@ mod tests {
         assert_eq!(p.a, scalars(&[1, 4, 3]));
     }
 
+    #[test]
+    fn test_coefficient_access() {
+        let mut p = Polynomial::with_coefficients(scalars(&[1, 2, 3]));
+        assert_eq!(p.constant(), &scalar(1));
+        assert_eq!(p.coefficients(), &scalars(&[1, 2, 3])[..]);
+        assert_eq!(p.iter().cloned().collect::<Vec<_>>(), scalars(&[1, 2, 3]));
+
+        *p.constant_mut() = scalar(5);
+        *p.coefficient_mut(2).unwrap() = scalar(6);
+        assert!(p.coefficient_mut(3).is_none());
+        assert_eq!(p.a, scalars(&[5, 2, 6]));
+
+        for ai in p.iter_mut() {
+            *ai += scalar(1);
+        }
+        assert_eq!(p.a, scalars(&[6, 3, 7]));
+    }
+
     #[test]
     fn test_zero_hole() {
         let mut p = Polynomial::with_coefficients(scalars(&[1, 2, 3]));
@@ -806,6 +995,97 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_degree() {
+        let test_cases = vec![
+            (vec![0], 0, true),
+            (vec![0, 0, 0], 0, true),
+            (vec![1], 0, false),
+            (vec![1, 2, 3], 2, false),
+            (vec![1, 2, 0, 0], 1, false),
+        ];
+
+        for (coefficients, degree, is_zero) in test_cases {
+            let p = Polynomial::with_coefficients(scalars(&coefficients));
+            assert_eq!(p.degree(), degree);
+            assert_eq!(p.is_zero(), is_zero);
+        }
+    }
+
+    #[test]
+    fn test_div_rem() {
+        let test_cases = vec![
+            // Exact division.
+            (vec![2, 8, 14, 12], vec![2, 4], vec![1, 2, 3], vec![0]),
+            // Non-zero remainder.
+            (vec![3, 8, 14, 12], vec![2, 4], vec![1, 2, 3], vec![1]),
+            (vec![1, 0, 0, 1], vec![1, 0, 1], vec![0, 1], vec![1, -1]),
+            // Trailing zeros.
+            (vec![2, 8, 14, 12, 0], vec![2, 4, 0], vec![1, 2, 3], vec![0]),
+            // Constant divisor.
+            (vec![2, 4, 6], vec![2], vec![1, 2, 3], vec![0]),
+            // Divisor of higher degree.
+            (vec![1, 2], vec![1, 2, 3], vec![0], vec![1, 2]),
+            // Zero dividend.
+            (vec![0], vec![1, 2], vec![0], vec![0]),
+        ];
+
+        for (coefficients_a, coefficients_d, coefficients_q, coefficients_r) in test_cases {
+            let a = Polynomial::with_coefficients(scalars(&coefficients_a));
+            let d = Polynomial::with_coefficients(scalars(&coefficients_d));
+            let (q, r) = a.div_rem(&d).unwrap();
+            assert_eq!(q.a, scalars(&coefficients_q));
+            assert_eq!(r.a, scalars(&coefficients_r));
+            assert_eq!((&q * &d + &r).eval(&scalar(5)), a.eval(&scalar(5)));
+        }
+
+        // Zero divisor.
+        let a = Polynomial::with_coefficients(scalars(&[1, 2, 3]));
+        let d = Polynomial::zero(2);
+        assert!(a.div_rem(&d).is_none());
+    }
+
+    #[test]
+    fn test_gcd() {
+        // (x + 1)(x + 2) and (x + 1)(x + 3).
+        let a = Polynomial::with_coefficients(scalars(&[2, 3, 1]));
+        let b = Polynomial::with_coefficients(scalars(&[3, 4, 1]));
+        assert_eq!(a.gcd(&b).a, scalars(&[1, 1]));
+        assert_eq!(b.gcd(&a).a, scalars(&[1, 1]));
+
+        // Non-monic polynomials: 2(x + 1)(x + 2) and 3(x + 1).
+        let a = Polynomial::with_coefficients(scalars(&[4, 6, 2, 0]));
+        let b = Polynomial::with_coefficients(scalars(&[3, 3]));
+        assert_eq!(a.gcd(&b).a, scalars(&[1, 1]));
+
+        // Coprime polynomials.
+        let a = Polynomial::with_coefficients(scalars(&[1, 1]));
+        let b = Polynomial::with_coefficients(scalars(&[2, 1]));
+        assert_eq!(a.gcd(&b).a, scalars(&[1]));
+
+        // Zero polynomials.
+        let z = Polynomial::zero(2);
+        assert_eq!(a.gcd(&z).a, scalars(&[1, 1]));
+        assert_eq!(z.gcd(&a).a, scalars(&[1, 1]));
+        assert_eq!(z.gcd(&z).a, scalars(&[0]));
+    }
+
+    #[test]
+    fn test_derivative() {
+        let test_cases = vec![
+            (vec![0], vec![0]),
+            (vec![5], vec![0]),
+            (vec![1, 2], vec![2]),
+            (vec![1, 2, 3, 4], vec![2, 6, 12]),
+            (vec![1, 2, 0], vec![2, 0]),
+        ];
+
+        for (coefficients, expected) in test_cases {
+            let p = Polynomial::with_coefficients(scalars(&coefficients));
+            assert_eq!(p.derivative().a, scalars(&expected));
+        }
+    }
+
     #[test]
     pub fn test_scalar_mul() {
         let test_cases = vec![
@@ -0,0 +1,89 @@
+use rand_core::{impls, CryptoRng, Error, RngCore};
+use sha3::{
+    digest::{ExtendableOutput, Update, XofReader},
+    Shake256, Shake256Reader,
+};
+
+/// Deterministic random number generator which expands a seed using
+/// the SHAKE256 extendable-output function.
+///
+/// The output stream is defined as:
+/// ```text
+/// SHAKE256(len(dst) || dst || seed)
+/// ```
+/// where `len(dst)` is the length of the domain separation tag encoded
+/// as a big-endian 64-bit integer.
+pub(crate) struct XofRng {
+    reader: Shake256Reader,
+}
+
+impl XofRng {
+    /// Creates a new generator from the given seed and domain separation tag.
+    pub(crate) fn new(seed: &[u8], dst: &[u8]) -> Self {
+        let mut hasher = Shake256::default();
+        hasher.update(&(dst.len() as u64).to_be_bytes());
+        hasher.update(dst);
+        hasher.update(seed);
+        let reader = hasher.finalize_xof();
+
+        Self { reader }
+    }
+}
+
+impl RngCore for XofRng {
+    fn next_u32(&mut self) -> u32 {
+        impls::next_u32_via_fill(self)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        impls::next_u64_via_fill(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.reader.read(dest);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl CryptoRng for XofRng {}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::RngCore;
+
+    use super::XofRng;
+
+    #[test]
+    fn test_xof_rng() {
+        let mut a = XofRng::new(b"seed", b"dst");
+        let mut b = XofRng::new(b"seed", b"dst");
+        assert_eq!(a.next_u64(), b.next_u64());
+        assert_eq!(a.next_u32(), b.next_u32());
+
+        // The stream is continuous.
+        let mut a = XofRng::new(b"seed", b"dst");
+        let mut b = XofRng::new(b"seed", b"dst");
+        let mut x = [0u8; 64];
+        let mut y = [0u8; 64];
+        a.fill_bytes(&mut x[..20]);
+        a.fill_bytes(&mut x[20..]);
+        b.fill_bytes(&mut y);
+        assert_eq!(x, y);
+
+        // Different seeds or tags.
+        let mut c = XofRng::new(b"seed", b"dsT");
+        let mut d = XofRng::new(b"seeD", b"dst");
+        let mut e = XofRng::new(b"", b"seeddst");
+        let mut z = [0u8; 64];
+        c.fill_bytes(&mut z);
+        assert_ne!(y, z);
+        d.fill_bytes(&mut z);
+        assert_ne!(y, z);
+        e.fill_bytes(&mut z);
+        assert_ne!(y, z);
+    }
+}
@@ -0,0 +1,120 @@
+use anyhow::Result;
+
+use bls12_381_plus::{G1Projective, Scalar};
+use elliptic_curve::hash2curve::ExpandMsgXmd;
+
+use super::{FieldDigest, GroupDigest};
+
+/// The BLS12-381 G1 elliptic curve group with the SHA-256 hash function used
+/// to encode arbitrary-length byte strings to elements of the underlying prime
+/// field or elliptic curve points.
+///
+/// Group elements are derived using the `BLS12381G1_XMD:SHA-256_SSWU_RO_`
+/// suite from RFC 9380, which is also used by the minimal-signature-size
+/// variant of BLS signatures.
+pub struct Sha256;
+
+impl GroupDigest for Sha256 {
+    type Output = G1Projective;
+
+    fn hash_to_group(msg: &[u8], dst: &[u8]) -> Result<Self::Output> {
+        let p = G1Projective::hash::<ExpandMsgXmd<sha2::Sha256>>(msg, dst);
+        Ok(p)
+    }
+}
+
+impl FieldDigest for Sha256 {
+    type Output = Scalar;
+
+    fn hash_to_field(msg: &[u8], dst: &[u8]) -> Result<Self::Output> {
+        let s = Scalar::hash::<ExpandMsgXmd<sha2::Sha256>>(msg, dst);
+        Ok(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate test;
+
+    use self::test::Bencher;
+
+    use std::fmt::Write;
+
+    use bls12_381_plus::G1Affine;
+    use group::GroupEncoding;
+    use rand::{rngs::StdRng, RngCore, SeedableRng};
+
+    use crate::{churp::Dealer, suites, vss::VerificationMatrix};
+
+    use super::{FieldDigest, G1Projective, GroupDigest, Sha256};
+
+    #[test]
+    fn test_hash_to_group_rfc9380() {
+        // Test vector from RFC 9380, appendix J.9.1 (BLS12381G1_XMD:SHA-256_SSWU_RO_).
+        let dst = b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_RO_";
+        let p = Sha256::hash_to_group(b"", dst).unwrap();
+        let bytes = G1Affine::from(p).to_uncompressed();
+        let hex = bytes.iter().fold(String::new(), |mut hex, b| {
+            let _ = write!(hex, "{:02x}", b);
+            hex
+        });
+        let expected = concat!(
+            "052926add2207b76ca4fa57a8734416c8dc95e24501772c814278700eed6d1e4",
+            "e8cf62d9c09db0fac349612b759e79a1",
+            "08ba738453bfed09cb546dbb0783dbb3a5f1f566ed67bb6be0e8c67e2e81a4cc",
+            "68ee29813bb7994998f3eae0c9c6a265",
+        );
+        assert_eq!(hex, expected);
+    }
+
+    #[test]
+    fn test_hash_to_field() {
+        let a = Sha256::hash_to_field(b"msg", b"dst").unwrap();
+        let b = Sha256::hash_to_field(b"msg", b"dst").unwrap();
+        let c = Sha256::hash_to_field(b"msg", b"other dst").unwrap();
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn test_churp() {
+        suites::tests::test_churp::<Sha256>();
+    }
+
+    #[test]
+    fn test_serialization() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let dealer = Dealer::<G1Projective>::new(2, &mut rng).unwrap();
+        let vm = dealer.verification_matrix();
+        let restored = VerificationMatrix::<G1Projective>::from_bytes(&vm.to_bytes())
+            .expect("deserialization should succeed");
+        assert_eq!(vm, &restored);
+
+        // Matrix elements are encoded as compressed G1 points.
+        let size = VerificationMatrix::<G1Projective>::element_byte_size();
+        assert_eq!(size, G1Projective::default().to_bytes().as_ref().len());
+        assert_eq!(size, 48);
+    }
+
+    #[bench]
+    fn bench_hash_to_field_bls12_381_g1_sha256(b: &mut Bencher) {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let mut data = [0; 64];
+
+        b.iter(|| {
+            rng.fill_bytes(&mut data);
+            let _ = Sha256::hash_to_field(&data[..32], &data[32..64]).unwrap();
+        });
+    }
+
+    #[bench]
+    fn bench_hash_to_group_bls12_381_g1_sha256(b: &mut Bencher) {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let mut data = [0; 64];
+
+        b.iter(|| {
+            rng.fill_bytes(&mut data);
+            let _ = Sha256::hash_to_group(&data[..32], &data[32..64]).unwrap();
+        });
+    }
+}
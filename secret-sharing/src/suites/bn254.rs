@@ -0,0 +1,336 @@
+use anyhow::{bail, Result};
+
+use elliptic_curve::hash2curve::ExpandMsgXmd;
+use group::{
+    ff::{Field, FromUniformBytes, PrimeField},
+    Curve,
+};
+use halo2curves::{
+    bn256::{Fq, Fr, G1Affine, G1},
+    CurveAffine, CurveExt,
+};
+
+use super::{
+    expand_message,
+    zeroizable::{Point, Scalar},
+    FieldDigest, GroupDigest,
+};
+
+/// The maximum number of attempts made when hashing to the group.
+const MAX_HASH_TO_GROUP_ATTEMPTS: u8 = 128;
+
+/// The number of uniform bytes reduced to an element of the base or
+/// the scalar field when hashing with Keccak-256.
+const KECCAK_UNIFORM_BYTES: usize = 48;
+
+/// The size of an encoded element of the base or the scalar field
+/// in the alt_bn128 encoding.
+const ALT_BN128_FIELD_SIZE: usize = 32;
+
+/// The size of an encoded point in the alt_bn128 encoding.
+pub const ALT_BN128_POINT_SIZE: usize = 2 * ALT_BN128_FIELD_SIZE;
+
+/// The BN254 (alt_bn128) elliptic curve group with the SHA-256 hash function
+/// used to encode arbitrary-length byte strings to elements of the underlying
+/// prime field or elliptic curve points.
+///
+/// Hashing to the curve requires the domain separation tag to be a valid
+/// UTF-8 string.
+pub struct Sha256;
+
+impl GroupDigest for Sha256 {
+    type Output = Point<G1>;
+
+    fn hash_to_group(msg: &[u8], dst: &[u8]) -> Result<Self::Output> {
+        let domain_prefix = std::str::from_utf8(dst)?;
+        let hasher = G1::hash_to_curve(domain_prefix);
+        Ok(Point(hasher(msg)))
+    }
+}
+
+impl FieldDigest for Sha256 {
+    type Output = Scalar<Fr>;
+
+    fn hash_to_field(msg: &[u8], dst: &[u8]) -> Result<Self::Output> {
+        let mut bytes = [0u8; 64];
+        expand_message::<ExpandMsgXmd<sha2::Sha256>>(msg, dst, &mut bytes)?;
+        Ok(Scalar::from_uniform_bytes(&bytes))
+    }
+}
+
+/// The BN254 (alt_bn128) elliptic curve group with the Keccak-256 hash
+/// function used to encode arbitrary-length byte strings to elements
+/// of the underlying prime field or elliptic curve points.
+///
+/// Both encodings only use `expand_message_xmd` with Keccak-256, big-endian
+/// reductions and modular arithmetic, so they can be recomputed cheaply
+/// in Solidity verifier contracts.
+pub struct Keccak256;
+
+impl GroupDigest for Keccak256 {
+    type Output = Point<G1>;
+
+    /// Hashes the message to a point using the try-and-increment method.
+    ///
+    /// RFC 9380 defines no hash-to-curve suite for BN254, so this encoding
+    /// is specific to this suite and is not interoperable with RFC 9380
+    /// implementations.
+    ///
+    /// In each attempt, the message and a one-byte counter are hashed
+    /// to a candidate x-coordinate. The first candidate for which
+    /// `x^3 + 3` is a square is accepted, together with the even square
+    /// root as the y-coordinate. As the curve has cofactor one, no cofactor
+    /// clearing is needed.
+    ///
+    /// This method is not constant time as the number of attempts depends
+    /// on the message.
+    fn hash_to_group(msg: &[u8], dst: &[u8]) -> Result<Self::Output> {
+        let mut data = msg.to_vec();
+        data.push(0);
+
+        for ctr in 0..MAX_HASH_TO_GROUP_ATTEMPTS {
+            *data.last_mut().unwrap() = ctr;
+            let x = keccak_hash_to_field::<Fq>(&data, dst)?;
+            let y2 = x.square() * x + G1::b();
+            let y = match Option::<Fq>::from(y2.sqrt()) {
+                Some(y) if bool::from(y.is_odd()) => -y,
+                Some(y) => y,
+                None => continue,
+            };
+
+            let p = Option::<G1Affine>::from(G1Affine::from_xy(x, y));
+            match p {
+                Some(p) => return Ok(Point(p.into())),
+                None => continue,
+            }
+        }
+
+        bail!("hash to group failed")
+    }
+}
+
+impl FieldDigest for Keccak256 {
+    type Output = Scalar<Fr>;
+
+    fn hash_to_field(msg: &[u8], dst: &[u8]) -> Result<Self::Output> {
+        keccak_hash_to_field::<Fr>(msg, dst).map(Scalar)
+    }
+}
+
+/// Hashes the message to a field element by expanding it to 48 bytes
+/// with `expand_message_xmd` and Keccak-256, and reducing the result,
+/// interpreted as a big-endian integer, modulo the field order.
+fn keccak_hash_to_field<F>(msg: &[u8], dst: &[u8]) -> Result<F>
+where
+    F: FromUniformBytes<KECCAK_UNIFORM_BYTES>,
+{
+    let mut bytes = [0u8; KECCAK_UNIFORM_BYTES];
+    expand_message::<ExpandMsgXmd<sha3::Keccak256>>(msg, dst, &mut bytes)?;
+    bytes.reverse();
+    Ok(F::from_uniform_bytes(&bytes))
+}
+
+/// Encodes the given point as the concatenation of its big-endian affine
+/// coordinates, as expected by the Ethereum alt_bn128 precompiles.
+///
+/// The point at infinity is encoded as all zeros.
+pub fn point_to_alt_bn128_bytes(point: &Point<G1>) -> [u8; ALT_BN128_POINT_SIZE] {
+    let point = point.0.to_affine();
+    let mut bytes = [0u8; ALT_BN128_POINT_SIZE];
+    let (x, y) = bytes.split_at_mut(ALT_BN128_FIELD_SIZE);
+    write_be(&point.x.to_repr(), x);
+    write_be(&point.y.to_repr(), y);
+    bytes
+}
+
+/// Decodes a point from the concatenation of its big-endian affine
+/// coordinates, as returned by the Ethereum alt_bn128 precompiles.
+pub fn point_from_alt_bn128_bytes(bytes: &[u8; ALT_BN128_POINT_SIZE]) -> Result<Point<G1>> {
+    let (x, y) = bytes.split_at(ALT_BN128_FIELD_SIZE);
+    let x = read_be::<Fq>(x)?;
+    let y = read_be::<Fq>(y)?;
+    let point = Option::<G1Affine>::from(G1Affine::from_xy(x, y));
+    match point {
+        Some(point) => Ok(Point(point.into())),
+        None => bail!("point not on curve"),
+    }
+}
+
+/// Encodes the given scalar as a big-endian integer, as expected
+/// by the Ethereum alt_bn128 precompiles.
+pub fn scalar_to_alt_bn128_bytes(scalar: &Scalar<Fr>) -> [u8; ALT_BN128_FIELD_SIZE] {
+    let mut bytes = [0u8; ALT_BN128_FIELD_SIZE];
+    write_be(&scalar.0.to_repr(), &mut bytes);
+    bytes
+}
+
+/// Decodes a scalar from a big-endian integer.
+pub fn scalar_from_alt_bn128_bytes(bytes: &[u8; ALT_BN128_FIELD_SIZE]) -> Result<Scalar<Fr>> {
+    read_be::<Fr>(bytes).map(Scalar)
+}
+
+/// Writes the little-endian representation of a field element
+/// in big-endian order.
+fn write_be<R: AsRef<[u8]>>(repr: &R, out: &mut [u8]) {
+    out.copy_from_slice(repr.as_ref());
+    out.reverse();
+}
+
+/// Reads a field element from its big-endian representation.
+fn read_be<F: PrimeField>(bytes: &[u8]) -> Result<F> {
+    let mut repr = F::Repr::default();
+    repr.as_mut().copy_from_slice(bytes);
+    repr.as_mut().reverse();
+    match Option::<F>::from(F::from_repr(repr)) {
+        Some(f) => Ok(f),
+        None => bail!("field element not canonical"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate test;
+
+    use self::test::Bencher;
+
+    use group::Group;
+    use rand::{rngs::StdRng, RngCore, SeedableRng};
+
+    use crate::{
+        churp::{encode_shareholder, Dealer, HandoffKind, VerifiableSecretShare},
+        suites::{self, Suite},
+    };
+
+    use super::{
+        point_from_alt_bn128_bytes, point_to_alt_bn128_bytes, scalar_from_alt_bn128_bytes,
+        scalar_to_alt_bn128_bytes, FieldDigest, GroupDigest, Keccak256, Sha256,
+    };
+
+    #[test]
+    fn test_hash_to_field() {
+        let a = Sha256::hash_to_field(b"msg", b"dst").unwrap();
+        let b = Sha256::hash_to_field(b"msg", b"dst").unwrap();
+        let c = Sha256::hash_to_field(b"msg", b"other dst").unwrap();
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn test_churp() {
+        suites::tests::test_churp::<Sha256>();
+    }
+
+    #[test]
+    fn test_alt_bn128_encoding() {
+        type G = <Sha256 as Suite>::Group;
+
+        // The generator is (1, 2).
+        let bytes = point_to_alt_bn128_bytes(&G::generator());
+        assert_eq!(bytes[31], 1);
+        assert_eq!(bytes[63], 2);
+        assert!(bytes[..31].iter().all(|&b| b == 0));
+        assert!(bytes[32..63].iter().all(|&b| b == 0));
+
+        // The point at infinity is encoded as all zeros.
+        let bytes = point_to_alt_bn128_bytes(&G::identity());
+        assert_eq!(bytes, [0; 64]);
+        let point = point_from_alt_bn128_bytes(&bytes).unwrap();
+        assert_eq!(point, G::identity());
+
+        // Round trip.
+        let point = Sha256::hash_to_group(b"msg", b"dst").unwrap();
+        let bytes = point_to_alt_bn128_bytes(&point);
+        let decoded = point_from_alt_bn128_bytes(&bytes).unwrap();
+        assert_eq!(point, decoded);
+
+        // Points not on the curve.
+        let mut bytes = bytes;
+        bytes[63] ^= 1;
+        point_from_alt_bn128_bytes(&bytes).expect_err("decoding should fail");
+
+        // Scalars.
+        let scalar = Sha256::hash_to_field(b"msg", b"dst").unwrap();
+        let bytes = scalar_to_alt_bn128_bytes(&scalar);
+        let decoded = scalar_from_alt_bn128_bytes(&bytes).unwrap();
+        assert_eq!(scalar, decoded);
+        scalar_from_alt_bn128_bytes(&[0xff; 32]).expect_err("decoding should fail");
+    }
+
+    #[test]
+    fn test_keccak256_variant() {
+        let a = Keccak256::hash_to_field(b"msg", b"dst").unwrap();
+        let b = Keccak256::hash_to_field(b"msg", b"dst").unwrap();
+        let c = Keccak256::hash_to_field(b"msg", b"other dst").unwrap();
+        let d = Sha256::hash_to_field(b"msg", b"dst").unwrap();
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert_ne!(a, d);
+
+        let a = Keccak256::hash_to_group(b"msg", b"dst").unwrap();
+        let b = Keccak256::hash_to_group(b"msg", b"dst").unwrap();
+        let c = Keccak256::hash_to_group(b"msg", b"other dst").unwrap();
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+
+        // The y-coordinate of the hashed point is even.
+        let bytes = point_to_alt_bn128_bytes(&a);
+        assert_eq!(bytes[63] & 1, 0);
+        assert_eq!(point_from_alt_bn128_bytes(&bytes).unwrap(), a);
+
+        // Shares can be verified.
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let threshold = 2;
+        let dealer = Dealer::<<Keccak256 as Suite>::Group>::new(threshold, &mut rng).unwrap();
+        let x = encode_shareholder::<Keccak256>(b"shareholder", b"dst").unwrap();
+        let share = dealer.make_share(x, HandoffKind::DealingPhase);
+        let vm = dealer.verification_matrix().clone();
+        let verifiable_share = VerifiableSecretShare::new(share, vm);
+        verifiable_share
+            .verify(threshold, false, true)
+            .expect("share should be valid");
+    }
+
+    #[bench]
+    fn bench_hash_to_field_bn254_sha256(b: &mut Bencher) {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let mut data = [0; 64];
+
+        b.iter(|| {
+            rng.fill_bytes(&mut data);
+            let _ = Sha256::hash_to_field(&data[..32], &data[32..64]).unwrap();
+        });
+    }
+
+    #[bench]
+    fn bench_hash_to_group_bn254_sha256(b: &mut Bencher) {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let mut data = [0; 64];
+
+        b.iter(|| {
+            rng.fill_bytes(&mut data);
+            let _ = Sha256::hash_to_group(&data[..32], b"dst").unwrap();
+        });
+    }
+
+    #[bench]
+    fn bench_hash_to_field_bn254_keccak256(b: &mut Bencher) {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let mut data = [0; 64];
+
+        b.iter(|| {
+            rng.fill_bytes(&mut data);
+            let _ = Keccak256::hash_to_field(&data[..32], &data[32..64]).unwrap();
+        });
+    }
+
+    #[bench]
+    fn bench_hash_to_group_bn254_keccak256(b: &mut Bencher) {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let mut data = [0; 64];
+
+        b.iter(|| {
+            rng.fill_bytes(&mut data);
+            let _ = Keccak256::hash_to_group(&data[..32], &data[32..64]).unwrap();
+        });
+    }
+}
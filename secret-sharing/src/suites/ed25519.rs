@@ -0,0 +1,372 @@
+use anyhow::{anyhow, bail, Result};
+
+use curve25519_dalek::{
+    constants::ED25519_BASEPOINT_POINT, edwards::CompressedEdwardsY, scalar::clamp_integer,
+    EdwardsPoint, Scalar,
+};
+use elliptic_curve::{
+    bigint::{impl_modulus, modular::constant_mod::Residue, Encoding, U256},
+    hash2curve::ExpandMsgXmd,
+};
+use sha2::{Digest, Sha512 as Sha512Hash};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+use zeroize::Zeroize;
+
+use super::{expand_message, FieldDigest, GroupDigest};
+
+impl_modulus!(
+    FieldModulus,
+    U256,
+    "7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffed"
+);
+
+/// An element of the base field of Curve25519, GF(2^255 - 19).
+type FieldElement = Residue<FieldModulus, { U256::LIMBS }>;
+
+/// The number of uniform bytes reduced to an element of the base field
+/// when hashing to the group (`L` in RFC 9380).
+const FIELD_UNIFORM_BYTES: usize = 48;
+
+/// The Montgomery curve coefficient `J` of Curve25519.
+const J: U256 = U256::from_u32(486662);
+
+/// The exponent `(p + 3) / 8` used to compute square roots.
+const SQRT_EXP: U256 =
+    U256::from_be_hex("0ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffe");
+
+/// The exponent `(p - 1) / 2` used to compute the Legendre symbol.
+const LEGENDRE_EXP: U256 =
+    U256::from_be_hex("3ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff6");
+
+/// The square root of -1 in the base field.
+const SQRT_M1: U256 =
+    U256::from_be_hex("2b8324804fc1df0b2b4d00993dfbd7a72f431806ad2fe478c4ee1b274a0ea0b0");
+
+/// The square root of -486664 in the base field with `sgn0` equal to zero,
+/// used by the rational map from Curve25519 to edwards25519.
+const SQRT_M486664: U256 =
+    U256::from_be_hex("0f26edf460a006bbd27b08dc03fc4f7ec5a1d3d14b7d1a82cc6e04aaff457e06");
+
+/// The Ed25519 group (the prime-order subgroup of the twisted Edwards form
+/// of Curve25519) with the SHA-512 hash function used to encode
+/// arbitrary-length byte strings to elements of the underlying prime field
+/// or group elements.
+///
+/// The shared secret is an Ed25519 scalar and can be used directly as the
+/// secret scalar of an expanded Ed25519 signing key, see [`public_key`].
+///
+/// Group elements decoded with [`Suite::group_from_bytes`] are rejected
+/// unless they are torsion-free, i.e. small-order and mixed-torsion points
+/// are never accepted.
+///
+/// [`Suite::group_from_bytes`]: super::Suite::group_from_bytes
+pub struct Sha512;
+
+impl GroupDigest for Sha512 {
+    type Output = EdwardsPoint;
+
+    /// Hashes the message to a point in the prime-order subgroup using
+    /// the `edwards25519_XMD:SHA-512_ELL2_RO_` suite from RFC 9380.
+    fn hash_to_group(msg: &[u8], dst: &[u8]) -> Result<Self::Output> {
+        let mut bytes = [0u8; 2 * FIELD_UNIFORM_BYTES];
+        expand_message::<ExpandMsgXmd<Sha512Hash>>(msg, dst, &mut bytes)?;
+
+        let u0 = field_from_uniform_bytes(&bytes[..FIELD_UNIFORM_BYTES]);
+        let u1 = field_from_uniform_bytes(&bytes[FIELD_UNIFORM_BYTES..]);
+        bytes.zeroize();
+
+        let q0 = map_to_curve(&u0)?;
+        let q1 = map_to_curve(&u1)?;
+
+        Ok((q0 + q1).mul_by_cofactor())
+    }
+
+    fn validate_group_element(p: &Self::Output) -> Result<()> {
+        if !p.is_torsion_free() {
+            bail!("group element not in the prime-order subgroup");
+        }
+        Ok(())
+    }
+}
+
+/// Reduces big-endian uniform bytes to an element of the base field.
+fn field_from_uniform_bytes(bytes: &[u8]) -> FieldElement {
+    let (hi, lo) = bytes.split_at(bytes.len() - 32);
+    let mut hi_bytes = [0u8; 32];
+    hi_bytes[32 - hi.len()..].copy_from_slice(hi);
+
+    // Since 2^256 = 38 (mod p), hi * 2^256 + lo = hi * 38 + lo (mod p).
+    let hi = FieldElement::new(&U256::from_be_slice(&hi_bytes));
+    let lo = FieldElement::new(&U256::from_be_slice(lo));
+    hi_bytes.zeroize();
+
+    hi.mul(&FieldElement::new(&U256::from_u8(38))).add(&lo)
+}
+
+/// Returns whether the given element is odd, i.e. `sgn0` from RFC 9380.
+fn sgn0(e: &FieldElement) -> Choice {
+    Choice::from(e.retrieve().to_le_bytes()[0] & 1)
+}
+
+/// Returns whether the given element is a square in the base field.
+fn is_square(e: &FieldElement) -> Choice {
+    let legendre = e.pow(&LEGENDRE_EXP);
+    legendre.ct_eq(&FieldElement::ONE) | legendre.ct_eq(&FieldElement::ZERO)
+}
+
+/// Returns a square root of the given element, provided it is a square.
+fn sqrt(e: &FieldElement) -> FieldElement {
+    let r = e.pow(&SQRT_EXP);
+    let r_m1 = r.mul(&FieldElement::new(&SQRT_M1));
+    FieldElement::conditional_select(&r_m1, &r, r.square().ct_eq(e))
+}
+
+/// Returns the inverse of the given element, or zero if the element is zero.
+fn inv0(e: &FieldElement) -> FieldElement {
+    let (inv, invertible) = e.invert();
+    FieldElement::conditional_select(&FieldElement::ZERO, &inv, invertible.into())
+}
+
+/// Returns `x^3 + J * x^2 + x`, the right-hand side of Curve25519.
+fn curve25519_rhs(x: &FieldElement, j: &FieldElement) -> FieldElement {
+    let x2 = x.square();
+    x2.mul(x).add(&j.mul(&x2)).add(x)
+}
+
+/// Maps an element of the base field to a point on edwards25519 using
+/// the Elligator 2 method for Curve25519 followed by the rational map
+/// to edwards25519, as specified in RFC 9380, sections 6.7.1 and 6.8.2.
+fn map_to_curve(u: &FieldElement) -> Result<EdwardsPoint> {
+    let j = FieldElement::new(&J);
+    let one = FieldElement::ONE;
+
+    // Elligator 2 with Z = 2.
+    let tv = one.add(&u.square().add(&u.square()));
+    let x1 = j.neg().mul(&inv0(&tv));
+    let x1 = FieldElement::conditional_select(&x1, &j.neg(), x1.ct_eq(&FieldElement::ZERO));
+    let gx1 = curve25519_rhs(&x1, &j);
+    let x2 = x1.neg().sub(&j);
+    let gx2 = curve25519_rhs(&x2, &j);
+
+    let e1 = is_square(&gx1);
+    let s = FieldElement::conditional_select(&x2, &x1, e1);
+    let y = sqrt(&FieldElement::conditional_select(&gx2, &gx1, e1));
+    let t = FieldElement::conditional_select(&y, &y.neg(), sgn0(&y) ^ e1);
+
+    // Rational map to edwards25519.
+    let s_plus_one = s.add(&one);
+    let v = FieldElement::new(&SQRT_M486664).mul(&s).mul(&inv0(&t));
+    let w = s.sub(&one).mul(&inv0(&s_plus_one));
+    let exceptional = t.ct_eq(&FieldElement::ZERO) | s_plus_one.ct_eq(&FieldElement::ZERO);
+    let v = FieldElement::conditional_select(&v, &FieldElement::ZERO, exceptional);
+    let w = FieldElement::conditional_select(&w, &one, exceptional);
+
+    let mut bytes = w.retrieve().to_le_bytes();
+    bytes[31] |= sgn0(&v).unwrap_u8() << 7;
+    CompressedEdwardsY(bytes)
+        .decompress()
+        .ok_or_else(|| anyhow!("hash to group failed"))
+}
+
+impl FieldDigest for Sha512 {
+    type Output = Scalar;
+
+    fn hash_to_field(msg: &[u8], dst: &[u8]) -> Result<Self::Output> {
+        let mut bytes = [0u8; 64];
+        expand_message::<ExpandMsgXmd<Sha512Hash>>(msg, dst, &mut bytes)?;
+        let s = Scalar::from_bytes_mod_order_wide(&bytes);
+        bytes.zeroize();
+        Ok(s)
+    }
+}
+
+/// Derives the secret scalar of an Ed25519 signing key from its 32-byte
+/// seed as specified in RFC 8032, i.e., the lower half of the SHA-512 digest
+/// of the seed is clamped and reduced modulo the group order.
+///
+/// The result can be used as the shared secret when an existing Ed25519 key
+/// needs to be secret-shared. Note that clamping is not a linear operation,
+/// so it must only be applied before sharing, never to individual shares
+/// or to the reconstructed secret.
+pub fn secret_scalar_from_seed(seed: &[u8; 32]) -> Scalar {
+    let mut digest: [u8; 64] = Sha512Hash::digest(seed).into();
+    let mut bytes = [0u8; 32];
+    bytes.copy_from_slice(&digest[..32]);
+    let s = Scalar::from_bytes_mod_order(clamp_integer(bytes));
+    digest.zeroize();
+    bytes.zeroize();
+    s
+}
+
+/// Returns the Ed25519 public key corresponding to the given secret scalar.
+pub fn public_key(secret: &Scalar) -> [u8; 32] {
+    (ED25519_BASEPOINT_POINT * secret).compress().to_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate test;
+
+    use self::test::Bencher;
+
+    use std::fmt::Write;
+
+    use curve25519_dalek::{
+        constants::ED25519_BASEPOINT_POINT, edwards::CompressedEdwardsY, EdwardsPoint, Scalar,
+    };
+    use group::Group;
+    use rand::{rngs::StdRng, RngCore, SeedableRng};
+
+    use crate::{
+        churp::{Dealer, HandoffKind, Player},
+        suites::{self, Suite},
+    };
+
+    use super::{
+        public_key, secret_scalar_from_seed, sgn0, FieldDigest, FieldElement, GroupDigest, Sha512,
+        SQRT_M1, SQRT_M486664, U256,
+    };
+
+    #[test]
+    fn test_hash_to_field() {
+        let a = Sha512::hash_to_field(b"msg", b"dst").unwrap();
+        let b = Sha512::hash_to_field(b"msg", b"dst").unwrap();
+        let c = Sha512::hash_to_field(b"msg", b"other dst").unwrap();
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn test_hash_to_group() {
+        for i in 0..32u8 {
+            let p = Sha512::hash_to_group(&[i], b"dst").unwrap();
+            assert!(p.is_torsion_free());
+            assert!(!bool::from(p.is_identity()));
+        }
+    }
+
+    #[test]
+    fn test_hash_to_group_rfc9380() {
+        // Test vectors from RFC 9380, appendix J.5.1 (edwards25519_XMD:SHA-512_ELL2_RO_).
+        let dst = b"QUUX-V01-CS02-with-edwards25519_XMD:SHA-512_ELL2_RO_";
+        let vectors: [(&[u8], &str); 2] = [
+            (
+                b"",
+                "21dc15e10253796df23a7699c8a383ea624cce88c52431f6be220b1a56c8a609",
+            ),
+            (
+                b"abc",
+                "31558a26887f23fb8218f143e69d5f0af2e7831130bd5b432ef23883b895839a",
+            ),
+        ];
+
+        for (msg, expected) in vectors {
+            let p = Sha512::hash_to_group(msg, dst).unwrap();
+            let hex = p
+                .compress()
+                .to_bytes()
+                .iter()
+                .fold(String::new(), |mut hex, b| {
+                    let _ = write!(hex, "{:02x}", b);
+                    hex
+                });
+            assert_eq!(hex, expected);
+        }
+    }
+
+    #[test]
+    fn test_group_codec_torsion() {
+        // The point (0, -1) of order two.
+        let mut small_order = [0xff; 32];
+        small_order[0] = 0xec;
+        small_order[31] = 0x7f;
+        Sha512::group_from_bytes(&small_order).expect_err("decoding should fail");
+
+        let p = CompressedEdwardsY(small_order).decompress().unwrap();
+        assert!(p.is_small_order());
+        assert!(!bool::from(p.is_identity()));
+
+        // A mixed-torsion point.
+        let mixed = (ED25519_BASEPOINT_POINT + p).compress().to_bytes();
+        Sha512::group_from_bytes(&mixed).expect_err("decoding should fail");
+
+        let bytes = ED25519_BASEPOINT_POINT.compress().to_bytes();
+        assert_eq!(
+            Sha512::group_from_bytes(&bytes).unwrap(),
+            ED25519_BASEPOINT_POINT
+        );
+    }
+
+    #[test]
+    fn test_field_constants() {
+        let sqrt_m1 = FieldElement::new(&SQRT_M1);
+        assert_eq!(sqrt_m1.square(), FieldElement::ONE.neg());
+
+        let c = FieldElement::new(&SQRT_M486664);
+        assert_eq!(c.square(), FieldElement::new(&U256::from_u32(486664)).neg());
+        assert!(!bool::from(sgn0(&c)));
+    }
+
+    #[test]
+    fn test_secret_scalar_from_seed() {
+        // Test vector 1 from RFC 8032, section 7.1.
+        let seed = [
+            0x9d, 0x61, 0xb1, 0x9d, 0xef, 0xfd, 0x5a, 0x60, 0xba, 0x84, 0x4a, 0xf4, 0x92, 0xec,
+            0x2c, 0xc4, 0x44, 0x49, 0xc5, 0x69, 0x7b, 0x32, 0x69, 0x19, 0x70, 0x3b, 0xac, 0x03,
+            0x1c, 0xae, 0x7f, 0x60,
+        ];
+        let expected = [
+            0xd7, 0x5a, 0x98, 0x01, 0x82, 0xb1, 0x0a, 0xb7, 0xd5, 0x4b, 0xfe, 0xd3, 0xc9, 0x64,
+            0x07, 0x3a, 0x0e, 0xe1, 0x72, 0xf3, 0xda, 0xa6, 0x23, 0x25, 0xaf, 0x02, 0x1a, 0x68,
+            0xf7, 0x07, 0x51, 0x1a,
+        ];
+
+        let secret = secret_scalar_from_seed(&seed);
+        assert_eq!(public_key(&secret), expected);
+    }
+
+    #[test]
+    fn test_churp() {
+        suites::tests::test_churp::<Sha512>();
+    }
+
+    #[test]
+    fn test_recover_signing_key() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let threshold = 2;
+        let dealer = Dealer::<EdwardsPoint>::new(threshold, &mut rng).unwrap();
+
+        // The reconstructed secret is the secret scalar of the signing key.
+        let kind = HandoffKind::DealingPhase;
+        let xs = (1..=3u64).map(Scalar::from).collect();
+        let shares = dealer.make_shares(xs, kind);
+        let secret = Player::new(threshold, kind)
+            .recover_secret(&shares)
+            .unwrap();
+        let pk = *dealer.verification_matrix().element(0, 0).unwrap();
+        assert_eq!(pk, ED25519_BASEPOINT_POINT * secret);
+        assert_eq!(public_key(&secret), pk.compress().to_bytes());
+    }
+
+    #[bench]
+    fn bench_hash_to_field_ed25519_sha512(b: &mut Bencher) {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let mut data = [0; 64];
+
+        b.iter(|| {
+            rng.fill_bytes(&mut data);
+            let _ = Sha512::hash_to_field(&data[..32], &data[32..64]).unwrap();
+        });
+    }
+
+    #[bench]
+    fn bench_hash_to_group_ed25519_sha512(b: &mut Bencher) {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let mut data = [0; 64];
+
+        b.iter(|| {
+            rng.fill_bytes(&mut data);
+            let _ = Sha512::hash_to_group(&data[..32], &data[32..64]).unwrap();
+        });
+    }
+}
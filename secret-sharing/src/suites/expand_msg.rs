@@ -0,0 +1,159 @@
+//! Custom `expand_message` implementations for hashing to fields and curves.
+
+use std::convert::TryFrom;
+
+use elliptic_curve::{
+    hash2curve::{ExpandMsg, Expander},
+    Error, Result,
+};
+
+/// Salt prepended to domain separation tags which are too long.
+const OVERSIZE_DST_SALT: &[u8] = b"H2C-OVERSIZE-DST-";
+
+/// Maximum length of a domain separation tag.
+const MAX_DST_LEN: usize = 255;
+
+/// Length of a hashed domain separation tag.
+const HASHED_DST_LEN: usize = 32;
+
+/// The `expand_message_xof` method from RFC 9380, section 5.3.2,
+/// instantiated with the BLAKE3 extendable-output function.
+///
+/// Domain separation tags longer than 255 bytes are hashed to 32 bytes
+/// as described in RFC 9380, section 5.3.3.
+pub struct ExpandMsgBlake3 {
+    reader: blake3::OutputReader,
+}
+
+impl<'a> ExpandMsg<'a> for ExpandMsgBlake3 {
+    type Expander = Self;
+
+    fn expand_message(
+        msgs: &[&[u8]],
+        dsts: &'a [&'a [u8]],
+        len_in_bytes: usize,
+    ) -> Result<Self::Expander> {
+        if len_in_bytes == 0 {
+            return Err(Error);
+        }
+        let len_in_bytes = u16::try_from(len_in_bytes).map_err(|_| Error)?;
+
+        let dst_len: usize = dsts.iter().map(|dst| dst.len()).sum();
+        if dst_len == 0 {
+            return Err(Error);
+        }
+
+        let mut hasher = blake3::Hasher::new();
+        for msg in msgs {
+            hasher.update(msg);
+        }
+        hasher.update(&len_in_bytes.to_be_bytes());
+
+        if dst_len > MAX_DST_LEN {
+            let mut dst_hasher = blake3::Hasher::new();
+            dst_hasher.update(OVERSIZE_DST_SALT);
+            for dst in dsts {
+                dst_hasher.update(dst);
+            }
+            let mut dst = [0u8; HASHED_DST_LEN];
+            dst_hasher.finalize_xof().fill(&mut dst);
+            hasher.update(&dst);
+            hasher.update(&[HASHED_DST_LEN as u8]);
+        } else {
+            for dst in dsts {
+                hasher.update(dst);
+            }
+            hasher.update(&[dst_len as u8]);
+        }
+
+        let reader = hasher.finalize_xof();
+        Ok(Self { reader })
+    }
+}
+
+impl Expander for ExpandMsgBlake3 {
+    fn fill_bytes(&mut self, okm: &mut [u8]) {
+        self.reader.fill(okm);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Write;
+
+    use crate::suites::expand_message;
+
+    use super::ExpandMsgBlake3;
+
+    fn expand(msg: &[u8], dst: &[u8], len: usize) -> Vec<u8> {
+        let mut out = vec![0u8; len];
+        expand_message::<ExpandMsgBlake3>(msg, dst, &mut out).unwrap();
+        out
+    }
+
+    #[test]
+    fn test_expand_msg_blake3() {
+        // The output is the BLAKE3 XOF of msg || I2OSP(len, 2) || DST_prime.
+        let dst = b"QUUX-V01-CS02-with-expander-BLAKE3";
+        for (msg, len) in [
+            (&b""[..], 32),
+            (&b"abc"[..], 32),
+            (&b"abcdef0123456789"[..], 128),
+        ] {
+            let mut hasher = blake3::Hasher::new();
+            hasher.update(msg);
+            hasher.update(&(len as u16).to_be_bytes());
+            hasher.update(dst);
+            hasher.update(&[dst.len() as u8]);
+            let mut expected = vec![0u8; len];
+            hasher.finalize_xof().fill(&mut expected);
+
+            assert_eq!(expand(msg, dst, len), expected);
+        }
+
+        // Test vectors.
+        let test_cases = [
+            (
+                &b""[..],
+                "8af6e85253cf5028c3c9f4aacfa14f958945ddcdaab29fc916b27f34db901f61",
+            ),
+            (
+                &b"abc"[..],
+                "fea99e6c80ab5fe385d0440da242dda6e582851766b5258725f5c870f7039f9e",
+            ),
+        ];
+        for (msg, expected) in test_cases {
+            assert_eq!(to_hex(&expand(msg, dst, 32)), expected);
+        }
+
+        // Different lengths result in different outputs.
+        let a = expand(b"abc", dst, 32);
+        let b = expand(b"abc", dst, 64);
+        assert_ne!(a[..], b[..32]);
+
+        // Long domain separation tags are hashed.
+        let long_dst = [b'a'; 256];
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(b"H2C-OVERSIZE-DST-");
+        hasher.update(&long_dst);
+        let mut hashed_dst = [0u8; 32];
+        hasher.finalize_xof().fill(&mut hashed_dst);
+        assert_eq!(
+            expand(b"abc", &long_dst, 32),
+            expand(b"abc", &hashed_dst, 32)
+        );
+
+        // Invalid parameters.
+        let mut out = [0u8; 0];
+        expand_message::<ExpandMsgBlake3>(b"abc", dst, &mut out).expect_err("should fail");
+        let mut out = [0u8; 32];
+        expand_message::<ExpandMsgBlake3>(b"abc", b"", &mut out).expect_err("should fail");
+    }
+
+    fn to_hex(bytes: &[u8]) -> String {
+        bytes.iter().fold(String::new(), |mut s, b| {
+            let _ = write!(s, "{:02x}", b);
+            s
+        })
+    }
+}
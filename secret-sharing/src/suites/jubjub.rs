@@ -0,0 +1,133 @@
+use anyhow::{bail, Result};
+
+use elliptic_curve::hash2curve::ExpandMsgXmd;
+use group::{cofactor::CofactorGroup, Group};
+use jubjub::{AffinePoint, ExtendedPoint, Fr, SubgroupPoint};
+use zeroize::Zeroize;
+
+use super::{
+    expand_message,
+    zeroizable::{Point, Scalar},
+    FieldDigest, GroupDigest,
+};
+
+/// The maximum number of attempts made when hashing to the group.
+const MAX_HASH_TO_GROUP_ATTEMPTS: u8 = 128;
+
+/// The Jubjub group (the prime-order subgroup of the twisted Edwards curve
+/// defined over the scalar field of BLS12-381) with the BLAKE2b-512 hash
+/// function used to encode arbitrary-length byte strings to elements
+/// of the underlying prime field or group elements.
+///
+/// The shared secret is a Jubjub scalar and can therefore be used as key
+/// material inside Zcash-style circuits over BLS12-381.
+pub struct Blake2b;
+
+impl GroupDigest for Blake2b {
+    type Output = Point<SubgroupPoint>;
+
+    /// Hashes the message to a point in the prime-order subgroup using
+    /// the try-and-increment method followed by cofactor clearing.
+    ///
+    /// This method is not constant time as the number of attempts depends
+    /// on the message.
+    fn hash_to_group(msg: &[u8], dst: &[u8]) -> Result<Self::Output> {
+        let mut bytes = [0u8; 33];
+        expand_message::<ExpandMsgXmd<blake2::Blake2b512>>(msg, dst, &mut bytes[..32])?;
+
+        for ctr in 0..MAX_HASH_TO_GROUP_ATTEMPTS {
+            bytes[32] = ctr;
+            let mut candidate = [0u8; 32];
+            expand_message::<ExpandMsgXmd<blake2::Blake2b512>>(&bytes, dst, &mut candidate)?;
+
+            let p = match Option::<AffinePoint>::from(AffinePoint::from_bytes(candidate)) {
+                Some(p) => ExtendedPoint::from(p).clear_cofactor(),
+                None => continue,
+            };
+            if bool::from(p.is_identity()) {
+                continue;
+            }
+
+            return Ok(Point(p));
+        }
+
+        bail!("hash to group failed")
+    }
+}
+
+impl FieldDigest for Blake2b {
+    type Output = Scalar<Fr>;
+
+    fn hash_to_field(msg: &[u8], dst: &[u8]) -> Result<Self::Output> {
+        let mut bytes = [0u8; 64];
+        expand_message::<ExpandMsgXmd<blake2::Blake2b512>>(msg, dst, &mut bytes)?;
+        let s = Fr::from_bytes_wide(&bytes);
+        bytes.zeroize();
+        Ok(Scalar(s))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate test;
+
+    use self::test::Bencher;
+
+    use group::{Group, GroupEncoding};
+    use rand::{rngs::StdRng, RngCore, SeedableRng};
+
+    use crate::suites::{self, Suite};
+
+    use super::{Blake2b, FieldDigest, GroupDigest};
+
+    #[test]
+    fn test_hash_to_field() {
+        let a = Blake2b::hash_to_field(b"msg", b"dst").unwrap();
+        let b = Blake2b::hash_to_field(b"msg", b"dst").unwrap();
+        let c = Blake2b::hash_to_field(b"msg", b"other dst").unwrap();
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn test_hash_to_group() {
+        let a = Blake2b::hash_to_group(b"msg", b"dst").unwrap();
+        let b = Blake2b::hash_to_group(b"msg", b"dst").unwrap();
+        let c = Blake2b::hash_to_group(b"msg", b"other dst").unwrap();
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert!(!bool::from(a.is_identity()));
+
+        // Encoded points must decode back into the prime-order subgroup.
+        let bytes = a.to_bytes();
+        let p = <Blake2b as Suite>::Group::from_bytes(&bytes).unwrap();
+        assert_eq!(a, p);
+    }
+
+    #[test]
+    fn test_churp() {
+        suites::tests::test_churp::<Blake2b>();
+    }
+
+    #[bench]
+    fn bench_hash_to_field_jubjub_blake2b(b: &mut Bencher) {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let mut data = [0; 64];
+
+        b.iter(|| {
+            rng.fill_bytes(&mut data);
+            let _ = Blake2b::hash_to_field(&data[..32], &data[32..64]).unwrap();
+        });
+    }
+
+    #[bench]
+    fn bench_hash_to_group_jubjub_blake2b(b: &mut Bencher) {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let mut data = [0; 64];
+
+        b.iter(|| {
+            rng.fill_bytes(&mut data);
+            let _ = Blake2b::hash_to_group(&data[..32], &data[32..64]).unwrap();
+        });
+    }
+}
@@ -0,0 +1,84 @@
+use anyhow::Result;
+
+use k256::{
+    elliptic_curve::hash2curve::{ExpandMsgXmd, GroupDigest as _},
+    ProjectivePoint, Scalar, Secp256k1,
+};
+
+use super::{FieldDigest, GroupDigest};
+
+/// The secp256k1 elliptic curve group with the SHA3-256 hash function used
+/// to encode arbitrary-length byte strings to elements of the underlying prime
+/// field or elliptic curve points.
+pub struct Sha3_256;
+
+impl GroupDigest for Sha3_256 {
+    type Output = ProjectivePoint;
+
+    fn hash_to_group(msg: &[u8], dst: &[u8]) -> Result<Self::Output> {
+        let msgs = [msg];
+        let dsts = [dst];
+        let p = Secp256k1::hash_from_bytes::<ExpandMsgXmd<sha3::Sha3_256>>(&msgs, &dsts)?;
+        Ok(p)
+    }
+}
+
+impl FieldDigest for Sha3_256 {
+    type Output = Scalar;
+
+    fn hash_to_field(msg: &[u8], dst: &[u8]) -> Result<Self::Output> {
+        let msgs = [msg];
+        let dsts = [dst];
+        let s = Secp256k1::hash_to_scalar::<ExpandMsgXmd<sha3::Sha3_256>>(&msgs, &dsts)?;
+        Ok(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate test;
+
+    use self::test::Bencher;
+
+    use rand::{rngs::StdRng, RngCore, SeedableRng};
+
+    use crate::suites;
+
+    use super::{FieldDigest, GroupDigest, Sha3_256};
+
+    #[test]
+    fn test_hash_to_field() {
+        let a = Sha3_256::hash_to_field(b"msg", b"dst").unwrap();
+        let b = Sha3_256::hash_to_field(b"msg", b"dst").unwrap();
+        let c = Sha3_256::hash_to_field(b"msg", b"other dst").unwrap();
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn test_churp() {
+        suites::tests::test_churp::<Sha3_256>();
+    }
+
+    #[bench]
+    fn bench_hash_to_field_k256_sha3_256(b: &mut Bencher) {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let mut data = [0; 64];
+
+        b.iter(|| {
+            rng.fill_bytes(&mut data);
+            let _ = Sha3_256::hash_to_field(&data[..32], &data[32..64]).unwrap();
+        });
+    }
+
+    #[bench]
+    fn bench_hash_to_group_k256_sha3_256(b: &mut Bencher) {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let mut data = [0; 64];
+
+        b.iter(|| {
+            rng.fill_bytes(&mut data);
+            let _ = Sha3_256::hash_to_group(&data[..32], &data[32..64]).unwrap();
+        });
+    }
+}
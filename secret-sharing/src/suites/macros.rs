@@ -0,0 +1,191 @@
+//! Macros for defining custom suites.
+
+/// Defines a new suite, i.e. a type implementing [`FieldDigest`]
+/// and [`GroupDigest`], and consequently [`Suite`].
+///
+/// Suites over curves implementing RFC 9380 hash-to-curve in the
+/// `elliptic-curve` crate only need the curve and the `expand_message`
+/// variant:
+///
+/// ```
+/// use p384::{elliptic_curve::hash2curve::ExpandMsgXmd, NistP384};
+/// use secret_sharing::{churp::encode_shareholder, define_suite};
+///
+/// define_suite! {
+///     /// The NIST P-384 suite with the SHA-512 hash function.
+///     pub P384Sha512,
+///     curve = NistP384,
+///     expander = ExpandMsgXmd<sha2::Sha512>,
+/// }
+///
+/// let x = encode_shareholder::<P384Sha512>(b"shareholder", b"dst").unwrap();
+/// ```
+///
+/// Other suites can be wired from a prime field, a group and functions
+/// hashing to them, with the signatures of [`FieldDigest::hash_to_field`]
+/// and [`GroupDigest::hash_to_group`], respectively:
+///
+/// ```
+/// use anyhow::Result;
+/// use p384::{ProjectivePoint, Scalar};
+/// use secret_sharing::{define_suite, suites::{p384::Sha3_384, FieldDigest, GroupDigest}};
+///
+/// fn hash_to_field(msg: &[u8], dst: &[u8]) -> Result<Scalar> {
+///     Sha3_384::hash_to_field(msg, &[b"custom-", dst].concat())
+/// }
+///
+/// fn hash_to_group(msg: &[u8], dst: &[u8]) -> Result<ProjectivePoint> {
+///     Sha3_384::hash_to_group(msg, &[b"custom-", dst].concat())
+/// }
+///
+/// define_suite! {
+///     pub Custom,
+///     field = Scalar,
+///     group = ProjectivePoint,
+///     hash_to_field = hash_to_field,
+///     hash_to_group = hash_to_group,
+/// }
+/// ```
+///
+/// [`FieldDigest`]: crate::suites::FieldDigest
+/// [`FieldDigest::hash_to_field`]: crate::suites::FieldDigest::hash_to_field
+/// [`GroupDigest`]: crate::suites::GroupDigest
+/// [`GroupDigest::hash_to_group`]: crate::suites::GroupDigest::hash_to_group
+/// [`Suite`]: crate::suites::Suite
+#[macro_export]
+macro_rules! define_suite {
+    (
+        $(#[$attr:meta])*
+        $vis:vis $name:ident,
+        curve = $curve:ty,
+        expander = $expander:ty $(,)?
+    ) => {
+        $(#[$attr])*
+        $vis struct $name;
+
+        impl $crate::suites::GroupDigest for $name {
+            type Output = <$curve as $crate::suites::__private::CurveArithmetic>::ProjectivePoint;
+
+            fn hash_to_group(
+                msg: &[u8],
+                dst: &[u8],
+            ) -> $crate::suites::__private::Result<Self::Output> {
+                let msgs = [msg];
+                let dsts = [dst];
+                let p = <$curve as $crate::suites::__private::GroupDigest>::hash_from_bytes::<
+                    $expander,
+                >(&msgs, &dsts)?;
+                Ok(p)
+            }
+        }
+
+        impl $crate::suites::FieldDigest for $name {
+            type Output = <$curve as $crate::suites::__private::CurveArithmetic>::Scalar;
+
+            fn hash_to_field(
+                msg: &[u8],
+                dst: &[u8],
+            ) -> $crate::suites::__private::Result<Self::Output> {
+                let msgs = [msg];
+                let dsts = [dst];
+                let s = <$curve as $crate::suites::__private::GroupDigest>::hash_to_scalar::<
+                    $expander,
+                >(&msgs, &dsts)?;
+                Ok(s)
+            }
+        }
+    };
+    (
+        $(#[$attr:meta])*
+        $vis:vis $name:ident,
+        field = $field:ty,
+        group = $group:ty,
+        hash_to_field = $hash_to_field:path,
+        hash_to_group = $hash_to_group:path $(,)?
+    ) => {
+        $(#[$attr])*
+        $vis struct $name;
+
+        impl $crate::suites::GroupDigest for $name {
+            type Output = $group;
+
+            fn hash_to_group(
+                msg: &[u8],
+                dst: &[u8],
+            ) -> $crate::suites::__private::Result<Self::Output> {
+                $hash_to_group(msg, dst)
+            }
+        }
+
+        impl $crate::suites::FieldDigest for $name {
+            type Output = $field;
+
+            fn hash_to_field(
+                msg: &[u8],
+                dst: &[u8],
+            ) -> $crate::suites::__private::Result<Self::Output> {
+                $hash_to_field(msg, dst)
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use p384::{elliptic_curve::hash2curve::ExpandMsgXmd, NistP384, ProjectivePoint, Scalar};
+
+    use crate::{
+        churp::encode_shareholder,
+        suites::{p384::Sha3_384, FieldDigest, GroupDigest, Suite},
+    };
+
+    define_suite! {
+        /// The NIST P-384 suite with the SHA3-384 hash function.
+        P384Sha3_384,
+        curve = NistP384,
+        expander = ExpandMsgXmd<sha3::Sha3_384>,
+    }
+
+    fn hash_to_field(msg: &[u8], dst: &[u8]) -> Result<Scalar> {
+        Sha3_384::hash_to_field(msg, dst)
+    }
+
+    fn hash_to_group(msg: &[u8], dst: &[u8]) -> Result<ProjectivePoint> {
+        Sha3_384::hash_to_group(msg, dst)
+    }
+
+    define_suite! {
+        Wrapped,
+        field = Scalar,
+        group = ProjectivePoint,
+        hash_to_field = hash_to_field,
+        hash_to_group = hash_to_group,
+    }
+
+    fn assert_suite<S: Suite>() {}
+
+    #[test]
+    fn test_define_suite() {
+        assert_suite::<P384Sha3_384>();
+        assert_suite::<Wrapped>();
+
+        let expected = Sha3_384::hash_to_field(b"msg", b"dst").unwrap();
+        assert_eq!(
+            P384Sha3_384::hash_to_field(b"msg", b"dst").unwrap(),
+            expected
+        );
+        assert_eq!(Wrapped::hash_to_field(b"msg", b"dst").unwrap(), expected);
+
+        let expected = Sha3_384::hash_to_group(b"msg", b"dst").unwrap();
+        assert_eq!(
+            P384Sha3_384::hash_to_group(b"msg", b"dst").unwrap(),
+            expected
+        );
+        assert_eq!(Wrapped::hash_to_group(b"msg", b"dst").unwrap(), expected);
+
+        let expected = encode_shareholder::<Sha3_384>(b"shareholder", b"dst").unwrap();
+        let x = encode_shareholder::<P384Sha3_384>(b"shareholder", b"dst").unwrap();
+        assert_eq!(x, expected);
+    }
+}
@@ -1,9 +1,44 @@
-use anyhow::Result;
+use anyhow::{bail, Result};
 
+use elliptic_curve::hash2curve::{ExpandMsg, Expander};
 use group::{ff::PrimeField, Group, GroupEncoding};
+use subtle::ConditionallySelectable;
 use zeroize::Zeroize;
 
+use crate::churp::encode_shareholders;
+
+#[cfg(feature = "bls12_381_g1")]
+pub mod bls12_381_g1;
+#[cfg(feature = "bn254")]
+pub mod bn254;
+#[cfg(feature = "ed25519")]
+pub mod ed25519;
+#[cfg(feature = "blake3")]
+pub mod expand_msg;
+#[cfg(feature = "jubjub")]
+pub mod jubjub;
+#[cfg(feature = "k256")]
+pub mod k256;
+mod macros;
+#[cfg(feature = "p256")]
+pub mod p256;
 pub mod p384;
+#[cfg(feature = "p521")]
+pub mod p521;
+#[cfg(feature = "pasta")]
+pub mod pallas;
+#[cfg(feature = "ristretto255")]
+pub mod ristretto255;
+#[cfg(feature = "pasta")]
+pub mod vesta;
+pub mod zeroizable;
+
+/// Re-exports used by the [`define_suite`](crate::define_suite) macro.
+#[doc(hidden)]
+pub mod __private {
+    pub use anyhow::Result;
+    pub use elliptic_curve::{hash2curve::GroupDigest, CurveArithmetic};
+}
 
 /// A trait for hashing arbitrary-length byte strings to elements of a prime field.
 pub trait FieldDigest {
@@ -23,27 +58,290 @@ pub trait GroupDigest {
     /// Hashes an arbitrary-length byte string to an element of the group
     /// using the given message and domain separation tag.
     fn hash_to_group(msg: &[u8], dst: &[u8]) -> Result<Self::Output>;
+
+    /// Validates an element decoded by [`Suite::group_from_bytes`], e.g. to
+    /// reject elements outside of the prime-order subgroup.
+    ///
+    /// Suites whose group decoding only yields elements of the prime-order
+    /// subgroup don't need to override this method.
+    fn validate_group_element(_p: &Self::Output) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// A field-only cipher suite containing a prime field, along with
+/// a matching field digest.
+///
+/// Field-only suites are sufficient for schemes that don't need
+/// commitments or verification matrices, e.g. pure-MPC additive or
+/// Shamir secret sharing, and can therefore be instantiated without
+/// a cryptographic group.
+pub trait FieldSuite: FieldDigest<Output = Self::Field> {
+    /// The type representing an element of the prime field.
+    type Field: PrimeField + Zeroize;
+
+    /// Encodes the given shareholder IDs to non-zero elements of the prime
+    /// field, as done by [`encode_shareholder`](crate::churp::encode_shareholder)
+    /// for a single ID.
+    ///
+    /// Fails if any of the IDs cannot be encoded.
+    fn encode_shareholders<I: AsRef<[u8]>>(ids: &[I], dst: &[u8]) -> Result<Vec<Self::Field>>
+    where
+        Self: Sized,
+    {
+        encode_shareholders::<Self, I>(ids, dst)
+    }
+
+    /// Returns the canonical byte representation of the given element
+    /// of the prime field.
+    fn scalar_to_bytes(s: &Self::Field) -> Vec<u8> {
+        s.to_repr().as_ref().to_vec()
+    }
+
+    /// Decodes an element of the prime field from its canonical byte
+    /// representation, rejecting inputs of invalid length and
+    /// non-canonical encodings.
+    fn scalar_from_bytes(bytes: &[u8]) -> Result<Self::Field> {
+        let mut repr = <Self::Field as PrimeField>::Repr::default();
+        if repr.as_ref().len() != bytes.len() {
+            bail!("invalid scalar length");
+        }
+        repr.as_mut().copy_from_slice(bytes);
+
+        let s: Option<Self::Field> = Self::Field::from_repr(repr).into();
+        match s {
+            Some(s) if s.to_repr().as_ref() == bytes => Ok(s),
+            _ => bail!("non-canonical scalar encoding"),
+        }
+    }
+}
+
+impl<S> FieldSuite for S
+where
+    S: FieldDigest,
+    <S as FieldDigest>::Output: Zeroize,
+{
+    type Field = <S as FieldDigest>::Output;
 }
 
 /// A cipher suite containing a cryptographic group, along with matching field
 /// and group digests.
+///
+/// Besides encoding shareholder identities to elements of the prime field
+/// via [`FieldDigest::hash_to_field`], every suite can map arbitrary messages
+/// to elements of its group via [`GroupDigest::hash_to_group`], so that
+/// higher-level protocols (e.g. key derivation, PVSS or threshold BLS) map
+/// messages to group elements consistently per suite.
+///
+/// Unless documented otherwise by the suite, group hashing follows
+/// the random-oracle `hash_to_curve` encoding from RFC 9380 using
+/// the suite's `expand_message` variant. Suites over curves for which
+/// RFC 9380 defines no suite, e.g. BN254, document their own encoding.
 pub trait Suite:
-    FieldDigest<Output = Self::PrimeField> + GroupDigest<Output = Self::Group>
+    FieldSuite<Field = Self::PrimeField>
+    + FieldDigest<Output = Self::PrimeField>
+    + GroupDigest<Output = Self::Group>
 {
     /// The type representing an element modulo the order of the group.
     type PrimeField: PrimeField + Zeroize;
 
     /// The type representing an element of a cryptographic group.
-    type Group: Group<Scalar = Self::PrimeField> + GroupEncoding + Zeroize;
+    type Group: Group<Scalar = Self::PrimeField> + GroupEncoding + ConditionallySelectable + Zeroize;
+
+    /// Returns the canonical byte representation of the given element
+    /// of the group.
+    fn group_to_bytes(p: &Self::Group) -> Vec<u8> {
+        p.to_bytes().as_ref().to_vec()
+    }
+
+    /// Decodes an element of the group from its canonical byte
+    /// representation, rejecting inputs of invalid length and
+    /// non-canonical encodings.
+    ///
+    /// Since not all group implementations reject non-canonical encodings,
+    /// decoded elements are re-encoded and compared to the input, and then
+    /// validated using [`GroupDigest::validate_group_element`].
+    fn group_from_bytes(bytes: &[u8]) -> Result<Self::Group> {
+        let mut repr = <Self::Group as GroupEncoding>::Repr::default();
+        if repr.as_ref().len() != bytes.len() {
+            bail!("invalid group element length");
+        }
+        repr.as_mut().copy_from_slice(bytes);
+
+        let p: Option<Self::Group> = Self::Group::from_bytes(&repr).into();
+        match p {
+            Some(p) if p.to_bytes().as_ref() == bytes => {
+                Self::validate_group_element(&p)?;
+                Ok(p)
+            }
+            _ => bail!("non-canonical group element encoding"),
+        }
+    }
 }
 
 impl<S> Suite for S
 where
     S: FieldDigest + GroupDigest,
     <S as GroupDigest>::Output: Group<Scalar = <S as FieldDigest>::Output> + GroupEncoding,
-    <S as GroupDigest>::Output: Zeroize,
+    <S as GroupDigest>::Output: ConditionallySelectable + Zeroize,
     <S as FieldDigest>::Output: Zeroize,
 {
     type PrimeField = <S as FieldDigest>::Output;
     type Group = <S as GroupDigest>::Output;
 }
+
+/// Fills the given buffer with uniformly random bytes derived from
+/// the message and domain separation tag using the given `expand_message`
+/// variant from RFC 9380.
+pub fn expand_message<E>(msg: &[u8], dst: &[u8], out: &mut [u8]) -> Result<()>
+where
+    E: for<'a> ExpandMsg<'a>,
+{
+    let msgs = [msg];
+    let dsts = [dst];
+    let mut expander = E::expand_message(&msgs, &dsts, out.len())?;
+    expander.fill_bytes(out);
+    Ok(())
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use group::Group;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::churp::{encode_shareholder, HandoffKind, VerifiableSecretShare};
+
+    use crate::shamir::{Dealer, Player};
+
+    use super::{p384, FieldDigest, FieldSuite, GroupDigest, Suite};
+
+    /// Verifies shares of a random bivariate polynomial dealt over
+    /// the group of the given suite.
+    pub(crate) fn test_churp<S: Suite>() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let threshold = 2;
+        let dealer = crate::churp::Dealer::<S::Group>::new(threshold, &mut rng).unwrap();
+        let x = encode_shareholder::<S>(b"shareholder", b"dst").unwrap();
+
+        for (kind, full_share) in [
+            (HandoffKind::DealingPhase, true),
+            (HandoffKind::CommitteeChanged, false),
+        ] {
+            let share = dealer.make_share(x, kind);
+            let vm = dealer.verification_matrix().clone();
+            let verifiable_share = VerifiableSecretShare::new(share, vm);
+            verifiable_share
+                .verify(threshold, false, full_share)
+                .expect("share should be valid");
+        }
+    }
+
+    /// A field-only suite without a cryptographic group.
+    struct FieldOnly;
+
+    impl FieldDigest for FieldOnly {
+        type Output = <p384::Sha3_384 as FieldDigest>::Output;
+
+        fn hash_to_field(msg: &[u8], dst: &[u8]) -> anyhow::Result<Self::Output> {
+            p384::Sha3_384::hash_to_field(msg, dst)
+        }
+    }
+
+    #[test]
+    fn test_field_suite() {
+        fn share<S: FieldSuite>(secret: S::Field) -> S::Field {
+            let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+            let threshold = 2;
+            let ids = [b"a", b"b", b"c"];
+            let xs = S::encode_shareholders(&ids, b"dst").unwrap();
+            let dealer = Dealer::new(threshold, secret, &mut rng);
+            let shares = dealer.make_shares(xs);
+            let player = Player::new(threshold);
+            player.recover_secret(&shares).unwrap()
+        }
+
+        let secret = FieldOnly::hash_to_field(b"secret", b"dst").unwrap();
+        assert_eq!(share::<FieldOnly>(secret), secret);
+        assert_eq!(share::<p384::Sha3_384>(secret), secret);
+
+        let bytes = FieldOnly::scalar_to_bytes(&secret);
+        assert_eq!(FieldOnly::scalar_from_bytes(&bytes).unwrap(), secret);
+    }
+
+    #[test]
+    fn test_encode_shareholders() {
+        type S = p384::Sha3_384;
+
+        let ids = [b"a".to_vec(), b"b".to_vec(), b"c".to_vec()];
+        let xs = S::encode_shareholders(&ids, b"dst").unwrap();
+        assert_eq!(xs.len(), ids.len());
+        for (id, x) in ids.iter().zip(&xs) {
+            assert_eq!(*x, encode_shareholder::<S>(id, b"dst").unwrap());
+        }
+
+        let ids: [&[u8]; 0] = [];
+        assert!(S::encode_shareholders(&ids, b"dst").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_scalar_codec() {
+        type S = p384::Sha3_384;
+
+        let s = S::hash_to_field(b"msg", b"dst").unwrap();
+        let bytes = S::scalar_to_bytes(&s);
+        assert_eq!(bytes.len(), 48);
+        assert_eq!(S::scalar_from_bytes(&bytes).unwrap(), s);
+
+        // Invalid length.
+        S::scalar_from_bytes(&bytes[1..]).expect_err("decoding should fail");
+        S::scalar_from_bytes(&[bytes.clone(), vec![0]].concat()).expect_err("decoding should fail");
+
+        // Non-canonical encoding (larger than the modulus).
+        S::scalar_from_bytes(&[0xff; 48]).expect_err("decoding should fail");
+
+        let zero = <S as Suite>::PrimeField::ZERO;
+        let bytes = S::scalar_to_bytes(&zero);
+        assert_eq!(S::scalar_from_bytes(&bytes).unwrap(), zero);
+    }
+
+    #[test]
+    fn test_group_codec() {
+        type S = p384::Sha3_384;
+
+        let p = S::hash_to_group(b"msg", b"dst").unwrap();
+        let bytes = S::group_to_bytes(&p);
+        assert_eq!(S::group_from_bytes(&bytes).unwrap(), p);
+
+        // Invalid length.
+        S::group_from_bytes(&bytes[1..]).expect_err("decoding should fail");
+
+        // Invalid encoding.
+        let mut invalid = bytes.clone();
+        invalid[0] = 0xff;
+        S::group_from_bytes(&invalid).expect_err("decoding should fail");
+
+        let identity = <S as Suite>::Group::identity();
+        let bytes = S::group_to_bytes(&identity);
+        assert_eq!(S::group_from_bytes(&bytes).unwrap(), identity);
+    }
+
+    #[cfg(feature = "ed25519")]
+    #[test]
+    fn test_group_codec_non_canonical() {
+        use super::ed25519;
+
+        type S = ed25519::Sha512;
+
+        // The identity point encoded canonically (y = 1) and non-canonically
+        // (y = p + 1).
+        let mut canonical = [0u8; 32];
+        canonical[0] = 1;
+        let mut non_canonical = [0xff; 32];
+        non_canonical[0] = 0xee;
+        non_canonical[31] = 0x7f;
+
+        let identity = <S as Suite>::Group::identity();
+        assert_eq!(S::group_from_bytes(&canonical).unwrap(), identity);
+        S::group_from_bytes(&non_canonical).expect_err("decoding should fail");
+    }
+}
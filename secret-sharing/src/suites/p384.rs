@@ -5,6 +5,8 @@ use p384::{
     NistP384, ProjectivePoint, Scalar,
 };
 
+#[cfg(feature = "blake3")]
+use super::expand_msg::ExpandMsgBlake3;
 use super::{FieldDigest, GroupDigest};
 
 /// The NIST P-384 elliptic curve group with the SHA3-384 hash function used
@@ -34,15 +36,144 @@ impl FieldDigest for Sha3_384 {
     }
 }
 
+/// The NIST P-384 elliptic curve group with the SHA-512 hash function used
+/// to encode arbitrary-length byte strings to elements of the underlying prime
+/// field or elliptic curve points.
+///
+/// This variant is intended for environments where SHA-2 is hardware
+/// accelerated and SHA-3 is not. Encodings are not compatible with those
+/// of [`Sha3_384`].
+pub struct Sha512;
+
+impl GroupDigest for Sha512 {
+    type Output = ProjectivePoint;
+
+    fn hash_to_group(msg: &[u8], dst: &[u8]) -> Result<Self::Output> {
+        let msgs = [msg];
+        let dsts = [dst];
+        let p = NistP384::hash_from_bytes::<ExpandMsgXmd<sha2::Sha512>>(&msgs, &dsts)?;
+        Ok(p)
+    }
+}
+
+impl FieldDigest for Sha512 {
+    type Output = Scalar;
+
+    fn hash_to_field(msg: &[u8], dst: &[u8]) -> Result<Self::Output> {
+        let msgs = [msg];
+        let dsts = [dst];
+        let s = NistP384::hash_to_scalar::<ExpandMsgXmd<sha2::Sha512>>(&msgs, &dsts)?;
+        Ok(s)
+    }
+}
+
+/// The NIST P-384 elliptic curve group with the BLAKE3 extendable-output
+/// function used to encode arbitrary-length byte strings to elements
+/// of the underlying prime field or elliptic curve points.
+///
+/// This variant is intended for fast batch encoding of shareholders
+/// in large committees. Encodings are not compatible with those
+/// of [`Sha3_384`].
+#[cfg(feature = "blake3")]
+pub struct Blake3;
+
+#[cfg(feature = "blake3")]
+impl GroupDigest for Blake3 {
+    type Output = ProjectivePoint;
+
+    fn hash_to_group(msg: &[u8], dst: &[u8]) -> Result<Self::Output> {
+        let msgs = [msg];
+        let dsts = [dst];
+        let p = NistP384::hash_from_bytes::<ExpandMsgBlake3>(&msgs, &dsts)?;
+        Ok(p)
+    }
+}
+
+#[cfg(feature = "blake3")]
+impl FieldDigest for Blake3 {
+    type Output = Scalar;
+
+    fn hash_to_field(msg: &[u8], dst: &[u8]) -> Result<Self::Output> {
+        let msgs = [msg];
+        let dsts = [dst];
+        let s = NistP384::hash_to_scalar::<ExpandMsgBlake3>(&msgs, &dsts)?;
+        Ok(s)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     extern crate test;
 
     use self::test::Bencher;
 
+    #[cfg(feature = "blake3")]
+    use std::fmt::Write;
+
     use rand::{rngs::StdRng, RngCore, SeedableRng};
 
-    use super::{FieldDigest, GroupDigest, Sha3_384};
+    #[cfg(feature = "blake3")]
+    use super::Blake3;
+    use super::{FieldDigest, GroupDigest, Sha3_384, Sha512};
+
+    #[test]
+    fn test_sha512_variant() {
+        let a = Sha512::hash_to_field(b"msg", b"dst").unwrap();
+        let b = Sha512::hash_to_field(b"msg", b"dst").unwrap();
+        let c = Sha3_384::hash_to_field(b"msg", b"dst").unwrap();
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+
+        let a = Sha512::hash_to_group(b"msg", b"dst").unwrap();
+        let b = Sha512::hash_to_group(b"msg", b"dst").unwrap();
+        let c = Sha3_384::hash_to_group(b"msg", b"dst").unwrap();
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    #[cfg(feature = "blake3")]
+    fn test_blake3_variant() {
+        let a = Blake3::hash_to_field(b"msg", b"dst").unwrap();
+        let b = Blake3::hash_to_field(b"msg", b"dst").unwrap();
+        let c = Blake3::hash_to_field(b"msg", b"other dst").unwrap();
+        let d = Sha3_384::hash_to_field(b"msg", b"dst").unwrap();
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert_ne!(a, d);
+
+        // Test vectors.
+        let test_cases = [
+            (
+                &b"shareholder 1"[..],
+                concat!(
+                    "282cb97697ae8748c702cb0cdca7b5a9823815c84f7e45d2",
+                    "dd7f690494fccfc30da2ba6cd3b25f9182a38a0e7a940cf1"
+                ),
+            ),
+            (
+                &b"shareholder 2"[..],
+                concat!(
+                    "b267d422de67a78981cfb86df32415c12758f005e943bd2c",
+                    "15d2c6875360ddbd5ffb55081dabbb07c90276cd12029398"
+                ),
+            ),
+        ];
+        for (msg, expected) in test_cases {
+            let s = Blake3::hash_to_field(msg, b"churp-p384-blake3").unwrap();
+            let hex = s.to_bytes().iter().fold(String::new(), |mut hex, b| {
+                let _ = write!(hex, "{:02x}", b);
+                hex
+            });
+            assert_eq!(hex, expected);
+        }
+
+        let a = Blake3::hash_to_group(b"msg", b"dst").unwrap();
+        let b = Blake3::hash_to_group(b"msg", b"dst").unwrap();
+        let c = Blake3::hash_to_group(b"msg", b"other dst").unwrap();
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
 
     #[bench]
     fn bench_hash_to_field_p384_sha3_384(b: &mut Bencher) {
@@ -65,4 +196,50 @@ mod tests {
             let _ = Sha3_384::hash_to_group(&data[..32], &data[32..64]).unwrap();
         });
     }
+
+    #[bench]
+    fn bench_hash_to_field_p384_sha512(b: &mut Bencher) {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let mut data = [0; 64];
+
+        b.iter(|| {
+            rng.fill_bytes(&mut data);
+            let _ = Sha512::hash_to_field(&data[..32], &data[32..64]).unwrap();
+        });
+    }
+
+    #[bench]
+    fn bench_hash_to_group_p384_sha512(b: &mut Bencher) {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let mut data = [0; 64];
+
+        b.iter(|| {
+            rng.fill_bytes(&mut data);
+            let _ = Sha512::hash_to_group(&data[..32], &data[32..64]).unwrap();
+        });
+    }
+
+    #[bench]
+    #[cfg(feature = "blake3")]
+    fn bench_hash_to_field_p384_blake3(b: &mut Bencher) {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let mut data = [0; 64];
+
+        b.iter(|| {
+            rng.fill_bytes(&mut data);
+            let _ = Blake3::hash_to_field(&data[..32], &data[32..64]).unwrap();
+        });
+    }
+
+    #[bench]
+    #[cfg(feature = "blake3")]
+    fn bench_hash_to_group_p384_blake3(b: &mut Bencher) {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let mut data = [0; 64];
+
+        b.iter(|| {
+            rng.fill_bytes(&mut data);
+            let _ = Blake3::hash_to_group(&data[..32], &data[32..64]).unwrap();
+        });
+    }
 }
@@ -0,0 +1,118 @@
+use anyhow::Result;
+
+use p521::{
+    elliptic_curve::hash2curve::{ExpandMsgXmd, GroupDigest as _},
+    NistP521, ProjectivePoint, Scalar,
+};
+
+use super::{FieldDigest, GroupDigest};
+
+/// The NIST P-521 elliptic curve group with the SHA3-512 hash function used
+/// to encode arbitrary-length byte strings to elements of the underlying prime
+/// field or elliptic curve points.
+pub struct Sha3_512;
+
+impl GroupDigest for Sha3_512 {
+    type Output = ProjectivePoint;
+
+    fn hash_to_group(msg: &[u8], dst: &[u8]) -> Result<Self::Output> {
+        let msgs = [msg];
+        let dsts = [dst];
+        let p = NistP521::hash_from_bytes::<ExpandMsgXmd<sha3::Sha3_512>>(&msgs, &dsts)?;
+        Ok(p)
+    }
+}
+
+impl FieldDigest for Sha3_512 {
+    type Output = Scalar;
+
+    fn hash_to_field(msg: &[u8], dst: &[u8]) -> Result<Self::Output> {
+        let msgs = [msg];
+        let dsts = [dst];
+        let s = NistP521::hash_to_scalar::<ExpandMsgXmd<sha3::Sha3_512>>(&msgs, &dsts)?;
+        Ok(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate test;
+
+    use self::test::Bencher;
+
+    use rand::{rngs::StdRng, RngCore, SeedableRng};
+
+    use crate::{
+        churp::{encode_shareholder, Dealer, HandoffKind},
+        suites::{self, p384, Suite},
+    };
+
+    use super::{FieldDigest, GroupDigest, Sha3_512};
+
+    #[test]
+    fn test_hash_to_field() {
+        let a = Sha3_512::hash_to_field(b"msg", b"dst").unwrap();
+        let b = Sha3_512::hash_to_field(b"msg", b"dst").unwrap();
+        let c = Sha3_512::hash_to_field(b"msg", b"other dst").unwrap();
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn test_churp() {
+        suites::tests::test_churp::<Sha3_512>();
+    }
+
+    #[test]
+    fn test_same_as_p384() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+
+        for threshold in [0, 1, 2, 5] {
+            let dealer = Dealer::<p521::ProjectivePoint>::new(threshold, &mut rng).unwrap();
+            let p384_dealer =
+                Dealer::<<p384::Sha3_384 as Suite>::Group>::new(threshold, &mut rng).unwrap();
+
+            // Matrix dimensions should match.
+            assert_eq!(
+                dealer.verification_matrix().dimensions(),
+                p384_dealer.verification_matrix().dimensions()
+            );
+
+            // Share degrees should match.
+            let x = encode_shareholder::<Sha3_512>(b"shareholder", b"dst").unwrap();
+            let p384_x = encode_shareholder::<p384::Sha3_384>(b"shareholder", b"dst").unwrap();
+
+            for kind in [
+                HandoffKind::DealingPhase,
+                HandoffKind::CommitteeUnchanged,
+                HandoffKind::CommitteeChanged,
+            ] {
+                let share = dealer.make_share(x, kind);
+                let p384_share = p384_dealer.make_share(p384_x, kind);
+                assert_eq!(share.polynomial().size(), p384_share.polynomial().size());
+            }
+        }
+    }
+
+    #[bench]
+    fn bench_hash_to_field_p521_sha3_512(b: &mut Bencher) {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let mut data = [0; 64];
+
+        b.iter(|| {
+            rng.fill_bytes(&mut data);
+            let _ = Sha3_512::hash_to_field(&data[..32], &data[32..64]).unwrap();
+        });
+    }
+
+    #[bench]
+    fn bench_hash_to_group_p521_sha3_512(b: &mut Bencher) {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let mut data = [0; 64];
+
+        b.iter(|| {
+            rng.fill_bytes(&mut data);
+            let _ = Sha3_512::hash_to_group(&data[..32], &data[32..64]).unwrap();
+        });
+    }
+}
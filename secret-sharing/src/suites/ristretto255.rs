@@ -0,0 +1,86 @@
+use anyhow::Result;
+
+use curve25519_dalek::{RistrettoPoint, Scalar};
+use elliptic_curve::hash2curve::ExpandMsgXmd;
+
+use super::{expand_message, FieldDigest, GroupDigest};
+
+/// The ristretto255 group with the SHA-512 hash function used to encode
+/// arbitrary-length byte strings to elements of the underlying prime field
+/// or group elements.
+///
+/// Group elements are derived as specified by the `hash_to_ristretto255`
+/// function in RFC 9496, i.e., 64 bytes obtained from `expand_message_xmd`
+/// are mapped to the group using the one-way map.
+pub struct Sha512;
+
+impl GroupDigest for Sha512 {
+    type Output = RistrettoPoint;
+
+    fn hash_to_group(msg: &[u8], dst: &[u8]) -> Result<Self::Output> {
+        let mut bytes = [0u8; 64];
+        expand_message::<ExpandMsgXmd<sha2::Sha512>>(msg, dst, &mut bytes)?;
+        let p = RistrettoPoint::from_uniform_bytes(&bytes);
+        Ok(p)
+    }
+}
+
+impl FieldDigest for Sha512 {
+    type Output = Scalar;
+
+    fn hash_to_field(msg: &[u8], dst: &[u8]) -> Result<Self::Output> {
+        let mut bytes = [0u8; 64];
+        expand_message::<ExpandMsgXmd<sha2::Sha512>>(msg, dst, &mut bytes)?;
+        let s = Scalar::from_bytes_mod_order_wide(&bytes);
+        Ok(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate test;
+
+    use self::test::Bencher;
+
+    use rand::{rngs::StdRng, RngCore, SeedableRng};
+
+    use crate::suites;
+
+    use super::{FieldDigest, GroupDigest, Sha512};
+
+    #[test]
+    fn test_hash_to_field() {
+        let a = Sha512::hash_to_field(b"msg", b"dst").unwrap();
+        let b = Sha512::hash_to_field(b"msg", b"dst").unwrap();
+        let c = Sha512::hash_to_field(b"msg", b"other dst").unwrap();
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn test_churp() {
+        suites::tests::test_churp::<Sha512>();
+    }
+
+    #[bench]
+    fn bench_hash_to_field_ristretto255_sha512(b: &mut Bencher) {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let mut data = [0; 64];
+
+        b.iter(|| {
+            rng.fill_bytes(&mut data);
+            let _ = Sha512::hash_to_field(&data[..32], &data[32..64]).unwrap();
+        });
+    }
+
+    #[bench]
+    fn bench_hash_to_group_ristretto255_sha512(b: &mut Bencher) {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let mut data = [0; 64];
+
+        b.iter(|| {
+            rng.fill_bytes(&mut data);
+            let _ = Sha512::hash_to_group(&data[..32], &data[32..64]).unwrap();
+        });
+    }
+}
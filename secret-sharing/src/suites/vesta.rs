@@ -0,0 +1,100 @@
+use anyhow::Result;
+
+use elliptic_curve::hash2curve::ExpandMsgXmd;
+use group::ff::FromUniformBytes;
+use pasta_curves::{arithmetic::CurveExt, vesta};
+
+use super::{
+    expand_message,
+    zeroizable::{Point, Scalar},
+    FieldDigest, GroupDigest,
+};
+
+/// The Vesta elliptic curve group with the BLAKE2b-512 hash function used
+/// to encode arbitrary-length byte strings to elements of the underlying prime
+/// field or elliptic curve points.
+///
+/// Hashing to the curve follows the Zcash construction, which requires
+/// the domain separation tag to be a valid UTF-8 string.
+pub struct Blake2b;
+
+impl GroupDigest for Blake2b {
+    type Output = Point<vesta::Point>;
+
+    fn hash_to_group(msg: &[u8], dst: &[u8]) -> Result<Self::Output> {
+        let domain_prefix = std::str::from_utf8(dst)?;
+        let hasher = vesta::Point::hash_to_curve(domain_prefix);
+        Ok(Point(hasher(msg)))
+    }
+}
+
+impl FieldDigest for Blake2b {
+    type Output = Scalar<vesta::Scalar>;
+
+    fn hash_to_field(msg: &[u8], dst: &[u8]) -> Result<Self::Output> {
+        let mut bytes = [0u8; 64];
+        expand_message::<ExpandMsgXmd<blake2::Blake2b512>>(msg, dst, &mut bytes)?;
+        Ok(Scalar::from_uniform_bytes(&bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate test;
+
+    use self::test::Bencher;
+
+    use rand::{rngs::StdRng, RngCore, SeedableRng};
+
+    use crate::suites;
+
+    use super::{Blake2b, FieldDigest, GroupDigest};
+
+    #[test]
+    fn test_hash_to_field() {
+        let a = Blake2b::hash_to_field(b"msg", b"dst").unwrap();
+        let b = Blake2b::hash_to_field(b"msg", b"dst").unwrap();
+        let c = Blake2b::hash_to_field(b"msg", b"other dst").unwrap();
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn test_hash_to_group() {
+        let a = Blake2b::hash_to_group(b"msg", b"dst").unwrap();
+        let b = Blake2b::hash_to_group(b"msg", b"dst").unwrap();
+        let c = Blake2b::hash_to_group(b"msg", b"other dst").unwrap();
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+
+        // Domain separation tags must be valid UTF-8 strings.
+        Blake2b::hash_to_group(b"msg", &[0xff]).expect_err("hashing should fail");
+    }
+
+    #[test]
+    fn test_churp() {
+        suites::tests::test_churp::<Blake2b>();
+    }
+
+    #[bench]
+    fn bench_hash_to_field_vesta_blake2b(b: &mut Bencher) {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let mut data = [0; 64];
+
+        b.iter(|| {
+            rng.fill_bytes(&mut data);
+            let _ = Blake2b::hash_to_field(&data[..32], &data[32..64]).unwrap();
+        });
+    }
+
+    #[bench]
+    fn bench_hash_to_group_vesta_blake2b(b: &mut Bencher) {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let mut data = [0; 64];
+
+        b.iter(|| {
+            rng.fill_bytes(&mut data);
+            let _ = Blake2b::hash_to_group(&data[..32], b"dst").unwrap();
+        });
+    }
+}
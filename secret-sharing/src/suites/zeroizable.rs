@@ -0,0 +1,301 @@
+//! Wrappers adding zeroization support to prime fields and groups.
+//!
+//! Suites require that elements of the prime field and the group can be
+//! zeroized. The wrappers defined here can be used for curve implementations
+//! that don't support zeroization natively.
+
+use std::{
+    iter::{Product, Sum},
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use group::{
+    ff::{Field, FromUniformBytes, PrimeField},
+    Group, GroupEncoding,
+};
+use rand_core::RngCore;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+use zeroize::DefaultIsZeroes;
+
+/// An element of a prime field which can be zeroized.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Scalar<F>(pub F);
+
+/// An element of a cryptographic group which can be zeroized.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Point<G>(pub G);
+
+/// Implements a binary operator, and its assignment variant, for the given
+/// wrapper, with both owned and borrowed right-hand side operands.
+macro_rules! impl_binop {
+    ($wrapper:ident, $bound:ident, $rhs:ty, $trait:ident, $fn:ident, $assign_trait:ident, $assign_fn:ident) => {
+        impl<T: $bound> $trait<$rhs> for $wrapper<T> {
+            type Output = $wrapper<T>;
+
+            #[inline]
+            fn $fn(self, rhs: $rhs) -> Self::Output {
+                $wrapper(self.0.$fn(rhs.0))
+            }
+        }
+
+        impl<'a, T: $bound> $trait<&'a $rhs> for $wrapper<T> {
+            type Output = $wrapper<T>;
+
+            #[inline]
+            fn $fn(self, rhs: &'a $rhs) -> Self::Output {
+                $wrapper(self.0.$fn(&rhs.0))
+            }
+        }
+
+        impl<T: $bound> $assign_trait<$rhs> for $wrapper<T> {
+            #[inline]
+            fn $assign_fn(&mut self, rhs: $rhs) {
+                self.0.$assign_fn(rhs.0)
+            }
+        }
+
+        impl<'a, T: $bound> $assign_trait<&'a $rhs> for $wrapper<T> {
+            #[inline]
+            fn $assign_fn(&mut self, rhs: &'a $rhs) {
+                self.0.$assign_fn(&rhs.0)
+            }
+        }
+    };
+}
+
+impl_binop!(
+    Scalar,
+    PrimeField,
+    Scalar<T>,
+    Add,
+    add,
+    AddAssign,
+    add_assign
+);
+impl_binop!(
+    Scalar,
+    PrimeField,
+    Scalar<T>,
+    Sub,
+    sub,
+    SubAssign,
+    sub_assign
+);
+impl_binop!(
+    Scalar,
+    PrimeField,
+    Scalar<T>,
+    Mul,
+    mul,
+    MulAssign,
+    mul_assign
+);
+impl_binop!(Point, Group, Point<T>, Add, add, AddAssign, add_assign);
+impl_binop!(Point, Group, Point<T>, Sub, sub, SubAssign, sub_assign);
+impl_binop!(
+    Point,
+    Group,
+    Scalar<T::Scalar>,
+    Mul,
+    mul,
+    MulAssign,
+    mul_assign
+);
+
+impl<F: PrimeField> Default for Scalar<F> {
+    fn default() -> Self {
+        Self(F::ZERO)
+    }
+}
+
+impl<F: PrimeField> DefaultIsZeroes for Scalar<F> {}
+
+impl<F: PrimeField> From<u64> for Scalar<F> {
+    fn from(value: u64) -> Self {
+        Self(F::from(value))
+    }
+}
+
+impl<F: PrimeField> Neg for Scalar<F> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+impl<F: PrimeField> Sum for Scalar<F> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Self(iter.map(|s| s.0).sum())
+    }
+}
+
+impl<'a, F: PrimeField> Sum<&'a Scalar<F>> for Scalar<F> {
+    fn sum<I: Iterator<Item = &'a Scalar<F>>>(iter: I) -> Self {
+        Self(iter.map(|s| s.0).sum())
+    }
+}
+
+impl<F: PrimeField> Product for Scalar<F> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Self(iter.map(|s| s.0).product())
+    }
+}
+
+impl<'a, F: PrimeField> Product<&'a Scalar<F>> for Scalar<F> {
+    fn product<I: Iterator<Item = &'a Scalar<F>>>(iter: I) -> Self {
+        Self(iter.map(|s| s.0).product())
+    }
+}
+
+impl<F: PrimeField> ConditionallySelectable for Scalar<F> {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self(F::conditional_select(&a.0, &b.0, choice))
+    }
+}
+
+impl<F: PrimeField> ConstantTimeEq for Scalar<F> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl<F: PrimeField> Field for Scalar<F> {
+    const ZERO: Self = Self(F::ZERO);
+    const ONE: Self = Self(F::ONE);
+
+    fn random(rng: impl RngCore) -> Self {
+        Self(F::random(rng))
+    }
+
+    fn square(&self) -> Self {
+        Self(self.0.square())
+    }
+
+    fn double(&self) -> Self {
+        Self(self.0.double())
+    }
+
+    fn invert(&self) -> CtOption<Self> {
+        self.0.invert().map(Self)
+    }
+
+    fn sqrt_ratio(num: &Self, div: &Self) -> (Choice, Self) {
+        let (is_square, res) = F::sqrt_ratio(&num.0, &div.0);
+        (is_square, Self(res))
+    }
+}
+
+impl<F: PrimeField> PrimeField for Scalar<F> {
+    type Repr = F::Repr;
+
+    const MODULUS: &'static str = F::MODULUS;
+    const NUM_BITS: u32 = F::NUM_BITS;
+    const CAPACITY: u32 = F::CAPACITY;
+    const TWO_INV: Self = Self(F::TWO_INV);
+    const MULTIPLICATIVE_GENERATOR: Self = Self(F::MULTIPLICATIVE_GENERATOR);
+    const S: u32 = F::S;
+    const ROOT_OF_UNITY: Self = Self(F::ROOT_OF_UNITY);
+    const ROOT_OF_UNITY_INV: Self = Self(F::ROOT_OF_UNITY_INV);
+    const DELTA: Self = Self(F::DELTA);
+
+    fn from_repr(repr: Self::Repr) -> CtOption<Self> {
+        F::from_repr(repr).map(Self)
+    }
+
+    fn to_repr(&self) -> Self::Repr {
+        self.0.to_repr()
+    }
+
+    fn is_odd(&self) -> Choice {
+        self.0.is_odd()
+    }
+}
+
+impl<F, const N: usize> FromUniformBytes<N> for Scalar<F>
+where
+    F: FromUniformBytes<N>,
+{
+    fn from_uniform_bytes(bytes: &[u8; N]) -> Self {
+        Self(F::from_uniform_bytes(bytes))
+    }
+}
+
+impl<G: Group> Default for Point<G> {
+    fn default() -> Self {
+        Self(G::identity())
+    }
+}
+
+impl<G: Group> DefaultIsZeroes for Point<G> {}
+
+impl<G: Group> Neg for Point<G> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+impl<G: Group> Sum for Point<G> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Self(iter.map(|p| p.0).sum())
+    }
+}
+
+impl<'a, G: Group> Sum<&'a Point<G>> for Point<G> {
+    fn sum<I: Iterator<Item = &'a Point<G>>>(iter: I) -> Self {
+        Self(iter.map(|p| p.0).sum())
+    }
+}
+
+impl<G: Group + ConditionallySelectable> ConditionallySelectable for Point<G> {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self(G::conditional_select(&a.0, &b.0, choice))
+    }
+}
+
+impl<G: Group> Group for Point<G> {
+    type Scalar = Scalar<G::Scalar>;
+
+    fn random(rng: impl RngCore) -> Self {
+        Self(G::random(rng))
+    }
+
+    fn identity() -> Self {
+        Self(G::identity())
+    }
+
+    fn generator() -> Self {
+        Self(G::generator())
+    }
+
+    fn is_identity(&self) -> Choice {
+        self.0.is_identity()
+    }
+
+    fn double(&self) -> Self {
+        Self(self.0.double())
+    }
+}
+
+impl<G> GroupEncoding for Point<G>
+where
+    G: Group + GroupEncoding + ConditionallySelectable + Default,
+{
+    type Repr = G::Repr;
+
+    fn from_bytes(bytes: &Self::Repr) -> CtOption<Self> {
+        G::from_bytes(bytes).map(Self)
+    }
+
+    fn from_bytes_unchecked(bytes: &Self::Repr) -> CtOption<Self> {
+        G::from_bytes_unchecked(bytes).map(Self)
+    }
+
+    fn to_bytes(&self) -> Self::Repr {
+        self.0.to_bytes()
+    }
+}
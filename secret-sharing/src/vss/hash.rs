@@ -0,0 +1,364 @@
+use group::ff::PrimeField;
+use rand_core::RngCore;
+
+use crate::poly::{BivariatePolynomial, Polynomial};
+
+use super::{MerkleNode, MerkleProof, MerkleTree};
+
+/// The size of a leaf salt in bytes.
+pub const HASH_COMMITMENT_SALT_SIZE: usize = 32;
+
+/// A salt used to hide a committed evaluation.
+pub type Salt = [u8; HASH_COMMITMENT_SALT_SIZE];
+
+/// Hash-based commitment to a bivariate polynomial.
+///
+/// Unlike the verification matrix, which commits to the coefficients
+/// of a bivariate polynomial `B(x,y)` in the exponent, the hash commitment
+/// is the root of a Merkle tree over the salted evaluations `B(x_i,x_j)`
+/// at all pairs of the given shareholder points. Since its binding property
+/// relies solely on the collision resistance of the hash function, it remains
+/// secure against quantum adversaries, even though the secret is still
+/// reconstructed using classical Lagrange interpolation.
+///
+/// Leaves are ordered row by row, where leaf `i * n + j` commits to
+/// the evaluation `B(x_i,x_j)`:
+/// ```text
+///     L_{i,j} = H(s_{i,j} || x_i || x_j || B(x_i,x_j))
+/// ```
+///
+/// A shareholder holding the univariate polynomial `B(x_i,y)` (or `B(x,x_i)`)
+/// verifies it by checking its evaluations at all shareholder points against
+/// the corresponding row (or column) of the tree. Since shareholders verify
+/// overlapping evaluations, the dealer cannot hand out inconsistent shares
+/// without being detected, provided that there are at least as many
+/// shareholder points as coefficients of each univariate polynomial.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HashCommitment<F: PrimeField> {
+    /// The number of coefficients of the committed bivariate polynomial
+    /// in the `x` variable.
+    rows: usize,
+    /// The number of coefficients of the committed bivariate polynomial
+    /// in the `y` variable.
+    cols: usize,
+    /// The shareholder points at which the polynomial was evaluated.
+    xs: Vec<F>,
+    /// The root of the Merkle tree over the salted evaluations.
+    root: MerkleNode,
+}
+
+impl<F> HashCommitment<F>
+where
+    F: PrimeField,
+{
+    /// Commits to the evaluations of the given bivariate polynomial
+    /// at all pairs of the given shareholder points.
+    ///
+    /// Returns the public commitment, along with the secret openings
+    /// which should be distributed to the shareholders together with
+    /// their shares.
+    pub fn commit(
+        bp: &BivariatePolynomial<F>,
+        xs: Vec<F>,
+        rng: &mut impl RngCore,
+    ) -> (Self, HashCommitmentOpenings) {
+        let n = xs.len();
+        let mut salts = Vec::with_capacity(n * n);
+        let mut leaves = Vec::with_capacity(n * n);
+
+        for xi in xs.iter() {
+            let p = bp.eval_x(xi);
+            for xj in xs.iter() {
+                let mut salt = Salt::default();
+                rng.fill_bytes(&mut salt);
+                leaves.push(Self::leaf(&salt, xi, xj, &p.eval(xj)));
+                salts.push(salt);
+            }
+        }
+
+        let tree = MerkleTree::new(&leaves);
+        let commitment = Self {
+            rows: bp.deg_x + 1,
+            cols: bp.deg_y + 1,
+            xs,
+            root: tree.root(),
+        };
+        let openings = HashCommitmentOpenings { n, salts, tree };
+
+        (commitment, openings)
+    }
+
+    /// Returns the dimensions (number of coefficients in the `x` and `y`
+    /// variables) of the committed bivariate polynomial.
+    pub fn dimensions(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// Returns the shareholder points at which the polynomial was evaluated.
+    pub fn points(&self) -> &[F] {
+        &self.xs
+    }
+
+    /// Returns the root of the Merkle tree over the salted evaluations.
+    pub fn root(&self) -> &MerkleNode {
+        &self.root
+    }
+
+    /// Verifies whether the committed bivariate polynomial evaluates
+    /// to the given value, i.e., if it holds `B(x,y) == v`.
+    ///
+    /// Both `x` and `y` must be shareholder points.
+    pub fn verify(&self, x: &F, y: &F, v: &F, salt: &Salt, proof: &MerkleProof) -> bool {
+        let (i, j) = match (self.position(x), self.position(y)) {
+            (Some(i), Some(j)) => (i, j),
+            _ => return false,
+        };
+
+        self.verify_leaf(i, j, v, salt, proof)
+    }
+
+    /// Verifies the polynomial `B(x,y)` resulting from the evaluation
+    /// of the bivariate polynomial with respect to the indeterminate x
+    /// against the given row of the commitment.
+    ///
+    /// The polynomial is valid iff it has the expected number of coefficients
+    /// and its evaluations at all shareholder points `x_j` match the committed
+    /// evaluations `B(x,x_j)`.
+    pub fn verify_x(&self, x: &F, opening: &HashOpening, polynomial: &Polynomial<F>) -> bool {
+        if polynomial.size() != self.cols {
+            return false;
+        }
+        let i = match self.position(x) {
+            Some(i) => i,
+            None => return false,
+        };
+        if !self.is_valid_opening(opening) {
+            return false;
+        }
+
+        self.xs
+            .iter()
+            .zip(opening.salts.iter().zip(opening.proofs.iter()))
+            .enumerate()
+            .all(|(j, (xj, (salt, proof)))| {
+                self.verify_leaf(i, j, &polynomial.eval(xj), salt, proof)
+            })
+    }
+
+    /// Verifies the polynomial `B(x,y)` resulting from the evaluation
+    /// of the bivariate polynomial with respect to the indeterminate y
+    /// against the given column of the commitment.
+    ///
+    /// The polynomial is valid iff it has the expected number of coefficients
+    /// and its evaluations at all shareholder points `x_i` match the committed
+    /// evaluations `B(x_i,y)`.
+    pub fn verify_y(&self, y: &F, opening: &HashOpening, polynomial: &Polynomial<F>) -> bool {
+        if polynomial.size() != self.rows {
+            return false;
+        }
+        let j = match self.position(y) {
+            Some(j) => j,
+            None => return false,
+        };
+        if !self.is_valid_opening(opening) {
+            return false;
+        }
+
+        self.xs
+            .iter()
+            .zip(opening.salts.iter().zip(opening.proofs.iter()))
+            .enumerate()
+            .all(|(i, (xi, (salt, proof)))| {
+                self.verify_leaf(i, j, &polynomial.eval(xi), salt, proof)
+            })
+    }
+
+    /// Verifies that the leaf at the given position commits to the given
+    /// value.
+    fn verify_leaf(&self, i: usize, j: usize, v: &F, salt: &Salt, proof: &MerkleProof) -> bool {
+        let n = self.xs.len();
+        if proof.index != i * n + j || proof.siblings.len() != Self::depth(n) {
+            return false;
+        }
+
+        let leaf = Self::leaf(salt, &self.xs[i], &self.xs[j], v);
+        proof.verify(&self.root, leaf)
+    }
+
+    /// Returns true if the opening contains one salt and one proof
+    /// for every shareholder point.
+    fn is_valid_opening(&self, opening: &HashOpening) -> bool {
+        opening.salts.len() == self.xs.len() && opening.proofs.len() == self.xs.len()
+    }
+
+    /// Returns the position of the given shareholder point.
+    fn position(&self, x: &F) -> Option<usize> {
+        self.xs.iter().position(|xi| xi == x)
+    }
+
+    /// Returns the depth of the Merkle tree for the given number
+    /// of shareholder points.
+    fn depth(n: usize) -> usize {
+        (n * n).max(1).next_power_of_two().trailing_zeros() as usize
+    }
+
+    /// Returns the leaf data committing to the evaluation `B(x,y) == v`.
+    fn leaf(salt: &Salt, x: &F, y: &F, v: &F) -> Vec<u8> {
+        let mut leaf = Vec::with_capacity(salt.len() + 3 * F::Repr::default().as_ref().len());
+        leaf.extend_from_slice(salt);
+        leaf.extend_from_slice(x.to_repr().as_ref());
+        leaf.extend_from_slice(y.to_repr().as_ref());
+        leaf.extend_from_slice(v.to_repr().as_ref());
+        leaf
+    }
+}
+
+/// Openings of a hash commitment, kept by the dealer.
+pub struct HashCommitmentOpenings {
+    /// The number of shareholder points.
+    n: usize,
+    /// The salts of all leaves, ordered row by row.
+    salts: Vec<Salt>,
+    /// The Merkle tree over the salted evaluations.
+    tree: MerkleTree,
+}
+
+impl HashCommitmentOpenings {
+    /// Returns the opening of the row belonging to the shareholder
+    /// at the given position, which can be used to verify the polynomial
+    /// `B(x_i,y)`.
+    pub fn row(&self, i: usize) -> Option<HashOpening> {
+        if i >= self.n {
+            return None;
+        }
+
+        self.opening((0..self.n).map(|j| i * self.n + j))
+    }
+
+    /// Returns the opening of the column belonging to the shareholder
+    /// at the given position, which can be used to verify the polynomial
+    /// `B(x,x_j)`.
+    pub fn column(&self, j: usize) -> Option<HashOpening> {
+        if j >= self.n {
+            return None;
+        }
+
+        self.opening((0..self.n).map(|i| i * self.n + j))
+    }
+
+    /// Returns the opening of the leaf `B(x_i,x_j)`.
+    pub fn element(&self, i: usize, j: usize) -> Option<(Salt, MerkleProof)> {
+        if i >= self.n || j >= self.n {
+            return None;
+        }
+
+        let index = i * self.n + j;
+        let proof = self.tree.proof(index)?;
+        Some((self.salts[index], proof))
+    }
+
+    /// Returns the opening of the leaves at the given indices.
+    fn opening(&self, indices: impl Iterator<Item = usize>) -> Option<HashOpening> {
+        let mut salts = Vec::with_capacity(self.n);
+        let mut proofs = Vec::with_capacity(self.n);
+        for index in indices {
+            salts.push(self.salts[index]);
+            proofs.push(self.tree.proof(index)?);
+        }
+
+        Some(HashOpening { salts, proofs })
+    }
+}
+
+/// Opening of a row or a column of a hash commitment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HashOpening {
+    /// The salts of the opened leaves.
+    salts: Vec<Salt>,
+    /// The Merkle proofs of the opened leaves.
+    proofs: Vec<MerkleProof>,
+}
+
+impl HashOpening {
+    /// Constructs a new opening from the given salts and proofs.
+    pub fn new(salts: Vec<Salt>, proofs: Vec<MerkleProof>) -> Self {
+        Self { salts, proofs }
+    }
+
+    /// Returns the salts of the opened leaves.
+    pub fn salts(&self) -> &[Salt] {
+        &self.salts
+    }
+
+    /// Returns the Merkle proofs of the opened leaves.
+    pub fn proofs(&self) -> &[MerkleProof] {
+        &self.proofs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::poly::BivariatePolynomial;
+
+    use super::HashCommitment;
+
+    type PrimeField = p384::Scalar;
+
+    #[test]
+    fn test_hash_commitment() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::<PrimeField>::random(2, 4, &mut rng);
+        let xs: Vec<_> = (1..=5).map(PrimeField::from_u64).collect();
+        let (commitment, openings) = HashCommitment::commit(&bp, xs.clone(), &mut rng);
+
+        assert_eq!(commitment.dimensions(), (3, 5));
+        assert_eq!(commitment.points(), &xs[..]);
+
+        for (i, x) in xs.iter().enumerate() {
+            // Full shares.
+            let p = bp.eval_x(x);
+            let row = openings.row(i).unwrap();
+            assert!(commitment.verify_x(x, &row, &p));
+
+            // Reduced shares.
+            let q = bp.eval_y(x);
+            let column = openings.column(i).unwrap();
+            assert!(commitment.verify_y(x, &column, &q));
+
+            // Wrong openings.
+            let other = openings.row((i + 1) % xs.len()).unwrap();
+            assert!(!commitment.verify_x(x, &other, &p));
+            assert!(!commitment.verify_y(x, &row, &q));
+
+            // Wrong polynomials.
+            assert!(!commitment.verify_x(x, &row, &bp.eval_x(&PrimeField::from_u64(6))));
+            assert!(!commitment.verify_y(x, &column, &bp.eval_y(&PrimeField::from_u64(6))));
+            assert!(!commitment.verify_x(x, &row, &q));
+
+            // Unknown shareholders.
+            let unknown = PrimeField::from_u64(6);
+            assert!(!commitment.verify_x(&unknown, &row, &p));
+            assert!(!commitment.verify_y(&unknown, &column, &q));
+
+            // Single evaluations.
+            for (j, y) in xs.iter().enumerate() {
+                let (salt, proof) = openings.element(i, j).unwrap();
+                let v = bp.eval(x, y);
+                assert!(commitment.verify(x, y, &v, &salt, &proof));
+                assert!(!commitment.verify(x, y, &(v + PrimeField::ONE), &salt, &proof));
+                assert!(!commitment.verify(y, x, &v, &salt, &proof) || i == j);
+            }
+        }
+
+        // Out of bounds.
+        assert!(openings.row(xs.len()).is_none());
+        assert!(openings.column(xs.len()).is_none());
+        assert!(openings.element(0, xs.len()).is_none());
+
+        // Commitments are hiding, so committing twice yields different roots.
+        let (other, _) = HashCommitment::commit(&bp, xs, &mut rng);
+        assert_ne!(commitment.root(), other.root());
+    }
+}
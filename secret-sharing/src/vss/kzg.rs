@@ -0,0 +1,425 @@
+use group::{ff::Field, prime::PrimeCurveAffine, Curve, Group, GroupEncoding};
+use pairing::Engine;
+use rand_core::RngCore;
+
+use crate::poly::{powers, BivariatePolynomial, Polynomial};
+
+/// Structured reference string for KZG commitments to bivariate polynomials.
+///
+/// The setup consists of the powers of two secret trapdoors `τ` and `σ`
+/// in the first group, and of the trapdoors themselves in the second group:
+/// ```text
+///     S_{i,j} = τ^i σ^j * G_1,  for 0 <= i <= deg_x and 0 <= j <= deg_y
+///     H = G_2, H_τ = τ * G_2, H_σ = σ * G_2
+/// ```
+///
+/// The same setup can be used to commit to any bivariate polynomial
+/// whose degrees don't exceed the degrees of the setup.
+#[derive(Debug, Clone)]
+pub struct KzgSetup<E: Engine> {
+    /// The maximum degree in the `x` variable.
+    deg_x: usize,
+    /// The maximum degree in the `y` variable.
+    deg_y: usize,
+    /// The powers of the trapdoors in the first group, where element
+    /// `i * (deg_y + 1) + j` represents `τ^i σ^j * G_1`.
+    g1: Vec<E::G1Affine>,
+    /// The generator of the second group.
+    g2: E::G2Affine,
+    /// The trapdoor `τ` in the second group.
+    tau_g2: E::G2Affine,
+    /// The trapdoor `σ` in the second group.
+    sigma_g2: E::G2Affine,
+}
+
+impl<E> KzgSetup<E>
+where
+    E: Engine,
+{
+    /// Generates a new setup for bivariate polynomials of the given degrees
+    /// using randomly selected trapdoors, which are discarded afterwards.
+    ///
+    /// Whoever generates the setup learns the trapdoors and can therefore
+    /// open commitments to arbitrary polynomials. In production, the setup
+    /// must be the output of a multi-party ceremony instead.
+    pub fn random(deg_x: u8, deg_y: u8, rng: &mut impl RngCore) -> Self {
+        let tau = E::Fr::random(&mut *rng);
+        let sigma = E::Fr::random(&mut *rng);
+
+        Self::from_trapdoors(deg_x, deg_y, &tau, &sigma)
+    }
+
+    /// Generates a new setup for bivariate polynomials of the given degrees
+    /// from the given trapdoors.
+    pub fn from_trapdoors(deg_x: u8, deg_y: u8, tau: &E::Fr, sigma: &E::Fr) -> Self {
+        let deg_x = deg_x as usize;
+        let deg_y = deg_y as usize;
+
+        let taus = powers(tau, deg_x);
+        let sigmas = powers(sigma, deg_y);
+
+        let mut g1 = Vec::with_capacity((deg_x + 1) * (deg_y + 1));
+        for taui in taus.iter() {
+            for sigmaj in sigmas.iter() {
+                g1.push(E::G1::generator() * (*taui * sigmaj));
+            }
+        }
+        let mut g1_affine = vec![E::G1Affine::identity(); g1.len()];
+        E::G1::batch_normalize(&g1, &mut g1_affine);
+
+        let g2 = E::G2::generator();
+        let tau_g2 = (g2 * tau).to_affine();
+        let sigma_g2 = (g2 * sigma).to_affine();
+
+        Self {
+            deg_x,
+            deg_y,
+            g1: g1_affine,
+            g2: g2.to_affine(),
+            tau_g2,
+            sigma_g2,
+        }
+    }
+
+    /// Returns the maximum degrees of the polynomials supported
+    /// by the setup.
+    pub fn degrees(&self) -> (usize, usize) {
+        (self.deg_x, self.deg_y)
+    }
+
+    /// Commits to the given bivariate polynomial.
+    ///
+    /// Returns `None` if the degrees of the polynomial exceed the degrees
+    /// of the setup.
+    pub fn commit(&self, bp: &BivariatePolynomial<E::Fr>) -> Option<KzgCommitment<E>> {
+        if !self.supports(bp) {
+            return None;
+        }
+
+        let c = self.combine(&bp.b);
+        Some(KzgCommitment { c })
+    }
+
+    /// Computes a proof that the univariate polynomial `B(x,y)` is a valid
+    /// row of the given bivariate polynomial, i.e. that it was obtained
+    /// by fixing the `x` variable.
+    ///
+    /// The proof is a commitment to the quotient `Q(x,y)` satisfying
+    /// `B(x,y) - B(x_0,y) = (x - x_0) Q(x,y)`.
+    ///
+    /// Returns `None` if the degrees of the polynomial exceed the degrees
+    /// of the setup.
+    pub fn open_x(&self, bp: &BivariatePolynomial<E::Fr>, x: &E::Fr) -> Option<KzgProof<E>> {
+        if !self.supports(bp) {
+            return None;
+        }
+
+        // Divide the polynomial by (x - x_0), treating it as a polynomial
+        // in the x variable whose coefficients are polynomials in y.
+        let mut q = vec![vec![E::Fr::ZERO; bp.deg_y + 1]; bp.deg_x];
+        let mut carry = vec![E::Fr::ZERO; bp.deg_y + 1];
+        for i in (1..=bp.deg_x).rev() {
+            for (j, cj) in carry.iter_mut().enumerate() {
+                *cj = *cj * x + bp.b[i][j];
+            }
+            q[i - 1].copy_from_slice(&carry);
+        }
+
+        let w = self.combine(&q);
+        Some(KzgProof { w })
+    }
+
+    /// Computes a proof that the univariate polynomial `B(x,y)` is a valid
+    /// column of the given bivariate polynomial, i.e. that it was obtained
+    /// by fixing the `y` variable.
+    ///
+    /// The proof is a commitment to the quotient `Q(x,y)` satisfying
+    /// `B(x,y) - B(x,y_0) = (y - y_0) Q(x,y)`.
+    ///
+    /// Returns `None` if the degrees of the polynomial exceed the degrees
+    /// of the setup.
+    pub fn open_y(&self, bp: &BivariatePolynomial<E::Fr>, y: &E::Fr) -> Option<KzgProof<E>> {
+        if !self.supports(bp) {
+            return None;
+        }
+
+        // Divide every row of the polynomial by (y - y_0).
+        let mut q = vec![vec![E::Fr::ZERO; bp.deg_y]; bp.deg_x + 1];
+        for (bi, qi) in bp.b.iter().zip(q.iter_mut()) {
+            let mut carry = E::Fr::ZERO;
+            for j in (1..=bp.deg_y).rev() {
+                carry = carry * y + bi[j];
+                qi[j - 1] = carry;
+            }
+        }
+
+        let w = self.combine(&q);
+        Some(KzgProof { w })
+    }
+
+    /// Verifies that the given univariate polynomial is the row `B(x,y)`
+    /// of the committed bivariate polynomial.
+    ///
+    /// This amounts to checking the pairing equation:
+    /// ```text
+    ///     e(C - P(σ) * G_1, G_2) == e(W, (τ - x) * G_2)
+    /// ```
+    pub fn verify_x(
+        &self,
+        commitment: &KzgCommitment<E>,
+        x: &E::Fr,
+        p: &Polynomial<E::Fr>,
+        proof: &KzgProof<E>,
+    ) -> bool {
+        // Short-circuit on the size of the polynomial, not its contents.
+        if p.a.len() > self.deg_y + 1 {
+            return false;
+        }
+
+        // The row polynomial is committed using the powers of σ, which form
+        // the first row of the setup.
+        let cp = self.combine_row(0, 1, &p.a);
+        let shift = E::G2::from(self.tau_g2) - self.g2 * x;
+
+        self.check(commitment.c - cp, proof.w, shift)
+    }
+
+    /// Verifies that the given univariate polynomial is the column `B(x,y)`
+    /// of the committed bivariate polynomial.
+    ///
+    /// This amounts to checking the pairing equation:
+    /// ```text
+    ///     e(C - P(τ) * G_1, G_2) == e(W, (σ - y) * G_2)
+    /// ```
+    pub fn verify_y(
+        &self,
+        commitment: &KzgCommitment<E>,
+        y: &E::Fr,
+        p: &Polynomial<E::Fr>,
+        proof: &KzgProof<E>,
+    ) -> bool {
+        // Short-circuit on the size of the polynomial, not its contents.
+        if p.a.len() > self.deg_x + 1 {
+            return false;
+        }
+
+        // The column polynomial is committed using the powers of τ, which
+        // form the first column of the setup.
+        let cp = self.combine_row(0, self.deg_y + 1, &p.a);
+        let shift = E::G2::from(self.sigma_g2) - self.g2 * y;
+
+        self.check(commitment.c - cp, proof.w, shift)
+    }
+
+    /// Returns true iff the degrees of the given polynomial don't exceed
+    /// the degrees of the setup.
+    fn supports(&self, bp: &BivariatePolynomial<E::Fr>) -> bool {
+        bp.deg_x <= self.deg_x && bp.deg_y <= self.deg_y
+    }
+
+    /// Checks whether `e(a, G_2) == e(w, shift)`.
+    fn check(&self, a: E::G1, w: E::G1, shift: E::G2) -> bool {
+        E::pairing(&a.to_affine(), &self.g2) == E::pairing(&w.to_affine(), &shift.to_affine())
+    }
+
+    /// Commits to the given coefficients, where `b[i][j]` is multiplied
+    /// by `τ^i σ^j * G_1`.
+    ///
+    /// The coefficients must fit into the setup.
+    fn combine(&self, b: &[Vec<E::Fr>]) -> E::G1 {
+        b.iter()
+            .enumerate()
+            .fold(E::G1::identity(), |acc, (i, bi)| {
+                acc + self.combine_row(i * (self.deg_y + 1), 1, bi)
+            })
+    }
+
+    /// Computes `\sum_k a_k * S_{start + k * step}`.
+    ///
+    /// The coefficients may be secret, so scalar multiplications are
+    /// computed one by one instead of using the variable-time multi-scalar
+    /// multiplication.
+    fn combine_row(&self, start: usize, step: usize, a: &[E::Fr]) -> E::G1 {
+        a.iter()
+            .enumerate()
+            .fold(E::G1::identity(), |acc, (k, ak)| {
+                acc + self.g1[start + k * step] * ak
+            })
+    }
+}
+
+/// KZG commitment to a bivariate polynomial.
+///
+/// Unlike the verification matrix, which consists of `(deg_x + 1) *
+/// (deg_y + 1)` group elements, the commitment is a single element
+/// of the first group, regardless of the degrees of the polynomial:
+/// ```text
+///     C = B(τ,σ) * G_1 = \sum_{i,j} b_{i,j} * S_{i,j}
+/// ```
+///
+/// Note that the commitment doesn't reveal the degrees of the polynomial,
+/// so these must be agreed upon in advance, e.g. by limiting the degrees
+/// of the setup.
+#[derive(Debug, Clone)]
+pub struct KzgCommitment<E: Engine> {
+    /// The committed polynomial evaluated at the trapdoors in the exponent.
+    c: E::G1,
+}
+
+impl<E> PartialEq for KzgCommitment<E>
+where
+    E: Engine,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.c == other.c
+    }
+}
+
+impl<E> Eq for KzgCommitment<E> where E: Engine {}
+
+impl<E> KzgCommitment<E>
+where
+    E: Engine,
+{
+    /// Returns the byte representation of the commitment.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.c.to_bytes().as_ref().to_vec()
+    }
+
+    /// Attempts to create a commitment from its byte representation.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let c = point_from_bytes::<E::G1>(bytes)?;
+        Some(Self { c })
+    }
+}
+
+/// KZG opening proof, consisting of a single element of the first group.
+#[derive(Debug, Clone)]
+pub struct KzgProof<E: Engine> {
+    /// The commitment to the quotient polynomial.
+    w: E::G1,
+}
+
+impl<E> PartialEq for KzgProof<E>
+where
+    E: Engine,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.w == other.w
+    }
+}
+
+impl<E> Eq for KzgProof<E> where E: Engine {}
+
+impl<E> KzgProof<E>
+where
+    E: Engine,
+{
+    /// Returns the byte representation of the proof.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.w.to_bytes().as_ref().to_vec()
+    }
+
+    /// Attempts to create a proof from its byte representation.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let w = point_from_bytes::<E::G1>(bytes)?;
+        Some(Self { w })
+    }
+}
+
+/// Attempts to decode a group element from its byte representation.
+fn point_from_bytes<G: GroupEncoding>(bytes: &[u8]) -> Option<G> {
+    let mut repr = G::Repr::default();
+    if repr.as_ref().len() != bytes.len() {
+        return None;
+    }
+    repr.as_mut().copy_from_slice(bytes);
+
+    G::from_bytes(&repr).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use group::ff::Field;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::poly::BivariatePolynomial;
+
+    use super::{KzgCommitment, KzgProof, KzgSetup};
+
+    #[cfg(feature = "bls12_381_g1")]
+    #[test]
+    fn test_kzg() {
+        type E = bls12_381_plus::Bls12;
+        type F = bls12_381_plus::Scalar;
+
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let setup = KzgSetup::<E>::random(2, 4, &mut rng);
+        assert_eq!(setup.degrees(), (2, 4));
+
+        for (deg_x, deg_y) in [(0, 0), (1, 0), (0, 1), (2, 4), (1, 3)] {
+            let bp = BivariatePolynomial::<F>::random(deg_x, deg_y, &mut rng);
+            let c = setup.commit(&bp).unwrap();
+
+            for _ in 0..3 {
+                let x = F::random(&mut rng);
+                let z = F::random(&mut rng);
+
+                // Rows.
+                let p = bp.eval_x(&x);
+                let proof = setup.open_x(&bp, &x).unwrap();
+                assert!(setup.verify_x(&c, &x, &p, &proof));
+                if deg_x > 0 {
+                    assert!(!setup.verify_x(&c, &z, &p, &proof));
+                    assert!(!setup.verify_x(&c, &x, &bp.eval_x(&z), &proof));
+                }
+
+                // Columns.
+                let p = bp.eval_y(&x);
+                let proof = setup.open_y(&bp, &x).unwrap();
+                assert!(setup.verify_y(&c, &x, &p, &proof));
+                if deg_y > 0 {
+                    assert!(!setup.verify_y(&c, &z, &p, &proof));
+                    assert!(!setup.verify_y(&c, &x, &bp.eval_y(&z), &proof));
+                }
+            }
+
+            // Commitments to different polynomials.
+            let other = BivariatePolynomial::<F>::random(deg_x, deg_y, &mut rng);
+            let x = F::random(&mut rng);
+            let proof = setup.open_x(&other, &x).unwrap();
+            assert!(!setup.verify_x(&c, &x, &other.eval_x(&x), &proof));
+        }
+
+        // Polynomial degrees exceed the setup.
+        let bp = BivariatePolynomial::<F>::random(3, 4, &mut rng);
+        assert!(setup.commit(&bp).is_none());
+        assert!(setup.open_x(&bp, &F::ONE).is_none());
+        let bp = BivariatePolynomial::<F>::random(2, 5, &mut rng);
+        assert!(setup.commit(&bp).is_none());
+        assert!(setup.open_y(&bp, &F::ONE).is_none());
+    }
+
+    #[cfg(feature = "bls12_381_g1")]
+    #[test]
+    fn test_kzg_serialization() {
+        type E = bls12_381_plus::Bls12;
+        type F = bls12_381_plus::Scalar;
+
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let setup = KzgSetup::<E>::random(2, 4, &mut rng);
+        let bp = BivariatePolynomial::<F>::random(2, 4, &mut rng);
+
+        let c = setup.commit(&bp).unwrap();
+        let bytes = c.to_bytes();
+        assert_eq!(bytes.len(), 48);
+        assert_eq!(KzgCommitment::<E>::from_bytes(&bytes), Some(c));
+        assert!(KzgCommitment::<E>::from_bytes(&bytes[1..]).is_none());
+
+        let proof = setup.open_x(&bp, &F::ONE).unwrap();
+        let bytes = proof.to_bytes();
+        assert_eq!(bytes.len(), 48);
+        assert_eq!(KzgProof::<E>::from_bytes(&bytes), Some(proof));
+        assert!(KzgProof::<E>::from_bytes(&[0; 47]).is_none());
+    }
+}
@@ -1,14 +1,61 @@
 use std::{
     cmp::max,
-    ops::{Add, AddAssign},
+    fmt,
+    io::{self, Read, Write},
+    ops::{Add, AddAssign, Index, Mul, Range},
 };
 
-use group::{Group, GroupEncoding};
-use subtle::Choice;
+use group::{ff::Field, Curve, Group, GroupEncoding};
+use rand_core::RngCore;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use subtle::{Choice, ConditionallySelectable};
 
 use crate::poly::{powers, BivariatePolynomial, Polynomial};
 
-use super::VerificationVector;
+use super::{
+    multiscalar_mul, FixedBaseTable, MerkleNode, MerkleProof, MerkleTree, VerificationVector,
+};
+
+/// The size of the header of the canonical byte representation
+/// of a verification matrix, containing its dimensions.
+const CANONICAL_HEADER_SIZE: usize = 4;
+
+/// The indeterminate with respect to which a bivariate polynomial
+/// was evaluated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+    /// Evaluation with respect to x, i.e. a full share `B(x_i,y)`.
+    X,
+    /// Evaluation with respect to y, i.e. a reduced share `B(x,x_i)`.
+    Y,
+}
+
+/// The reason why a share failed to verify against a verification matrix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShareVerificationFailure {
+    /// The polynomial has an invalid number of coefficients.
+    InvalidSize { axis: Axis },
+    /// The coefficient with the given index doesn't match the verification
+    /// matrix.
+    InvalidCoefficient { axis: Axis, index: usize },
+}
+
+impl fmt::Display for ShareVerificationFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let share = |axis: &Axis| match axis {
+            Axis::X => "full share",
+            Axis::Y => "reduced share",
+        };
+
+        match self {
+            Self::InvalidSize { axis } => write!(f, "invalid size of the {}", share(axis)),
+            Self::InvalidCoefficient { axis, index } => {
+                write!(f, "invalid coefficient {} of the {}", index, share(axis))
+            }
+        }
+    }
+}
 
 /// Verification matrix for a bivariate polynomial.
 ///
@@ -55,6 +102,72 @@ where
         self.m.get(i).and_then(|bi| bi.get(j))
     }
 
+    /// Returns the number of rows in the verification matrix.
+    pub fn num_rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns in the verification matrix.
+    pub fn num_cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns the elements of the i-th row of the verification matrix
+    /// without copying them.
+    pub fn row_elements(&self, i: usize) -> Option<&[G]> {
+        self.m.get(i).map(|mi| mi.as_slice())
+    }
+
+    /// Returns an iterator over the rows of the verification matrix.
+    pub fn iter_rows(&self) -> impl Iterator<Item = &[G]> {
+        self.m.iter().map(|mi| mi.as_slice())
+    }
+
+    /// Returns a view of the whole verification matrix.
+    pub fn view(&self) -> VerificationMatrixView<'_, G> {
+        VerificationMatrixView {
+            m: &self.m,
+            row_offset: 0,
+            col_offset: 0,
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+
+    /// Returns a view of the submatrix consisting of the given rows
+    /// and columns, or `None` if the ranges are empty or out of bounds.
+    pub fn submatrix(
+        &self,
+        rows: Range<usize>,
+        cols: Range<usize>,
+    ) -> Option<VerificationMatrixView<'_, G>> {
+        self.view().submatrix(rows, cols)
+    }
+
+    /// Returns the i-th row of the verification matrix as a verification
+    /// vector.
+    ///
+    /// The row `[M_{i,j}]` is the verification vector of the univariate
+    /// polynomial `\sum_{j=0}^{deg_y} b_{i,j} y^j`, i.e. of the coefficient
+    /// of the term `x^i` of the bivariate polynomial.
+    pub fn row(&self, i: usize) -> Option<VerificationVector<G>> {
+        self.m.get(i).cloned().map(VerificationVector::new)
+    }
+
+    /// Returns the j-th column of the verification matrix as a verification
+    /// vector.
+    ///
+    /// The column `[M_{i,j}]` is the verification vector of the univariate
+    /// polynomial `\sum_{i=0}^{deg_x} b_{i,j} x^i`, i.e. of the coefficient
+    /// of the term `y^j` of the bivariate polynomial.
+    pub fn column(&self, j: usize) -> Option<VerificationVector<G>> {
+        if j >= self.cols {
+            return None;
+        }
+
+        Some(VerificationVector::new(self.column_elements(j)))
+    }
+
     /// Returns true if and only if `M_{0,0}` is the identity element
     /// of the group.
     pub fn is_zero_hole(&self) -> bool {
@@ -64,15 +177,58 @@ where
     /// Verifies whether the underlying bivariate polynomial evaluates
     /// to the given value, i.e., if it holds `B(x,y) == v`.
     pub fn verify(&self, x: &G::Scalar, y: &G::Scalar, v: &G::Scalar) -> bool {
-        let mut diff = G::generator().neg() * v;
         let xpows = powers(x, self.rows - 1); // [x^i]
         let ypows = powers(y, self.cols - 1); // [y^j]
+        let mut points = Vec::with_capacity(self.rows * self.cols);
+        let mut scalars = Vec::with_capacity(self.rows * self.cols);
         for (i, xpow) in xpows.into_iter().enumerate() {
             for (j, ypow) in ypows.iter().enumerate() {
-                diff += self.m[i][j] * (xpow * ypow); // x^i * y^j * M_{i,j} = b_{i,j} x^i * y^j * G
+                points.push(self.m[i][j]);
+                scalars.push(xpow * ypow);
             }
         }
 
+        // x^i * y^j * M_{i,j} = b_{i,j} x^i * y^j * G
+        let diff = multiscalar_mul(&points, &scalars) - G::generator() * v;
+
+        diff.is_identity().into()
+    }
+
+    /// Verifies whether the underlying bivariate polynomial evaluates
+    /// to the given values, i.e., if it holds `B(x_k,y_k) == v_k` for all
+    /// given points.
+    ///
+    /// Instead of verifying each point separately, the equations are folded
+    /// into a single check using random scalars `r_k`:
+    /// ```text
+    /// \sum_k r_k v_k * G = \sum_{i,j} (\sum_k r_k x_k^i y_k^j) * M_{i,j}
+    /// ```
+    /// which requires only one multi-scalar multiplication. If any of
+    /// the points is invalid, the check fails with overwhelming probability.
+    pub fn verify_batch(
+        &self,
+        points: &[(G::Scalar, G::Scalar, G::Scalar)],
+        rng: &mut impl RngCore,
+    ) -> bool {
+        let mut scalars = vec![G::Scalar::ZERO; self.rows * self.cols];
+        let mut v = G::Scalar::ZERO;
+
+        for (x, y, vk) in points {
+            let rk = G::Scalar::random(&mut *rng);
+            let xpows = powers(x, self.rows - 1); // [x^i]
+            let ypows = powers(y, self.cols - 1); // [y^j]
+            for (i, xpow) in xpows.iter().enumerate() {
+                let rxpow = rk * xpow;
+                for (j, ypow) in ypows.iter().enumerate() {
+                    scalars[i * self.cols + j] += rxpow * ypow; // r_k x_k^i y_k^j
+                }
+            }
+            v += rk * vk; // r_k v_k
+        }
+
+        let points: Vec<_> = self.m.iter().flatten().copied().collect();
+        let diff = multiscalar_mul(&points, &scalars) - G::generator() * v;
+
         diff.is_identity().into()
     }
 
@@ -83,11 +239,7 @@ where
         let mut v = Vec::with_capacity(self.rows);
         let ypows = powers(y, self.cols - 1); // [y^i]
         for i in 0..self.rows {
-            let mut vi = G::identity();
-            for (j, ypow) in ypows.iter().enumerate() {
-                vi += self.m[i][j] * ypow;
-            }
-            v.push(vi);
+            v.push(multiscalar_mul(&self.m[i], &ypows));
         }
 
         VerificationVector::new(v)
@@ -100,11 +252,7 @@ where
         let mut v = Vec::with_capacity(self.cols);
         let xpows = powers(x, self.rows - 1); // [x^i]
         for j in 0..self.cols {
-            let mut vj = G::identity();
-            for (i, xpow) in xpows.iter().enumerate() {
-                vj += self.m[i][j] * xpow;
-            }
-            v.push(vj);
+            v.push(multiscalar_mul(&self.column_elements(j), &xpows));
         }
 
         VerificationVector::new(v)
@@ -146,9 +294,7 @@ where
             // of the group: a_j * G - \sum_{i=0}^{deg_x} x^i * M_{i,j}.
             let aj = polynomial.coefficient(j).expect("size checked above");
             let mut diff = G::generator() * aj; // a_j * G
-            for (i, xpow) in xpows.iter().enumerate() {
-                diff -= self.m[i][j] * xpow; // x^i * M_{i,j} = b_{i,j} x^i * G
-            }
+            diff -= multiscalar_mul(&self.column_elements(j), &xpows); // x^i * M_{i,j} = b_{i,j} x^i * G
 
             verified &= diff.is_identity();
         }
@@ -193,15 +339,232 @@ where
             // of the group: a_i * G - \sum_{j=0}^{deg_y} y^j * M_{i,j}.
             let ai = polynomial.coefficient(i).expect("size checked above");
             let mut diff = G::generator() * ai; // a_i * G
-            for (j, ypow) in ypows.iter().enumerate() {
-                diff -= self.m[i][j] * ypow; // y^j * M_{i,j} = b_{i,j} y^j * G
-            }
+            diff -= multiscalar_mul(&self.m[i], &ypows); // y^j * M_{i,j} = b_{i,j} y^j * G
 
             verified &= diff.is_identity();
         }
 
         verified.into()
     }
+
+    /// Probabilistically verifies the polynomial resulting from
+    /// the evaluation of the bivariate polynomial with respect to
+    /// the indeterminate x against the verification matrix.
+    ///
+    /// Instead of verifying every coefficient, the polynomial `A(y)` is
+    /// evaluated at a random point `r`, and it is checked that
+    /// `A(r) == B(x,r)`. If the polynomials differ, they agree on at most
+    /// `deg_y` points, so the check fails to detect an invalid polynomial
+    /// with probability at most `deg_y / |F|`. This requires a single
+    /// multi-scalar multiplication, so it can be used as a cheap pre-filter
+    /// before `verify_x`.
+    ///
+    /// The random point must be unpredictable to the creator
+    /// of the polynomial.
+    ///
+    /// This method is not constant time if the size of the polynomial
+    /// is invalid.
+    pub fn verify_x_probabilistic(
+        &self,
+        x: &G::Scalar,
+        polynomial: &Polynomial<G::Scalar>,
+        rng: &mut impl RngCore,
+    ) -> bool {
+        // Short-circuit on the size of the polynomial, not its contents.
+        if polynomial.size() != self.cols {
+            return false;
+        }
+
+        let r = G::Scalar::random(rng);
+        self.verify(x, &r, &polynomial.eval(&r))
+    }
+
+    /// Probabilistically verifies the polynomial resulting from
+    /// the evaluation of the bivariate polynomial with respect to
+    /// the indeterminate y against the verification matrix.
+    ///
+    /// The polynomial `A(x)` is evaluated at a random point `r`, and it is
+    /// checked that `A(r) == B(r,y)`. The check fails to detect an invalid
+    /// polynomial with probability at most `deg_x / |F|`.
+    ///
+    /// The random point must be unpredictable to the creator
+    /// of the polynomial.
+    ///
+    /// This method is not constant time if the size of the polynomial
+    /// is invalid.
+    pub fn verify_y_probabilistic(
+        &self,
+        y: &G::Scalar,
+        polynomial: &Polynomial<G::Scalar>,
+        rng: &mut impl RngCore,
+    ) -> bool {
+        // Short-circuit on the size of the polynomial, not its contents.
+        if polynomial.size() != self.rows {
+            return false;
+        }
+
+        let r = G::Scalar::random(rng);
+        self.verify(&r, y, &polynomial.eval(&r))
+    }
+
+    /// Verifies both the full share `B(x_i,y)` and the reduced share
+    /// `B(x,x_i)` of the shareholder with the given `x_i` value against
+    /// the verification matrix, i.e. the polynomials resulting from
+    /// the evaluation of the bivariate polynomial with respect to
+    /// the indeterminates x and y, respectively.
+    ///
+    /// Unlike `verify_x` and `verify_y`, this method reports which axis
+    /// and which coefficient failed to verify, which can be used as evidence
+    /// in a dispute against the dealer. The full share is verified first.
+    ///
+    /// This method is not constant time if the verification fails.
+    pub fn verify_share(
+        &self,
+        x_i: &G::Scalar,
+        full: &Polynomial<G::Scalar>,
+        reduced: &Polynomial<G::Scalar>,
+    ) -> Result<(), ShareVerificationFailure> {
+        self.verify_x_detailed(x_i, full)?;
+        self.verify_y_detailed(x_i, reduced)
+    }
+
+    /// Verifies coefficients of the polynomial resulting from the evaluation
+    /// of the bivariate polynomial with respect to the indeterminate x,
+    /// reporting the first coefficient that failed to verify.
+    ///
+    /// This method is not constant time if the verification fails.
+    pub fn verify_x_detailed(
+        &self,
+        x: &G::Scalar,
+        polynomial: &Polynomial<G::Scalar>,
+    ) -> Result<(), ShareVerificationFailure> {
+        if polynomial.size() != self.cols {
+            return Err(ShareVerificationFailure::InvalidSize { axis: Axis::X });
+        }
+
+        let xpows = powers(x, self.rows - 1); // [x^i]
+        let failed = (0..self.cols).position(|j| {
+            let aj = polynomial.coefficient(j).expect("size checked above");
+            let diff = G::generator() * aj - multiscalar_mul(&self.column_elements(j), &xpows);
+            !bool::from(diff.is_identity())
+        });
+
+        match failed {
+            Some(index) => Err(ShareVerificationFailure::InvalidCoefficient {
+                axis: Axis::X,
+                index,
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Verifies coefficients of the polynomial resulting from the evaluation
+    /// of the bivariate polynomial with respect to the indeterminate y,
+    /// reporting the first coefficient that failed to verify.
+    ///
+    /// This method is not constant time if the verification fails.
+    pub fn verify_y_detailed(
+        &self,
+        y: &G::Scalar,
+        polynomial: &Polynomial<G::Scalar>,
+    ) -> Result<(), ShareVerificationFailure> {
+        if polynomial.size() != self.rows {
+            return Err(ShareVerificationFailure::InvalidSize { axis: Axis::Y });
+        }
+
+        let ypows = powers(y, self.cols - 1); // [y^j]
+        let failed = (0..self.rows).position(|i| {
+            let ai = polynomial.coefficient(i).expect("size checked above");
+            let diff = G::generator() * ai - multiscalar_mul(&self.m[i], &ypows);
+            !bool::from(diff.is_identity())
+        });
+
+        match failed {
+            Some(index) => Err(ShareVerificationFailure::InvalidCoefficient {
+                axis: Axis::Y,
+                index,
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Adds the given verification matrix element-wise, provided that both
+    /// matrices have the same dimensions.
+    ///
+    /// The sum is the verification matrix of the sum of the underlying
+    /// bivariate polynomials, which allows computing the commitment to
+    /// a combined secret from the matrices of individual dealers.
+    ///
+    /// Returns `None` if the dimensions of the matrices differ.
+    pub fn checked_add(&self, rhs: &VerificationMatrix<G>) -> Option<VerificationMatrix<G>> {
+        if self.dimensions() != rhs.dimensions() {
+            return None;
+        }
+
+        Some(self + rhs)
+    }
+
+    /// Aggregates the given verification matrices into the verification
+    /// matrix of the sum of the underlying bivariate polynomials.
+    ///
+    /// Returns `None` if no matrices are given, or if their dimensions differ.
+    pub fn aggregate(vms: &[VerificationMatrix<G>]) -> Option<VerificationMatrix<G>> {
+        let (first, rest) = vms.split_first()?;
+        if rest.iter().any(|vm| vm.dimensions() != first.dimensions()) {
+            return None;
+        }
+
+        let mut sum = first.clone();
+        for vm in rest {
+            sum += vm;
+        }
+
+        Some(sum)
+    }
+
+    /// Returns the elements of the given column of the verification matrix.
+    fn column_elements(&self, j: usize) -> Vec<G> {
+        self.m.iter().map(|mi| mi[j]).collect()
+    }
+}
+
+impl<G> VerificationMatrix<G>
+where
+    G: Group + ConditionallySelectable,
+{
+    /// Constructs a new verification matrix from the given bivariate
+    /// polynomial using the given fixed-base precomputation table
+    /// of the group generator.
+    ///
+    /// Converting the polynomial with [`From`] uses the shared table
+    /// of the group generator, see [`FixedBaseTable::shared_generator`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the base of the table is not the group generator.
+    pub fn from_table(bp: &BivariatePolynomial<G::Scalar>, table: &FixedBaseTable<G>) -> Self {
+        assert!(
+            *table.base() == G::generator(),
+            "table base should be the generator"
+        );
+
+        let rows = bp.deg_x + 1;
+        let cols = bp.deg_y + 1;
+
+        #[cfg(not(feature = "parallel"))]
+        let m =
+            bp.b.iter()
+                .map(|bi| bi.iter().map(|bij| table.mul(bij)).collect()) // b_{i,j} * G
+                .collect();
+
+        #[cfg(feature = "parallel")]
+        let m =
+            bp.b.par_iter()
+                .map(|bi| bi.par_iter().map(|bij| table.mul(bij)).collect()) // b_{i,j} * G
+                .collect();
+
+        Self { rows, cols, m }
+    }
 }
 
 impl<G> VerificationMatrix<G>
@@ -266,6 +629,204 @@ where
         Some(Self { cols, rows, m })
     }
 
+    /// Writes the byte representation of the verification matrix
+    /// to the given writer, one element at a time.
+    ///
+    /// The output is identical to the output of [`Self::to_bytes`],
+    /// but the full representation is never held in memory.
+    pub fn write_to<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        let deg_x = (self.rows - 1) as u8;
+        let deg_y = (self.cols - 1) as u8;
+        writer.write_all(&[deg_x, deg_y])?;
+        for mi in &self.m {
+            for mij in mi {
+                writer.write_all(mij.to_bytes().as_ref())?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Attempts to read a verification matrix from its byte representation
+    /// from the given reader, one element at a time.
+    ///
+    /// Returns an error of kind [`io::ErrorKind::InvalidData`] if any
+    /// of the elements is invalid.
+    pub fn read_from<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let mut degs = [0u8; 2];
+        reader.read_exact(&mut degs)?;
+        let rows = degs[0] as usize + 1;
+        let cols = degs[1] as usize + 1;
+
+        let mut m = Vec::with_capacity(rows);
+        let mut repr: G::Repr = Default::default();
+
+        for _ in 0..rows {
+            let mut mi = Vec::with_capacity(cols);
+
+            for _ in 0..cols {
+                reader.read_exact(repr.as_mut())?;
+
+                let mij = Option::from(G::from_bytes(&repr))
+                    .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid element"))?;
+
+                mi.push(mij);
+            }
+            m.push(mi);
+        }
+
+        Ok(Self { cols, rows, m })
+    }
+
+    /// Returns the canonical byte representation of the verification matrix.
+    ///
+    /// The representation starts with the number of rows and columns, each
+    /// encoded as a big-endian `u16`, followed by the compressed encodings
+    /// of the matrix elements in row-major order.
+    pub fn to_canonical_bytes(&self) -> Vec<u8> {
+        let cap = Self::canonical_byte_size(self.rows, self.cols);
+        let mut bytes = Vec::with_capacity(cap);
+        bytes.extend_from_slice(&(self.rows as u16).to_be_bytes());
+        bytes.extend_from_slice(&(self.cols as u16).to_be_bytes());
+        for mi in &self.m {
+            for mij in mi {
+                bytes.extend_from_slice(mij.to_bytes().as_ref());
+            }
+        }
+
+        bytes
+    }
+
+    /// Attempts to create a verification matrix from its canonical byte
+    /// representation.
+    ///
+    /// Decoding is strict: the matrix must have at least one row and one
+    /// column, the length of the input must match the dimensions exactly,
+    /// and every element must be a valid group element whose encoding
+    /// is canonical, i.e. re-encoding it yields the same bytes.
+    ///
+    /// This method is not constant time since the verification matrix doesn't
+    /// contain sensitive information.
+    pub fn from_canonical_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() < CANONICAL_HEADER_SIZE {
+            return None;
+        }
+
+        let rows = u16::from_be_bytes([bytes[0], bytes[1]]) as usize;
+        let cols = u16::from_be_bytes([bytes[2], bytes[3]]) as usize;
+        if rows == 0 || cols == 0 {
+            return None;
+        }
+        if bytes.len() != Self::canonical_byte_size(rows, cols) {
+            return None;
+        }
+
+        let element_size = Self::element_byte_size();
+        let mut m = Vec::with_capacity(rows);
+
+        for chunks in bytes[CANONICAL_HEADER_SIZE..].chunks(element_size * cols) {
+            let mut mi = Vec::with_capacity(cols);
+
+            for chunk in chunks.chunks(element_size) {
+                let mut repr: G::Repr = Default::default();
+                repr.as_mut().copy_from_slice(chunk);
+
+                let mij: G = Option::from(G::from_bytes(&repr))?;
+                if mij.to_bytes().as_ref() != chunk {
+                    return None;
+                }
+
+                mi.push(mij);
+            }
+            m.push(mi);
+        }
+
+        Some(Self { rows, cols, m })
+    }
+
+    /// Returns the size of the canonical byte representation
+    /// of the verification matrix.
+    pub fn canonical_byte_size(rows: usize, cols: usize) -> usize {
+        CANONICAL_HEADER_SIZE + rows * cols * Self::element_byte_size()
+    }
+
+    /// Returns the Merkle tree over the elements of the verification matrix.
+    ///
+    /// The leaves are the compressed encodings of the elements in row-major
+    /// order, each prefixed with the dimensions of the matrix, so that
+    /// the root also commits to the shape of the matrix.
+    pub fn merkle_tree(&self) -> MerkleTree {
+        let mut leaves = Vec::with_capacity(self.rows * self.cols);
+        for mi in &self.m {
+            for mij in mi {
+                leaves.push(Self::merkle_leaf(self.dimensions(), mij));
+            }
+        }
+
+        MerkleTree::new(&leaves)
+    }
+
+    /// Returns the root of the Merkle tree over the elements
+    /// of the verification matrix.
+    ///
+    /// The root is a compact commitment to the matrix, against which
+    /// individual elements can be verified using Merkle proofs.
+    pub fn merkle_root(&self) -> MerkleNode {
+        self.merkle_tree().root()
+    }
+
+    /// Returns a Merkle proof for the element `m_{i,j}` of the verification
+    /// matrix.
+    ///
+    /// When proving multiple elements, construct the tree once using
+    /// [`Self::merkle_tree`] and use [`Self::merkle_leaf_index`] instead.
+    pub fn merkle_proof(&self, i: usize, j: usize) -> Option<MerkleProof> {
+        if i >= self.rows || j >= self.cols {
+            return None;
+        }
+
+        self.merkle_tree()
+            .proof(Self::merkle_leaf_index(self.cols, i, j))
+    }
+
+    /// Verifies that the given element is the element `m_{i,j}` of the
+    /// verification matrix with the given dimensions and Merkle root.
+    pub fn verify_merkle_proof(
+        root: &MerkleNode,
+        (rows, cols): (usize, usize),
+        i: usize,
+        j: usize,
+        element: &G,
+        proof: &MerkleProof,
+    ) -> bool {
+        if i >= rows || j >= cols {
+            return false;
+        }
+        let depth = (rows * cols).next_power_of_two().trailing_zeros() as usize;
+        if proof.index != Self::merkle_leaf_index(cols, i, j) || proof.siblings.len() != depth {
+            return false;
+        }
+
+        proof.verify(root, Self::merkle_leaf((rows, cols), element))
+    }
+
+    /// Returns the index of the leaf of the Merkle tree containing
+    /// the element `m_{i,j}` of a verification matrix with the given number
+    /// of columns.
+    pub fn merkle_leaf_index(cols: usize, i: usize, j: usize) -> usize {
+        i * cols + j
+    }
+
+    /// Returns the leaf data of the Merkle tree for the given element
+    /// of a verification matrix with the given dimensions.
+    fn merkle_leaf((rows, cols): (usize, usize), element: &G) -> Vec<u8> {
+        let mut leaf = Vec::with_capacity(CANONICAL_HEADER_SIZE + Self::element_byte_size());
+        leaf.extend_from_slice(&(rows as u16).to_be_bytes());
+        leaf.extend_from_slice(&(cols as u16).to_be_bytes());
+        leaf.extend_from_slice(element.to_bytes().as_ref());
+        leaf
+    }
+
     /// Returns the size of the byte representation of a matrix element.
     pub fn element_byte_size() -> usize {
         // Is there a better way?
@@ -278,31 +839,79 @@ where
     }
 }
 
-impl<G> From<&BivariatePolynomial<G::Scalar>> for VerificationMatrix<G>
+impl<G> VerificationMatrix<G>
 where
-    G: Group,
+    G: Curve + GroupEncoding,
+    G::AffineRepr: GroupEncoding<Repr = G::Repr> + Default + Copy,
 {
-    /// Constructs a new verification matrix from the given bivariate
-    /// polynomial.
-    fn from(bp: &BivariatePolynomial<G::Scalar>) -> Self {
-        let rows = bp.deg_x + 1;
-        let cols = bp.deg_y + 1;
-        let mut m = Vec::new();
-        for bi in bp.b.iter() {
-            let mut mi = Vec::new();
-            for bij in bi.iter() {
-                mi.push(G::generator() * bij) // b_{i,j} * G
+    /// Returns the elements of the verification matrix in affine
+    /// coordinates.
+    ///
+    /// The elements are kept in projective coordinates, so that matrices
+    /// can be combined and verified without field inversions. Converting
+    /// them one by one to affine coordinates requires an inversion per
+    /// element, whereas this method normalizes all of them in a single batch,
+    /// which curve implementations can do with a single inversion.
+    pub fn to_affine(&self) -> Vec<Vec<G::AffineRepr>> {
+        let elements: Vec<G> = self.m.iter().flatten().copied().collect();
+        let mut affine = vec![G::AffineRepr::default(); elements.len()];
+        G::batch_normalize(&elements, &mut affine);
+
+        affine.chunks(self.cols).map(|ai| ai.to_vec()).collect()
+    }
+
+    /// Returns the byte representation of the verification matrix,
+    /// normalizing the elements to affine coordinates in a single batch.
+    ///
+    /// The result is the same as the one of `to_bytes`.
+    pub fn to_bytes_normalized(&self) -> Vec<u8> {
+        let cap = Self::byte_size(self.rows, self.cols);
+        let mut bytes = Vec::with_capacity(cap);
+        let deg_x = (self.rows - 1) as u8;
+        let deg_y = (self.cols - 1) as u8;
+        bytes.extend([deg_x, deg_y].iter());
+        for ai in self.to_affine() {
+            for aij in ai {
+                bytes.extend_from_slice(aij.to_bytes().as_ref());
             }
-            m.push(mi)
         }
 
-        Self { rows, cols, m }
+        bytes
     }
 }
 
-impl<G> From<BivariatePolynomial<G::Scalar>> for VerificationMatrix<G>
+impl<G> Index<(usize, usize)> for VerificationMatrix<G>
 where
     G: Group,
+{
+    type Output = G;
+
+    /// Returns the element `M_{i,j}`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    fn index(&self, (i, j): (usize, usize)) -> &G {
+        &self.m[i][j]
+    }
+}
+
+impl<G> From<&BivariatePolynomial<G::Scalar>> for VerificationMatrix<G>
+where
+    G: Group + ConditionallySelectable,
+{
+    /// Constructs a new verification matrix from the given bivariate
+    /// polynomial using the shared fixed-base precomputation table
+    /// of the group generator.
+    fn from(bp: &BivariatePolynomial<G::Scalar>) -> Self {
+        let table = FixedBaseTable::shared_generator();
+        Self::from_table(bp, &table)
+    }
+}
+
+impl<G> From<BivariatePolynomial<G::Scalar>> for VerificationMatrix<G>
+where
+    G: Group + ConditionallySelectable,
 {
     /// Constructs a new verification matrix from the given bivariate
     /// polynomial.
@@ -410,8 +1019,129 @@ where
     }
 }
 
+impl<G> Mul<&G::Scalar> for VerificationMatrix<G>
+where
+    G: Group,
+{
+    type Output = VerificationMatrix<G>;
+
+    #[inline]
+    fn mul(self, rhs: &G::Scalar) -> VerificationMatrix<G> {
+        &self * rhs
+    }
+}
+
+impl<G> Mul<&G::Scalar> for &VerificationMatrix<G>
+where
+    G: Group,
+{
+    type Output = VerificationMatrix<G>;
+
+    /// Multiplies the verification matrix by the given scalar, resulting
+    /// in the verification matrix of the scaled bivariate polynomial.
+    fn mul(self, rhs: &G::Scalar) -> VerificationMatrix<G> {
+        let m = self
+            .m
+            .iter()
+            .map(|mi| mi.iter().map(|mij| *mij * rhs).collect())
+            .collect();
+
+        VerificationMatrix {
+            rows: self.rows,
+            cols: self.cols,
+            m,
+        }
+    }
+}
+
+/// Borrowed view of a (sub)matrix of a verification matrix.
+///
+/// Views are cheap to construct and copy, as they only reference
+/// the elements of the underlying verification matrix.
+#[derive(Debug, Clone, Copy)]
+pub struct VerificationMatrixView<'a, G>
+where
+    G: Group,
+{
+    /// The rows of the underlying verification matrix.
+    m: &'a [Vec<G>],
+    /// The index of the first row of the view.
+    row_offset: usize,
+    /// The index of the first column of the view.
+    col_offset: usize,
+    /// The number of rows in the view.
+    rows: usize,
+    /// The number of columns in the view.
+    cols: usize,
+}
+
+impl<'a, G> VerificationMatrixView<'a, G>
+where
+    G: Group,
+{
+    /// Returns the dimensions (number of rows and columns) of the view.
+    pub fn dimensions(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// Returns the element `M_{i,j}` of the view.
+    pub fn element(&self, i: usize, j: usize) -> Option<&'a G> {
+        if j >= self.cols {
+            return None;
+        }
+
+        self.row(i).map(|mi| &mi[j])
+    }
+
+    /// Returns the elements of the i-th row of the view.
+    pub fn row(&self, i: usize) -> Option<&'a [G]> {
+        if i >= self.rows {
+            return None;
+        }
+
+        let mi = &self.m[self.row_offset + i];
+        Some(&mi[self.col_offset..self.col_offset + self.cols])
+    }
+
+    /// Returns an iterator over the rows of the view.
+    pub fn iter_rows(&self) -> impl Iterator<Item = &'a [G]> {
+        let (col_offset, cols) = (self.col_offset, self.cols);
+        self.m[self.row_offset..self.row_offset + self.rows]
+            .iter()
+            .map(move |mi| &mi[col_offset..col_offset + cols])
+    }
+
+    /// Returns a view of the submatrix consisting of the given rows
+    /// and columns of this view, or `None` if the ranges are empty
+    /// or out of bounds.
+    pub fn submatrix(&self, rows: Range<usize>, cols: Range<usize>) -> Option<Self> {
+        if rows.is_empty() || rows.end > self.rows || cols.is_empty() || cols.end > self.cols {
+            return None;
+        }
+
+        Some(Self {
+            m: self.m,
+            row_offset: self.row_offset + rows.start,
+            col_offset: self.col_offset + cols.start,
+            rows: rows.len(),
+            cols: cols.len(),
+        })
+    }
+
+    /// Copies the elements of the view into a new verification matrix.
+    pub fn to_matrix(&self) -> VerificationMatrix<G> {
+        VerificationMatrix {
+            rows: self.rows,
+            cols: self.cols,
+            m: self.iter_rows().map(|mi| mi.to_vec()).collect(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use std::{io, panic};
+
     use group::Group as _;
     use rand::{rngs::StdRng, SeedableRng};
 
@@ -462,6 +1192,35 @@ mod tests {
         let _ = VerificationMatrix::from(&bp);
     }
 
+    #[test]
+    fn test_from_table() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let table = vss::FixedBaseTable::generator();
+
+        let bp = BivariatePolynomial::zero(2, 3);
+        let vm = VerificationMatrix::from_table(&bp, &table);
+        assert_eq!(vm, VerificationMatrix::from(&bp));
+
+        let bp = BivariatePolynomial::random(5, 10, &mut rng);
+        let vm = VerificationMatrix::from_table(&bp, &table);
+        assert_eq!(vm, VerificationMatrix::from(&bp));
+
+        // The table yields the same elements as plain multiplication.
+        for (i, bi) in bp.b.iter().enumerate() {
+            for (j, bij) in bi.iter().enumerate() {
+                assert_eq!(vm.element(i, j).unwrap(), &(Group::GENERATOR * bij));
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "table base should be the generator")]
+    fn test_from_table_invalid_base() {
+        let table = vss::FixedBaseTable::new(Group::GENERATOR.double(), 4);
+        let bp = BivariatePolynomial::zero(2, 3);
+        let _ = VerificationMatrix::from_table(&bp, &table);
+    }
+
     #[test]
     fn test_dimensions() {
         let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
@@ -483,6 +1242,70 @@ mod tests {
         assert_eq!(&e, vm.element(1, 2).unwrap());
     }
 
+    #[test]
+    fn test_index() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(2, 3, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+
+        assert_eq!(vm.num_rows(), 3);
+        assert_eq!(vm.num_cols(), 4);
+        for i in 0..3 {
+            for j in 0..4 {
+                assert_eq!(&vm[(i, j)], vm.element(i, j).unwrap());
+            }
+        }
+
+        let res = panic::catch_unwind(|| vm[(3, 0)]);
+        assert!(res.is_err());
+        let res = panic::catch_unwind(|| vm[(0, 4)]);
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn test_views() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(3, 4, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+
+        // Rows.
+        assert_eq!(vm.row_elements(1), Some(&vm.m[1][..]));
+        assert_eq!(vm.row_elements(4), None);
+        assert!(vm.iter_rows().eq(vm.m.iter().map(|mi| &mi[..])));
+
+        // Whole matrix.
+        let view = vm.view();
+        assert_eq!(view.dimensions(), (4, 5));
+        assert_eq!(view.to_matrix(), vm);
+
+        // Submatrix.
+        let view = vm.submatrix(1..3, 2..5).unwrap();
+        assert_eq!(view.dimensions(), (2, 3));
+        assert_eq!(view.element(0, 0), vm.element(1, 2));
+        assert_eq!(view.element(1, 2), vm.element(2, 4));
+        assert_eq!(view.element(2, 0), None);
+        assert_eq!(view.element(0, 3), None);
+        assert_eq!(view.row(1), Some(&vm.m[2][2..5]));
+        assert_eq!(view.row(2), None);
+        assert_eq!(view.iter_rows().count(), 2);
+
+        let sub = view.to_matrix();
+        assert_eq!(sub.dimensions(), (2, 3));
+        assert_eq!(sub.element(1, 1), vm.element(2, 3));
+
+        // Nested submatrix.
+        let nested = view.submatrix(1..2, 1..3).unwrap();
+        assert_eq!(nested.dimensions(), (1, 2));
+        assert_eq!(nested.element(0, 0), vm.element(2, 3));
+
+        // Invalid ranges.
+        assert!(vm.submatrix(0..0, 0..1).is_none());
+        assert!(vm.submatrix(0..1, 2..2).is_none());
+        assert!(vm.submatrix(0..5, 0..1).is_none());
+        assert!(vm.submatrix(0..1, 3..6).is_none());
+        assert!(view.submatrix(0..3, 0..1).is_none());
+    }
+
     #[test]
     fn test_verify() {
         let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
@@ -496,6 +1319,33 @@ mod tests {
         assert!(!vm.verify(&x3, &x2, &s));
     }
 
+    #[test]
+    fn test_verify_batch() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(2, 4, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+
+        let mut points: Vec<_> = (1..=5)
+            .flat_map(|x| (1..=5).map(move |y| (scalar(x), scalar(y))))
+            .map(|(x, y)| (x, y, bp.eval(&x, &y)))
+            .collect();
+
+        // Valid points.
+        assert!(vm.verify_batch(&[], &mut rng));
+        assert!(vm.verify_batch(&points[..1], &mut rng));
+        assert!(vm.verify_batch(&points, &mut rng));
+
+        // One invalid point.
+        points[7].2 += PrimeField::ONE;
+        assert!(!vm.verify_batch(&points, &mut rng));
+        assert!(vm.verify_batch(&points[..7], &mut rng));
+
+        // Invalid points which cancel out without randomization.
+        points[8].2 -= PrimeField::ONE;
+        assert!(!vm.verify_batch(&points, &mut rng));
+        assert!(!vm.verify_batch(&points[7..9], &mut rng));
+    }
+
     #[test]
     fn test_verification_polynomial_for_x() {
         let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
@@ -570,6 +1420,87 @@ mod tests {
         assert!(vm.verify_y(&y2, &p));
     }
 
+    #[test]
+    fn test_verify_probabilistic() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let x2 = scalar(2);
+        let x3 = scalar(3);
+
+        let bp = BivariatePolynomial::random(2, 4, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+
+        // Full shares.
+        let p = bp.eval_x(&x2);
+        assert!(vm.verify_x_probabilistic(&x2, &p, &mut rng));
+        assert!(!vm.verify_x_probabilistic(&x3, &p, &mut rng));
+        assert!(!vm.verify_y_probabilistic(&x2, &p, &mut rng)); // Invalid degree.
+
+        let mut invalid = p.clone();
+        *invalid.coefficient_mut(4).unwrap() += scalar(1);
+        assert!(!vm.verify_x_probabilistic(&x2, &invalid, &mut rng));
+
+        // Reduced shares.
+        let p = bp.eval_y(&x2);
+        assert!(vm.verify_y_probabilistic(&x2, &p, &mut rng));
+        assert!(!vm.verify_y_probabilistic(&x3, &p, &mut rng));
+        assert!(!vm.verify_x_probabilistic(&x2, &p, &mut rng)); // Invalid degree.
+
+        let mut invalid = p.clone();
+        *invalid.constant_mut() += scalar(1);
+        assert!(!vm.verify_y_probabilistic(&x2, &invalid, &mut rng));
+    }
+
+    #[test]
+    fn test_verify_share() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let x2 = scalar(2);
+
+        let bp = BivariatePolynomial::random(2, 4, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+        let full = bp.eval_x(&x2);
+        let reduced = bp.eval_y(&x2);
+        assert_eq!(vm.verify_share(&x2, &full, &reduced), Ok(()));
+        assert_eq!(vm.verify_x_detailed(&x2, &full), Ok(()));
+        assert_eq!(vm.verify_y_detailed(&x2, &reduced), Ok(()));
+
+        // Invalid sizes.
+        assert_eq!(
+            vm.verify_share(&x2, &reduced, &reduced),
+            Err(vss::ShareVerificationFailure::InvalidSize { axis: vss::Axis::X })
+        );
+        assert_eq!(
+            vm.verify_share(&x2, &full, &full),
+            Err(vss::ShareVerificationFailure::InvalidSize { axis: vss::Axis::Y })
+        );
+
+        // Invalid coefficients.
+        let mut invalid = full.clone();
+        *invalid.coefficient_mut(3).unwrap() += scalar(1);
+        assert_eq!(
+            vm.verify_share(&x2, &invalid, &reduced),
+            Err(vss::ShareVerificationFailure::InvalidCoefficient {
+                axis: vss::Axis::X,
+                index: 3
+            })
+        );
+
+        let mut invalid = reduced.clone();
+        *invalid.coefficient_mut(1).unwrap() += scalar(1);
+        *invalid.coefficient_mut(2).unwrap() += scalar(1);
+        assert_eq!(
+            vm.verify_share(&x2, &full, &invalid),
+            Err(vss::ShareVerificationFailure::InvalidCoefficient {
+                axis: vss::Axis::Y,
+                index: 1
+            })
+        );
+
+        // Shares of another shareholder.
+        let x3 = scalar(3);
+        assert!(vm.verify_share(&x3, &full, &reduced).is_err());
+        assert!(vm.verify_share(&x3, &bp.eval_x(&x3), &reduced).is_err());
+    }
+
     #[test]
     fn test_serialization() {
         let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
@@ -581,6 +1512,189 @@ mod tests {
         assert_eq!(vm, restored);
     }
 
+    #[test]
+    fn test_normalization() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(2, 3, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+
+        let affine = vm.to_affine();
+        assert_eq!(affine.len(), 3);
+        for (ai, mi) in affine.iter().zip(vm.m.iter()) {
+            assert_eq!(ai.len(), 4);
+            for (aij, mij) in ai.iter().zip(mi.iter()) {
+                assert_eq!(aij, &mij.to_affine());
+            }
+        }
+        assert_eq!(vm.to_bytes_normalized(), vm.to_bytes());
+    }
+
+    #[cfg(feature = "pasta")]
+    #[test]
+    fn test_batch_normalization() {
+        // Pallas implements batch normalization with a single inversion.
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = poly::BivariatePolynomial::random(2, 3, &mut rng);
+        let mut vm = vss::VerificationMatrix::<pasta_curves::pallas::Point>::from(&bp);
+        vm.m[1][2] = pasta_curves::pallas::Point::identity();
+
+        assert_eq!(vm.to_bytes_normalized(), vm.to_bytes());
+    }
+
+    #[test]
+    fn test_streaming_serialization() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(2, 3, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+
+        let mut bytes = Vec::new();
+        vm.write_to(&mut bytes)
+            .expect("serialization should succeed");
+        assert_eq!(bytes, vm.to_bytes());
+
+        let restored =
+            VerificationMatrix::read_from(&mut &bytes[..]).expect("deserialization should succeed");
+        assert_eq!(vm, restored);
+
+        // Truncated input.
+        let res = VerificationMatrix::read_from(&mut &bytes[..bytes.len() - 1]);
+        assert_eq!(res.err().unwrap().kind(), io::ErrorKind::UnexpectedEof);
+
+        // Invalid element.
+        let mut invalid = bytes.clone();
+        let size = VerificationMatrix::element_byte_size();
+        invalid[2..2 + size].fill(0xFF);
+        let res = VerificationMatrix::read_from(&mut &invalid[..]);
+        assert_eq!(res.err().unwrap().kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_canonical_serialization() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(2, 3, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+
+        let bytes = vm.to_canonical_bytes();
+        assert_eq!(bytes.len(), VerificationMatrix::canonical_byte_size(3, 4));
+        assert_eq!(bytes[..4], [0, 3, 0, 4]);
+        assert_eq!(bytes.len(), 4 + 3 * 4 * 49);
+
+        let restored = VerificationMatrix::from_canonical_bytes(&bytes)
+            .expect("deserialization should succeed");
+        assert_eq!(vm, restored);
+
+        // Zero-hole matrices contain the identity element.
+        let mut bp = bp;
+        bp.to_zero_hole();
+        let vm = VerificationMatrix::from(&bp);
+        let restored = VerificationMatrix::from_canonical_bytes(&vm.to_canonical_bytes())
+            .expect("deserialization should succeed");
+        assert_eq!(vm, restored);
+
+        // Invalid length.
+        assert!(VerificationMatrix::from_canonical_bytes(&[]).is_none());
+        assert!(VerificationMatrix::from_canonical_bytes(&bytes[..3]).is_none());
+        assert!(VerificationMatrix::from_canonical_bytes(&bytes[..bytes.len() - 1]).is_none());
+        let mut invalid = bytes.clone();
+        invalid.push(0);
+        assert!(VerificationMatrix::from_canonical_bytes(&invalid).is_none());
+
+        // Invalid dimensions.
+        let mut invalid = bytes.clone();
+        invalid[1] = 4;
+        assert!(VerificationMatrix::from_canonical_bytes(&invalid).is_none());
+        assert!(VerificationMatrix::from_canonical_bytes(&[0, 0, 0, 0]).is_none());
+        assert!(VerificationMatrix::from_canonical_bytes(&[0, 1, 0, 0]).is_none());
+
+        // Invalid element.
+        let mut invalid = bytes.clone();
+        invalid[4] = 0xff;
+        assert!(VerificationMatrix::from_canonical_bytes(&invalid).is_none());
+    }
+
+    #[cfg(feature = "ed25519")]
+    #[test]
+    fn test_canonical_serialization_non_canonical_element() {
+        type VerificationMatrix = vss::VerificationMatrix<curve25519_dalek::EdwardsPoint>;
+
+        // The identity point encoded canonically (y = 1) and non-canonically
+        // (y = p + 1).
+        let mut canonical = vec![0, 1, 0, 1, 1];
+        canonical.extend_from_slice(&[0u8; 31]);
+        let mut non_canonical = vec![0, 1, 0, 1, 0xee];
+        non_canonical.extend_from_slice(&[0xff; 30]);
+        non_canonical.push(0x7f);
+
+        let vm = VerificationMatrix::from_canonical_bytes(&canonical).unwrap();
+        assert!(vm.is_zero_hole());
+        assert!(VerificationMatrix::from_bytes(&[&[0, 0], &non_canonical[4..]].concat()).is_some());
+        assert!(VerificationMatrix::from_canonical_bytes(&non_canonical).is_none());
+    }
+
+    #[test]
+    fn test_merkle_root() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(2, 3, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+        let root = vm.merkle_root();
+        let dims = vm.dimensions();
+
+        assert_eq!(root, vm.merkle_tree().root());
+        assert_eq!(root.len(), 32);
+
+        for i in 0..3 {
+            for j in 0..4 {
+                let element = vm.element(i, j).unwrap();
+                let proof = vm.merkle_proof(i, j).unwrap();
+                assert!(VerificationMatrix::verify_merkle_proof(
+                    &root, dims, i, j, element, &proof
+                ));
+
+                // Wrong element.
+                let other = *element + Group::generator();
+                assert!(!VerificationMatrix::verify_merkle_proof(
+                    &root, dims, i, j, &other, &proof
+                ));
+
+                // Wrong position.
+                assert!(!VerificationMatrix::verify_merkle_proof(
+                    &root,
+                    dims,
+                    (i + 1) % 3,
+                    j,
+                    element,
+                    &proof
+                ));
+
+                // Wrong dimensions.
+                assert!(!VerificationMatrix::verify_merkle_proof(
+                    &root,
+                    (4, 3),
+                    i,
+                    j,
+                    element,
+                    &proof
+                ));
+            }
+        }
+
+        // Out of bounds.
+        assert!(vm.merkle_proof(3, 0).is_none());
+        assert!(vm.merkle_proof(0, 4).is_none());
+
+        // Different matrices have different roots.
+        let other = VerificationMatrix::from(&BivariatePolynomial::random(2, 3, &mut rng));
+        assert_ne!(root, other.merkle_root());
+
+        // Matrices with the same elements but different dimensions have
+        // different roots.
+        let b = vec![scalars(&[1, 2, 3, 4])];
+        let vm1 = VerificationMatrix::from(&BivariatePolynomial::with_coefficients(b));
+        let b = vec![scalars(&[1, 2]), scalars(&[3, 4])];
+        let vm2 = VerificationMatrix::from(&BivariatePolynomial::with_coefficients(b));
+        assert_ne!(vm1.merkle_root(), vm2.merkle_root());
+    }
+
     #[test]
     fn test_element_byte_size() {
         let size = VerificationMatrix::element_byte_size();
@@ -593,6 +1707,22 @@ mod tests {
         assert_eq!(size, 2 + 2 * 3 * 49);
     }
 
+    #[test]
+    pub fn test_mul() {
+        let bp =
+            BivariatePolynomial::with_coefficients(vec![scalars(&[0, 1, 2]), scalars(&[3, 4, 5])]);
+        let scaled = BivariatePolynomial::with_coefficients(vec![
+            scalars(&[0, 3, 6]),
+            scalars(&[9, 12, 15]),
+        ]);
+        let vm = VerificationMatrix::from(&bp);
+        let expected = VerificationMatrix::from(&scaled);
+        let c = scalar(3);
+
+        assert_eq!(&vm * &c, expected);
+        assert_eq!(vm * &c, expected);
+    }
+
     #[test]
     pub fn test_add() {
         let test_cases = vec![
@@ -657,4 +1787,64 @@ mod tests {
             assert_eq!(sum, vm3);
         }
     }
+
+    #[test]
+    fn test_aggregate() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bps: Vec<_> = (0..3)
+            .map(|_| BivariatePolynomial::random(2, 4, &mut rng))
+            .collect();
+        let vms: Vec<_> = bps.iter().map(VerificationMatrix::from).collect();
+        let bp: BivariatePolynomial = bps.iter().sum();
+        let vm = VerificationMatrix::from(&bp);
+
+        // Same dimensions.
+        assert_eq!(vms[0].checked_add(&vms[1]), Some(&vms[0] + &vms[1]));
+        assert_eq!(VerificationMatrix::aggregate(&vms), Some(vm.clone()));
+        assert_eq!(
+            VerificationMatrix::aggregate(&vms[..1]),
+            Some(vms[0].clone())
+        );
+
+        // The aggregate verifies shares of the combined polynomial.
+        let x = scalar(2);
+        let y = scalar(3);
+        assert!(vm.verify(&x, &y, &bp.eval(&x, &y)));
+
+        // No matrices.
+        assert_eq!(VerificationMatrix::aggregate(&[]), None);
+
+        // Different dimensions.
+        let other = VerificationMatrix::from(&BivariatePolynomial::random(2, 3, &mut rng));
+        assert_eq!(vms[0].checked_add(&other), None);
+        assert_eq!(other.checked_add(&vms[0]), None);
+        assert_eq!(
+            VerificationMatrix::aggregate(&[vms[0].clone(), other, vms[1].clone()]),
+            None
+        );
+    }
+
+    #[test]
+    fn test_row_and_column() {
+        let b = vec![scalars(&[1, 2, 3]), scalars(&[4, 5, 6])];
+        let bp = BivariatePolynomial::with_coefficients(b);
+        let vm = VerificationMatrix::from(&bp);
+
+        let row = vm.row(1).unwrap();
+        let p = poly::Polynomial::with_coefficients(scalars(&[4, 5, 6]));
+        assert!(row.is_from(&p));
+
+        let column = vm.column(2).unwrap();
+        let p = poly::Polynomial::with_coefficients(scalars(&[3, 6]));
+        assert!(column.is_from(&p));
+
+        // Out of bounds.
+        assert!(vm.row(2).is_none());
+        assert!(vm.column(3).is_none());
+
+        // The first row and column are the vectors for zero values.
+        let zero = PrimeField::ZERO;
+        assert_eq!(vm.row(0), Some(vm.verification_vector_for_y(&zero)));
+        assert_eq!(vm.column(0), Some(vm.verification_vector_for_x(&zero)));
+    }
 }
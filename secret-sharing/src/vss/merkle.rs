@@ -0,0 +1,170 @@
+use sha3::{Digest, Sha3_256};
+
+/// The size of a Merkle tree node in bytes.
+pub const MERKLE_NODE_SIZE: usize = 32;
+
+/// A Merkle tree node.
+pub type MerkleNode = [u8; MERKLE_NODE_SIZE];
+
+/// Domain separation prefix for leaf nodes.
+const LEAF_PREFIX: u8 = 0x00;
+
+/// Domain separation prefix for internal nodes.
+const INTERNAL_PREFIX: u8 = 0x01;
+
+/// Node used to pad the leaves to a power of two.
+const EMPTY_NODE: MerkleNode = [0; MERKLE_NODE_SIZE];
+
+/// Binary Merkle tree over SHA3-256.
+///
+/// Leaf and internal nodes are domain separated, and the leaves are padded
+/// with empty nodes to the nearest power of two, so that all proofs of
+/// a tree have the same length.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MerkleTree {
+    /// The number of leaves, excluding padding.
+    size: usize,
+    /// Tree levels, from the (padded) leaves up to the root.
+    levels: Vec<Vec<MerkleNode>>,
+}
+
+impl MerkleTree {
+    /// Constructs a new Merkle tree from the given leaf data.
+    pub fn new<T: AsRef<[u8]>>(leaves: &[T]) -> Self {
+        let size = leaves.len();
+        let width = size.max(1).next_power_of_two();
+
+        let mut level: Vec<MerkleNode> = leaves.iter().map(Self::hash_leaf).collect();
+        level.resize(width, EMPTY_NODE);
+
+        let mut levels = vec![level];
+        while levels[levels.len() - 1].len() > 1 {
+            let level = levels[levels.len() - 1]
+                .chunks(2)
+                .map(|pair| Self::hash_internal(&pair[0], &pair[1]))
+                .collect();
+            levels.push(level);
+        }
+
+        Self { size, levels }
+    }
+
+    /// Returns the root of the Merkle tree.
+    pub fn root(&self) -> MerkleNode {
+        self.levels[self.levels.len() - 1][0]
+    }
+
+    /// Returns the number of leaves, excluding padding.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns the length of the proofs, i.e. the depth of the tree.
+    pub fn depth(&self) -> usize {
+        self.levels.len() - 1
+    }
+
+    /// Returns an inclusion proof for the leaf at the given index.
+    pub fn proof(&self, index: usize) -> Option<MerkleProof> {
+        if index >= self.size {
+            return None;
+        }
+
+        let mut siblings = Vec::with_capacity(self.depth());
+        let mut idx = index;
+        for level in &self.levels[..self.depth()] {
+            siblings.push(level[idx ^ 1]);
+            idx >>= 1;
+        }
+
+        Some(MerkleProof { index, siblings })
+    }
+
+    /// Hashes leaf data to a leaf node.
+    pub fn hash_leaf(data: impl AsRef<[u8]>) -> MerkleNode {
+        let mut hasher = Sha3_256::new();
+        hasher.update([LEAF_PREFIX]);
+        hasher.update(data);
+        hasher.finalize().into()
+    }
+
+    /// Hashes two child nodes to their parent node.
+    fn hash_internal(left: &MerkleNode, right: &MerkleNode) -> MerkleNode {
+        let mut hasher = Sha3_256::new();
+        hasher.update([INTERNAL_PREFIX]);
+        hasher.update(left);
+        hasher.update(right);
+        hasher.finalize().into()
+    }
+}
+
+/// Merkle inclusion proof.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MerkleProof {
+    /// The index of the leaf.
+    pub index: usize,
+    /// The sibling nodes on the path from the leaf to the root.
+    pub siblings: Vec<MerkleNode>,
+}
+
+impl MerkleProof {
+    /// Verifies that the given leaf data is included in the tree with
+    /// the given root, at the index of the proof.
+    pub fn verify(&self, root: &MerkleNode, leaf: impl AsRef<[u8]>) -> bool {
+        let mut node = MerkleTree::hash_leaf(leaf);
+        let mut idx = self.index;
+        for sibling in &self.siblings {
+            node = match idx & 1 {
+                0 => MerkleTree::hash_internal(&node, sibling),
+                _ => MerkleTree::hash_internal(sibling, &node),
+            };
+            idx >>= 1;
+        }
+
+        idx == 0 && &node == root
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MerkleTree;
+
+    #[test]
+    fn test_merkle_tree() {
+        for size in 1..=9 {
+            let leaves: Vec<_> = (0..size).map(|i| vec![i as u8; i + 1]).collect();
+            let tree = MerkleTree::new(&leaves);
+            let root = tree.root();
+
+            assert_eq!(tree.size(), size);
+            assert_eq!(1 << tree.depth(), size.next_power_of_two());
+            assert!(tree.proof(size).is_none());
+
+            for (i, leaf) in leaves.iter().enumerate() {
+                let proof = tree.proof(i).unwrap();
+                assert_eq!(proof.siblings.len(), tree.depth());
+                assert!(proof.verify(&root, leaf));
+
+                // Invalid leaf.
+                assert!(!proof.verify(&root, b"invalid"));
+
+                // Invalid index.
+                let mut invalid = proof.clone();
+                invalid.index = i ^ 1;
+                assert!(!invalid.verify(&root, leaf));
+                invalid.index = i + (1 << tree.depth());
+                assert!(!invalid.verify(&root, leaf));
+            }
+        }
+
+        // Different leaves result in different roots.
+        let a = MerkleTree::new(&[b"a", b"b"]);
+        let b = MerkleTree::new(&[b"b", b"a"]);
+        assert_ne!(a.root(), b.root());
+
+        // Padding is not equivalent to an empty leaf.
+        let a = MerkleTree::new(&[&b"a"[..]]);
+        let b = MerkleTree::new(&[&b"a"[..], &b""[..]]);
+        assert_ne!(a.root(), b.root());
+    }
+}
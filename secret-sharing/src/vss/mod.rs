@@ -1,7 +1,19 @@
 //! Verifiable secret sharing.
 
+mod hash;
+#[cfg(feature = "kzg")]
+mod kzg;
 mod matrix;
+mod merkle;
+mod msm;
+mod pedersen;
+mod scheme;
+mod table;
 mod vector;
 
 // Re-exports.
-pub use self::{matrix::*, vector::*};
+#[cfg(feature = "kzg")]
+pub use self::kzg::*;
+pub use self::{
+    hash::*, matrix::*, merkle::*, msm::*, pedersen::*, scheme::*, table::*, vector::*,
+};
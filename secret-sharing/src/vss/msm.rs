@@ -0,0 +1,211 @@
+use group::{ff::PrimeField, Group};
+
+/// The number of points below which scalar multiplications are computed
+/// one by one, as bucket accumulation doesn't pay off.
+const MSM_THRESHOLD: usize = 4;
+
+/// Computes the multi-scalar multiplication `\sum_i s_i * P_i` using
+/// Pippenger's bucket method.
+///
+/// Instead of computing every scalar multiplication on its own, scalars
+/// are split into windows of `c` bits, points are accumulated into buckets
+/// according to the digits of their scalars, and the buckets are combined
+/// using a running sum. This replaces most of the doublings with additions
+/// and significantly reduces the cost when verifying many coefficients
+/// at once.
+///
+/// This function is not constant time and must not be used with secret
+/// scalars.
+///
+/// # Panics
+///
+/// Panics if the number of points and scalars differ.
+pub fn multiscalar_mul<G: Group>(points: &[G], scalars: &[G::Scalar]) -> G {
+    assert_eq!(points.len(), scalars.len(), "length mismatch");
+
+    if points.len() < MSM_THRESHOLD {
+        return points
+            .iter()
+            .zip(scalars)
+            .fold(G::identity(), |acc, (p, s)| acc + *p * s);
+    }
+
+    let c = window_size(points.len());
+    let num_windows = (G::Scalar::NUM_BITS as usize + c - 1) / c;
+    let scalars: Vec<_> = scalars.iter().map(to_le_bytes).collect();
+
+    let mut buckets = vec![G::identity(); (1 << c) - 1];
+    let mut acc = G::identity();
+
+    for w in (0..num_windows).rev() {
+        for _ in 0..c {
+            acc = acc.double();
+        }
+
+        // Accumulate points into buckets according to the digits
+        // of their scalars in the current window.
+        buckets.iter_mut().for_each(|b| *b = G::identity());
+        for (p, s) in points.iter().zip(scalars.iter()) {
+            let digit = window(s, w * c, c);
+            if digit != 0 {
+                buckets[digit - 1] += p;
+            }
+        }
+
+        // Compute \sum_d d * B_d using a running sum.
+        let mut running = G::identity();
+        let mut sum = G::identity();
+        for b in buckets.iter().rev() {
+            running += b;
+            sum += running;
+        }
+
+        acc += sum;
+    }
+
+    acc
+}
+
+/// Returns the window size which minimizes the number of group operations
+/// for the given number of points.
+fn window_size(n: usize) -> usize {
+    match n {
+        0..=31 => 3,
+        _ => (usize::BITS - n.leading_zeros()) as usize * 69 / 100 + 2,
+    }
+}
+
+/// Returns the little-endian byte representation of the given scalar.
+///
+/// The endianness of the canonical representation is not defined
+/// by the `PrimeField` trait, so it is inferred from the representation
+/// of the multiplicative identity.
+pub(super) fn to_le_bytes<F: PrimeField>(s: &F) -> Vec<u8> {
+    let mut bytes = s.to_repr().as_ref().to_vec();
+    if F::ONE.to_repr().as_ref()[0] != 1 {
+        bytes.reverse();
+    }
+    bytes
+}
+
+/// Returns `c` bits of the given little-endian byte string, starting
+/// at the given bit offset.
+pub(super) fn window(bytes: &[u8], offset: usize, c: usize) -> usize {
+    let mut digit = 0;
+    for k in 0..c {
+        let bit = offset + k;
+        let byte = match bytes.get(bit / 8) {
+            Some(byte) => *byte,
+            None => break,
+        };
+        digit |= (((byte >> (bit % 8)) & 1) as usize) << k;
+    }
+    digit
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate test;
+
+    use group::{ff::Field, Group as _};
+    use rand::{rngs::StdRng, SeedableRng};
+    use test::Bencher;
+
+    use crate::{poly::BivariatePolynomial, vss::VerificationMatrix};
+
+    use super::{multiscalar_mul, to_le_bytes, window};
+
+    fn naive<G: group::Group>(points: &[G], scalars: &[G::Scalar]) -> G {
+        points
+            .iter()
+            .zip(scalars)
+            .fold(G::identity(), |acc, (p, s)| acc + *p * s)
+    }
+
+    fn test_multiscalar_mul<G: group::Group>() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+
+        for n in [0, 1, 2, 3, 4, 5, 16, 33, 100] {
+            let points: Vec<_> = (0..n).map(|_| G::random(&mut rng)).collect();
+            let mut scalars: Vec<_> = (0..n).map(|_| G::Scalar::random(&mut rng)).collect();
+
+            // Special scalars.
+            if n > 2 {
+                scalars[0] = G::Scalar::ZERO;
+                scalars[1] = G::Scalar::ONE;
+                scalars[2] = -G::Scalar::ONE;
+            }
+
+            assert_eq!(multiscalar_mul(&points, &scalars), naive(&points, &scalars));
+        }
+    }
+
+    #[test]
+    fn test_multiscalar_mul_p384() {
+        // Big-endian scalar representation.
+        test_multiscalar_mul::<p384::ProjectivePoint>();
+    }
+
+    #[cfg(feature = "ristretto255")]
+    #[test]
+    fn test_multiscalar_mul_ristretto255() {
+        // Little-endian scalar representation.
+        test_multiscalar_mul::<curve25519_dalek::RistrettoPoint>();
+    }
+
+    #[test]
+    #[should_panic(expected = "length mismatch")]
+    fn test_multiscalar_mul_length_mismatch() {
+        let points = vec![p384::ProjectivePoint::generator(); 2];
+        let scalars = vec![p384::Scalar::ONE; 3];
+        multiscalar_mul(&points, &scalars);
+    }
+
+    #[test]
+    fn test_window() {
+        let s = p384::Scalar::from_u64(0x1234);
+        let bytes = to_le_bytes(&s);
+        assert_eq!(bytes[..3], [0x34, 0x12, 0x00]);
+        assert_eq!(window(&bytes, 0, 4), 0x4);
+        assert_eq!(window(&bytes, 4, 8), 0x23);
+        assert_eq!(window(&bytes, 12, 3), 0x1);
+        assert_eq!(window(&bytes, 380, 8), 0x0);
+    }
+
+    fn verification_matrix(
+        threshold: u8,
+    ) -> (VerificationMatrix<p384::ProjectivePoint>, p384::Scalar) {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let bp = BivariatePolynomial::random(threshold, 2 * threshold, &mut rng);
+        let vm = VerificationMatrix::from(&bp);
+        (vm, p384::Scalar::random(&mut rng))
+    }
+
+    #[bench]
+    fn bench_verify_x_t16_naive(b: &mut Bencher) {
+        let (vm, x) = verification_matrix(16);
+        let xpows = crate::poly::powers(&x, 16);
+        let (rows, cols) = vm.dimensions();
+
+        b.iter(|| {
+            for j in 0..cols {
+                let column: Vec<_> = (0..rows).map(|i| *vm.element(i, j).unwrap()).collect();
+                let _ = naive(&column, &xpows);
+            }
+        });
+    }
+
+    #[bench]
+    fn bench_verify_x_t16_msm(b: &mut Bencher) {
+        let (vm, x) = verification_matrix(16);
+        let xpows = crate::poly::powers(&x, 16);
+        let (rows, cols) = vm.dimensions();
+
+        b.iter(|| {
+            for j in 0..cols {
+                let column: Vec<_> = (0..rows).map(|i| *vm.element(i, j).unwrap()).collect();
+                let _ = multiscalar_mul(&column, &xpows);
+            }
+        });
+    }
+}
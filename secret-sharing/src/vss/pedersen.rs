@@ -0,0 +1,458 @@
+use group::{
+    ff::{Field, PrimeField},
+    Group, GroupEncoding,
+};
+use rand_core::RngCore;
+use subtle::Choice;
+use zeroize::Zeroize;
+
+use crate::poly::{powers, BivariatePolynomial, Polynomial, XofRng};
+
+use super::multiscalar_mul;
+
+/// Domain separation tag for zero-hole proof challenges.
+const ZERO_HOLE_PROOF_DST: &[u8] = b"PedersenMatrix-ZeroHoleProof";
+
+/// Pedersen verification matrix for a bivariate polynomial.
+///
+/// The Pedersen matrix `M` commits to the coefficients of a secret bivariate
+/// polynomial `B(x,y)` using the coefficients of a random blinding bivariate
+/// polynomial `R(x,y)` of the same degrees, and two group generators `G`
+/// and `H` whose discrete logarithm relation is unknown.
+///
+/// Pedersen matrix:
+/// ```text
+///     M = [b_{i,j} * G + r_{i,j} * H]
+/// ```
+///
+/// Unlike the Feldman verification matrix, which reveals `B(0,0) * G`,
+/// the Pedersen matrix is perfectly hiding, i.e. it leaks no information
+/// about the secret even to computationally unbounded observers. On the other
+/// hand, it is only computationally binding, and shareholders need to receive
+/// the blinding polynomial `R(x_i,y)` (or `R(x,x_i)`) together with their
+/// share in order to verify it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PedersenMatrix<G>
+where
+    G: Group,
+{
+    /// The number of rows in the Pedersen matrix, determined by
+    /// the degree of the bivariate polynomial in the `x` variable.
+    rows: usize,
+    /// The number of columns in the Pedersen matrix, determined by
+    /// the degree of the bivariate polynomial in the `y` variable.
+    cols: usize,
+    /// The blinding generator.
+    h: G,
+    /// The matrix elements, where `m[i][j]` represents the element
+    /// `b_{i,j} * G + r_{i,j} * H`.
+    m: Vec<Vec<G>>,
+}
+
+impl<G> PedersenMatrix<G>
+where
+    G: Group,
+{
+    /// Constructs a new Pedersen matrix from the given bivariate polynomial,
+    /// blinding polynomial and blinding generator.
+    ///
+    /// The blinding generator must be chosen so that nobody knows its
+    /// discrete logarithm with respect to the group generator, e.g. by
+    /// hashing to the curve.
+    ///
+    /// Returns `None` if the degrees of the polynomials differ.
+    pub fn new(
+        bp: &BivariatePolynomial<G::Scalar>,
+        rp: &BivariatePolynomial<G::Scalar>,
+        h: G,
+    ) -> Option<Self> {
+        if bp.deg_x != rp.deg_x || bp.deg_y != rp.deg_y {
+            return None;
+        }
+
+        let rows = bp.deg_x + 1;
+        let cols = bp.deg_y + 1;
+        let m =
+            bp.b.iter()
+                .zip(rp.b.iter())
+                .map(|(bi, ri)| {
+                    bi.iter()
+                        .zip(ri.iter())
+                        .map(|(bij, rij)| G::generator() * bij + h * rij)
+                        .collect()
+                })
+                .collect();
+
+        Some(Self { rows, cols, h, m })
+    }
+
+    /// Returns the dimensions (number of rows and columns) of the Pedersen
+    /// matrix.
+    pub fn dimensions(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// Returns the blinding generator.
+    pub fn blinding_generator(&self) -> &G {
+        &self.h
+    }
+
+    /// Returns the element `M_{i,j}`.
+    pub fn element(&self, i: usize, j: usize) -> Option<&G> {
+        self.m.get(i).and_then(|mi| mi.get(j))
+    }
+
+    /// Verifies whether the underlying bivariate polynomials evaluate
+    /// to the given values, i.e., if it holds `B(x,y) == v`
+    /// and `R(x,y) == r`.
+    pub fn verify(&self, x: &G::Scalar, y: &G::Scalar, v: &G::Scalar, r: &G::Scalar) -> bool {
+        let xpows = powers(x, self.rows - 1); // [x^i]
+        let ypows = powers(y, self.cols - 1); // [y^j]
+        let mut points = Vec::with_capacity(self.rows * self.cols);
+        let mut scalars = Vec::with_capacity(self.rows * self.cols);
+        for (i, xpow) in xpows.into_iter().enumerate() {
+            for (j, ypow) in ypows.iter().enumerate() {
+                points.push(self.m[i][j]);
+                scalars.push(xpow * ypow);
+            }
+        }
+
+        let diff = multiscalar_mul(&points, &scalars) - self.commit(v, r);
+
+        diff.is_identity().into()
+    }
+
+    /// Verifies coefficients of the polynomials resulting from the evaluation
+    /// of the bivariate polynomials with respect to the indeterminate x,
+    /// i.e. `B(x,y)` and the blinding polynomial `R(x,y)`.
+    ///
+    /// The polynomials are valid iff for every `j` the following holds:
+    /// ```text
+    /// a_j * G + s_j * H = \sum_{i=0}^{deg_x} x^i * M_{i,j}
+    /// ```
+    ///
+    /// This method is not constant time if the size of the polynomials
+    /// is invalid.
+    pub fn verify_x(
+        &self,
+        x: &G::Scalar,
+        polynomial: &Polynomial<G::Scalar>,
+        blinding: &Polynomial<G::Scalar>,
+    ) -> bool {
+        // Short-circuit on the size of the polynomials, not their contents.
+        if polynomial.size() != self.cols || blinding.size() != self.cols {
+            return false;
+        }
+
+        // Don't short-circuit this loop to avoid revealing which coefficient
+        // failed to verify.
+        let xpows = powers(x, self.rows - 1); // [x^i]
+        let mut verified = Choice::from(1);
+
+        for j in 0..self.cols {
+            let aj = polynomial.coefficient(j).expect("size checked above");
+            let sj = blinding.coefficient(j).expect("size checked above");
+            let column: Vec<_> = self.m.iter().map(|mi| mi[j]).collect();
+            let diff = self.commit(aj, sj) - multiscalar_mul(&column, &xpows);
+
+            verified &= diff.is_identity();
+        }
+
+        verified.into()
+    }
+
+    /// Verifies coefficients of the polynomials resulting from the evaluation
+    /// of the bivariate polynomials with respect to the indeterminate y,
+    /// i.e. `B(x,y)` and the blinding polynomial `R(x,y)`.
+    ///
+    /// The polynomials are valid iff for every `i` the following holds:
+    /// ```text
+    /// a_i * G + s_i * H = \sum_{j=0}^{deg_y} y^j * M_{i,j}
+    /// ```
+    ///
+    /// This method is not constant time if the size of the polynomials
+    /// is invalid.
+    pub fn verify_y(
+        &self,
+        y: &G::Scalar,
+        polynomial: &Polynomial<G::Scalar>,
+        blinding: &Polynomial<G::Scalar>,
+    ) -> bool {
+        // Short-circuit on the size of the polynomials, not their contents.
+        if polynomial.size() != self.rows || blinding.size() != self.rows {
+            return false;
+        }
+
+        // Don't short-circuit this loop to avoid revealing which coefficient
+        // failed to verify.
+        let ypows = powers(y, self.cols - 1); // [y^j]
+        let mut verified = Choice::from(1);
+
+        for i in 0..self.rows {
+            let ai = polynomial.coefficient(i).expect("size checked above");
+            let si = blinding.coefficient(i).expect("size checked above");
+            let diff = self.commit(ai, si) - multiscalar_mul(&self.m[i], &ypows);
+
+            verified &= diff.is_identity();
+        }
+
+        verified.into()
+    }
+
+    /// Computes the Pedersen commitment `v * G + r * H`.
+    fn commit(&self, v: &G::Scalar, r: &G::Scalar) -> G {
+        G::generator() * v + self.h * r
+    }
+}
+
+/// Non-interactive proof that a Pedersen matrix commits to a zero-hole
+/// bivariate polynomial, i.e. that `B(0,0) = 0`.
+///
+/// For a zero-hole polynomial, the element `M_{0,0} = r_{0,0} * H` is
+/// a multiple of the blinding generator only, so the dealer proves knowledge
+/// of its discrete logarithm `r_{0,0}` with respect to `H` using a Schnorr
+/// proof made non-interactive with the Fiat-Shamir transform:
+/// ```text
+///     R = k * H
+///     c = Hash(H || M_{0,0} || R || context)
+///     s = k + c * r_{0,0}
+/// ```
+/// The proof is valid iff `s * H = R + c * M_{0,0}`. Since it binds
+/// the matrix and the given context, e.g. the handoff epoch, an invalid
+/// proactivization dealing can be rejected with publishable evidence.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ZeroHoleProof<G>
+where
+    G: Group,
+{
+    /// The commitment to the nonce `R = k * H`.
+    r: G,
+    /// The response `s = k + c * r_{0,0}`.
+    s: G::Scalar,
+}
+
+impl<G> ZeroHoleProof<G>
+where
+    G: Group + GroupEncoding,
+{
+    /// Proves that the given Pedersen matrix, computed using the given
+    /// blinding polynomial, commits to a zero-hole bivariate polynomial.
+    ///
+    /// Returns `None` if the matrix doesn't commit to a zero-hole polynomial
+    /// or wasn't computed using the given blinding polynomial.
+    pub fn prove(
+        pm: &PedersenMatrix<G>,
+        rp: &BivariatePolynomial<G::Scalar>,
+        context: &[u8],
+        rng: &mut impl RngCore,
+    ) -> Option<Self>
+    where
+        G::Scalar: Zeroize,
+    {
+        let r00 = rp.constant();
+        if pm.m[0][0] != pm.h * r00 {
+            return None;
+        }
+
+        let mut k = G::Scalar::random(rng);
+        let r = pm.h * k;
+        let c = Self::challenge(pm, &r, context);
+        let s = k + c * r00;
+        k.zeroize();
+
+        Some(Self { r, s })
+    }
+
+    /// Verifies that the given Pedersen matrix commits to a zero-hole
+    /// bivariate polynomial.
+    pub fn verify(&self, pm: &PedersenMatrix<G>, context: &[u8]) -> bool {
+        let c = Self::challenge(pm, &self.r, context);
+        let diff = pm.h * self.s - self.r - pm.m[0][0] * c;
+
+        diff.is_identity().into()
+    }
+
+    /// Returns the byte representation of the proof.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(Self::byte_size());
+        bytes.extend_from_slice(self.r.to_bytes().as_ref());
+        bytes.extend_from_slice(self.s.to_repr().as_ref());
+        bytes
+    }
+
+    /// Attempts to create a proof from its byte representation.
+    ///
+    /// This method is not constant time since the proof doesn't contain
+    /// sensitive information.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != Self::byte_size() {
+            return None;
+        }
+
+        let (r_bytes, s_bytes) = bytes.split_at(Self::group_byte_size());
+        let mut r_repr: G::Repr = Default::default();
+        r_repr.as_mut().copy_from_slice(r_bytes);
+        let mut s_repr = <G::Scalar as PrimeField>::Repr::default();
+        s_repr.as_mut().copy_from_slice(s_bytes);
+
+        let r = Option::from(G::from_bytes(&r_repr))?;
+        let s = Option::from(G::Scalar::from_repr(s_repr))?;
+
+        Some(Self { r, s })
+    }
+
+    /// Returns the size of the byte representation of the proof.
+    pub fn byte_size() -> usize {
+        Self::group_byte_size() + <G::Scalar as PrimeField>::Repr::default().as_ref().len()
+    }
+
+    /// Returns the size of the byte representation of a group element.
+    fn group_byte_size() -> usize {
+        G::Repr::default().as_ref().len()
+    }
+
+    /// Computes the Fiat-Shamir challenge.
+    fn challenge(pm: &PedersenMatrix<G>, r: &G, context: &[u8]) -> G::Scalar {
+        let mut transcript = Vec::new();
+        for p in [&pm.h, &pm.m[0][0], r] {
+            transcript.extend_from_slice(p.to_bytes().as_ref());
+        }
+        transcript.extend_from_slice(context);
+
+        G::Scalar::random(XofRng::new(&transcript, ZERO_HOLE_PROOF_DST))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use group::Group as _;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::poly::BivariatePolynomial;
+
+    use super::{PedersenMatrix, ZeroHoleProof};
+
+    type PrimeField = p384::Scalar;
+    type Group = p384::ProjectivePoint;
+
+    #[test]
+    fn test_new() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let h = Group::random(&mut rng);
+
+        let bp = BivariatePolynomial::<PrimeField>::random(2, 3, &mut rng);
+        let rp = BivariatePolynomial::<PrimeField>::random(2, 3, &mut rng);
+        let pm = PedersenMatrix::new(&bp, &rp, h).unwrap();
+        assert_eq!(pm.dimensions(), (3, 4));
+        assert_eq!(pm.blinding_generator(), &h);
+        assert_eq!(
+            pm.element(1, 2),
+            Some(&(Group::generator() * bp.b[1][2] + h * rp.b[1][2]))
+        );
+        assert_eq!(pm.element(3, 0), None);
+
+        // Degree mismatch.
+        let rp = BivariatePolynomial::<PrimeField>::random(3, 2, &mut rng);
+        assert!(PedersenMatrix::new(&bp, &rp, h).is_none());
+    }
+
+    #[test]
+    fn test_hiding() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let h = Group::random(&mut rng);
+
+        // Without the blinding polynomial, the matrix commits to zero.
+        let bp = BivariatePolynomial::<PrimeField>::random(2, 2, &mut rng);
+        let rp = BivariatePolynomial::<PrimeField>::zero(2, 2);
+        let pm = PedersenMatrix::new(&bp, &rp, h).unwrap();
+        assert_eq!(pm.element(0, 0), Some(&(Group::generator() * bp.b[0][0])));
+
+        // With the blinding polynomial, the secret is hidden.
+        let rp = BivariatePolynomial::<PrimeField>::random(2, 2, &mut rng);
+        let pm = PedersenMatrix::new(&bp, &rp, h).unwrap();
+        assert_ne!(pm.element(0, 0), Some(&(Group::generator() * bp.b[0][0])));
+    }
+
+    #[test]
+    fn test_verify() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let h = Group::random(&mut rng);
+
+        let bp = BivariatePolynomial::<PrimeField>::random(2, 4, &mut rng);
+        let rp = BivariatePolynomial::<PrimeField>::random(2, 4, &mut rng);
+        let pm = PedersenMatrix::new(&bp, &rp, h).unwrap();
+
+        for x in 0..3 {
+            let y = PrimeField::from_u64(x + 7);
+            let x = PrimeField::from_u64(x);
+
+            // Points.
+            let v = bp.eval(&x, &y);
+            let r = rp.eval(&x, &y);
+            assert!(pm.verify(&x, &y, &v, &r));
+            assert!(!pm.verify(&x, &y, &v, &(r + PrimeField::ONE)));
+            assert!(!pm.verify(&x, &y, &(v + PrimeField::ONE), &r));
+
+            // Rows.
+            let p = bp.eval_x(&x);
+            let s = rp.eval_x(&x);
+            assert!(pm.verify_x(&x, &p, &s));
+            assert!(!pm.verify_x(&x, &p, &rp.eval_x(&y)));
+            assert!(!pm.verify_x(&x, &bp.eval_x(&y), &s));
+            assert!(!pm.verify_y(&x, &p, &s));
+
+            // Columns.
+            let p = bp.eval_y(&y);
+            let s = rp.eval_y(&y);
+            assert!(pm.verify_y(&y, &p, &s));
+            assert!(!pm.verify_y(&y, &p, &rp.eval_y(&x)));
+            assert!(!pm.verify_y(&y, &bp.eval_y(&x), &s));
+            assert!(!pm.verify_x(&y, &p, &s));
+        }
+    }
+
+    #[test]
+    fn test_zero_hole_proof() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let h = Group::random(&mut rng);
+
+        let mut bp = BivariatePolynomial::<PrimeField>::random(2, 4, &mut rng);
+        bp.to_zero_hole();
+        let rp = BivariatePolynomial::<PrimeField>::random(2, 4, &mut rng);
+        let pm = PedersenMatrix::new(&bp, &rp, h).unwrap();
+
+        let proof = ZeroHoleProof::prove(&pm, &rp, b"context", &mut rng).unwrap();
+        assert!(proof.verify(&pm, b"context"));
+        assert!(!proof.verify(&pm, b"other context"));
+
+        // Serialization.
+        let bytes = proof.to_bytes();
+        assert_eq!(bytes.len(), ZeroHoleProof::<Group>::byte_size());
+        let restored = ZeroHoleProof::<Group>::from_bytes(&bytes).unwrap();
+        assert_eq!(proof, restored);
+        assert!(ZeroHoleProof::<Group>::from_bytes(&bytes[1..]).is_none());
+
+        // Tampered proof.
+        let mut invalid = proof.clone();
+        invalid.s += PrimeField::ONE;
+        assert!(!invalid.verify(&pm, b"context"));
+
+        // Another matrix.
+        let rp = BivariatePolynomial::<PrimeField>::random(2, 4, &mut rng);
+        let other = PedersenMatrix::new(&bp, &rp, h).unwrap();
+        assert!(!proof.verify(&other, b"context"));
+
+        // Wrong blinding polynomial.
+        assert!(ZeroHoleProof::prove(&pm, &rp, b"context", &mut rng).is_none());
+
+        // Not a zero-hole polynomial.
+        let bp = BivariatePolynomial::<PrimeField>::random(2, 4, &mut rng);
+        let pm = PedersenMatrix::new(&bp, &rp, h).unwrap();
+        assert!(ZeroHoleProof::prove(&pm, &rp, b"context", &mut rng).is_none());
+        let forged = ZeroHoleProof::<Group> {
+            r: proof.r,
+            s: proof.s,
+        };
+        assert!(!forged.verify(&pm, b"context"));
+    }
+}
@@ -0,0 +1,85 @@
+use group::Group;
+use subtle::ConditionallySelectable;
+
+use crate::poly::{BivariatePolynomial, Polynomial};
+
+use super::VerificationMatrix;
+
+/// A scheme for committing to a secret bivariate polynomial, which allows
+/// shareholders to verify their shares against the commitment.
+pub trait CommitmentScheme<G>: Clone
+where
+    G: Group,
+{
+    /// Commits to the given bivariate polynomial.
+    fn commit(bp: &BivariatePolynomial<G::Scalar>) -> Self;
+
+    /// Verifies the polynomial resulting from the evaluation of the committed
+    /// bivariate polynomial with respect to the indeterminate x, i.e. a full
+    /// share `B(x, y)`.
+    fn verify_x(&self, x: &G::Scalar, polynomial: &Polynomial<G::Scalar>) -> bool;
+
+    /// Verifies the polynomial resulting from the evaluation of the committed
+    /// bivariate polynomial with respect to the indeterminate y, i.e.
+    /// a reduced share `B(x, y)`.
+    fn verify_y(&self, y: &G::Scalar, polynomial: &Polynomial<G::Scalar>) -> bool;
+
+    /// Returns true if and only if the committed bivariate polynomial
+    /// is zero-hole.
+    fn is_zero_hole(&self) -> bool;
+}
+
+/// Feldman commitment scheme.
+impl<G> CommitmentScheme<G> for VerificationMatrix<G>
+where
+    G: Group + ConditionallySelectable,
+{
+    fn commit(bp: &BivariatePolynomial<G::Scalar>) -> Self {
+        VerificationMatrix::from(bp)
+    }
+
+    fn verify_x(&self, x: &G::Scalar, polynomial: &Polynomial<G::Scalar>) -> bool {
+        VerificationMatrix::verify_x(self, x, polynomial)
+    }
+
+    fn verify_y(&self, y: &G::Scalar, polynomial: &Polynomial<G::Scalar>) -> bool {
+        VerificationMatrix::verify_y(self, y, polynomial)
+    }
+
+    fn is_zero_hole(&self) -> bool {
+        VerificationMatrix::is_zero_hole(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::poly::BivariatePolynomial;
+
+    use super::{CommitmentScheme, VerificationMatrix};
+
+    type PrimeField = p384::Scalar;
+    type Group = p384::ProjectivePoint;
+
+    fn test_commitment_scheme<C: CommitmentScheme<Group>>() {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let mut bp = BivariatePolynomial::<PrimeField>::random(2, 4, &mut rng);
+        let x = PrimeField::from_u64(5);
+
+        let commitment = C::commit(&bp);
+        assert!(commitment.verify_x(&x, &bp.eval_x(&x)));
+        assert!(commitment.verify_y(&x, &bp.eval_y(&x)));
+        assert!(!commitment.verify_x(&x, &bp.eval_x(&PrimeField::ONE)));
+        assert!(!commitment.verify_y(&x, &bp.eval_y(&PrimeField::ONE)));
+        assert!(!commitment.is_zero_hole());
+
+        bp.to_zero_hole();
+        assert!(C::commit(&bp).is_zero_hole());
+    }
+
+    #[test]
+    fn test_verification_matrix() {
+        test_commitment_scheme::<VerificationMatrix<Group>>();
+    }
+}
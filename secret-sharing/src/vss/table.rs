@@ -0,0 +1,233 @@
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    sync::{Arc, Mutex, OnceLock},
+};
+
+use group::{ff::PrimeField, Group};
+use subtle::{ConditionallySelectable, ConstantTimeEq};
+
+use super::msm::{to_le_bytes, window};
+
+/// The default window size of fixed-base precomputation tables.
+pub const DEFAULT_WINDOW_SIZE: usize = 4;
+
+/// Fixed-base precomputation table.
+///
+/// The table stores the multiples `d * 2^{w*c} * P` of a fixed base point `P`
+/// for every window `w` and every digit `d` of `c` bits, so that the scalar
+/// multiplication `s * P` can be computed using only additions:
+/// ```text
+///     s * P = \sum_w T[w][s_w]
+/// ```
+/// where `s_w` is the `w`-th digit of the scalar `s`.
+///
+/// Building the table is expensive, but it can be shared across all
+/// multiplications with the same base, e.g. when computing the elements
+/// of a verification matrix, which are all multiples of the group generator
+/// (see [`FixedBaseTable::shared_generator`]).
+#[derive(Debug, Clone)]
+pub struct FixedBaseTable<G>
+where
+    G: Group,
+{
+    /// The base point.
+    base: G,
+    /// The window size in bits.
+    window: usize,
+    /// The precomputed multiples, where `table[w][d]` represents
+    /// the element `d * 2^{w*c} * P`.
+    table: Vec<Vec<G>>,
+}
+
+impl<G> FixedBaseTable<G>
+where
+    G: Group + ConditionallySelectable,
+{
+    /// Constructs a new table for the given base point using windows
+    /// of the given size.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the window size is not between 1 and 8 bits.
+    pub fn new(base: G, window: usize) -> Self {
+        assert!((1..=8).contains(&window), "invalid window size");
+
+        let num_windows = (G::Scalar::NUM_BITS as usize + window - 1) / window;
+        let mut table = Vec::with_capacity(num_windows);
+        let mut point = base; // 2^{w*c} * P
+
+        for _ in 0..num_windows {
+            let mut multiples = Vec::with_capacity(1 << window);
+            let mut multiple = G::identity();
+            for _ in 0..1 << window {
+                multiples.push(multiple);
+                multiple += point;
+            }
+            point = multiple; // 2^c * 2^{w*c} * P
+            table.push(multiples);
+        }
+
+        Self {
+            base,
+            window,
+            table,
+        }
+    }
+
+    /// Constructs a new table for the group generator using windows
+    /// of the default size.
+    pub fn generator() -> Self {
+        Self::new(G::generator(), DEFAULT_WINDOW_SIZE)
+    }
+
+    /// Returns the table for the group generator using windows of the default
+    /// size, which is built on first use and shared by all callers.
+    pub fn shared_generator() -> Arc<Self> {
+        static TABLES: OnceLock<Mutex<HashMap<TypeId, Arc<dyn Any + Send + Sync>>>> =
+            OnceLock::new();
+
+        let mut tables = TABLES.get_or_init(Default::default).lock().unwrap();
+        let table = tables
+            .entry(TypeId::of::<G>())
+            .or_insert_with(|| Arc::new(Self::generator()))
+            .clone();
+
+        table
+            .downcast::<Self>()
+            .expect("table should be built for the group")
+    }
+
+    /// Returns the base point.
+    pub fn base(&self) -> &G {
+        &self.base
+    }
+
+    /// Returns the window size in bits.
+    pub fn window_size(&self) -> usize {
+        self.window
+    }
+
+    /// Computes the scalar multiplication `s * P`.
+    ///
+    /// The digits of the scalar are used to select the precomputed multiples
+    /// in constant time, i.e. all entries of a window are always read.
+    pub fn mul(&self, s: &G::Scalar) -> G {
+        let bytes = to_le_bytes(s);
+        let mut acc = G::identity();
+
+        for (w, multiples) in self.table.iter().enumerate() {
+            let digit = window(&bytes, w * self.window, self.window) as u8;
+            let mut selected = G::identity();
+            for (d, multiple) in multiples.iter().enumerate() {
+                selected.conditional_assign(multiple, (d as u8).ct_eq(&digit));
+            }
+            acc += selected;
+        }
+
+        acc
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate test;
+
+    use group::{ff::Field, Group as _};
+    use rand::{rngs::StdRng, SeedableRng};
+    use test::Bencher;
+
+    use crate::{poly::BivariatePolynomial, vss::VerificationMatrix};
+
+    use super::FixedBaseTable;
+
+    fn test_mul<G>()
+    where
+        G: group::Group + subtle::ConditionallySelectable,
+    {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        let base = G::random(&mut rng);
+
+        for window in 1..=8 {
+            let table = FixedBaseTable::new(base, window);
+            assert_eq!(table.base(), &base);
+            assert_eq!(table.window_size(), window);
+
+            let mut scalars: Vec<_> = (0..5).map(|_| G::Scalar::random(&mut rng)).collect();
+            scalars.push(G::Scalar::ZERO);
+            scalars.push(G::Scalar::ONE);
+            scalars.push(-G::Scalar::ONE);
+
+            for s in scalars.iter() {
+                assert_eq!(table.mul(s), base * s);
+            }
+        }
+    }
+
+    #[test]
+    fn test_mul_p384() {
+        // Big-endian scalar representation.
+        test_mul::<p384::ProjectivePoint>();
+    }
+
+    #[cfg(feature = "ristretto255")]
+    #[test]
+    fn test_mul_ristretto255() {
+        // Little-endian scalar representation.
+        test_mul::<curve25519_dalek::RistrettoPoint>();
+    }
+
+    #[test]
+    fn test_shared_generator() {
+        let table = FixedBaseTable::<p384::ProjectivePoint>::shared_generator();
+        assert_eq!(table.base(), &p384::ProjectivePoint::generator());
+        assert!(std::sync::Arc::ptr_eq(
+            &table,
+            &FixedBaseTable::shared_generator()
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid window size")]
+    fn test_invalid_window_size() {
+        FixedBaseTable::new(p384::ProjectivePoint::generator(), 9);
+    }
+
+    fn bivariate_polynomial(threshold: u8) -> BivariatePolynomial<p384::Scalar> {
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+        BivariatePolynomial::random(threshold, 2 * threshold, &mut rng)
+    }
+
+    #[bench]
+    fn bench_verification_matrix_t16(b: &mut Bencher) {
+        let bp = bivariate_polynomial(16);
+        b.iter(|| {
+            let _: VerificationMatrix<p384::ProjectivePoint> = VerificationMatrix::from(&bp);
+        });
+    }
+
+    #[bench]
+    fn bench_verification_matrix_t16_generator_mul(b: &mut Bencher) {
+        let bp = bivariate_polynomial(16);
+        b.iter(|| {
+            let _: Vec<Vec<_>> =
+                bp.b.iter()
+                    .map(|bi| {
+                        bi.iter()
+                            .map(|bij| p384::ProjectivePoint::generator() * bij)
+                            .collect()
+                    })
+                    .collect();
+        });
+    }
+
+    #[bench]
+    fn bench_verification_matrix_t16_table(b: &mut Bencher) {
+        let bp = bivariate_polynomial(16);
+        let table = FixedBaseTable::generator();
+        b.iter(|| {
+            let _: VerificationMatrix<p384::ProjectivePoint> =
+                VerificationMatrix::from_table(&bp, &table);
+        });
+    }
+}
@@ -1,8 +1,11 @@
-use group::Group;
+use group::{ff::Field, Group};
+use rand_core::RngCore;
 use subtle::Choice;
 
 use crate::poly::{powers, Polynomial};
 
+use super::multiscalar_mul;
+
 /// Verification vector for a univariate polynomial.
 ///
 /// The verification vector `V` is computed through scalar multiplication
@@ -37,6 +40,11 @@ where
         Self { v }
     }
 
+    /// Returns the elements of the verification vector.
+    pub fn elements(&self) -> &[G] {
+        &self.v
+    }
+
     /// Verifies if the verification vector belongs to the given univariate
     /// polynomial.
     ///
@@ -63,14 +71,46 @@ where
     /// Verifies if the underlying univariate polynomial evaluates
     /// to the given value, i.e., if it holds `P(x) == v`.
     pub fn verify(&self, x: &G::Scalar, v: &G::Scalar) -> bool {
-        let mut diff = G::generator().neg() * v;
-        let xpows = powers(x, self.v.len() - 1); // [x^i]
-        for (i, xpow) in xpows.into_iter().enumerate() {
-            diff += self.v[i] * xpow; // x^i * V_i = a_i x^i * G
+        let diff = self.eval(x) - G::generator() * v;
+
+        diff.is_identity().into()
+    }
+
+    /// Verifies if the underlying univariate polynomial evaluates
+    /// to the given values, i.e., if it holds `P(x_k) == v_k` for all
+    /// given points.
+    ///
+    /// The equations are folded into a single check using random scalars
+    /// `r_k`:
+    /// ```text
+    /// \sum_k r_k v_k * G = \sum_i (\sum_k r_k x_k^i) * V_i
+    /// ```
+    /// which requires only one multi-scalar multiplication. If any of
+    /// the points is invalid, the check fails with overwhelming probability.
+    pub fn verify_batch(&self, points: &[(G::Scalar, G::Scalar)], rng: &mut impl RngCore) -> bool {
+        let mut scalars = vec![G::Scalar::ZERO; self.v.len()];
+        let mut v = G::Scalar::ZERO;
+
+        for (x, vk) in points {
+            let rk = G::Scalar::random(&mut *rng);
+            let xpows = powers(x, self.v.len() - 1); // [x^i]
+            for (i, xpow) in xpows.iter().enumerate() {
+                scalars[i] += rk * xpow; // r_k x_k^i
+            }
+            v += rk * vk; // r_k v_k
         }
 
+        let diff = multiscalar_mul(&self.v, &scalars) - G::generator() * v;
+
         diff.is_identity().into()
     }
+
+    /// Evaluates the underlying univariate polynomial at the given value
+    /// in the exponent, i.e. returns `P(x) * G`.
+    pub fn eval(&self, x: &G::Scalar) -> G {
+        let xpows = powers(x, self.v.len() - 1); // [x^i]
+        multiscalar_mul(&self.v, &xpows) // x^i * V_i = a_i x^i * G
+    }
 }
 
 impl<G> From<&Polynomial<G::Scalar>> for VerificationVector<G>
@@ -102,6 +142,9 @@ where
 
 #[cfg(test)]
 mod tests {
+    use group::Group;
+    use rand::{rngs::StdRng, SeedableRng};
+
     use crate::{poly::Polynomial, vss::VerificationVector};
 
     fn scalar(value: i64) -> p384::Scalar {
@@ -145,4 +188,37 @@ mod tests {
         assert!(vv.verify(&x2, &s));
         assert!(!vv.verify(&x3, &s));
     }
+
+    #[test]
+    fn test_verify_batch() {
+        let p = Polynomial::<p384::Scalar>::with_coefficients(scalars(&[1, 2, 3]));
+        let vv: VerificationVector<p384::ProjectivePoint> = VerificationVector::from(&p);
+        let mut rng: StdRng = SeedableRng::from_seed([1u8; 32]);
+
+        let mut points: Vec<_> = (1..=10).map(scalar).map(|x| (x, p.eval(&x))).collect();
+
+        // Valid points.
+        assert!(vv.verify_batch(&[], &mut rng));
+        assert!(vv.verify_batch(&points[..1], &mut rng));
+        assert!(vv.verify_batch(&points, &mut rng));
+
+        // One invalid point.
+        points[4].1 += p384::Scalar::ONE;
+        assert!(!vv.verify_batch(&points, &mut rng));
+        assert!(vv.verify_batch(&points[..4], &mut rng));
+
+        // Invalid points which cancel out without randomization.
+        points[5].1 -= p384::Scalar::ONE;
+        assert!(!vv.verify_batch(&points, &mut rng));
+        assert!(!vv.verify_batch(&points[4..6], &mut rng));
+    }
+
+    #[test]
+    fn test_eval() {
+        let p = Polynomial::<p384::Scalar>::with_coefficients(scalars(&[1, 2, 3]));
+        let vv: VerificationVector<p384::ProjectivePoint> = VerificationVector::from(&p);
+        let x = scalar(2);
+
+        assert_eq!(vv.eval(&x), p384::ProjectivePoint::generator() * p.eval(&x));
+    }
 }